@@ -1,34 +1,141 @@
+#[cfg(feature = "capnp")]
 extern crate capnpc;
 
-use std::path::Path;
+use std::env;
+#[allow(unused_imports)]
+use std::fs;
+#[allow(unused_imports)]
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+// Everything below is generated from the schemas, and checked in to `src/`
+#[cfg(feature = "capnp")]
+const CAPNP_OUTPUT: &str = "marketdata_capnp.rs";
+#[cfg(feature = "flatbuffers")]
+const FLATBUFFERS_OUTPUT: &str = "marketdata_generated.rs";
+#[cfg(feature = "sbe")]
+const SBE_SCHEMAS: &[(&str, &str)] = &[
+    ("marketdata.xml", "marketdata_sbe.rs"),
+    // Alternate ways of emulating unions in SBE; see the comments in `marketdata.xml`
+    ("marketdata_templates.xml", "marketdata_sbe_templates.rs"),
+    ("marketdata_groups.xml", "marketdata_sbe_groups.rs"),
+    ("marketdata_chained.xml", "marketdata_sbe_chained.rs"),
+];
+
 fn main() {
-    capnpc::CompilerCommand::new()
-        .src_prefix("")
-        .file("marketdata.capnp")
-        .output_path("src/")
-        .run()
-        .expect("Unable to compile capnpc");
-
-    flatc_rust::run(flatc_rust::Args {
-        inputs: &[Path::new("marketdata.fbs")],
-        out_dir: Path::new("src/"),
-        ..Default::default()
-    })
-    .expect("Unable to compile flatc");
-
-    // There's no Rust-style builder crate for SBE,
-    // so we need to run the command by hand.
-    // TODO: Automatically download the SBE JAR?
-    let _output = Command::new("java")
-        .arg("-Dsbe.output.dir=src")
-        .arg("-Dsbe.xinclude.aware=true")
-        .arg("-Dsbe.target.language=uk.co.real_logic.sbe.generation.rust.Rust")
-        .arg("-Dsbe.target.namespace=marketdata_sbe")
-        .arg("-jar")
-        .arg("sbe-all-1.13.2-all.jar")
-        .arg("marketdata.xml")
+    // Benchmark results record which revision produced them
+    let git_revision = Command::new("git")
+        .args(["rev-parse", "HEAD"])
         .output()
-        .expect("Unable to execute SBE compiler");
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=GIT_REVISION={}", git_revision);
+
+    // By default we regenerate code straight into `src/`. The `check-generated`
+    // and `out-dir-generated` features leave `src/` alone, and generate into
+    // `OUT_DIR` instead.
+    #[allow(unused_variables)] // Avro doesn't need any code generation
+    let out_dir = if cfg!(any(
+        feature = "check-generated",
+        feature = "out-dir-generated"
+    )) {
+        PathBuf::from(env::var("OUT_DIR").unwrap())
+    } else {
+        PathBuf::from("src/")
+    };
+
+    // Each format only needs its generator if the matching feature is enabled
+    #[allow(unused_mut, unused_variables)]
+    let mut outputs: Vec<&str> = Vec::new();
+
+    #[cfg(feature = "capnp")]
+    {
+        capnpc::CompilerCommand::new()
+            .src_prefix("")
+            .file("marketdata.capnp")
+            .output_path(&out_dir)
+            .run()
+            .expect("Unable to compile capnpc");
+        outputs.push(CAPNP_OUTPUT);
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    {
+        flatc_rust::run(flatc_rust::Args {
+            inputs: &[Path::new("marketdata.fbs")],
+            out_dir: &out_dir,
+            ..Default::default()
+        })
+        .expect("Unable to compile flatc");
+        outputs.push(FLATBUFFERS_OUTPUT);
+    }
+
+    // The reference SBE tool needs a JRE, so we generate the codecs with our own
+    // generator instead; see the `sbe_gen` crate.
+    #[cfg(feature = "sbe")]
+    for (schema, output) in SBE_SCHEMAS.iter() {
+        let xml = fs::read_to_string(schema).expect("Unable to read SBE schema");
+        let code = sbe_gen::generate(&xml).expect("Unable to compile SBE schema");
+        // Unformatted code is still valid, so we don't require rustfmt to build
+        let code = sbe_gen::rustfmt(&code).unwrap_or(code);
+
+        // Only write on changes, so we don't force everything to recompile
+        let path = out_dir.join(output);
+        if fs::read_to_string(&path).ok().as_ref() != Some(&code) {
+            fs::write(&path, code).expect("Unable to write SBE codec");
+        }
+        outputs.push(output);
+    }
+
+    #[cfg(feature = "check-generated")]
+    {
+        let drifted: Vec<String> = outputs
+            .into_iter()
+            .filter_map(|output| compare_generated(&out_dir, output))
+            .collect();
+        if !drifted.is_empty() {
+            panic!(
+                "Generated code in src/ doesn't match the schemas.\n\n{}\n\n\
+                 Build without the `check-generated` feature to regenerate it.",
+                drifted.join("\n\n")
+            );
+        }
+    }
+}
+
+/// Compare freshly generated code against the copy checked in to `src/`,
+/// and describe the first difference (if any).
+#[cfg(feature = "check-generated")]
+fn compare_generated(out_dir: &Path, output: &str) -> Option<String> {
+    let generated =
+        fs::read_to_string(out_dir.join(output)).expect("Unable to read generated code");
+    // The checked-in copies are formatted, but not all generators format their output
+    let generated = sbe_gen::rustfmt(&generated).expect("Unable to format generated code");
+    let checked_in = match fs::read_to_string(Path::new("src").join(output)) {
+        Ok(checked_in) => checked_in,
+        Err(_) => return Some(format!("src/{} is missing", output)),
+    };
+    if generated == checked_in {
+        return None;
+    }
+
+    let mut generated_lines = generated.lines();
+    let mut checked_in_lines = checked_in.lines();
+    let mut line = 1;
+    loop {
+        match (generated_lines.next(), checked_in_lines.next()) {
+            (Some(g), Some(c)) if g == c => line += 1,
+            (g, c) => {
+                return Some(format!(
+                    "src/{} differs starting at line {}:\n  generated:  {}\n  checked in: {}",
+                    output,
+                    line,
+                    g.unwrap_or("<end of file>"),
+                    c.unwrap_or("<end of file>")
+                ))
+            }
+        }
+    }
 }
@@ -2,34 +2,34 @@
 DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" >/dev/null 2>&1 && pwd )"
 
 function run_shootout() {
-    RUN_PREFIX="$1"
+    RUN_OPTIONS="$1"
     OUTPUT_NAME="$2"
 
     for f in data_feeds*.pcap; do
         echo "$f" >> "$OUTPUT_NAME"
-        echo $RUN_PREFIX ./target/release/md_shootout -f "$f"
-        sudo $RUN_PREFIX ./target/release/md_shootout -f "$f" >> "$OUTPUT_NAME"
+        echo ./target/release/md_shootout -f "$f" $RUN_OPTIONS
+        sudo ./target/release/md_shootout -f "$f" $RUN_OPTIONS >> "$OUTPUT_NAME"
     done
 }
 
-PREFIXES=(
+OPTIONS=(
     "|shootout_normal.txt" # No CPU Pinning
-    "taskset 2|shootout_taskset.txt" # Pin to CPU 1
-    "taskset 2 nice -n-19|shootout_nice.txt" # Pin to CPU 2 with highest priority
+    "--cpu 1|shootout_taskset.txt" # Pin to CPU 1
+    "--cpu 1 --nice -19|shootout_nice.txt" # Pin to CPU 1 with highest priority
     # Kinda dangerous, caused the processor to lock when running in graphical session,
     # but seemed OK in runlevel 3 until the kernel switched time sources
-    #"taskset 2 chrt -f 99|shootout_chrt.txt" # Pin to CPU 3 with real-time priority
+    #"--cpu 1 --sched-fifo 99 --lock-memory|shootout_chrt.txt" # Pin to CPU 1 with real-time priority
 )
 RUN_COUNT=10
 
-for prefix in "${PREFIXES[@]}"; do
+for options in "${OPTIONS[@]}"; do
     (
-        RUN_PREFIX="$(echo "$prefix" | cut -d'|' -f1)"
-        OUTPUT_NAME="$(echo "$prefix" | cut -d'|' -f2)"
+        RUN_OPTIONS="$(echo "$options" | cut -d'|' -f1)"
+        OUTPUT_NAME="$(echo "$options" | cut -d'|' -f2)"
 
         rm "$OUTPUT_NAME"
         for i in $(seq 1 $RUN_COUNT); do
-            run_shootout "$RUN_PREFIX" "$OUTPUT_NAME"
+            run_shootout "$RUN_OPTIONS" "$OUTPUT_NAME"
         done
     )
     wait
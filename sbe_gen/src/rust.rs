@@ -0,0 +1,1041 @@
+//! Rust codec generation, modeled on the output of the reference SBE tool's Rust
+//! generator (sbe-all 1.13.2): every stage of encoding or decoding a message gets
+//! its own type, so the compiler makes sure fields are visited in wire order.
+
+use std::fmt::Write;
+
+use crate::schema::{Composite, Data, Message, Primitive, Schema, Type, TypeDef};
+use crate::Error;
+
+/// Error handling and scratch buffer helpers shared by all codecs
+const PRELUDE: &str = include_str!("../templates/prelude.rs");
+
+pub fn generate(schema: &Schema) -> Result<String, Error> {
+    let header = MessageHeader::new(schema)?;
+
+    let mut out = String::new();
+    writeln!(out, "// Generated code for SBE package {}", schema.package).unwrap();
+    out.push_str(PRELUDE);
+    out.push('\n');
+
+    let types = used_types(schema)?;
+    for t in types.iter() {
+        if let TypeDef::Enum(e) = t {
+            let repr = e.encoding.rust_type();
+            writeln!(out, "/// Enum {}", e.name).unwrap();
+            out.push_str(
+                "#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]\n",
+            );
+            writeln!(out, "#[repr({})]", repr).unwrap();
+            writeln!(out, "pub enum {} {{", e.name).unwrap();
+            for (name, value) in e.values.iter() {
+                writeln!(out, "    {} = {}{},", name, value, repr).unwrap();
+            }
+            // Checked when the schema was parsed
+            let null = e.encoding.enum_null_value().unwrap();
+            out.push_str("    #[default]\n");
+            writeln!(out, "    NullVal = {}{},", null, repr).unwrap();
+            out.push_str("}\n\n");
+        }
+    }
+    for t in types.iter() {
+        if let TypeDef::Composite(c) = t {
+            let name = type_name(&c.name);
+            writeln!(out, "/// {}", name).unwrap();
+            write_struct(&mut out, schema, &name, &c.members)?;
+            out.push('\n');
+        }
+    }
+
+    writeln!(out, "/// {} Decoder entry point", header.name).unwrap();
+    writeln!(
+        out,
+        "pub fn start_decoding_{}<'d>(data: &'d [u8]) -> CodecResult<(&'d {}, ScratchDecoderData<'d>)> {{",
+        field_name(&header.composite.name),
+        header.name
+    )
+    .unwrap();
+    out.push_str("    let mut scratch = ScratchDecoderData { data, pos: 0 };\n");
+    writeln!(
+        out,
+        "    let v = scratch.read_type::<{}>({})?;",
+        header.name, header.size
+    )
+    .unwrap();
+    out.push_str("    Ok((v, scratch))\n}\n");
+
+    for message in schema.messages.iter() {
+        out.push('\n');
+        MessageCodec::new(schema, &header, message)?.write(&mut out)?;
+    }
+
+    Ok(out)
+}
+
+/// The composite at the start of every message, which holds the schema and
+/// template IDs that tell readers what comes next.
+struct MessageHeader<'s> {
+    composite: &'s Composite,
+    name: String,
+    size: usize,
+}
+
+impl<'s> MessageHeader<'s> {
+    fn new(schema: &'s Schema) -> Result<Self, Error> {
+        let composite = schema.find_composite(&schema.header_type)?;
+        let expected = ["blockLength", "templateId", "schemaId", "version"];
+        let names: Vec<&str> = composite.members.iter().map(|(n, _)| n.as_str()).collect();
+        if names != expected {
+            return Err(Error::Schema(format!(
+                "`{}` must contain exactly {:?}",
+                composite.name, expected
+            )));
+        }
+        for (_, ty) in composite.members.iter() {
+            primitive_of(ty, &composite.name)?;
+        }
+
+        Ok(MessageHeader {
+            composite,
+            name: type_name(&composite.name),
+            size: schema.size_of(&Type::Named(composite.name.clone()))?,
+        })
+    }
+}
+
+/// Layout of the `groupSizeEncoding` (or equivalent) composite at the start of a group
+struct GroupDimension {
+    name: String,
+    size: usize,
+    block_length: Primitive,
+    num_in_group: Primitive,
+    num_in_group_field: String,
+}
+
+impl GroupDimension {
+    fn new(schema: &Schema, type_name_: &str) -> Result<Self, Error> {
+        let composite = schema.find_composite(type_name_)?;
+        match &composite.members[..] {
+            [(block_length_name, block_length), (num_in_group_name, num_in_group)]
+                if block_length_name == "blockLength" && num_in_group_name == "numInGroup" =>
+            {
+                Ok(GroupDimension {
+                    name: type_name(&composite.name),
+                    size: schema.size_of(&Type::Named(composite.name.clone()))?,
+                    block_length: primitive_of(block_length, &composite.name)?,
+                    num_in_group: primitive_of(num_in_group, &composite.name)?,
+                    num_in_group_field: field_name(num_in_group_name),
+                })
+            }
+            _ => Err(Error::Schema(format!(
+                "`{}` must contain exactly `blockLength` and `numInGroup`",
+                composite.name
+            ))),
+        }
+    }
+}
+
+/// Layout of the `varDataEncoding` (or equivalent) composite for variable-length data
+struct VarData {
+    name: String,
+    field: String,
+    length: Primitive,
+    max_length: &'static str,
+    element: Primitive,
+}
+
+impl VarData {
+    fn new(schema: &Schema, data: &Data) -> Result<Self, Error> {
+        let composite = schema.find_composite(&data.type_name)?;
+        let (length, element) = match &composite.members[..] {
+            [(length_name, length), (var_data_name, var_data)]
+                if length_name == "length" && var_data_name == "varData" =>
+            {
+                (
+                    primitive_of(length, &composite.name)?,
+                    primitive_of(var_data, &composite.name)?,
+                )
+            }
+            _ => {
+                return Err(Error::Schema(format!(
+                    "`{}` must contain exactly `length` and `varData`",
+                    composite.name
+                )))
+            }
+        };
+        let max_length = length.max_length().ok_or_else(|| {
+            Error::Schema(format!("`{}` length must be unsigned", composite.name))
+        })?;
+
+        Ok(VarData {
+            name: data.name.clone(),
+            field: field_name(&data.name),
+            length,
+            max_length,
+            element,
+        })
+    }
+}
+
+struct GroupCodec {
+    name: String,
+    field: String,
+    block_length: usize,
+    dimension: GroupDimension,
+    data: Vec<VarData>,
+}
+
+struct MessageCodec<'a> {
+    schema: &'a Schema,
+    header: &'a MessageHeader<'a>,
+    message: &'a Message,
+    name: String,
+    block_length: usize,
+    groups: Vec<GroupCodec>,
+    data: Vec<VarData>,
+}
+
+/// Which side of the codec we're generating
+#[derive(Clone, Copy, PartialEq)]
+enum Side {
+    Decoder,
+    Encoder,
+}
+
+impl Side {
+    fn suffix(self) -> &'static str {
+        match self {
+            Side::Decoder => "Decoder",
+            Side::Encoder => "Encoder",
+        }
+    }
+
+    fn scratch(self) -> &'static str {
+        match self {
+            Side::Decoder => "ScratchDecoderData",
+            Side::Encoder => "ScratchEncoderData",
+        }
+    }
+}
+
+impl<'a> MessageCodec<'a> {
+    fn new(
+        schema: &'a Schema,
+        header: &'a MessageHeader<'a>,
+        message: &'a Message,
+    ) -> Result<Self, Error> {
+        let mut groups = Vec::new();
+        for group in message.groups.iter() {
+            groups.push(GroupCodec {
+                name: type_name(&group.name),
+                field: field_name(&group.name),
+                block_length: block_length(schema, &group.fields)?,
+                dimension: GroupDimension::new(schema, &group.dimension_type)?,
+                data: var_data(schema, &group.data)?,
+            });
+        }
+
+        Ok(MessageCodec {
+            schema,
+            header,
+            message,
+            name: type_name(&message.name),
+            block_length: block_length(schema, &message.fields)?,
+            groups,
+            data: var_data(schema, &message.data)?,
+        })
+    }
+
+    /// Type that takes over once the fixed fields (`index == 0`), or the group
+    /// at `index - 1`, are finished.
+    fn next_after(&self, index: usize, side: Side) -> String {
+        if let Some(group) = self.groups.get(index) {
+            format!("{}{}Header{}", self.name, group.name, side.suffix())
+        } else if let Some(data) = self.data.first() {
+            format!("{}{}{}", self.name, type_name(&data.name), side.suffix())
+        } else {
+            format!("{}{}Done", self.name, side.suffix())
+        }
+    }
+
+    fn write(&self, out: &mut String) -> Result<(), Error> {
+        let name = &self.name;
+        writeln!(
+            out,
+            "/// {} Fixed-size Fields ({} bytes)",
+            name, self.block_length
+        )
+        .unwrap();
+        write_struct(
+            out,
+            self.schema,
+            &format!("{}Fields", name),
+            &self.message.fields,
+        )?;
+        out.push('\n');
+        self.write_message_header(out);
+
+        out.push_str("// Group fixed-field member representations\n");
+        for (group, codec) in self.message.groups.iter().zip(self.groups.iter()) {
+            write_struct(
+                out,
+                self.schema,
+                &format!("{}{}Member", name, codec.name),
+                &group.fields,
+            )?;
+        }
+
+        for side in [Side::Decoder, Side::Encoder].iter().cloned() {
+            out.push('\n');
+            self.write_done(out, side);
+            for (index, data) in self.data.iter().enumerate().rev() {
+                self.write_message_data(out, side, index, data);
+            }
+            for (index, group) in self.groups.iter().enumerate().rev() {
+                for (data_index, data) in group.data.iter().enumerate().rev() {
+                    self.write_group_data(out, side, index, group, data_index, data);
+                }
+                self.write_group(out, side, index, group);
+            }
+            self.write_fields(out, side);
+            self.write_header(out, side);
+            self.write_entry_point(out, side);
+        }
+        Ok(())
+    }
+
+    fn write_message_header(&self, out: &mut String) {
+        let name = &self.name;
+        let header = &self.header.name;
+        let types: Vec<&str> = self
+            .header
+            .composite
+            .members
+            .iter()
+            // Checked in `MessageHeader::new`
+            .map(|(_, ty)| primitive_of(ty, "").unwrap().rust_type())
+            .collect();
+        let values = [
+            self.block_length.to_string(),
+            self.message.id.to_string(),
+            self.schema.id.to_string(),
+            self.schema.version.to_string(),
+        ];
+        let consts = ["BLOCK_LENGTH", "TEMPLATE_ID", "SCHEMA_ID", "VERSION"];
+
+        writeln!(out, "/// {} specific Message Header", name).unwrap();
+        out.push_str("#[repr(C, packed)]\n");
+        writeln!(out, "pub struct {}{} {{", name, header).unwrap();
+        writeln!(
+            out,
+            "    pub {}: {},",
+            field_name(&self.header.composite.name),
+            header
+        )
+        .unwrap();
+        out.push_str("}\n");
+        writeln!(out, "impl {}{} {{", name, header).unwrap();
+        for i in 0..consts.len() {
+            writeln!(
+                out,
+                "    pub const {}: {} = {};",
+                consts[i], types[i], values[i]
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+        writeln!(out, "impl Default for {}{} {{", name, header).unwrap();
+        writeln!(out, "    fn default() -> {}{} {{", name, header).unwrap();
+        writeln!(out, "        {}{} {{", name, header).unwrap();
+        writeln!(
+            out,
+            "            {}: {} {{",
+            field_name(&self.header.composite.name),
+            header
+        )
+        .unwrap();
+        for (i, (member, _)) in self.header.composite.members.iter().enumerate() {
+            writeln!(
+                out,
+                "                {}: {}{},",
+                field_name(member),
+                values[i],
+                types[i]
+            )
+            .unwrap();
+        }
+        out.push_str("            },\n        }\n    }\n}\n\n");
+    }
+
+    fn write_done(&self, out: &mut String, side: Side) {
+        let done = format!("{}{}Done", self.name, side.suffix());
+        let verb = match side {
+            Side::Decoder => "decoded",
+            Side::Encoder => "encoded",
+        };
+        writeln!(out, "/// {}", done).unwrap();
+        writeln!(out, "pub struct {}<'d> {{", done).unwrap();
+        writeln!(out, "    scratch: {}<'d>,", side.scratch()).unwrap();
+        out.push_str("}\n");
+        writeln!(out, "impl<'d> {}<'d> {{", done).unwrap();
+        writeln!(out, "    /// Returns the number of bytes {}", verb).unwrap();
+        out.push_str("    pub fn unwrap(self) -> usize {\n        self.scratch.pos\n    }\n\n");
+        writeln!(
+            out,
+            "    pub fn wrap(scratch: {}<'d>) -> {}<'d> {{",
+            side.scratch(),
+            done
+        )
+        .unwrap();
+        writeln!(out, "        {} {{ scratch }}", done).unwrap();
+        out.push_str("    }\n}\n\n");
+    }
+
+    /// Variable-length data at the end of the message, after all groups
+    fn write_message_data(&self, out: &mut String, side: Side, index: usize, data: &VarData) {
+        let codec = format!("{}{}{}", self.name, type_name(&data.name), side.suffix());
+        let next = match self.data.get(index + 1) {
+            Some(next) => format!("{}{}{}", self.name, type_name(&next.name), side.suffix()),
+            None => format!("{}{}Done", self.name, side.suffix()),
+        };
+
+        writeln!(out, "/// {} variable-length data", data.name).unwrap();
+        writeln!(out, "pub struct {}<'d> {{", codec).unwrap();
+        writeln!(out, "    scratch: {}<'d>,", side.scratch()).unwrap();
+        out.push_str("}\n");
+        writeln!(out, "impl<'d> {}<'d> {{", codec).unwrap();
+        writeln!(
+            out,
+            "    fn wrap(scratch: {}<'d>) -> Self {{",
+            side.scratch()
+        )
+        .unwrap();
+        writeln!(out, "        {} {{ scratch }}", codec).unwrap();
+        out.push_str("    }\n");
+        let next_type = format!("{}<'d>", next);
+        let next_value = format!("{}::wrap(self.scratch)", next);
+        write_data_fn(out, side, data, "self.scratch", &next_type, &next_value);
+        out.push_str("}\n\n");
+    }
+
+    /// Variable-length data at the end of each group member
+    fn write_group_data(
+        &self,
+        out: &mut String,
+        side: Side,
+        group_index: usize,
+        group: &GroupCodec,
+        index: usize,
+        data: &VarData,
+    ) {
+        let prefix = format!("{}{}", self.name, group.name);
+        let codec = format!("{}{}{}", prefix, type_name(&data.name), side.suffix());
+        let member = format!("{}Member{}", prefix, side.suffix());
+        let (next_type, next_value) = match (group.data.get(index + 1), side) {
+            (Some(next), _) => {
+                let next = format!("{}{}{}", prefix, type_name(&next.name), side.suffix());
+                (
+                    format!("{}<'d>", next),
+                    format!("{}::wrap(self.parent)", next),
+                )
+            }
+            (None, Side::Decoder) => (
+                format!(
+                    "Either<{}<'d>, {}<'d>>",
+                    member,
+                    self.next_after(group_index + 1, side)
+                ),
+                "self.parent.after_member()".to_owned(),
+            ),
+            (None, Side::Encoder) => (format!("{}<'d>", member), "self.parent".to_owned()),
+        };
+
+        writeln!(out, "/// {} variable-length data", data.name).unwrap();
+        writeln!(out, "pub struct {}<'d> {{", codec).unwrap();
+        writeln!(out, "    parent: {}<'d>,", member).unwrap();
+        out.push_str("}\n");
+        writeln!(out, "impl<'d> {}<'d> {{", codec).unwrap();
+        writeln!(out, "    fn wrap(parent: {}<'d>) -> Self {{", member).unwrap();
+        writeln!(out, "        {} {{ parent }}", codec).unwrap();
+        out.push_str("    }\n");
+        write_data_fn(
+            out,
+            side,
+            data,
+            "self.parent.scratch",
+            &next_type,
+            &next_value,
+        );
+        out.push_str("}\n\n");
+    }
+
+    fn write_group(&self, out: &mut String, side: Side, index: usize, group: &GroupCodec) {
+        let prefix = format!("{}{}", self.name, group.name);
+        let member_codec = format!("{}Member{}", prefix, side.suffix());
+        let header_codec = format!("{}Header{}", prefix, side.suffix());
+        let member = format!("{}Member", prefix);
+        let exit = self.next_after(index + 1, side);
+        let count = group.dimension.num_in_group;
+        let count_type = count.rust_type();
+        let scratch = side.scratch();
+
+        writeln!(
+            out,
+            "/// {} {} for fields and header",
+            prefix,
+            side.suffix()
+        )
+        .unwrap();
+        writeln!(out, "pub struct {}<'d> {{", member_codec).unwrap();
+        writeln!(out, "    scratch: {}<'d>,", scratch).unwrap();
+        match side {
+            Side::Decoder => {
+                writeln!(out, "    max_index: {},", count_type).unwrap();
+                writeln!(out, "    index: {},", count_type).unwrap();
+            }
+            Side::Encoder => {
+                out.push_str("    count_write_pos: usize,\n");
+                writeln!(out, "    count: {},", count_type).unwrap();
+            }
+        }
+        out.push_str("}\n\n");
+        writeln!(out, "impl<'d> {}<'d> {{", member_codec).unwrap();
+
+        let first_data = group
+            .data
+            .first()
+            .map(|d| format!("{}{}{}", prefix, type_name(&d.name), side.suffix()));
+        match side {
+            Side::Decoder => {
+                writeln!(
+                    out,
+                    "    fn new(scratch: {}<'d>, count: {}) -> Self {{",
+                    scratch, count_type
+                )
+                .unwrap();
+                writeln!(out, "        assert!(count > 0{});", count_type).unwrap();
+                writeln!(out, "        {} {{", member_codec).unwrap();
+                out.push_str("            scratch,\n");
+                out.push_str("            max_index: count - 1,\n");
+                out.push_str("            index: 0,\n");
+                out.push_str("        }\n    }\n\n");
+
+                let either = format!("Either<{}<'d>, {}<'d>>", member_codec, exit);
+                let (next_type, next_value) = match &first_data {
+                    Some(data) => (format!("{}<'d>", data), format!("{}::wrap(self)", data)),
+                    None => (either.clone(), "self.after_member()".to_owned()),
+                };
+                writeln!(
+                    out,
+                    "    pub fn next_{}_member(mut self) -> CodecResult<(&'d {}, {})> {{",
+                    group.field, member, next_type
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        let v = self.scratch.read_type::<{}>({})?;",
+                    member, group.block_length
+                )
+                .unwrap();
+                out.push_str("        self.index += 1;\n");
+                writeln!(out, "        Ok((v, {}))", next_value).unwrap();
+                out.push_str("    }\n");
+                out.push_str("    #[inline]\n");
+                writeln!(out, "    fn after_member(self) -> {} {{", either).unwrap();
+                out.push_str("        if self.index <= self.max_index {\n");
+                out.push_str("            Either::Left(self)\n");
+                out.push_str("        } else {\n");
+                writeln!(
+                    out,
+                    "            Either::Right({}::wrap(self.scratch))",
+                    exit
+                )
+                .unwrap();
+                out.push_str("        }\n    }\n}\n");
+            }
+            Side::Encoder => {
+                out.push_str("    #[inline]\n");
+                writeln!(
+                    out,
+                    "    fn new(scratch: {}<'d>, count_write_pos: usize) -> Self {{",
+                    scratch
+                )
+                .unwrap();
+                writeln!(out, "        {} {{", member_codec).unwrap();
+                out.push_str("            scratch,\n");
+                out.push_str("            count_write_pos,\n");
+                out.push_str("            count: 0,\n");
+                out.push_str("        }\n    }\n\n");
+
+                let (next_type, next_value) = match &first_data {
+                    Some(data) => (format!("{}<'d>", data), format!("{}::wrap(self)", data)),
+                    None => (format!("{}<'d>", member_codec), "self".to_owned()),
+                };
+                out.push_str("    #[inline]\n");
+                writeln!(
+                    out,
+                    "    pub fn next_{}_member(mut self, fields: &{}) -> CodecResult<{}> {{",
+                    group.field, member, next_type
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        self.scratch.write_type::<{}>(fields, {})?; // block length",
+                    member, group.block_length
+                )
+                .unwrap();
+                out.push_str("        self.count += 1;\n");
+                writeln!(out, "        Ok({})", next_value).unwrap();
+                out.push_str("    }\n");
+                out.push_str("    #[inline]\n");
+                writeln!(
+                    out,
+                    "    pub fn done_with_{}(mut self) -> CodecResult<{}<'d>> {{",
+                    group.field, exit
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        self.scratch.write_at_position::<{}>(self.count_write_pos, &self.count, {})?;",
+                    count_type,
+                    count.size()
+                )
+                .unwrap();
+                writeln!(out, "        Ok({}::wrap(self.scratch))", exit).unwrap();
+                out.push_str("    }\n}\n");
+            }
+        }
+
+        writeln!(out, "pub struct {}<'d> {{", header_codec).unwrap();
+        writeln!(out, "    scratch: {}<'d>,", scratch).unwrap();
+        out.push_str("}\n");
+        writeln!(out, "impl<'d> {}<'d> {{", header_codec).unwrap();
+        if side == Side::Encoder {
+            out.push_str("    #[inline]\n");
+        }
+        writeln!(out, "    fn wrap(scratch: {}<'d>) -> Self {{", scratch).unwrap();
+        writeln!(out, "        {} {{ scratch }}", header_codec).unwrap();
+        out.push_str("    }\n");
+        match side {
+            Side::Decoder => {
+                writeln!(
+                    out,
+                    "    pub fn {}_individually(mut self) -> CodecResult<Either<{}<'d>, {}<'d>>> {{",
+                    group.field, member_codec, exit
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        let dim = self.scratch.read_type::<{}>({})?;",
+                    group.dimension.name, group.dimension.size
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        if dim.{} > 0 {{",
+                    group.dimension.num_in_group_field
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "            Ok(Either::Left({}::new(self.scratch, dim.{})))",
+                    member_codec, group.dimension.num_in_group_field
+                )
+                .unwrap();
+                out.push_str("        } else {\n");
+                writeln!(
+                    out,
+                    "            Ok(Either::Right({}::wrap(self.scratch)))",
+                    exit
+                )
+                .unwrap();
+                out.push_str("        }\n    }\n}\n\n");
+            }
+            Side::Encoder => {
+                let block_length = group.dimension.block_length;
+                out.push_str("    #[inline]\n");
+                writeln!(
+                    out,
+                    "    pub fn {}_individually(mut self) -> CodecResult<{}<'d>> {{",
+                    group.field, member_codec
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        self.scratch.write_type::<{}>(&{}{}, {})?; // block length",
+                    block_length.rust_type(),
+                    group.block_length,
+                    block_length.rust_type(),
+                    block_length.size()
+                )
+                .unwrap();
+                out.push_str("        let count_pos = self.scratch.pos;\n");
+                writeln!(
+                    out,
+                    "        self.scratch.write_type::<{}>(&0, {})?; // preliminary group member count",
+                    count_type,
+                    count.size()
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        Ok({}::new(self.scratch, count_pos))",
+                    member_codec
+                )
+                .unwrap();
+                out.push_str("    }\n}\n\n");
+            }
+        }
+    }
+
+    fn write_fields(&self, out: &mut String, side: Side) {
+        let name = &self.name;
+        let codec = format!("{}Fields{}", name, side.suffix());
+        let fields = format!("{}Fields", name);
+        let method = format!("{}_fields", field_name(&self.message.name));
+        let next = self.next_after(0, side);
+
+        writeln!(out, "/// {} Fixed fields {}", name, side.suffix()).unwrap();
+        self.write_stage_start(out, side, &codec);
+        self.write_stage_access(out, side, &method, &fields, self.block_length, &next);
+    }
+
+    fn write_header(&self, out: &mut String, side: Side) {
+        let codec = format!("{}{}{}", self.name, self.header.name, side.suffix());
+        let next = format!("{}Fields{}", self.name, side.suffix());
+
+        writeln!(out, "/// {}", codec).unwrap();
+        self.write_stage_start(out, side, &codec);
+        self.write_stage_access(
+            out,
+            side,
+            "header",
+            &self.header.name,
+            self.header.size,
+            &next,
+        );
+    }
+
+    fn write_stage_start(&self, out: &mut String, side: Side, codec: &str) {
+        writeln!(out, "pub struct {}<'d> {{", codec).unwrap();
+        writeln!(out, "    scratch: {}<'d>,", side.scratch()).unwrap();
+        out.push_str("}\n");
+        writeln!(out, "impl<'d> {}<'d> {{", codec).unwrap();
+        writeln!(
+            out,
+            "    pub fn wrap(scratch: {}<'d>) -> {}<'d> {{",
+            side.scratch(),
+            codec
+        )
+        .unwrap();
+        writeln!(out, "        {} {{ scratch }}", codec).unwrap();
+        out.push_str("    }\n");
+    }
+
+    /// Accessors for a fixed-size block (message header or fields)
+    fn write_stage_access(
+        &self,
+        out: &mut String,
+        side: Side,
+        method: &str,
+        block: &str,
+        size: usize,
+        next: &str,
+    ) {
+        match side {
+            Side::Decoder => {
+                writeln!(
+                    out,
+                    "    pub fn {}(mut self) -> CodecResult<(&'d {}, {}<'d>)> {{",
+                    method, block, next
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        let v = self.scratch.read_type::<{}>({})?;",
+                    block, size
+                )
+                .unwrap();
+                writeln!(out, "        Ok((v, {}::wrap(self.scratch)))", next).unwrap();
+                out.push_str("    }\n}\n\n");
+            }
+            Side::Encoder => {
+                out.push('\n');
+                out.push_str(
+                    "    /// Create a mutable struct reference overlaid atop the data buffer\n",
+                );
+                out.push_str("    /// such that changes to the struct directly edit the buffer.\n");
+                out.push_str(
+                    "    /// Note that the initial content of the struct's fields may be garbage.\n",
+                );
+                writeln!(
+                    out,
+                    "    pub fn {}(mut self) -> CodecResult<(&'d mut {}, {}<'d>)> {{",
+                    method, block, next
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        let v = self.scratch.writable_overlay::<{}>({})?;",
+                    block, size
+                )
+                .unwrap();
+                writeln!(out, "        Ok((v, {}::wrap(self.scratch)))", next).unwrap();
+                out.push_str("    }\n\n");
+                out.push_str("    /// Copy the bytes of a value into the data buffer\n");
+                writeln!(
+                    out,
+                    "    pub fn {}_copy(mut self, t: &{}) -> CodecResult<{}<'d>> {{",
+                    method, block, next
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "        self.scratch.write_type::<{}>(t, {})?;",
+                    block, size
+                )
+                .unwrap();
+                writeln!(out, "        Ok({}::wrap(self.scratch))", next).unwrap();
+                out.push_str("    }\n}\n\n");
+            }
+        }
+    }
+
+    fn write_entry_point(&self, out: &mut String, side: Side) {
+        let codec = format!("{}{}{}", self.name, self.header.name, side.suffix());
+        let (verb, data) = match side {
+            Side::Decoder => ("decoding", "&'d [u8]"),
+            Side::Encoder => ("encoding", "&'d mut [u8]"),
+        };
+
+        writeln!(out, "/// {} {} entry point", self.name, side.suffix()).unwrap();
+        writeln!(
+            out,
+            "pub fn start_{}_{}<'d>(data: {}) -> {}<'d> {{",
+            verb,
+            field_name(&self.message.name),
+            data,
+            codec
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    {}::wrap({} {{ data, pos: 0 }})",
+            codec,
+            side.scratch()
+        )
+        .unwrap();
+        out.push_str("}\n");
+    }
+}
+
+/// Accessor for a single variable-length data field
+fn write_data_fn(
+    out: &mut String,
+    side: Side,
+    data: &VarData,
+    scratch: &str,
+    next_type: &str,
+    next_value: &str,
+) {
+    let length = data.length.rust_type();
+    let element = data.element.rust_type();
+    match side {
+        Side::Decoder => {
+            writeln!(
+                out,
+                "    pub fn {}(mut self) -> CodecResult<(&'d [{}], {})> {{",
+                data.field, element, next_type
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "        let count = {}.read_unaligned::<{}>({})?;",
+                scratch,
+                length,
+                data.length.size()
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "        Ok(({}.read_slice::<{}>(count as usize, {})?, {}))",
+                scratch,
+                element,
+                data.element.size(),
+                next_value
+            )
+            .unwrap();
+        }
+        Side::Encoder => {
+            writeln!(
+                out,
+                "    pub fn {}(mut self, s: &'d [{}]) -> CodecResult<{}> {{",
+                data.field, element, next_type
+            )
+            .unwrap();
+            out.push_str("        let l = s.len();\n");
+            writeln!(out, "        if l > {} {{", data.max_length).unwrap();
+            out.push_str("            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);\n");
+            out.push_str("        }\n");
+            out.push_str("        // Write data length\n");
+            writeln!(
+                out,
+                "        {}.write_type::<{}>(&(l as {}), {})?; // group length",
+                scratch,
+                length,
+                length,
+                data.length.size()
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "        {}.write_slice_without_count::<{}>(s, {})?;",
+                scratch,
+                element,
+                data.element.size()
+            )
+            .unwrap();
+            writeln!(out, "        Ok({})", next_value).unwrap();
+        }
+    }
+    out.push_str("    }\n");
+}
+
+/// Packed struct (and empty impl block) for a composite or block of fields
+fn write_struct(
+    out: &mut String,
+    schema: &Schema,
+    name: &str,
+    members: &[(String, Type)],
+) -> Result<(), Error> {
+    out.push_str("#[repr(C, packed)]\n#[derive(Default)]\n");
+    writeln!(out, "pub struct {} {{", name).unwrap();
+    for (member, ty) in members.iter() {
+        let rust_type = match ty {
+            Type::Primitive(p, length) if *length > 1 => format!("[{}; {}]", p.rust_type(), length),
+            Type::Primitive(p, _) => p.rust_type().to_owned(),
+            Type::Named(name) => type_name(schema.find_type(name)?.name()),
+        };
+        writeln!(out, "    pub {}: {},", field_name(member), rust_type).unwrap();
+    }
+    out.push_str("}\n\n");
+    writeln!(out, "impl {} {{}}", name).unwrap();
+    Ok(())
+}
+
+fn block_length(schema: &Schema, fields: &[(String, Type)]) -> Result<usize, Error> {
+    fields.iter().map(|(_, ty)| schema.size_of(ty)).sum()
+}
+
+fn var_data(schema: &Schema, data: &[Data]) -> Result<Vec<VarData>, Error> {
+    data.iter().map(|d| VarData::new(schema, d)).collect()
+}
+
+fn primitive_of(ty: &Type, composite: &str) -> Result<Primitive, Error> {
+    match ty {
+        Type::Primitive(p, _) => Ok(*p),
+        Type::Named(_) => Err(Error::Schema(format!(
+            "`{}` must only contain primitive types",
+            composite
+        ))),
+    }
+}
+
+/// Enums and composites that are used by at least one message, in the order
+/// they're emitted.
+///
+/// The reference generator collects these into a Java `HashMap` keyed by type name,
+/// so we reproduce its iteration order to keep output identical to the codecs
+/// it generated for us in the past.
+fn used_types(schema: &Schema) -> Result<Vec<&TypeDef>, Error> {
+    let mut used: Vec<&TypeDef> = Vec::new();
+    add_used_type(schema, &schema.header_type, &mut used)?;
+    for message in schema.messages.iter() {
+        add_used_fields(schema, &message.fields, &mut used)?;
+        for group in message.groups.iter() {
+            add_used_type(schema, &group.dimension_type, &mut used)?;
+            add_used_fields(schema, &group.fields, &mut used)?;
+            for data in group.data.iter() {
+                add_used_type(schema, &data.type_name, &mut used)?;
+            }
+        }
+        for data in message.data.iter() {
+            add_used_type(schema, &data.type_name, &mut used)?;
+        }
+    }
+
+    let mut capacity = 16;
+    while used.len() * 4 > capacity * 3 {
+        capacity *= 2;
+    }
+    // `sort_by_key` is stable, so entries in the same bucket stay in insertion order
+    used.sort_by_key(|t| {
+        let hash = java_string_hash(t.name());
+        (hash ^ (hash >> 16)) as usize & (capacity - 1)
+    });
+    Ok(used)
+}
+
+fn add_used_fields<'s>(
+    schema: &'s Schema,
+    fields: &[(String, Type)],
+    used: &mut Vec<&'s TypeDef>,
+) -> Result<(), Error> {
+    for (_, ty) in fields.iter() {
+        if let Type::Named(name) = ty {
+            add_used_type(schema, name, used)?;
+        }
+    }
+    Ok(())
+}
+
+fn add_used_type<'s>(
+    schema: &'s Schema,
+    name: &str,
+    used: &mut Vec<&'s TypeDef>,
+) -> Result<(), Error> {
+    if used.iter().any(|t| t.name() == name) {
+        return Ok(());
+    }
+    let t = schema.find_type(name)?;
+    used.push(t);
+    if let TypeDef::Composite(c) = t {
+        add_used_fields(schema, &c.members, used)?;
+    }
+    Ok(())
+}
+
+/// Java's `String.hashCode()`
+fn java_string_hash(s: &str) -> u32 {
+    s.encode_utf16()
+        .fold(0u32, |h, c| h.wrapping_mul(31).wrapping_add(c as u32))
+}
+
+/// `messageHeader` -> `MessageHeader`, `price_level` -> `PriceLevel`
+fn type_name(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `MultiMessage` -> `multi_message`, `blockLength` -> `block_length`
+fn field_name(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
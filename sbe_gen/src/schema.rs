@@ -0,0 +1,385 @@
+//! Intermediate representation of an SBE message schema.
+//!
+//! Only the parts of the SBE spec that we actually use are supported: composites
+//! of primitives and enums, messages with fixed fields, repeating groups
+//! (not nested) and variable-length data. Anything else is rejected up front,
+//! rather than generating a codec that silently gets the wire format wrong.
+
+use roxmltree::Node;
+
+use crate::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Primitive {
+    Char,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Uint8,
+    Uint16,
+    Uint32,
+    Uint64,
+    Float,
+    Double,
+}
+
+impl Primitive {
+    fn parse(name: &str) -> Option<Primitive> {
+        match name {
+            "char" => Some(Primitive::Char),
+            "int8" => Some(Primitive::Int8),
+            "int16" => Some(Primitive::Int16),
+            "int32" => Some(Primitive::Int32),
+            "int64" => Some(Primitive::Int64),
+            "uint8" => Some(Primitive::Uint8),
+            "uint16" => Some(Primitive::Uint16),
+            "uint32" => Some(Primitive::Uint32),
+            "uint64" => Some(Primitive::Uint64),
+            "float" => Some(Primitive::Float),
+            "double" => Some(Primitive::Double),
+            _ => None,
+        }
+    }
+
+    pub fn rust_type(self) -> &'static str {
+        match self {
+            Primitive::Char => "u8",
+            Primitive::Int8 => "i8",
+            Primitive::Int16 => "i16",
+            Primitive::Int32 => "i32",
+            Primitive::Int64 => "i64",
+            Primitive::Uint8 => "u8",
+            Primitive::Uint16 => "u16",
+            Primitive::Uint32 => "u32",
+            Primitive::Uint64 => "u64",
+            Primitive::Float => "f32",
+            Primitive::Double => "f64",
+        }
+    }
+
+    pub fn size(self) -> usize {
+        match self {
+            Primitive::Char | Primitive::Int8 | Primitive::Uint8 => 1,
+            Primitive::Int16 | Primitive::Uint16 => 2,
+            Primitive::Int32 | Primitive::Uint32 | Primitive::Float => 4,
+            Primitive::Int64 | Primitive::Uint64 | Primitive::Double => 8,
+        }
+    }
+
+    /// Null value used by SBE for this type, if it can be used as an enum encoding
+    pub fn enum_null_value(self) -> Option<&'static str> {
+        match self {
+            Primitive::Int8 => Some("-128"),
+            Primitive::Int16 => Some("-32768"),
+            Primitive::Int32 => Some("-2147483648"),
+            Primitive::Int64 => Some("-9223372036854775808"),
+            Primitive::Uint8 => Some("255"),
+            Primitive::Uint16 => Some("65535"),
+            Primitive::Uint32 => Some("4294967295"),
+            Primitive::Uint64 => Some("18446744073709551615"),
+            Primitive::Char | Primitive::Float | Primitive::Double => None,
+        }
+    }
+
+    /// Largest non-null value, if this type can be used as a length
+    pub fn max_length(self) -> Option<&'static str> {
+        match self {
+            Primitive::Uint8 => Some("254"),
+            Primitive::Uint16 => Some("65534"),
+            Primitive::Uint32 => Some("4294967294"),
+            Primitive::Uint64 => Some("18446744073709551614"),
+            _ => None,
+        }
+    }
+}
+
+/// Type of a composite member or message field
+#[derive(Clone, Debug)]
+pub enum Type {
+    /// A primitive; lengths greater than one are encoded as fixed-size arrays
+    Primitive(Primitive, usize),
+    /// Reference to an enum or composite in the `<types>` block
+    Named(String),
+}
+
+#[derive(Debug)]
+pub struct Enum {
+    pub name: String,
+    pub encoding: Primitive,
+    pub values: Vec<(String, String)>,
+}
+
+#[derive(Debug)]
+pub struct Composite {
+    pub name: String,
+    pub members: Vec<(String, Type)>,
+}
+
+#[derive(Debug)]
+pub enum TypeDef {
+    Enum(Enum),
+    Composite(Composite),
+}
+
+impl TypeDef {
+    pub fn name(&self) -> &str {
+        match self {
+            TypeDef::Enum(e) => &e.name,
+            TypeDef::Composite(c) => &c.name,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Data {
+    pub name: String,
+    pub type_name: String,
+}
+
+#[derive(Debug)]
+pub struct Group {
+    pub name: String,
+    pub dimension_type: String,
+    pub fields: Vec<(String, Type)>,
+    pub data: Vec<Data>,
+}
+
+#[derive(Debug)]
+pub struct Message {
+    pub name: String,
+    pub id: u16,
+    pub fields: Vec<(String, Type)>,
+    pub groups: Vec<Group>,
+    pub data: Vec<Data>,
+}
+
+#[derive(Debug)]
+pub struct Schema {
+    pub package: String,
+    pub id: u16,
+    pub version: u16,
+    pub header_type: String,
+    pub types: Vec<TypeDef>,
+    pub messages: Vec<Message>,
+}
+
+impl Schema {
+    pub fn parse(xml: &str) -> Result<Schema, Error> {
+        let doc = roxmltree::Document::parse(xml).map_err(Error::Xml)?;
+        let root = doc.root_element();
+        if root.tag_name().name() != "messageSchema" {
+            return Err(schema_err("root element must be <messageSchema>"));
+        }
+
+        let mut types = Vec::new();
+        let mut messages = Vec::new();
+        for node in elements(root) {
+            match node.tag_name().name() {
+                "types" => {
+                    for t in elements(node) {
+                        types.push(parse_type(t)?);
+                    }
+                }
+                "message" => messages.push(parse_message(node)?),
+                other => return Err(unsupported(other, root)),
+            }
+        }
+
+        Ok(Schema {
+            package: required(root, "package")?.to_owned(),
+            id: parse_number(root, "id")?,
+            version: root
+                .attribute("version")
+                .map_or(Ok(0), |_| parse_number(root, "version"))?,
+            header_type: root
+                .attribute("headerType")
+                .unwrap_or("messageHeader")
+                .to_owned(),
+            types,
+            messages,
+        })
+    }
+
+    pub fn find_type(&self, name: &str) -> Result<&TypeDef, Error> {
+        self.types
+            .iter()
+            .find(|t| t.name() == name)
+            .ok_or_else(|| schema_err(&format!("unknown type `{}`", name)))
+    }
+
+    pub fn find_composite(&self, name: &str) -> Result<&Composite, Error> {
+        match self.find_type(name)? {
+            TypeDef::Composite(c) => Ok(c),
+            TypeDef::Enum(_) => Err(schema_err(&format!("`{}` must be a composite", name))),
+        }
+    }
+
+    /// Encoded size of a type in bytes
+    pub fn size_of(&self, ty: &Type) -> Result<usize, Error> {
+        match ty {
+            Type::Primitive(p, length) => Ok(p.size() * (*length).max(1)),
+            Type::Named(name) => match self.find_type(name)? {
+                TypeDef::Enum(e) => Ok(e.encoding.size()),
+                TypeDef::Composite(c) => c.members.iter().map(|(_, t)| self.size_of(t)).sum(),
+            },
+        }
+    }
+}
+
+fn parse_type(node: Node) -> Result<TypeDef, Error> {
+    let name = required(node, "name")?.to_owned();
+    match node.tag_name().name() {
+        "enum" => {
+            let encoding = required(node, "encodingType")?;
+            let encoding = Primitive::parse(encoding)
+                .filter(|p| p.enum_null_value().is_some())
+                .ok_or_else(|| {
+                    schema_err(&format!(
+                        "unsupported encoding `{}` for enum `{}`",
+                        encoding, name
+                    ))
+                })?;
+            let mut values = Vec::new();
+            for v in elements(node) {
+                if v.tag_name().name() != "validValue" {
+                    return Err(unsupported(v.tag_name().name(), node));
+                }
+                let value = v.text().unwrap_or("").trim().to_owned();
+                values.push((required(v, "name")?.to_owned(), value));
+            }
+            Ok(TypeDef::Enum(Enum {
+                name,
+                encoding,
+                values,
+            }))
+        }
+        "composite" => {
+            let mut members = Vec::new();
+            for m in elements(node) {
+                let ty = match m.tag_name().name() {
+                    "type" => parse_primitive(m)?,
+                    "ref" => Type::Named(required(m, "type")?.to_owned()),
+                    other => return Err(unsupported(other, node)),
+                };
+                members.push((required(m, "name")?.to_owned(), ty));
+            }
+            Ok(TypeDef::Composite(Composite { name, members }))
+        }
+        other => Err(unsupported(other, node)),
+    }
+}
+
+fn parse_primitive(node: Node) -> Result<Type, Error> {
+    let primitive = required(node, "primitiveType")?;
+    let primitive = Primitive::parse(primitive)
+        .ok_or_else(|| schema_err(&format!("unknown primitive type `{}`", primitive)))?;
+    let length = match node.attribute("length") {
+        Some(_) => parse_number(node, "length")?,
+        None => 1,
+    };
+    Ok(Type::Primitive(primitive, length))
+}
+
+fn parse_message(node: Node) -> Result<Message, Error> {
+    let name = required(node, "name")?.to_owned();
+    let (fields, groups, data) = parse_block(node, true)?;
+    Ok(Message {
+        name,
+        id: parse_number(node, "id")?,
+        fields,
+        groups,
+        data,
+    })
+}
+
+fn parse_group(node: Node) -> Result<Group, Error> {
+    let (fields, _, data) = parse_block(node, false)?;
+    Ok(Group {
+        name: required(node, "name")?.to_owned(),
+        dimension_type: node
+            .attribute("dimensionType")
+            .unwrap_or("groupSizeEncoding")
+            .to_owned(),
+        fields,
+        data,
+    })
+}
+
+type Block = (Vec<(String, Type)>, Vec<Group>, Vec<Data>);
+
+/// Parse the body of a message or group; SBE requires fields to come first,
+/// then groups, then variable-length data.
+fn parse_block(node: Node, allow_groups: bool) -> Result<Block, Error> {
+    let (mut fields, mut groups, mut data) = (Vec::new(), Vec::new(), Vec::new());
+    for child in elements(node) {
+        if child.attribute("offset").is_some() || child.attribute("blockLength").is_some() {
+            return Err(unsupported("explicit offsets", child));
+        }
+        match child.tag_name().name() {
+            "field" if groups.is_empty() && data.is_empty() => {
+                if child.attribute("presence") == Some("constant") {
+                    return Err(unsupported("constant fields", child));
+                }
+                let type_name = required(child, "type")?;
+                let ty = match Primitive::parse(type_name) {
+                    Some(p) => Type::Primitive(p, 1),
+                    None => Type::Named(type_name.to_owned()),
+                };
+                fields.push((required(child, "name")?.to_owned(), ty));
+            }
+            "group" if allow_groups && data.is_empty() => groups.push(parse_group(child)?),
+            "data" => data.push(Data {
+                name: required(child, "name")?.to_owned(),
+                type_name: required(child, "type")?.to_owned(),
+            }),
+            "group" if !allow_groups => return Err(unsupported("nested groups", child)),
+            other => {
+                return Err(schema_err(&format!(
+                    "unexpected <{}> in `{}`; fields, groups and data must come in that order",
+                    other,
+                    node.attribute("name").unwrap_or("")
+                )))
+            }
+        }
+    }
+    Ok((fields, groups, data))
+}
+
+fn elements<'a, 'input>(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(|n| n.is_element())
+}
+
+fn required<'a>(node: Node<'a, '_>, attribute: &str) -> Result<&'a str, Error> {
+    node.attribute(attribute).ok_or_else(|| {
+        schema_err(&format!(
+            "<{}> is missing the `{}` attribute",
+            node.tag_name().name(),
+            attribute
+        ))
+    })
+}
+
+fn parse_number<T: std::str::FromStr>(node: Node, attribute: &str) -> Result<T, Error> {
+    let value = required(node, attribute)?;
+    value.parse().map_err(|_| {
+        schema_err(&format!(
+            "invalid `{}` value `{}` on <{}>",
+            attribute,
+            value,
+            node.tag_name().name()
+        ))
+    })
+}
+
+fn unsupported(what: &str, node: Node) -> Error {
+    schema_err(&format!(
+        "{} (in <{}>) not supported",
+        what,
+        node.tag_name().name()
+    ))
+}
+
+fn schema_err(msg: &str) -> Error {
+    Error::Schema(msg.to_owned())
+}
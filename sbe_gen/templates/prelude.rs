@@ -0,0 +1,216 @@
+
+/// Imports core rather than std to broaden usable environments.
+extern crate core;
+
+// Result types for error handling
+
+/// Errors that may occur during the course of encoding or decoding.
+#[derive(Debug)]
+pub enum CodecErr {
+    /// Too few bytes in the byte-slice to read or write the data structure relevant
+    /// to the current state of the codec
+    NotEnoughBytes,
+
+    /// Groups and vardata are constrained by the numeric type chosen to represent their
+    /// length as well as optional maxima imposed by the schema
+    SliceIsLongerThanAllowedBySchema,
+}
+
+pub type CodecResult<T> = core::result::Result<T, CodecErr>;
+
+/// Scratch Decoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchDecoderData<'d> {
+    data: &'d [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchDecoderData<'d> {
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that the struct's contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the struct in bytes.
+    #[inline]
+    fn read_type<T>(&mut self, num_bytes: usize) -> CodecResult<&'d T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *mut T;
+            let v: &'d T = unsafe { &*s };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy a primitive out of the data buffer. Unlike `read_type`, the value
+    /// doesn't need to be aligned, so this is safe for length prefixes that
+    /// follow variable-length data.
+    /// Advances the `pos` index by the size of the value in bytes.
+    #[inline]
+    fn read_unaligned<T: Copy>(&mut self, num_bytes: usize) -> CodecResult<T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *const T;
+            let v = unsafe { core::ptr::read_unaligned(s) };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a slice reference overlaid atop the data buffer
+    /// such that the slice's members' contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the slice contents in bytes.
+    #[inline]
+    fn read_slice<T>(&mut self, count: usize, bytes_per_item: usize) -> CodecResult<&'d [T]> {
+        let num_bytes = bytes_per_item * count;
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d [T] = unsafe {
+                core::slice::from_raw_parts(self.data[self.pos..end].as_ptr() as *const T, count)
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Scratch Encoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchEncoderData<'d> {
+    data: &'d mut [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchEncoderData<'d> {
+    /// Copy the bytes of a value into the data buffer
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn write_type<T>(&mut self, t: &T, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn writable_overlay<T>(&mut self, num_bytes: usize) -> CodecResult<&'d mut T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d mut T = unsafe {
+                let s = self.data.as_ptr().add(self.pos) as *mut T;
+                &mut *s
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the bytes of a value into the data buffer at a specific position
+    /// Does **not** alter the `pos` index.
+    #[inline]
+    fn write_at_position<T>(
+        &mut self,
+        position: usize,
+        t: &T,
+        num_bytes: usize,
+    ) -> CodecResult<()> {
+        let end = position + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[position..end].copy_from_slice(source_bytes);
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+    /// Create a mutable slice overlaid atop the data buffer directly
+    /// such that changes to the slice contents directly edit the buffer
+    /// Note that the initial content of the slice's members' fields may be garbage.
+    /// Advances the `pos` index to after the region representing the slice.
+    #[inline]
+    fn writable_slice<T>(
+        &mut self,
+        count: usize,
+        bytes_per_item: usize,
+    ) -> CodecResult<&'d mut [T]> {
+        let end = self.pos + (count * bytes_per_item);
+        if end <= self.data.len() {
+            let v: &'d mut [T] = unsafe {
+                core::slice::from_raw_parts_mut(
+                    self.data[self.pos..end].as_mut_ptr() as *mut T,
+                    count,
+                )
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the raw bytes of a slice's contents into the data buffer
+    /// Does **not** encode the length of the slice explicitly into the buffer.
+    /// Advances the `pos` index to after the newly-written slice bytes.
+    #[inline]
+    fn write_slice_without_count<T>(&mut self, t: &[T], bytes_per_item: usize) -> CodecResult<()> {
+        let content_bytes_size = bytes_per_item * t.len();
+        let end = self.pos + content_bytes_size;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t.as_ptr() as *const u8, content_bytes_size) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Convenience Either enum
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
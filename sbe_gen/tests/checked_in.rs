@@ -0,0 +1,49 @@
+//! The codecs checked in to `src/` are what `sbe_gen` generates for each
+//! schema; make sure they haven't drifted from it. `java_codec.rs` checks
+//! them against what the reference SBE tool's codecs write.
+
+use std::fs;
+use std::path::Path;
+
+fn check(schema: &str, codec: &str) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let xml = fs::read_to_string(root.join(schema)).unwrap();
+    let expected = fs::read_to_string(root.join("src").join(codec)).unwrap();
+
+    let generated = sbe_gen::generate(&xml).unwrap();
+    let generated = sbe_gen::rustfmt(&generated).expect("Unable to run rustfmt");
+
+    // Comparing the full files would print thousands of lines on failure
+    for (i, (g, e)) in generated.lines().zip(expected.lines()).enumerate() {
+        assert!(
+            g == e,
+            "{} differs from src/{} at line {}:\ngenerated: {}\nexpected:  {}",
+            schema,
+            codec,
+            i + 1,
+            g,
+            e
+        );
+    }
+    assert_eq!(generated.lines().count(), expected.lines().count());
+}
+
+#[test]
+fn marketdata() {
+    check("marketdata.xml", "marketdata_sbe.rs");
+}
+
+#[test]
+fn marketdata_templates() {
+    check("marketdata_templates.xml", "marketdata_sbe_templates.rs");
+}
+
+#[test]
+fn marketdata_groups() {
+    check("marketdata_groups.xml", "marketdata_sbe_groups.rs");
+}
+
+#[test]
+fn marketdata_chained() {
+    check("marketdata_chained.xml", "marketdata_sbe_chained.rs");
+}
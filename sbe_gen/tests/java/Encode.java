@@ -0,0 +1,179 @@
+// Writes the messages checked by `tests/java_codec.rs`, using codecs generated
+// by the reference SBE tool (sbe-all 1.13.2):
+//
+//   for schema in marketdata marketdata_templates marketdata_groups marketdata_chained; do
+//       java -Dsbe.output.dir=gen -Dsbe.target.language=Java -Dsbe.target.namespace=$schema \
+//           -jar sbe-all-1.13.2-all.jar ../../../$schema.xml
+//   done
+//   javac -cp sbe-all-1.13.2-all.jar -d classes $(find gen -name '*.java') Encode.java
+//   java -cp sbe-all-1.13.2-all.jar:classes Encode
+//
+// The values have to match the ones in `tests/java_codec.rs`.
+
+import java.io.FileOutputStream;
+import java.io.IOException;
+import java.nio.ByteBuffer;
+
+import org.agrona.concurrent.UnsafeBuffer;
+
+public class Encode
+{
+    static final long SEQUENCE_NUMBER = 42;
+    static final long TIMESTAMP = 1_567_468_800_000_000_000L;
+
+    public static void main(final String[] args) throws IOException
+    {
+        write("marketdata.bin", marketdata());
+        write("marketdata_templates.bin", templates());
+        write("marketdata_groups.bin", groups());
+        write("marketdata_chained.bin", chained());
+    }
+
+    static void write(final String name, final byte[] data) throws IOException
+    {
+        try (FileOutputStream out = new FileOutputStream(name))
+        {
+            out.write(data);
+        }
+    }
+
+    static byte[] finish(final UnsafeBuffer buffer, final int length)
+    {
+        final byte[] data = new byte[length];
+        buffer.getBytes(0, data);
+        return data;
+    }
+
+    static byte[] marketdata()
+    {
+        final UnsafeBuffer buffer = new UnsafeBuffer(ByteBuffer.allocate(4096));
+        final marketdata.MultiMessageEncoder multiMessage = new marketdata.MultiMessageEncoder()
+            .wrapAndApplyHeader(buffer, 0, new marketdata.MessageHeaderEncoder())
+            .sequence_number(SEQUENCE_NUMBER);
+
+        final marketdata.MultiMessageEncoder.MessagesEncoder messages = multiMessage.messagesCount(4);
+        messages.next().timestamp(TIMESTAMP + 1).msg_type(marketdata.MsgType.Trade)
+            .trade().price(1_500_000).size(100).sale_condition((short)0x40).trade_id(10_000);
+        messages.symbol("AAPL");
+        // The 161-byte block puts everything after the first symbol at an odd offset
+        messages.next().timestamp(TIMESTAMP + 2).msg_type(marketdata.MsgType.Quote)
+            .quote().price(1_499_000).size(200).flags((short)1).side(marketdata.Side.Sell);
+        messages.symbol("ZIEXT");
+        messages.next().timestamp(TIMESTAMP + 3).msg_type(marketdata.MsgType.TradingStatus)
+            .trading_status().trading_status((short)'T').reason("T1  ");
+        messages.symbol("SPY");
+        messages.next().timestamp(TIMESTAMP + 4).msg_type(marketdata.MsgType.SystemEvent)
+            .system_event().system_event((short)'R');
+        messages.symbol("");
+
+        return finish(buffer, marketdata.MessageHeaderEncoder.ENCODED_LENGTH + multiMessage.encodedLength());
+    }
+
+    static byte[] templates()
+    {
+        final UnsafeBuffer buffer = new UnsafeBuffer(ByteBuffer.allocate(4096));
+        final marketdata_templates.MessageHeaderEncoder header = new marketdata_templates.MessageHeaderEncoder();
+        int offset = 0;
+
+        final marketdata_templates.PayloadHeaderEncoder payloadHeader = new marketdata_templates.PayloadHeaderEncoder()
+            .wrapAndApplyHeader(buffer, offset, header)
+            .sequence_number(SEQUENCE_NUMBER)
+            .message_count(4);
+        offset += header.encodedLength() + payloadHeader.encodedLength();
+
+        final marketdata_templates.TradeMessageEncoder trade = new marketdata_templates.TradeMessageEncoder()
+            .wrapAndApplyHeader(buffer, offset, header)
+            .timestamp(TIMESTAMP + 1).price(1_500_000).size(100).sale_condition((short)0x40).trade_id(10_000)
+            .symbol("AAPL");
+        offset += header.encodedLength() + trade.encodedLength();
+
+        final marketdata_templates.QuoteMessageEncoder quote = new marketdata_templates.QuoteMessageEncoder()
+            .wrapAndApplyHeader(buffer, offset, header)
+            .timestamp(TIMESTAMP + 2).price(1_499_000).size(200).flags((short)1).side(marketdata_templates.Side.Sell)
+            .symbol("ZIEXT");
+        offset += header.encodedLength() + quote.encodedLength();
+
+        final marketdata_templates.TradingStatusMessageEncoder tradingStatus =
+            new marketdata_templates.TradingStatusMessageEncoder()
+            .wrapAndApplyHeader(buffer, offset, header)
+            .timestamp(TIMESTAMP + 3).trading_status((short)'T');
+        tradingStatus.reason().code("T1  ");
+        tradingStatus.symbol("SPY");
+        offset += header.encodedLength() + tradingStatus.encodedLength();
+
+        final marketdata_templates.SystemEventMessageEncoder systemEvent =
+            new marketdata_templates.SystemEventMessageEncoder()
+            .wrapAndApplyHeader(buffer, offset, header)
+            .timestamp(TIMESTAMP + 4).system_event((short)'R');
+        offset += header.encodedLength() + systemEvent.encodedLength();
+
+        return finish(buffer, offset);
+    }
+
+    static byte[] groups()
+    {
+        final UnsafeBuffer buffer = new UnsafeBuffer(ByteBuffer.allocate(4096));
+        final marketdata_groups.MultiMessageEncoder multiMessage = new marketdata_groups.MultiMessageEncoder()
+            .wrapAndApplyHeader(buffer, 0, new marketdata_groups.MessageHeaderEncoder())
+            .sequence_number(SEQUENCE_NUMBER);
+
+        final marketdata_groups.MultiMessageEncoder.TradesEncoder trades = multiMessage.tradesCount(1);
+        trades.next().timestamp(TIMESTAMP + 1)
+            .trade().price(1_500_000).size(100).sale_condition((short)0x40).trade_id(10_000);
+        trades.symbol("AAPL");
+        final marketdata_groups.MultiMessageEncoder.QuotesEncoder quotes = multiMessage.quotesCount(1);
+        quotes.next().timestamp(TIMESTAMP + 2)
+            .quote().price(1_499_000).size(200).flags((short)1).side(marketdata_groups.Side.Sell);
+        quotes.symbol("ZIEXT");
+        multiMessage.system_eventsCount(1).next().timestamp(TIMESTAMP + 4)
+            .system_event().system_event((short)'R');
+        multiMessage.security_directoriesCount(0);
+        final marketdata_groups.MultiMessageEncoder.Trading_statusesEncoder tradingStatuses =
+            multiMessage.trading_statusesCount(1);
+        tradingStatuses.next().timestamp(TIMESTAMP + 3)
+            .trading_status().trading_status((short)'T').reason("T1  ");
+        tradingStatuses.symbol("SPY");
+        multiMessage.operational_halt_statusesCount(0);
+        multiMessage.short_sale_price_testsCount(0);
+        multiMessage.security_eventsCount(0);
+        multiMessage.official_pricesCount(0);
+        multiMessage.trade_breaksCount(0);
+        multiMessage.auction_informationsCount(0);
+
+        return finish(buffer, marketdata_groups.MessageHeaderEncoder.ENCODED_LENGTH + multiMessage.encodedLength());
+    }
+
+    static byte[] chained()
+    {
+        // Only the header is a real message; the chain itself is laid out by hand,
+        // so this checks every block the chain uses instead
+        final UnsafeBuffer buffer = new UnsafeBuffer(ByteBuffer.allocate(4096));
+        final marketdata_chained.MessageHeaderEncoder header = new marketdata_chained.MessageHeaderEncoder();
+
+        final marketdata_chained.MultiMessageEncoder multiMessage = new marketdata_chained.MultiMessageEncoder()
+            .wrapAndApplyHeader(buffer, 0, header)
+            .sequence_number(SEQUENCE_NUMBER)
+            .message_count(4);
+        final int offset = header.encodedLength() + multiMessage.encodedLength();
+
+        final marketdata_chained.ChainBlocksEncoder blocks = new marketdata_chained.ChainBlocksEncoder()
+            .wrapAndApplyHeader(buffer, offset, header);
+        blocks.message_start().timestamp(TIMESTAMP + 1).msg_type(marketdata_chained.MsgType.AuctionInformation);
+        blocks.trade().price(1_500_000).size(100).sale_condition((short)0x40).trade_id(10_000);
+        blocks.quote().price(1_499_000).size(200).flags((short)1).side(marketdata_chained.Side.Sell);
+        blocks.symbol_length().length(5);
+        blocks.system_event().system_event((short)'R');
+        blocks.security_directory().flags((short)0x80).lot_size(100).previous_closing(1_000_000).luld_tier((short)1);
+        blocks.trading_status().trading_status((short)'T').reason("T1  ");
+        blocks.operational_halt_status().halt_status((short)'N');
+        blocks.short_sale_price_test().sspt_status((short)1).detail((short)'A');
+        blocks.security_event().security_event((short)'O');
+        blocks.official_price().price_type((short)'Q').official_price(1_500_500);
+        blocks.auction_information().auction_type((short)'C').paired_shares(5_000).reference_price(1_500_100)
+            .indicative_clearing_price(1_500_200).imbalance_shares(700).imbalance_side((short)'B')
+            .extension_number((short)1).scheduled_auction(1_567_526_400L).auction_book_clearing_price(1_500_300)
+            .collar_reference_price(1_500_400).lower_auction_collar(1_400_000).upper_auction_collar(1_600_000);
+
+        return finish(buffer, offset + header.encodedLength() + blocks.encodedLength());
+    }
+}
@@ -0,0 +1,750 @@
+//! Messages written by the codecs the reference SBE tool generates for Java,
+//! which the codecs `sbe_gen` generated into `src/` have to decode, and then
+//! encode to exactly the same bytes. `java/Encode.java` wrote the fixtures, and
+//! its values have to match the ones here.
+
+use std::mem;
+
+#[allow(dead_code)]
+#[path = "../../src/marketdata_sbe.rs"]
+mod marketdata_sbe;
+#[allow(dead_code)]
+#[path = "../../src/marketdata_sbe_chained.rs"]
+mod marketdata_sbe_chained;
+#[allow(dead_code)]
+#[path = "../../src/marketdata_sbe_groups.rs"]
+mod marketdata_sbe_groups;
+#[allow(dead_code)]
+#[path = "../../src/marketdata_sbe_templates.rs"]
+mod marketdata_sbe_templates;
+
+const SEQUENCE_NUMBER: u64 = 42;
+const TIMESTAMP: i64 = 1_567_468_800_000_000_000;
+
+/// Java leaves anything it isn't asked to write as zero, where `Default` would
+/// fill enums with their null value, so blocks we encode start out zeroed too.
+fn zeroed<T>() -> T {
+    // Every generated block is plain integers and enums that have a zero variant
+    unsafe { mem::zeroed() }
+}
+
+fn assert_same_bytes(encoded: &[u8], java: &[u8]) {
+    if let Some(i) = encoded.iter().zip(java).position(|(e, j)| e != j) {
+        panic!(
+            "byte {} differs: encoded {:#04x}, Java wrote {:#04x}",
+            i, encoded[i], java[i]
+        );
+    }
+    assert_eq!(encoded.len(), java.len());
+}
+
+mod marketdata {
+    use super::marketdata_sbe::*;
+    use super::{assert_same_bytes, zeroed, SEQUENCE_NUMBER, TIMESTAMP};
+
+    const JAVA: &[u8] = include_bytes!("java/marketdata.bin");
+
+    fn trade() -> Trade {
+        Trade {
+            price: 1_500_000,
+            size: 100,
+            sale_condition: 0x40,
+            trade_id: 10_000,
+        }
+    }
+
+    fn quote() -> Quote {
+        Quote {
+            price: 1_499_000,
+            size: 200,
+            flags: 1,
+            side: Side::Sell,
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let (_header, decoder) = start_decoding_multi_message(JAVA).header().unwrap();
+        let (fields, decoder) = decoder.multi_message_fields().unwrap();
+        assert_eq!({ fields.sequence_number }, SEQUENCE_NUMBER);
+
+        let mut members = Vec::new();
+        let mut decoder = decoder.messages_individually().unwrap();
+        while let Either::Left(member_decoder) = decoder {
+            let (member, symbol_decoder) = member_decoder.next_messages_member().unwrap();
+            let (symbol, next) = symbol_decoder.symbol().unwrap();
+            members.push((member, symbol));
+            decoder = next;
+        }
+        match decoder {
+            Either::Right(done) => assert_eq!(done.unwrap(), JAVA.len()),
+            Either::Left(_) => unreachable!(),
+        }
+        assert_eq!(members.len(), 4);
+
+        // The first member's block ends at byte 181, so its symbol's length is
+        // read from an odd offset
+        let (member, symbol) = members[0];
+        assert_eq!(
+            ({ member.timestamp }, member.msg_type),
+            (TIMESTAMP + 1, MsgType::Trade)
+        );
+        let t = &member.trade;
+        let expected = trade();
+        assert_eq!(
+            ({ t.price }, { t.size }, t.sale_condition, { t.trade_id }),
+            (
+                { expected.price },
+                { expected.size },
+                expected.sale_condition,
+                { expected.trade_id }
+            )
+        );
+        assert_eq!(symbol, b"AAPL");
+
+        let (member, symbol) = members[1];
+        assert_eq!(
+            ({ member.timestamp }, member.msg_type),
+            (TIMESTAMP + 2, MsgType::Quote)
+        );
+        let q = &member.quote;
+        assert_eq!(
+            ({ q.price }, { q.size }, q.flags, q.side),
+            (1_499_000, 200, 1, Side::Sell)
+        );
+        assert_eq!(symbol, b"ZIEXT");
+
+        let (member, symbol) = members[2];
+        assert_eq!(
+            ({ member.timestamp }, member.msg_type),
+            (TIMESTAMP + 3, MsgType::TradingStatus)
+        );
+        let ts = &member.trading_status;
+        assert_eq!((ts.trading_status, ts.reason), (b'T', *b"T1  "));
+        assert_eq!(symbol, b"SPY");
+
+        let (member, symbol) = members[3];
+        assert_eq!(
+            ({ member.timestamp }, member.msg_type),
+            (TIMESTAMP + 4, MsgType::SystemEvent)
+        );
+        assert_eq!(member.system_event.system_event, b'R');
+        assert_eq!(symbol, b"");
+    }
+
+    #[test]
+    fn encode() {
+        let mut trade_member: MultiMessageMessagesMember = zeroed();
+        trade_member.timestamp = TIMESTAMP + 1;
+        trade_member.msg_type = MsgType::Trade;
+        trade_member.trade = trade();
+
+        let mut quote_member: MultiMessageMessagesMember = zeroed();
+        quote_member.timestamp = TIMESTAMP + 2;
+        quote_member.msg_type = MsgType::Quote;
+        quote_member.quote = quote();
+
+        let mut trading_status_member: MultiMessageMessagesMember = zeroed();
+        trading_status_member.timestamp = TIMESTAMP + 3;
+        trading_status_member.msg_type = MsgType::TradingStatus;
+        trading_status_member.trading_status = TradingStatus {
+            trading_status: b'T',
+            reason: *b"T1  ",
+        };
+
+        let mut system_event_member: MultiMessageMessagesMember = zeroed();
+        system_event_member.timestamp = TIMESTAMP + 4;
+        system_event_member.msg_type = MsgType::SystemEvent;
+        system_event_member.system_event = SystemEvent { system_event: b'R' };
+
+        let mut buffer = vec![0; JAVA.len()];
+        let (fields, encoder) = start_encoding_multi_message(&mut buffer)
+            .header_copy(&MultiMessageMessageHeader::default().message_header)
+            .unwrap()
+            .multi_message_fields()
+            .unwrap();
+        fields.sequence_number = SEQUENCE_NUMBER;
+        let len = encoder
+            .messages_individually()
+            .unwrap()
+            .next_messages_member(&trade_member)
+            .unwrap()
+            .symbol(b"AAPL")
+            .unwrap()
+            .next_messages_member(&quote_member)
+            .unwrap()
+            .symbol(b"ZIEXT")
+            .unwrap()
+            .next_messages_member(&trading_status_member)
+            .unwrap()
+            .symbol(b"SPY")
+            .unwrap()
+            .next_messages_member(&system_event_member)
+            .unwrap()
+            .symbol(b"")
+            .unwrap()
+            .done_with_messages()
+            .unwrap()
+            .unwrap();
+
+        assert_same_bytes(&buffer[..len], JAVA);
+    }
+}
+
+mod templates {
+    use super::marketdata_sbe_templates::*;
+    use super::{assert_same_bytes, zeroed, SEQUENCE_NUMBER, TIMESTAMP};
+
+    const JAVA: &[u8] = include_bytes!("java/marketdata_templates.bin");
+
+    fn trade() -> TradeMessageFields {
+        let mut trade: TradeMessageFields = zeroed();
+        trade.timestamp = TIMESTAMP + 1;
+        trade.price = 1_500_000;
+        trade.size = 100;
+        trade.sale_condition = 0x40;
+        trade.trade_id = 10_000;
+        trade
+    }
+
+    fn quote() -> QuoteMessageFields {
+        let mut quote: QuoteMessageFields = zeroed();
+        quote.timestamp = TIMESTAMP + 2;
+        quote.price = 1_499_000;
+        quote.size = 200;
+        quote.flags = 1;
+        quote.side = Side::Sell;
+        quote
+    }
+
+    #[test]
+    fn decode() {
+        let (_header, decoder) = start_decoding_payload_header(JAVA).header().unwrap();
+        let (fields, done) = decoder.payload_header_fields().unwrap();
+        assert_eq!(
+            ({ fields.sequence_number }, { fields.message_count }),
+            (SEQUENCE_NUMBER, 4)
+        );
+        let mut pos = done.unwrap();
+
+        let (header, decoder) = start_decoding_trade_message(&JAVA[pos..]).header().unwrap();
+        assert_eq!({ header.template_id }, 2);
+        let (fields, decoder) = decoder.trade_message_fields().unwrap();
+        let expected = trade();
+        assert_eq!(
+            (
+                { fields.timestamp },
+                { fields.price },
+                { fields.size },
+                fields.sale_condition,
+                { fields.trade_id }
+            ),
+            (
+                { expected.timestamp },
+                { expected.price },
+                { expected.size },
+                expected.sale_condition,
+                { expected.trade_id }
+            )
+        );
+        let (symbol, done) = decoder.symbol().unwrap();
+        assert_eq!(symbol, b"AAPL");
+        pos += done.unwrap();
+
+        let (header, decoder) = start_decoding_quote_message(&JAVA[pos..]).header().unwrap();
+        assert_eq!({ header.template_id }, 3);
+        let (fields, decoder) = decoder.quote_message_fields().unwrap();
+        assert_eq!(
+            (
+                { fields.timestamp },
+                { fields.price },
+                { fields.size },
+                fields.flags,
+                fields.side
+            ),
+            (TIMESTAMP + 2, 1_499_000, 200, 1, Side::Sell)
+        );
+        let (symbol, done) = decoder.symbol().unwrap();
+        assert_eq!(symbol, b"ZIEXT");
+        pos += done.unwrap();
+
+        let (header, decoder) = start_decoding_trading_status_message(&JAVA[pos..])
+            .header()
+            .unwrap();
+        assert_eq!({ header.template_id }, 6);
+        let (fields, decoder) = decoder.trading_status_message_fields().unwrap();
+        assert_eq!(
+            (
+                { fields.timestamp },
+                fields.trading_status,
+                fields.reason.code
+            ),
+            (TIMESTAMP + 3, b'T', *b"T1  ")
+        );
+        let (symbol, done) = decoder.symbol().unwrap();
+        assert_eq!(symbol, b"SPY");
+        pos += done.unwrap();
+
+        let (header, decoder) = start_decoding_system_event_message(&JAVA[pos..])
+            .header()
+            .unwrap();
+        assert_eq!({ header.template_id }, 4);
+        let (fields, done) = decoder.system_event_message_fields().unwrap();
+        assert_eq!(
+            ({ fields.timestamp }, fields.system_event),
+            (TIMESTAMP + 4, b'R')
+        );
+        pos += done.unwrap();
+
+        assert_eq!(pos, JAVA.len());
+    }
+
+    #[test]
+    fn encode() {
+        let mut buffer = vec![0; JAVA.len()];
+
+        let mut len = start_encoding_payload_header(&mut buffer)
+            .header_copy(&PayloadHeaderMessageHeader::default().message_header)
+            .unwrap()
+            .payload_header_fields_copy(&PayloadHeaderFields {
+                sequence_number: SEQUENCE_NUMBER,
+                message_count: 4,
+            })
+            .unwrap()
+            .unwrap();
+
+        len += start_encoding_trade_message(&mut buffer[len..])
+            .header_copy(&TradeMessageMessageHeader::default().message_header)
+            .unwrap()
+            .trade_message_fields_copy(&trade())
+            .unwrap()
+            .symbol(b"AAPL")
+            .unwrap()
+            .unwrap();
+
+        len += start_encoding_quote_message(&mut buffer[len..])
+            .header_copy(&QuoteMessageMessageHeader::default().message_header)
+            .unwrap()
+            .quote_message_fields_copy(&quote())
+            .unwrap()
+            .symbol(b"ZIEXT")
+            .unwrap()
+            .unwrap();
+
+        let mut trading_status: TradingStatusMessageFields = zeroed();
+        trading_status.timestamp = TIMESTAMP + 3;
+        trading_status.trading_status = b'T';
+        trading_status.reason = Reason { code: *b"T1  " };
+        len += start_encoding_trading_status_message(&mut buffer[len..])
+            .header_copy(&TradingStatusMessageMessageHeader::default().message_header)
+            .unwrap()
+            .trading_status_message_fields_copy(&trading_status)
+            .unwrap()
+            .symbol(b"SPY")
+            .unwrap()
+            .unwrap();
+
+        len += start_encoding_system_event_message(&mut buffer[len..])
+            .header_copy(&SystemEventMessageMessageHeader::default().message_header)
+            .unwrap()
+            .system_event_message_fields_copy(&SystemEventMessageFields {
+                timestamp: TIMESTAMP + 4,
+                system_event: b'R',
+            })
+            .unwrap()
+            .unwrap();
+
+        assert_same_bytes(&buffer[..len], JAVA);
+    }
+}
+
+mod groups {
+    use super::marketdata_sbe_groups::*;
+    use super::{assert_same_bytes, zeroed, SEQUENCE_NUMBER, TIMESTAMP};
+
+    const JAVA: &[u8] = include_bytes!("java/marketdata_groups.bin");
+
+    /// Move past a group that Java left empty.
+    macro_rules! empty_group {
+        ($decoder:expr, $individually:ident) => {
+            match $decoder.$individually().unwrap() {
+                Either::Right(next) => next,
+                Either::Left(_) => panic!(concat!(stringify!($individually), " isn't empty")),
+            }
+        };
+    }
+
+    /// Read the only member of a group, along with its symbol.
+    macro_rules! only_member {
+        ($decoder:expr, $individually:ident, $next_member:ident) => {{
+            let group = match $decoder.$individually().unwrap() {
+                Either::Left(group) => group,
+                Either::Right(_) => panic!(concat!(stringify!($individually), " is empty")),
+            };
+            let (member, symbol_decoder) = group.$next_member().unwrap();
+            let (symbol, next) = symbol_decoder.symbol().unwrap();
+            match next {
+                Either::Right(next) => (member, symbol, next),
+                Either::Left(_) => panic!(concat!(stringify!($individually), " has more members")),
+            }
+        }};
+    }
+
+    fn trades_member() -> MultiMessageTradesMember {
+        MultiMessageTradesMember {
+            timestamp: TIMESTAMP + 1,
+            trade: Trade {
+                price: 1_500_000,
+                size: 100,
+                sale_condition: 0x40,
+                trade_id: 10_000,
+            },
+        }
+    }
+
+    fn quotes_member() -> MultiMessageQuotesMember {
+        MultiMessageQuotesMember {
+            timestamp: TIMESTAMP + 2,
+            quote: Quote {
+                price: 1_499_000,
+                size: 200,
+                flags: 1,
+                side: Side::Sell,
+            },
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let (_header, decoder) = start_decoding_multi_message(JAVA).header().unwrap();
+        let (fields, decoder) = decoder.multi_message_fields().unwrap();
+        assert_eq!({ fields.sequence_number }, SEQUENCE_NUMBER);
+
+        let (member, symbol, decoder) =
+            only_member!(decoder, trades_individually, next_trades_member);
+        let (t, expected) = (&member.trade, trades_member().trade);
+        assert_eq!(
+            (
+                { member.timestamp },
+                { t.price },
+                { t.size },
+                t.sale_condition,
+                { t.trade_id }
+            ),
+            (
+                TIMESTAMP + 1,
+                { expected.price },
+                { expected.size },
+                expected.sale_condition,
+                { expected.trade_id }
+            )
+        );
+        assert_eq!(symbol, b"AAPL");
+
+        // "AAPL" leaves the quotes group header at an odd offset
+        let (member, symbol, decoder) =
+            only_member!(decoder, quotes_individually, next_quotes_member);
+        let q = &member.quote;
+        assert_eq!(
+            (
+                { member.timestamp },
+                { q.price },
+                { q.size },
+                q.flags,
+                q.side
+            ),
+            (TIMESTAMP + 2, 1_499_000, 200, 1, Side::Sell)
+        );
+        assert_eq!(symbol, b"ZIEXT");
+
+        let group = match decoder.system_events_individually().unwrap() {
+            Either::Left(group) => group,
+            Either::Right(_) => panic!("system_events is empty"),
+        };
+        let (member, next) = group.next_system_events_member().unwrap();
+        assert_eq!(
+            ({ member.timestamp }, member.system_event.system_event),
+            (TIMESTAMP + 4, b'R')
+        );
+        let decoder = match next {
+            Either::Right(next) => next,
+            Either::Left(_) => panic!("system_events has more members"),
+        };
+
+        let decoder = empty_group!(decoder, security_directories_individually);
+        let (member, symbol, decoder) = only_member!(
+            decoder,
+            trading_statuses_individually,
+            next_trading_statuses_member
+        );
+        let ts = &member.trading_status;
+        assert_eq!(
+            ({ member.timestamp }, ts.trading_status, ts.reason),
+            (TIMESTAMP + 3, b'T', *b"T1  ")
+        );
+        assert_eq!(symbol, b"SPY");
+
+        let decoder = empty_group!(decoder, operational_halt_statuses_individually);
+        let decoder = empty_group!(decoder, short_sale_price_tests_individually);
+        let decoder = empty_group!(decoder, security_events_individually);
+        let decoder = empty_group!(decoder, official_prices_individually);
+        let decoder = empty_group!(decoder, trade_breaks_individually);
+        let done = empty_group!(decoder, auction_informations_individually);
+        assert_eq!(done.unwrap(), JAVA.len());
+    }
+
+    #[test]
+    fn encode() {
+        let mut trading_status_member: MultiMessageTradingStatusesMember = zeroed();
+        trading_status_member.timestamp = TIMESTAMP + 3;
+        trading_status_member.trading_status = TradingStatus {
+            trading_status: b'T',
+            reason: *b"T1  ",
+        };
+
+        let mut buffer = vec![0; JAVA.len()];
+        let len = start_encoding_multi_message(&mut buffer)
+            .header_copy(&MultiMessageMessageHeader::default().message_header)
+            .unwrap()
+            .multi_message_fields_copy(&MultiMessageFields {
+                sequence_number: SEQUENCE_NUMBER,
+            })
+            .unwrap()
+            .trades_individually()
+            .unwrap()
+            .next_trades_member(&trades_member())
+            .unwrap()
+            .symbol(b"AAPL")
+            .unwrap()
+            .done_with_trades()
+            .unwrap()
+            .quotes_individually()
+            .unwrap()
+            .next_quotes_member(&quotes_member())
+            .unwrap()
+            .symbol(b"ZIEXT")
+            .unwrap()
+            .done_with_quotes()
+            .unwrap()
+            .system_events_individually()
+            .unwrap()
+            .next_system_events_member(&MultiMessageSystemEventsMember {
+                timestamp: TIMESTAMP + 4,
+                system_event: SystemEvent { system_event: b'R' },
+            })
+            .unwrap()
+            .done_with_system_events()
+            .unwrap()
+            .security_directories_individually()
+            .unwrap()
+            .done_with_security_directories()
+            .unwrap()
+            .trading_statuses_individually()
+            .unwrap()
+            .next_trading_statuses_member(&trading_status_member)
+            .unwrap()
+            .symbol(b"SPY")
+            .unwrap()
+            .done_with_trading_statuses()
+            .unwrap()
+            .operational_halt_statuses_individually()
+            .unwrap()
+            .done_with_operational_halt_statuses()
+            .unwrap()
+            .short_sale_price_tests_individually()
+            .unwrap()
+            .done_with_short_sale_price_tests()
+            .unwrap()
+            .security_events_individually()
+            .unwrap()
+            .done_with_security_events()
+            .unwrap()
+            .official_prices_individually()
+            .unwrap()
+            .done_with_official_prices()
+            .unwrap()
+            .trade_breaks_individually()
+            .unwrap()
+            .done_with_trade_breaks()
+            .unwrap()
+            .auction_informations_individually()
+            .unwrap()
+            .done_with_auction_informations()
+            .unwrap()
+            .unwrap();
+
+        assert_same_bytes(&buffer[..len], JAVA);
+    }
+}
+
+mod chained {
+    use super::marketdata_sbe_chained::*;
+    use super::{assert_same_bytes, SEQUENCE_NUMBER, TIMESTAMP};
+
+    const JAVA: &[u8] = include_bytes!("java/marketdata_chained.bin");
+
+    /// The chain itself is laid out by hand, so Java wrote every block the chain
+    /// uses into a `ChainBlocks` message after the `MultiMessage` header.
+    fn chain_blocks() -> ChainBlocksFields {
+        ChainBlocksFields {
+            message_start: MessageStart {
+                timestamp: TIMESTAMP + 1,
+                msg_type: MsgType::AuctionInformation,
+            },
+            trade: Trade {
+                price: 1_500_000,
+                size: 100,
+                sale_condition: 0x40,
+                trade_id: 10_000,
+            },
+            quote: Quote {
+                price: 1_499_000,
+                size: 200,
+                flags: 1,
+                side: Side::Sell,
+            },
+            symbol_length: SymbolLength { length: 5 },
+            system_event: SystemEvent { system_event: b'R' },
+            security_directory: SecurityDirectory {
+                flags: 0x80,
+                lot_size: 100,
+                previous_closing: 1_000_000,
+                luld_tier: 1,
+            },
+            trading_status: TradingStatus {
+                trading_status: b'T',
+                reason: *b"T1  ",
+            },
+            operational_halt_status: OperationalHaltStatus { halt_status: b'N' },
+            short_sale_price_test: ShortSalePriceTest {
+                sspt_status: 1,
+                detail: b'A',
+            },
+            security_event: SecurityEvent {
+                security_event: b'O',
+            },
+            official_price: OfficialPrice {
+                price_type: b'Q',
+                official_price: 1_500_500,
+            },
+            auction_information: AuctionInformation {
+                auction_type: b'C',
+                paired_shares: 5_000,
+                reference_price: 1_500_100,
+                indicative_clearing_price: 1_500_200,
+                imbalance_shares: 700,
+                imbalance_side: b'B',
+                extension_number: 1,
+                scheduled_auction: 1_567_526_400,
+                auction_book_clearing_price: 1_500_300,
+                collar_reference_price: 1_500_400,
+                lower_auction_collar: 1_400_000,
+                upper_auction_collar: 1_600_000,
+            },
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let (_header, decoder) = start_decoding_multi_message(JAVA).header().unwrap();
+        let (fields, done) = decoder.multi_message_fields().unwrap();
+        assert_eq!(
+            ({ fields.sequence_number }, { fields.message_count }),
+            (SEQUENCE_NUMBER, 4)
+        );
+        let pos = done.unwrap();
+
+        let (_header, decoder) = start_decoding_chain_blocks(&JAVA[pos..]).header().unwrap();
+        let (blocks, done) = decoder.chain_blocks_fields().unwrap();
+        assert_eq!(pos + done.unwrap(), JAVA.len());
+
+        let expected = chain_blocks();
+        let (start, expected_start) = (&blocks.message_start, &expected.message_start);
+        assert_eq!(
+            ({ start.timestamp }, start.msg_type),
+            ({ expected_start.timestamp }, expected_start.msg_type)
+        );
+        let (t, q) = (&blocks.trade, &blocks.quote);
+        assert_eq!(
+            ({ t.price }, { t.size }, t.sale_condition, { t.trade_id }),
+            (1_500_000, 100, 0x40, 10_000)
+        );
+        assert_eq!(
+            ({ q.price }, { q.size }, q.flags, q.side),
+            (1_499_000, 200, 1, Side::Sell)
+        );
+        assert_eq!({ blocks.symbol_length.length }, 5);
+        assert_eq!(blocks.system_event.system_event, b'R');
+        let sd = &blocks.security_directory;
+        assert_eq!(
+            (
+                sd.flags,
+                { sd.lot_size },
+                { sd.previous_closing },
+                sd.luld_tier
+            ),
+            (0x80, 100, 1_000_000, 1)
+        );
+        let ts = &blocks.trading_status;
+        assert_eq!((ts.trading_status, ts.reason), (b'T', *b"T1  "));
+        assert_eq!(blocks.operational_halt_status.halt_status, b'N');
+        let sspt = &blocks.short_sale_price_test;
+        assert_eq!((sspt.sspt_status, sspt.detail), (1, b'A'));
+        assert_eq!(blocks.security_event.security_event, b'O');
+        let op = &blocks.official_price;
+        assert_eq!((op.price_type, { op.official_price }), (b'Q', 1_500_500));
+        let ai = &blocks.auction_information;
+        assert_eq!(
+            (
+                ai.auction_type,
+                { ai.paired_shares },
+                { ai.reference_price },
+                { ai.indicative_clearing_price },
+                { ai.imbalance_shares },
+                ai.imbalance_side,
+                ai.extension_number,
+                { ai.scheduled_auction },
+                { ai.auction_book_clearing_price },
+                { ai.collar_reference_price },
+                { ai.lower_auction_collar },
+                { ai.upper_auction_collar },
+            ),
+            (
+                b'C',
+                5_000,
+                1_500_100,
+                1_500_200,
+                700,
+                b'B',
+                1,
+                1_567_526_400,
+                1_500_300,
+                1_500_400,
+                1_400_000,
+                1_600_000,
+            )
+        );
+    }
+
+    #[test]
+    fn encode() {
+        let mut buffer = vec![0; JAVA.len()];
+        let mut len = start_encoding_multi_message(&mut buffer)
+            .header_copy(&MultiMessageMessageHeader::default().message_header)
+            .unwrap()
+            .multi_message_fields_copy(&MultiMessageFields {
+                sequence_number: SEQUENCE_NUMBER,
+                message_count: 4,
+            })
+            .unwrap()
+            .unwrap();
+        len += start_encoding_chain_blocks(&mut buffer[len..])
+            .header_copy(&ChainBlocksMessageHeader::default().message_header)
+            .unwrap()
+            .chain_blocks_fields_copy(&chain_blocks())
+            .unwrap()
+            .unwrap();
+
+        assert_same_bytes(&buffer[..len], JAVA);
+    }
+}
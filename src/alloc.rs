@@ -0,0 +1,327 @@
+#[cfg(any(test, feature = "count-allocations"))]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ops::{AddAssign, Sub};
+
+/// Whether allocations are being counted; otherwise every count reads as zero.
+/// Counting is always on for tests, so they can check runners don't allocate.
+pub const ENABLED: bool = cfg!(any(test, feature = "count-allocations"));
+
+#[cfg(any(test, feature = "count-allocations"))]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Counted per thread, so tests running in parallel don't see each other's allocations
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static DEALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES_ALLOCATED: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Heap activity on the current thread.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub deallocations: u64,
+    pub bytes_allocated: u64,
+}
+
+impl Sub for AllocStats {
+    type Output = AllocStats;
+
+    fn sub(self, rhs: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations - rhs.allocations,
+            deallocations: self.deallocations - rhs.deallocations,
+            bytes_allocated: self.bytes_allocated - rhs.bytes_allocated,
+        }
+    }
+}
+
+impl AddAssign for AllocStats {
+    fn add_assign(&mut self, rhs: AllocStats) {
+        self.allocations += rhs.allocations;
+        self.deallocations += rhs.deallocations;
+        self.bytes_allocated += rhs.bytes_allocated;
+    }
+}
+
+/// Everything allocated on this thread so far; subtract two of these to count
+/// allocations in between.
+#[inline(always)]
+pub fn current() -> AllocStats {
+    if !ENABLED {
+        return AllocStats::default();
+    }
+
+    AllocStats {
+        allocations: ALLOCATIONS.with(Cell::get),
+        deallocations: DEALLOCATIONS.with(Cell::get),
+        bytes_allocated: BYTES_ALLOCATED.with(Cell::get),
+    }
+}
+
+#[cfg(any(test, feature = "count-allocations"))]
+fn count(counter: &'static std::thread::LocalKey<Cell<u64>>, amount: u64) {
+    // Threads can still allocate while their thread-locals are being torn down
+    let _ = counter.try_with(|c| c.set(c.get() + amount));
+}
+
+/// Passes everything through to the system allocator, counting as it goes.
+/// A `realloc` counts as both an allocation and a deallocation.
+#[cfg(any(test, feature = "count-allocations"))]
+pub struct CountingAllocator;
+
+#[cfg(any(test, feature = "count-allocations"))]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(&ALLOCATIONS, 1);
+        count(&BYTES_ALLOCATED, layout.size() as u64);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(&ALLOCATIONS, 1);
+        count(&BYTES_ALLOCATED, layout.size() as u64);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        count(&DEALLOCATIONS, 1);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(&ALLOCATIONS, 1);
+        count(&DEALLOCATIONS, 1);
+        count(&BYTES_ALLOCATED, new_size as u64);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{current, AllocStats};
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    use crate::framing::StreamBuf;
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    use crate::iex::{IexParser, IexPayload};
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    use crate::sample;
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    use crate::{RunnerDeserialize, RunnerSerialize, Summarizer, WriteMode};
+
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    const SYMBOLS: &[&[u8; 8]] = &[b"AAPL    ", b"MSFT    ", b"SPY     ", b"ZIEXT   "];
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    const SAMPLE_PACKETS: u64 = 40;
+
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    /// A pcapng capture of IEX DEEP packets holding anywhere from 1 to 12
+    /// quote updates and trades, in the same shape as the real captures.
+    fn sample_pcap() -> Vec<u8> {
+        let mut payloads = Vec::new();
+        let mut seq_no = 1u64;
+        for packet in 0..SAMPLE_PACKETS {
+            let msg_count = packet % 12 + 1;
+            let mut messages = Vec::new();
+            for i in 0..msg_count {
+                let symbol = SYMBOLS[((packet + i) % SYMBOLS.len() as u64) as usize];
+                let timestamp = 1_567_468_800_000_000_000i64 + (packet * 100 + i) as i64;
+                let mut message = Vec::new();
+                if i % 3 == 2 {
+                    // TradeReport
+                    message.extend_from_slice(&[0x54, 0]);
+                    message.extend_from_slice(&timestamp.to_le_bytes());
+                    message.extend_from_slice(symbol);
+                    message.extend_from_slice(&(100 + i as u32).to_le_bytes());
+                    message.extend_from_slice(&(1_500_000 + packet).to_le_bytes());
+                    message.extend_from_slice(&seq_no.to_le_bytes());
+                } else {
+                    // PriceLevelUpdate, alternating buy and sell
+                    message.extend_from_slice(&[if i % 2 == 0 { 0x38 } else { 0x35 }, 1]);
+                    message.extend_from_slice(&timestamp.to_le_bytes());
+                    message.extend_from_slice(symbol);
+                    message.extend_from_slice(&(200 + i as u32).to_le_bytes());
+                    message.extend_from_slice(&(1_499_000 + packet * 10 + i).to_le_bytes());
+                }
+                let mut prefixed = (message.len() as u16).to_le_bytes().to_vec();
+                prefixed.extend_from_slice(&message);
+                messages.push(prefixed);
+            }
+
+            payloads.push(sample::iex_payload(seq_no, &messages));
+            seq_no += msg_count;
+        }
+        sample::pcap(&payloads)
+    }
+
+    #[cfg(any(feature = "sbe", feature = "flatbuffers", feature = "capnp"))]
+    /// Allocations while serializing and deserializing the sample data a second
+    /// time, once every buffer has had the chance to grow to size.
+    fn steady_state<S, D>(serializer: &mut S, deserializer: &mut D) -> (AllocStats, AllocStats)
+    where
+        S: RunnerSerialize,
+        D: RunnerDeserialize,
+    {
+        let pcap = sample_pcap();
+        let iex_payloads: Vec<IexPayload> = IexParser::new(&pcap).collect();
+        let mut output = Vec::new();
+        let mut summarizer = Summarizer::default();
+
+        let mut allocs = (AllocStats::default(), AllocStats::default());
+        for _pass in 0..2 {
+            output.clear();
+            let allocs_start = current();
+            for iex_payload in &iex_payloads {
+                serializer.serialize(iex_payload, &mut output);
+            }
+            allocs.0 = current() - allocs_start;
+
+            let mut read_buf = StreamBuf::new(&output);
+            let allocs_start = current();
+            while deserializer
+                .deserialize(&mut read_buf, &mut summarizer)
+                .is_ok()
+            {}
+            allocs.1 = current() - allocs_start;
+        }
+        allocs
+    }
+
+    #[cfg(any(feature = "sbe", feature = "flatbuffers"))]
+    fn assert_allocation_free<S, D>(name: &str, serializer: &mut S, deserializer: &mut D)
+    where
+        S: RunnerSerialize,
+        D: RunnerDeserialize,
+    {
+        let (serialize, deserialize) = steady_state(serializer, deserializer);
+        assert_eq!(
+            serialize,
+            AllocStats::default(),
+            "{} allocated while serializing",
+            name
+        );
+        assert_eq!(
+            deserialize,
+            AllocStats::default(),
+            "{} allocated while deserializing",
+            name
+        );
+    }
+
+    #[test]
+    fn counts_allocations() {
+        let start = current();
+        let v: Vec<u64> = Vec::with_capacity(4);
+        drop(black_box(v));
+
+        let expected = AllocStats {
+            allocations: 1,
+            deallocations: 1,
+            bytes_allocated: 32,
+        };
+        assert_eq!(current() - start, expected);
+    }
+
+    #[cfg(feature = "sbe")]
+    #[test]
+    fn sbe_doesnt_allocate() {
+        use crate::{sbe_chained_runner, sbe_groups_runner, sbe_runner, sbe_templates_runner};
+
+        for &write_mode in &[WriteMode::Scratch, WriteMode::InPlace] {
+            assert_allocation_free(
+                "SBE",
+                &mut sbe_runner::SBEWriter::new(write_mode),
+                &mut sbe_runner::SBEReader::new(),
+            );
+            assert_allocation_free(
+                "SBE Templates",
+                &mut sbe_templates_runner::SBETemplatesWriter::new(write_mode),
+                &mut sbe_templates_runner::SBETemplatesReader::new(),
+            );
+            assert_allocation_free(
+                "SBE Groups",
+                &mut sbe_groups_runner::SBEGroupsWriter::new(write_mode),
+                &mut sbe_groups_runner::SBEGroupsReader::new(),
+            );
+            assert_allocation_free(
+                "SBE Chained",
+                &mut sbe_chained_runner::SBEChainedWriter::new(write_mode),
+                &mut sbe_chained_runner::SBEChainedReader::new(),
+            );
+        }
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    #[test]
+    fn flatbuffers_doesnt_allocate() {
+        use crate::flatbuffers_runner::{FlatbuffersReader, FlatbuffersWriter};
+
+        for &verify in &[false, true] {
+            assert_allocation_free(
+                "Flatbuffers",
+                &mut FlatbuffersWriter::new(),
+                &mut FlatbuffersReader::new(verify),
+            );
+        }
+    }
+
+    // Cap'n Proto can't get to zero: every `Builder` sets up an arena, and reading
+    // allocates a segment table. Those should stay small and be freed with each
+    // message, with the segments themselves re-used.
+    #[cfg(feature = "capnp")]
+    #[test]
+    fn capnp_allocates_a_bounded_amount_per_message() {
+        use crate::capnp_runner::{CapnpReader, CapnpWriter};
+
+        const MAX_ALLOCATIONS: u64 = 4;
+        const MAX_BYTES: u64 = 256;
+
+        // Packed messages are always built from scratch
+        let modes = [
+            (false, WriteMode::Scratch),
+            (false, WriteMode::InPlace),
+            (true, WriteMode::Scratch),
+        ];
+        for &(packed, write_mode) in &modes {
+            let mut writer = CapnpWriter::new(packed, write_mode);
+            let mut reader = CapnpReader::new(packed);
+
+            let (serialize, deserialize) = steady_state(&mut writer, &mut reader);
+            for (stage, stats) in &[("serialize", serialize), ("deserialize", deserialize)] {
+                let label = format!("{} packed={} {:?}", stage, packed, write_mode);
+                assert_eq!(
+                    stats.allocations, stats.deallocations,
+                    "{} kept memory",
+                    label
+                );
+                assert!(
+                    stats.allocations <= MAX_ALLOCATIONS * SAMPLE_PACKETS,
+                    "{}: {:?}",
+                    label,
+                    stats
+                );
+                assert!(
+                    stats.bytes_allocated <= MAX_BYTES * SAMPLE_PACKETS,
+                    "{}: {:?}",
+                    label,
+                    stats
+                );
+            }
+
+            // Running the same data through again shouldn't need bigger segments
+            let segment_bytes = writer.segment_bytes();
+            steady_state(&mut writer, &mut reader);
+            assert_eq!(
+                writer.segment_bytes(),
+                segment_bytes,
+                "packed={} {:?}",
+                packed,
+                write_mode
+            );
+        }
+    }
+}
@@ -0,0 +1,788 @@
+use std::cell::Cell;
+use std::convert::TryInto;
+use std::io::{BufRead, Read};
+
+use avro_rs::types::Value;
+use avro_rs::{from_avro_datum, to_avro_datum, Schema};
+
+use crate::framing::{SelfDelimiting, StreamBuf};
+use crate::iex::{IexMessage, IexPayload};
+use crate::verify::{NormalizedBody, NormalizedMessage};
+use crate::{RunnerDeserialize, RunnerSerialize, Summarizer};
+
+const WRITER_SCHEMA: &str = include_str!("../marketdata.avsc");
+/// Same as the writer schema, but with extra fields (that have defaults) added to
+/// `MultiMessage` and `Message`; reading with this schema forces Avro to resolve
+/// each datum against the schema it was written with.
+const READER_SCHEMA: &str = include_str!("../marketdata_v2.avsc");
+
+/// Single-object encoding marker: https://avro.apache.org/docs/1.9.0/spec.html#single_object_encoding
+const SINGLE_OBJECT_MARKER: [u8; 2] = [0xC3, 0x01];
+const CONTAINER_MAGIC: [u8; 4] = [b'O', b'b', b'j', 1];
+/// Container files are supposed to use a random sync marker; because we only ever
+/// read back what we wrote, a fixed (and easy to spot in a hex dump) marker is fine.
+const CONTAINER_SYNC: [u8; 16] = *b"md_shootout_sync";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AvroFraming {
+    /// Each `MultiMessage` is prefixed by the single-object marker and the
+    /// CRC-64-AVRO fingerprint of the writer schema.
+    SingleObject,
+    /// Output is an Avro object container file; the file header is written once,
+    /// and each `MultiMessage` is written as its own block.
+    Container,
+}
+
+pub struct AvroWriter {
+    schema: Schema,
+    framing: AvroFraming,
+    fingerprint: [u8; 8],
+    header_written: bool,
+}
+
+impl AvroWriter {
+    /// `avro-rs` only hands back encoded datums as a freshly-allocated `Vec`, so there's
+    /// no way to encode directly into the output, and so no in-place mode.
+    pub fn new(framing: AvroFraming) -> AvroWriter {
+        let schema = Schema::parse_str(WRITER_SCHEMA).unwrap();
+        let fingerprint = rabin_fingerprint(schema.canonical_form().as_bytes()).to_le_bytes();
+
+        AvroWriter {
+            schema,
+            framing,
+            fingerprint,
+            header_written: false,
+        }
+    }
+
+    fn write_container_header(&mut self, output: &mut Vec<u8>) {
+        output.extend_from_slice(&CONTAINER_MAGIC);
+
+        // File metadata is a `map<bytes>`, written as a single block
+        encode_long(2, output);
+        encode_bytes(b"avro.schema", output);
+        encode_bytes(WRITER_SCHEMA.as_bytes(), output);
+        encode_bytes(b"avro.codec", output);
+        encode_bytes(b"null", output);
+        encode_long(0, output);
+
+        output.extend_from_slice(&CONTAINER_SYNC);
+        self.header_written = true;
+    }
+}
+
+impl RunnerSerialize for AvroWriter {
+    fn serialize(&mut self, payload: &IexPayload, output: &mut Vec<u8>) {
+        // The Avro library works with a dynamically-typed `Value` tree, so there's no
+        // getting around allocating for every field (including the field names).
+        let messages: Vec<Value> = payload
+            .messages
+            .iter()
+            .map(|m| match m {
+                // System events aren't tied to a symbol, so it's left empty
+                IexMessage::SystemEvent(se) => message_value(
+                    se.timestamp,
+                    "",
+                    "SystemEvent",
+                    Value::Record(vec![field(
+                        "system_event",
+                        Value::Int(se.system_event as i32),
+                    )]),
+                ),
+                IexMessage::SecurityDirectory(sd) => message_value(
+                    sd.timestamp,
+                    crate::parse_symbol(&sd.symbol),
+                    "SecurityDirectory",
+                    Value::Record(vec![
+                        field("flags", Value::Int(sd.flags as i32)),
+                        field("lot_size", Value::Long(sd.lot_size as i64)),
+                        field("previous_closing", Value::Long(sd.previous_closing as i64)),
+                        field("luld_tier", Value::Int(sd.luld_tier as i32)),
+                    ]),
+                ),
+                IexMessage::TradingStatus(ts) => message_value(
+                    ts.timestamp,
+                    crate::parse_symbol(&ts.symbol),
+                    "TradingStatus",
+                    Value::Record(vec![
+                        field("trading_status", Value::Int(ts.trading_status as i32)),
+                        field(
+                            "reason",
+                            Value::String(crate::parse_reason(&ts.reason).to_owned()),
+                        ),
+                    ]),
+                ),
+                IexMessage::OperationalHaltStatus(ohs) => message_value(
+                    ohs.timestamp,
+                    crate::parse_symbol(&ohs.symbol),
+                    "OperationalHaltStatus",
+                    Value::Record(vec![field(
+                        "halt_status",
+                        Value::Int(ohs.halt_status as i32),
+                    )]),
+                ),
+                IexMessage::ShortSalePriceTest(sspt) => message_value(
+                    sspt.timestamp,
+                    crate::parse_symbol(&sspt.symbol),
+                    "ShortSalePriceTest",
+                    Value::Record(vec![
+                        field("sspt_status", Value::Int(sspt.sspt_status as i32)),
+                        field("detail", Value::Int(sspt.detail as i32)),
+                    ]),
+                ),
+                IexMessage::SecurityEvent(se) => message_value(
+                    se.timestamp,
+                    crate::parse_symbol(&se.symbol),
+                    "SecurityEvent",
+                    Value::Record(vec![field(
+                        "security_event",
+                        Value::Int(se.security_event as i32),
+                    )]),
+                ),
+                IexMessage::PriceLevelUpdate(plu) => message_value(
+                    plu.timestamp,
+                    crate::parse_symbol(&plu.symbol),
+                    "LevelUpdate",
+                    Value::Record(vec![
+                        field("price", Value::Long(plu.price as i64)),
+                        field("size", Value::Long(plu.size as i64)),
+                        field("flags", Value::Int(plu.event_flags as i32)),
+                        field(
+                            "side",
+                            if plu.msg_type == 0x38 {
+                                Value::Enum(0, "Buy".to_owned())
+                            } else {
+                                Value::Enum(1, "Sell".to_owned())
+                            },
+                        ),
+                    ]),
+                ),
+                IexMessage::TradeReport(tr) => message_value(
+                    tr.timestamp,
+                    crate::parse_symbol(&tr.symbol),
+                    "Trade",
+                    Value::Record(vec![
+                        field("price", Value::Long(tr.price as i64)),
+                        field("size", Value::Long(tr.size as i64)),
+                        field("sale_condition", Value::Int(tr.sale_condition as i32)),
+                        field("trade_id", Value::Long(tr.trade_id as i64)),
+                    ]),
+                ),
+                IexMessage::OfficialPrice(op) => message_value(
+                    op.timestamp,
+                    crate::parse_symbol(&op.symbol),
+                    "OfficialPrice",
+                    Value::Record(vec![
+                        field("price_type", Value::Int(op.price_type as i32)),
+                        field("official_price", Value::Long(op.official_price as i64)),
+                    ]),
+                ),
+                IexMessage::TradeBreak(tb) => message_value(
+                    tb.timestamp,
+                    crate::parse_symbol(&tb.symbol),
+                    "TradeBreak",
+                    Value::Record(vec![
+                        field("price", Value::Long(tb.price as i64)),
+                        field("size", Value::Long(tb.size as i64)),
+                        field("sale_condition", Value::Int(tb.sale_condition as i32)),
+                        field("trade_id", Value::Long(tb.trade_id as i64)),
+                    ]),
+                ),
+                IexMessage::AuctionInformation(ai) => message_value(
+                    ai.timestamp,
+                    crate::parse_symbol(&ai.symbol),
+                    "AuctionInformation",
+                    Value::Record(vec![
+                        field("auction_type", Value::Int(ai.auction_type as i32)),
+                        field("paired_shares", Value::Long(ai.paired_shares as i64)),
+                        field("reference_price", Value::Long(ai.reference_price as i64)),
+                        field(
+                            "indicative_clearing_price",
+                            Value::Long(ai.indicative_clearing_price as i64),
+                        ),
+                        field("imbalance_shares", Value::Long(ai.imbalance_shares as i64)),
+                        field("imbalance_side", Value::Int(ai.imbalance_side as i32)),
+                        field("extension_number", Value::Int(ai.extension_number as i32)),
+                        field(
+                            "scheduled_auction",
+                            Value::Long(ai.scheduled_auction as i64),
+                        ),
+                        field(
+                            "auction_book_clearing_price",
+                            Value::Long(ai.auction_book_clearing_price as i64),
+                        ),
+                        field(
+                            "collar_reference_price",
+                            Value::Long(ai.collar_reference_price as i64),
+                        ),
+                        field(
+                            "lower_auction_collar",
+                            Value::Long(ai.lower_auction_collar as i64),
+                        ),
+                        field(
+                            "upper_auction_collar",
+                            Value::Long(ai.upper_auction_collar as i64),
+                        ),
+                    ]),
+                ),
+            })
+            .collect();
+
+        let multimsg = Value::Record(vec![
+            field("seq_no", Value::Long(payload.first_seq_no as i64)),
+            field("messages", Value::Array(messages)),
+        ]);
+        let datum = to_avro_datum(&self.schema, multimsg).unwrap();
+
+        match self.framing {
+            AvroFraming::SingleObject => {
+                output.extend_from_slice(&SINGLE_OBJECT_MARKER);
+                output.extend_from_slice(&self.fingerprint);
+                output.extend_from_slice(&datum);
+            }
+            AvroFraming::Container => {
+                if !self.header_written {
+                    self.write_container_header(output);
+                }
+
+                encode_long(1, output);
+                encode_long(datum.len() as i64, output);
+                output.extend_from_slice(&datum);
+                output.extend_from_slice(&CONTAINER_SYNC);
+            }
+        }
+    }
+}
+
+/// Every `BodyType` symbol, along with the `Message` field that holds that type
+/// of body, in schema order.
+const BODY_TYPES: [(&str, &str); 11] = [
+    ("SystemEvent", "system_event"),
+    ("SecurityDirectory", "security_directory"),
+    ("TradingStatus", "trading_status"),
+    ("OperationalHaltStatus", "operational_halt_status"),
+    ("ShortSalePriceTest", "short_sale_price_test"),
+    ("SecurityEvent", "security_event"),
+    ("LevelUpdate", "level_update"),
+    ("Trade", "trade"),
+    ("OfficialPrice", "official_price"),
+    ("TradeBreak", "trade_break"),
+    ("AuctionInformation", "auction_information"),
+];
+
+fn message_value(ts: i64, symbol: &str, body_type: &str, body: Value) -> Value {
+    let index = BODY_TYPES
+        .iter()
+        .position(|(name, _)| *name == body_type)
+        .unwrap();
+
+    let mut fields = Vec::with_capacity(3 + BODY_TYPES.len());
+    fields.push(field("ts", Value::Long(ts)));
+    fields.push(field("symbol", Value::String(symbol.to_owned())));
+    fields.push(field(
+        "body_type",
+        Value::Enum(index as i32, body_type.to_owned()),
+    ));
+    // Only the field for this message's type is set; the rest are null
+    let mut body = Some(body);
+    for (i, (_, name)) in BODY_TYPES.iter().enumerate() {
+        let value = if i == index {
+            body.take().unwrap()
+        } else {
+            Value::Null
+        };
+        fields.push(field(name, Value::Union(Box::new(value))));
+    }
+    Value::Record(fields)
+}
+
+fn field(name: &str, value: Value) -> (String, Value) {
+    (name.to_owned(), value)
+}
+
+pub struct AvroReader {
+    writer_schema: Schema,
+    reader_schema: Option<Schema>,
+    framing: AvroFraming,
+    fingerprint: [u8; 8],
+    // `deserialize` only gets `&self`, but container files need to know
+    // whether the file header has been read yet.
+    header_read: Cell<bool>,
+}
+
+impl AvroReader {
+    /// When `resolve` is set, messages are read using a newer version of the schema
+    /// than they were written with, to measure the cost of Avro schema resolution.
+    pub fn new(framing: AvroFraming, resolve: bool) -> AvroReader {
+        let writer_schema = Schema::parse_str(WRITER_SCHEMA).unwrap();
+        let fingerprint =
+            rabin_fingerprint(writer_schema.canonical_form().as_bytes()).to_le_bytes();
+
+        AvroReader {
+            writer_schema,
+            reader_schema: if resolve {
+                Some(Schema::parse_str(READER_SCHEMA).unwrap())
+            } else {
+                None
+            },
+            framing,
+            fingerprint,
+            header_read: Cell::new(false),
+        }
+    }
+
+    fn read_container_header(&self, buf: &mut StreamBuf) -> Result<(), ()> {
+        let mut magic = [0u8; 4];
+        buf.read_exact(&mut magic).map_err(|_| ())?;
+        if magic != CONTAINER_MAGIC {
+            return Err(());
+        }
+
+        // We know what schema we wrote with, so the metadata is just skipped
+        loop {
+            let mut block_count = decode_long(buf)?;
+            if block_count == 0 {
+                break;
+            } else if block_count < 0 {
+                // Negative counts are followed by the block size in bytes
+                block_count = -block_count;
+                decode_long(buf)?;
+            }
+
+            for _ in 0..block_count {
+                skip_bytes(buf)?;
+                skip_bytes(buf)?;
+            }
+        }
+
+        let mut sync = [0u8; 16];
+        buf.read_exact(&mut sync).map_err(|_| ())?;
+        if sync != CONTAINER_SYNC {
+            return Err(());
+        }
+
+        self.header_read.set(true);
+        Ok(())
+    }
+
+    fn read_datum(&self, buf: &mut StreamBuf) -> Result<Value, ()> {
+        from_avro_datum(&self.writer_schema, buf, self.reader_schema.as_ref()).map_err(|_| ())
+    }
+
+    /// Read the next `MultiMessage`, along with the container file header if
+    /// `read_header` is set and we're using container framing.
+    fn read_multimessage(&self, buf: &mut StreamBuf, read_header: bool) -> Result<Value, ()> {
+        // Nothing says how long a datum is, so the frame ends wherever decoding stops
+        buf.read_frame(&SelfDelimiting, |data| {
+            let mut frame = StreamBuf::new(data);
+            let multimsg = self.decode_frame(&mut frame, read_header)?;
+            Ok((multimsg, frame.position()))
+        })
+    }
+
+    fn decode_frame(&self, buf: &mut StreamBuf, read_header: bool) -> Result<Value, ()> {
+        let multimsg = match self.framing {
+            AvroFraming::SingleObject => {
+                let mut header = [0u8; 10];
+                buf.read_exact(&mut header).map_err(|_| ())?;
+                if header[..2] != SINGLE_OBJECT_MARKER || header[2..] != self.fingerprint {
+                    return Err(());
+                }
+
+                self.read_datum(buf)?
+            }
+            AvroFraming::Container => {
+                if read_header {
+                    self.read_container_header(buf)?;
+                }
+
+                // Every block we write holds exactly one `MultiMessage`
+                let block_count = decode_long(buf)?;
+                let _block_size = decode_long(buf)?;
+                if block_count != 1 {
+                    return Err(());
+                }
+
+                let multimsg = self.read_datum(buf)?;
+
+                let mut sync = [0u8; 16];
+                buf.read_exact(&mut sync).map_err(|_| ())?;
+                if sync != CONTAINER_SYNC {
+                    return Err(());
+                }
+
+                multimsg
+            }
+        };
+
+        Ok(multimsg)
+    }
+}
+
+impl RunnerDeserialize for AvroReader {
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()> {
+        let multimsg = self.read_multimessage(buf, !self.header_read.get())?;
+
+        let messages = match record_field(&multimsg, "messages") {
+            Some(Value::Array(m)) => m,
+            _ => return Err(()),
+        };
+
+        for msg in messages {
+            let sym = match record_field(msg, "symbol") {
+                Some(Value::String(s)) => s,
+                _ => return Err(()),
+            };
+            match body_type(msg)? {
+                "Trade" => {
+                    let trade = body_fields(msg, "trade")?;
+                    stats.append_trade_volume(sym, long_field(trade, "size")? as u64);
+                }
+                "LevelUpdate" => {
+                    let update = body_fields(msg, "level_update")?;
+                    stats.update_quote_prices(
+                        sym,
+                        long_field(update, "price")? as u64,
+                        is_buy(update)?,
+                    );
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn decode(
+        &self,
+        buf: &mut StreamBuf,
+        messages: &mut Vec<NormalizedMessage>,
+    ) -> Result<u64, ()> {
+        // Decoding can start over from the beginning of a buffer that `deserialize`
+        // has already read the header from, so check for the header directly.
+        let read_header = buf.remaining().starts_with(&CONTAINER_MAGIC);
+        let multimsg = self.read_multimessage(buf, read_header)?;
+
+        let seq_no = match record_field(&multimsg, "seq_no") {
+            Some(Value::Long(s)) => *s as u64,
+            _ => return Err(()),
+        };
+        let msg_values = match record_field(&multimsg, "messages") {
+            Some(Value::Array(m)) => m,
+            _ => return Err(()),
+        };
+
+        for msg in msg_values {
+            let timestamp = match record_field(msg, "ts") {
+                Some(Value::Long(ts)) => *ts,
+                _ => return Err(()),
+            };
+            let symbol = match record_field(msg, "symbol") {
+                Some(Value::String(s)) => s.clone(),
+                _ => return Err(()),
+            };
+            let body_type = body_type(msg)?;
+            let field_name = BODY_TYPES
+                .iter()
+                .find(|(name, _)| *name == body_type)
+                .map(|(_, field_name)| *field_name)
+                .ok_or(())?;
+            let fields = body_fields(msg, field_name)?;
+
+            let body = match body_type {
+                "SystemEvent" => NormalizedBody::SystemEvent {
+                    system_event: int_field(fields, "system_event")? as u8,
+                },
+                "SecurityDirectory" => NormalizedBody::SecurityDirectory {
+                    flags: int_field(fields, "flags")? as u8,
+                    lot_size: u32_field(fields, "lot_size")?,
+                    previous_closing: long_field(fields, "previous_closing")? as u64,
+                    luld_tier: int_field(fields, "luld_tier")? as u8,
+                },
+                "TradingStatus" => NormalizedBody::TradingStatus {
+                    trading_status: int_field(fields, "trading_status")? as u8,
+                    reason: match record_field_in(fields, "reason") {
+                        Some(Value::String(r)) => r.as_bytes().try_into().map_err(|_| ())?,
+                        _ => return Err(()),
+                    },
+                },
+                "OperationalHaltStatus" => NormalizedBody::OperationalHaltStatus {
+                    halt_status: int_field(fields, "halt_status")? as u8,
+                },
+                "ShortSalePriceTest" => NormalizedBody::ShortSalePriceTest {
+                    sspt_status: int_field(fields, "sspt_status")? as u8,
+                    detail: int_field(fields, "detail")? as u8,
+                },
+                "SecurityEvent" => NormalizedBody::SecurityEvent {
+                    security_event: int_field(fields, "security_event")? as u8,
+                },
+                "LevelUpdate" => NormalizedBody::PriceLevelUpdate {
+                    is_buy: is_buy(fields)?,
+                    event_flags: int_field(fields, "flags")? as u8,
+                    size: u32_field(fields, "size")?,
+                    price: long_field(fields, "price")? as u64,
+                },
+                "Trade" => NormalizedBody::TradeReport {
+                    sale_condition: int_field(fields, "sale_condition")? as u8,
+                    size: u32_field(fields, "size")?,
+                    price: long_field(fields, "price")? as u64,
+                    trade_id: long_field(fields, "trade_id")? as u64,
+                },
+                "OfficialPrice" => NormalizedBody::OfficialPrice {
+                    price_type: int_field(fields, "price_type")? as u8,
+                    official_price: long_field(fields, "official_price")? as u64,
+                },
+                "TradeBreak" => NormalizedBody::TradeBreak {
+                    sale_condition: int_field(fields, "sale_condition")? as u8,
+                    size: u32_field(fields, "size")?,
+                    price: long_field(fields, "price")? as u64,
+                    trade_id: long_field(fields, "trade_id")? as u64,
+                },
+                "AuctionInformation" => NormalizedBody::AuctionInformation {
+                    auction_type: int_field(fields, "auction_type")? as u8,
+                    paired_shares: u32_field(fields, "paired_shares")?,
+                    reference_price: long_field(fields, "reference_price")? as u64,
+                    indicative_clearing_price: long_field(fields, "indicative_clearing_price")?
+                        as u64,
+                    imbalance_shares: u32_field(fields, "imbalance_shares")?,
+                    imbalance_side: int_field(fields, "imbalance_side")? as u8,
+                    extension_number: int_field(fields, "extension_number")? as u8,
+                    scheduled_auction: u32_field(fields, "scheduled_auction")?,
+                    auction_book_clearing_price: long_field(fields, "auction_book_clearing_price")?
+                        as u64,
+                    collar_reference_price: long_field(fields, "collar_reference_price")? as u64,
+                    lower_auction_collar: long_field(fields, "lower_auction_collar")? as u64,
+                    upper_auction_collar: long_field(fields, "upper_auction_collar")? as u64,
+                },
+                _ => return Err(()),
+            };
+
+            messages.push(NormalizedMessage {
+                timestamp,
+                symbol,
+                body,
+            });
+        }
+
+        Ok(seq_no)
+    }
+}
+
+fn record_field<'v>(record: &'v Value, name: &str) -> Option<&'v Value> {
+    match record {
+        Value::Record(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+        _ => None,
+    }
+}
+
+fn record_field_in<'v>(fields: &'v [(String, Value)], name: &str) -> Option<&'v Value> {
+    fields.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+}
+
+/// Which `BodyType` a `Message` holds.
+fn body_type(msg: &Value) -> Result<&str, ()> {
+    match record_field(msg, "body_type") {
+        Some(Value::Enum(_, body_type)) => Ok(body_type),
+        _ => Err(()),
+    }
+}
+
+/// The fields of the body stored in `field_name`, which has to be set.
+fn body_fields<'v>(msg: &'v Value, field_name: &str) -> Result<&'v [(String, Value)], ()> {
+    match record_field(msg, field_name) {
+        Some(Value::Union(body)) => match **body {
+            Value::Record(ref fields) => Ok(fields),
+            _ => Err(()),
+        },
+        // Schema resolution hands back the union's value without the `Union` around it
+        Some(Value::Record(fields)) => Ok(fields),
+        _ => Err(()),
+    }
+}
+
+fn is_buy(level_update: &[(String, Value)]) -> Result<bool, ()> {
+    match record_field_in(level_update, "side") {
+        Some(Value::Enum(side, _)) => Ok(*side == 0),
+        _ => Err(()),
+    }
+}
+
+fn int_field(fields: &[(String, Value)], name: &str) -> Result<i32, ()> {
+    match record_field_in(fields, name) {
+        Some(Value::Int(i)) => Ok(*i),
+        _ => Err(()),
+    }
+}
+
+fn long_field(fields: &[(String, Value)], name: &str) -> Result<i64, ()> {
+    match record_field_in(fields, name) {
+        Some(Value::Long(l)) => Ok(*l),
+        _ => Err(()),
+    }
+}
+
+/// Unsigned 32-bit IEX fields are stored as `long`, since an Avro `int` is signed.
+fn u32_field(fields: &[(String, Value)], name: &str) -> Result<u32, ()> {
+    long_field(fields, name)?.try_into().map_err(|_| ())
+}
+
+/// Avro's CRC-64-AVRO ("Rabin") fingerprint, as described in the spec:
+/// https://avro.apache.org/docs/1.9.0/spec.html#schema_fingerprints
+fn rabin_fingerprint(buf: &[u8]) -> u64 {
+    const EMPTY: u64 = 0xc15d213aa4d7a795;
+
+    let mut fp = EMPTY;
+    for b in buf {
+        let mut entry = (fp ^ *b as u64) & 0xff;
+        for _ in 0..8 {
+            entry = (entry >> 1) ^ (EMPTY & (entry & 1).wrapping_neg());
+        }
+        fp = (fp >> 8) ^ entry;
+    }
+    fp
+}
+
+// The Avro library keeps its zig-zag encoding private, so the framing code
+// has to carry its own copy.
+fn encode_long(n: i64, output: &mut Vec<u8>) {
+    let mut z = ((n << 1) ^ (n >> 63)) as u64;
+    while z & !0x7f != 0 {
+        output.push((z & 0x7f) as u8 | 0x80);
+        z >>= 7;
+    }
+    output.push(z as u8);
+}
+
+fn encode_bytes(b: &[u8], output: &mut Vec<u8>) {
+    encode_long(b.len() as i64, output);
+    output.extend_from_slice(b);
+}
+
+fn decode_long<R: Read>(input: &mut R) -> Result<i64, ()> {
+    let mut z = 0u64;
+    let mut byte = [0u8; 1];
+    for shift in (0..64).step_by(7) {
+        input.read_exact(&mut byte).map_err(|_| ())?;
+        z |= ((byte[0] & 0x7f) as u64) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok((z >> 1) as i64 ^ -((z & 1) as i64));
+        }
+    }
+    Err(())
+}
+
+fn skip_bytes(buf: &mut StreamBuf) -> Result<(), ()> {
+    let len = decode_long(buf)?;
+    if len < 0 || buf.remaining().len() < len as usize {
+        return Err(());
+    }
+    buf.consume(len as usize);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use avro_rs::types::Value;
+    use avro_rs::{from_avro_datum, Schema};
+
+    use super::{
+        body_fields, record_field, record_field_in, AvroFraming, AvroReader, AvroWriter,
+        WRITER_SCHEMA,
+    };
+    use crate::framing::StreamBuf;
+    use crate::iex::IexPayload;
+    use crate::sample;
+    use crate::verify::NormalizedMessage;
+    use crate::{RunnerDeserialize, RunnerSerialize, Summarizer};
+
+    fn round_trip(framing: AvroFraming, resolve: bool) {
+        let payloads: Vec<Vec<u8>> = (0..3)
+            .map(|n| sample::iex_payload(n * 12, &sample::every_message_type(b"ZIEXT   ", n)))
+            .collect();
+
+        // Container files only get a header ahead of the first block
+        let mut writer = AvroWriter::new(framing);
+        let mut output = Vec::new();
+        for payload in &payloads {
+            writer.serialize(&IexPayload::parse(payload).unwrap().1, &mut output);
+        }
+
+        let reader = AvroReader::new(framing, resolve);
+        let mut buf = StreamBuf::new(&output);
+        for (n, payload) in payloads.iter().enumerate() {
+            let (_, payload) = IexPayload::parse(payload).unwrap();
+            let expected: Vec<_> = payload
+                .messages
+                .iter()
+                .map(NormalizedMessage::from)
+                .collect();
+
+            let mut decoded = Vec::new();
+            assert_eq!(reader.decode(&mut buf, &mut decoded), Ok(n as u64 * 12));
+            assert_eq!(decoded, expected);
+        }
+        assert!(buf.remaining().is_empty());
+
+        let reader = AvroReader::new(framing, resolve);
+        let mut stats = Summarizer::default();
+        buf.rewind();
+        for _ in &payloads {
+            reader.deserialize(&mut buf, &mut stats).unwrap();
+        }
+        assert!(buf.remaining().is_empty());
+    }
+
+    #[test]
+    fn single_object_round_trip() {
+        round_trip(AvroFraming::SingleObject, false);
+    }
+
+    #[test]
+    fn single_object_resolved_round_trip() {
+        round_trip(AvroFraming::SingleObject, true);
+    }
+
+    #[test]
+    fn container_round_trip() {
+        round_trip(AvroFraming::Container, false);
+    }
+
+    #[test]
+    fn container_resolved_round_trip() {
+        round_trip(AvroFraming::Container, true);
+    }
+
+    /// Unsigned 32-bit fields have to come out of any Avro reader the way they
+    /// went in, not just out of ours.
+    #[test]
+    fn unsigned_fields_stay_positive() {
+        let mut messages = sample::every_message_type(b"ZIEXT   ", 0);
+        // The trade report's size, after its length, type, flags, timestamp and symbol
+        messages[8][20..24].copy_from_slice(&3_000_000_000u32.to_le_bytes());
+        let payload = sample::iex_payload(0, &messages);
+        let (_, payload) = IexPayload::parse(&payload).unwrap();
+
+        let mut writer = AvroWriter::new(AvroFraming::SingleObject);
+        let mut output = Vec::new();
+        writer.serialize(&payload, &mut output);
+
+        // Skip the marker and fingerprint, and read the datum with nothing but the schema
+        let schema = Schema::parse_str(WRITER_SCHEMA).unwrap();
+        let multimsg = from_avro_datum(&schema, &mut &output[10..], None).unwrap();
+        let trade = match record_field(&multimsg, "messages") {
+            Some(Value::Array(messages)) => body_fields(&messages[8], "trade").unwrap(),
+            _ => panic!("No messages"),
+        };
+        assert_eq!(
+            record_field_in(trade, "size"),
+            Some(&Value::Long(3_000_000_000))
+        );
+
+        let reader = AvroReader::new(AvroFraming::SingleObject, false);
+        let mut decoded = Vec::new();
+        reader
+            .decode(&mut StreamBuf::new(&output), &mut decoded)
+            .unwrap();
+        assert_eq!(decoded[8], NormalizedMessage::from(&payload.messages[8]));
+    }
+}
@@ -1,136 +1,360 @@
+use std::cell::RefCell;
+use std::cmp::max;
 use std::convert::TryInto;
-use std::io::{BufRead, Read};
-use std::mem::size_of;
+use std::io::Read;
+use std::mem::{align_of, size_of};
 
-use capnp::message::{ReaderOptions, ScratchSpace, ScratchSpaceHeapAllocator};
+use capnp::message::{Allocator, ReaderOptions};
 use capnp::serialize::write_message;
-use capnp::serialize_packed::{
-    read_message as read_message_packed, write_message as write_message_packed,
-};
 use capnp::Error;
 
+use crate::framing::{SegmentTable, SelfDelimiting, StreamBuf};
 use crate::iex::{IexMessage, IexPayload};
 use crate::marketdata_capnp::message;
 use crate::marketdata_capnp::{multi_message, Side};
-use crate::{RunnerDeserialize, RunnerSerialize, StreamVec, Summarizer};
+use crate::verify::{NormalizedBody, NormalizedMessage};
+use crate::{RunnerDeserialize, RunnerSerialize, Summarizer, WriteMode};
 
-pub struct CapnpWriter<'a> {
+pub struct CapnpWriter {
     // We have to be very careful with how messages are built, as running
     // `init_root` and rebuilding will still accumulate garbage if using
     // the standard HeapAllocator.
     // https://github.com/capnproto/capnproto-rust/issues/111
-    _words: Vec<capnp::Word>,
-    scratch: ScratchSpace<'a>,
+    // Instead, each message gets a new `Builder`, and `ReusableAllocator` hands it
+    // segments from memory that we hold on to between messages.
+    segments: Vec<Vec<capnp::Word>>,
     packed: bool,
+    // Packed messages are written out unpacked first, then packed from this buffer.
+    unpacked: Vec<u8>,
+    write_mode: WriteMode,
 }
 
-impl<'a> CapnpWriter<'a> {
-    pub fn new(packed: bool) -> CapnpWriter<'a> {
-        // Cap'n'Proto words are 8 bytes, MTU is 1500 bytes, theoretically need only 188 words.
-        // In practice, let's just make sure everything fits.
-        let mut words = capnp::Word::allocate_zeroed_vec(1024);
-
-        let scratch = ScratchSpace::new(unsafe { std::mem::transmute(&mut words[..]) });
-
+impl CapnpWriter {
+    pub fn new(packed: bool, write_mode: WriteMode) -> CapnpWriter {
         CapnpWriter {
-            _words: words,
-            scratch,
+            // Cap'n'Proto words are 8 bytes, MTU is 1500 bytes, theoretically need only 188 words.
+            // In practice, let's just make sure everything fits.
+            segments: vec![capnp::Word::allocate_zeroed_vec(FIRST_SEGMENT_WORDS)],
             packed,
+            unpacked: Vec::with_capacity(FIRST_SEGMENT_WORDS * size_of::<capnp::Word>()),
+            write_mode,
         }
     }
 
-    fn builder(&mut self) -> capnp::message::Builder<ScratchSpaceHeapAllocator<'a, 'a>> {
-        // Builders are only safe to use for serializing a single message. We can re-use the
-        // backing memory (unsafe because now both `self` and the returned builder have a
-        // mutable reference to `self.scratch), but Bad Things happen if we don't drop
-        // in between serialization.
-        capnp::message::Builder::new(ScratchSpaceHeapAllocator::new(unsafe {
-            std::mem::transmute(&mut self.scratch)
-        }))
+    /// Bytes held in segments kept between messages.
+    #[cfg(test)]
+    pub(crate) fn segment_bytes(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|s| s.len() * size_of::<capnp::Word>())
+            .sum()
+    }
+
+    /// When `first_segment` is provided, the builder uses it for the first segment
+    /// of the message instead of memory owned by the writer.
+    fn builder<'a>(
+        &'a mut self,
+        first_segment: Option<&'a mut [capnp::Word]>,
+    ) -> capnp::message::Builder<ReusableAllocator<'a>> {
+        // Builders are only safe to use for serializing a single message; the borrow
+        // of `self.segments` guarantees that the builder is dropped (and the memory
+        // it used is zeroed) before the next message is serialized.
+        debug_assert!(
+            self.segments[0].iter().all(|w| *w == ZERO_WORD),
+            "Previous message left garbage in the first segment"
+        );
+
+        capnp::message::Builder::new(ReusableAllocator {
+            segments: &mut self.segments,
+            first_segment,
+            next_segment: 0,
+        })
+    }
+
+    fn serialize_scratch(&mut self, payload: &IexPayload, num_msgs: usize, output: &mut Vec<u8>) {
+        // The builder borrows all of `self`, so the unpacking buffer is moved out while it's alive
+        let mut unpacked = std::mem::take(&mut self.unpacked);
+        let packed = self.packed;
+
+        // This is the faster version that re-uses memory
+        let mut builder = self.builder(None);
+        build_multimessage(&mut builder, payload, num_msgs);
+
+        if packed {
+            // capnp 0.10's `serialize_packed::write_message` can corrupt the heap, so
+            // we pack the finished message ourselves.
+            unpacked.clear();
+            write_message(&mut unpacked, &builder).unwrap();
+            drop(builder);
+            pack_message(&unpacked, output);
+        } else {
+            write_message(output, &builder).unwrap();
+            drop(builder);
+        }
+        self.unpacked = unpacked;
+    }
+
+    /// Build the message with its first segment sitting directly in `output`, right
+    /// after space reserved for the segment table. This only works for unpacked
+    /// messages; packing has to transform the finished segments.
+    fn serialize_in_place(&mut self, payload: &IexPayload, num_msgs: usize, output: &mut Vec<u8>) {
+        let start = output.len();
+        let segment_words = IN_PLACE_WORDS_PER_MESSAGE * (num_msgs + 1);
+        output.resize(
+            start + SEGMENT_TABLE_BYTES + segment_words * size_of::<capnp::Word>(),
+            0,
+        );
+
+        let (table, segment) = output[start..].split_at_mut(SEGMENT_TABLE_BYTES);
+        if !(segment.as_ptr() as usize).is_multiple_of(align_of::<capnp::Word>()) {
+            // Every message we write is a whole number of words, so this only happens
+            // if the output buffer itself isn't word-aligned.
+            output.truncate(start);
+            return self.serialize_scratch(payload, num_msgs, output);
+        }
+        let segment = unsafe { capnp::Word::bytes_to_words_mut(segment) };
+        let segment_ptr = segment.as_ptr();
+
+        let mut builder = self.builder(Some(segment));
+        build_multimessage(&mut builder, payload, num_msgs);
+
+        let segments = builder.get_segments_for_output();
+        let written = if segments.len() == 1 && segments[0].as_ptr() == segment_ptr {
+            // Single-segment table: segment count minus one, then the segment length in words
+            table[..4].copy_from_slice(&0u32.to_le_bytes());
+            table[4..].copy_from_slice(&(segments[0].len() as u32).to_le_bytes());
+            Ok(segments[0].len())
+        } else {
+            // The message didn't fit in the reserved space; the segment table would
+            // need more room, so fall back to copying the whole message.
+            Err(capnp::serialize::write_message_to_words(&builder))
+        };
+        drop(builder);
+
+        match written {
+            Ok(words) => {
+                output.truncate(start + SEGMENT_TABLE_BYTES + words * size_of::<capnp::Word>())
+            }
+            Err(message) => {
+                output.truncate(start);
+                output.extend_from_slice(capnp::Word::words_to_bytes(&message));
+            }
+        }
     }
 }
 
-impl<'a> RunnerSerialize for CapnpWriter<'a> {
-    fn serialize(&mut self, payload: &IexPayload, mut output: &mut Vec<u8>) {
-        // First, count the messages we actually care about.
-        let num_msgs = payload
-            .messages
-            .iter()
-            .map(|m| match m {
-                IexMessage::TradeReport(_) | IexMessage::PriceLevelUpdate(_) => 1,
-                _ => 0,
-            })
-            .fold(0, |sum, i| sum + i);
+const FIRST_SEGMENT_WORDS: usize = 1024;
+/// Upper bound on the words needed per message when building in place (message
+/// struct, message body and symbol text), with room to spare. The widest body
+/// is `AuctionInformation` at 8 words.
+const IN_PLACE_WORDS_PER_MESSAGE: usize = 24;
+/// Segment table for a single-segment message: segment count and segment length.
+const SEGMENT_TABLE_BYTES: usize = 8;
+const ZERO_WORD: capnp::Word = capnp::word(0, 0, 0, 0, 0, 0, 0, 0);
+
+/// Allocator that hands out segments owned by a `CapnpWriter`, so that building
+/// a message doesn't need to allocate once the segments are large enough.
+pub struct ReusableAllocator<'a> {
+    segments: &'a mut Vec<Vec<capnp::Word>>,
+    /// Memory to use for the first segment instead of `segments[0]`
+    first_segment: Option<&'a mut [capnp::Word]>,
+    next_segment: usize,
+}
+
+unsafe impl<'a> Allocator for ReusableAllocator<'a> {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut capnp::Word, u32) {
+        let idx = self.next_segment;
+        self.next_segment += 1;
+
+        let minimum_size = minimum_size as usize;
+        if idx == 0 {
+            match self.first_segment.take() {
+                Some(segment) if segment.len() >= minimum_size => {
+                    let (ptr, len) = (segment.as_mut_ptr(), segment.len());
+                    self.first_segment = Some(segment);
+                    return (ptr, len as u32);
+                }
+                // Too small to be useful; `segments[0]` is used (and cleaned up) as normal
+                _ => (),
+            }
+        }
+
+        // Segments that grew for an earlier message are only handed out at the size a
+        // fresh writer would use, so a message's layout doesn't depend on what came before.
+        let size = max(minimum_size, FIRST_SEGMENT_WORDS);
+        if idx == self.segments.len() {
+            self.segments.push(capnp::Word::allocate_zeroed_vec(size));
+        } else if self.segments[idx].len() < size {
+            // Segments are only handed out once per message, so nobody
+            // is holding a pointer into the segment being replaced.
+            self.segments[idx] = capnp::Word::allocate_zeroed_vec(size);
+        } else if idx > 0 {
+            // `pre_drop` only tells us how much of the first segment was used,
+            // so everything else gets zeroed when it's re-used.
+            for w in self.segments[idx][..size].iter_mut() {
+                *w = ZERO_WORD;
+            }
+        }
+
+        (self.segments[idx].as_mut_ptr(), size as u32)
+    }
+
+    fn pre_drop(&mut self, segment0_currently_allocated: u32) {
+        if self.first_segment.is_some() {
+            // The first segment is the message itself, and not ours to clean up.
+            return;
+        }
+
+        for w in self.segments[0][..segment0_currently_allocated as usize].iter_mut() {
+            *w = ZERO_WORD;
+        }
+    }
+}
+
+impl RunnerSerialize for CapnpWriter {
+    fn serialize(&mut self, payload: &IexPayload, output: &mut Vec<u8>) {
+        // Every IEX message gets written, but heartbeat payloads don't have any.
+        let num_msgs = payload.messages.len();
 
         if num_msgs == 0 {
             return;
         }
 
-        // And actually serialize the IEX payload to CapNProto format
+        if self.write_mode == WriteMode::InPlace && !self.packed {
+            self.serialize_in_place(payload, num_msgs, output);
+        } else {
+            self.serialize_scratch(payload, num_msgs, output);
+        }
+    }
+}
 
-        // This is the safe builder used for testing
-        //let mut builder = capnp::message::Builder::new_default();
-        //let mut multimsg = builder.init_root::<multi_message::Builder>();
+fn build_multimessage<A: Allocator>(
+    builder: &mut capnp::message::Builder<A>,
+    payload: &IexPayload,
+    num_msgs: usize,
+) {
+    // And actually serialize the IEX payload to CapNProto format
 
-        // This is the unsafe (but faster) version
-        let mut builder = self.builder();
-        let mut multimsg = builder.init_root::<multi_message::Builder>();
+    // This is the safe builder used for testing
+    //let mut builder = capnp::message::Builder::new_default();
+    //let mut multimsg = builder.init_root::<multi_message::Builder>();
 
-        multimsg.set_seq_no(payload.first_seq_no);
+    let mut multimsg = builder.init_root::<multi_message::Builder>();
 
-        let mut messages = multimsg.init_messages(num_msgs as u32);
-        let mut current_msg_no = 0;
-        for iex_msg in payload.messages.iter() {
-            match iex_msg {
-                IexMessage::TradeReport(tr) => {
-                    let mut message = messages.reborrow().get(current_msg_no);
-                    current_msg_no += 1;
-                    message.set_ts(tr.timestamp);
+    multimsg.set_seq_no(payload.first_seq_no);
 
-                    let sym = crate::parse_symbol(&tr.symbol);
-                    message.reborrow().init_symbol(sym.len() as u32);
-                    message.set_symbol(sym);
+    let mut messages = multimsg.init_messages(num_msgs as u32);
+    for (i, iex_msg) in payload.messages.iter().enumerate() {
+        let mut message = messages.reborrow().get(i as u32);
 
-                    let mut msg_tr = message.init_trade();
-                    msg_tr.set_size(tr.size);
-                    msg_tr.set_price(tr.price);
-                }
-                IexMessage::PriceLevelUpdate(plu) => {
-                    let mut message = messages.reborrow().get(current_msg_no);
-                    current_msg_no += 1;
-                    message.set_ts(plu.timestamp);
-
-                    let sym = crate::parse_symbol(&plu.symbol);
-                    message.reborrow().init_symbol(sym.len() as u32);
-                    message.set_symbol(sym);
-
-                    let mut msg_plu = message.init_quote();
-                    msg_plu.set_price(plu.price);
-                    msg_plu.set_size(plu.size);
-                    msg_plu.set_flags(plu.event_flags);
-                    msg_plu.set_side(if plu.msg_type == 0x38 {
-                        Side::Buy
-                    } else {
-                        Side::Sell
-                    });
-                }
-                _ => (),
+        match iex_msg {
+            IexMessage::SystemEvent(se) => {
+                // System events aren't tied to a symbol, so it's left unset
+                message.set_ts(se.timestamp);
+                message
+                    .init_system_event()
+                    .set_system_event(se.system_event);
+            }
+            IexMessage::SecurityDirectory(sd) => {
+                set_header(message.reborrow(), sd.timestamp, &sd.symbol);
+                let mut msg_sd = message.init_security_directory();
+                msg_sd.set_flags(sd.flags);
+                msg_sd.set_lot_size(sd.lot_size);
+                msg_sd.set_previous_closing(sd.previous_closing);
+                msg_sd.set_luld_tier(sd.luld_tier);
+            }
+            IexMessage::TradingStatus(ts) => {
+                set_header(message.reborrow(), ts.timestamp, &ts.symbol);
+                let mut msg_ts = message.init_trading_status();
+                msg_ts.set_trading_status(ts.trading_status);
+                msg_ts.set_reason(crate::parse_reason(&ts.reason));
+            }
+            IexMessage::OperationalHaltStatus(ohs) => {
+                set_header(message.reborrow(), ohs.timestamp, &ohs.symbol);
+                message
+                    .init_operational_halt_status()
+                    .set_halt_status(ohs.halt_status);
+            }
+            IexMessage::ShortSalePriceTest(sspt) => {
+                set_header(message.reborrow(), sspt.timestamp, &sspt.symbol);
+                let mut msg_sspt = message.init_short_sale_price_test();
+                msg_sspt.set_sspt_status(sspt.sspt_status);
+                msg_sspt.set_detail(sspt.detail);
+            }
+            IexMessage::SecurityEvent(se) => {
+                set_header(message.reborrow(), se.timestamp, &se.symbol);
+                message
+                    .init_security_event()
+                    .set_security_event(se.security_event);
+            }
+            IexMessage::PriceLevelUpdate(plu) => {
+                set_header(message.reborrow(), plu.timestamp, &plu.symbol);
+                let mut msg_plu = message.init_quote();
+                msg_plu.set_price(plu.price);
+                msg_plu.set_size(plu.size);
+                msg_plu.set_flags(plu.event_flags);
+                msg_plu.set_side(if plu.msg_type == 0x38 {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                });
+            }
+            IexMessage::TradeReport(tr) => {
+                set_header(message.reborrow(), tr.timestamp, &tr.symbol);
+                let mut msg_tr = message.init_trade();
+                msg_tr.set_size(tr.size);
+                msg_tr.set_price(tr.price);
+                msg_tr.set_sale_condition(tr.sale_condition);
+                msg_tr.set_trade_id(tr.trade_id);
+            }
+            IexMessage::OfficialPrice(op) => {
+                set_header(message.reborrow(), op.timestamp, &op.symbol);
+                let mut msg_op = message.init_official_price();
+                msg_op.set_price_type(op.price_type);
+                msg_op.set_official_price(op.official_price);
+            }
+            IexMessage::TradeBreak(tb) => {
+                set_header(message.reborrow(), tb.timestamp, &tb.symbol);
+                let mut msg_tb = message.init_trade_break();
+                msg_tb.set_size(tb.size);
+                msg_tb.set_price(tb.price);
+                msg_tb.set_sale_condition(tb.sale_condition);
+                msg_tb.set_trade_id(tb.trade_id);
+            }
+            IexMessage::AuctionInformation(ai) => {
+                set_header(message.reborrow(), ai.timestamp, &ai.symbol);
+                let mut msg_ai = message.init_auction_information();
+                msg_ai.set_auction_type(ai.auction_type);
+                msg_ai.set_paired_shares(ai.paired_shares);
+                msg_ai.set_reference_price(ai.reference_price);
+                msg_ai.set_indicative_clearing_price(ai.indicative_clearing_price);
+                msg_ai.set_imbalance_shares(ai.imbalance_shares);
+                msg_ai.set_imbalance_side(ai.imbalance_side);
+                msg_ai.set_extension_number(ai.extension_number);
+                msg_ai.set_scheduled_auction(ai.scheduled_auction);
+                msg_ai.set_auction_book_clearing_price(ai.auction_book_clearing_price);
+                msg_ai.set_collar_reference_price(ai.collar_reference_price);
+                msg_ai.set_lower_auction_collar(ai.lower_auction_collar);
+                msg_ai.set_upper_auction_collar(ai.upper_auction_collar);
             }
         }
+    }
+}
 
-        let write_fn = if self.packed {
-            write_message_packed
-        } else {
-            write_message
-        };
+fn set_header(mut message: message::Builder, ts: i64, symbol: &[u8; 8]) {
+    message.set_ts(ts);
 
-        write_fn(&mut output, &builder).unwrap();
-    }
+    let sym = crate::parse_symbol(symbol);
+    message.reborrow().init_symbol(sym.len() as u32);
+    message.set_symbol(sym);
 }
 
 pub struct CapnpReader {
     read_opts: ReaderOptions,
     packed: bool,
+    // Packed messages are unpacked into this buffer before reading; `deserialize`
+    // only gets `&self`, so we need interior mutability to re-use it.
+    unpacked: RefCell<Vec<capnp::Word>>,
 }
 
 impl CapnpReader {
@@ -138,116 +362,241 @@ impl CapnpReader {
         CapnpReader {
             read_opts: ReaderOptions::new(),
             packed,
+            // Same sizing logic as `CapnpWriter`; the buffer will grow if necessary.
+            unpacked: RefCell::new(Vec::with_capacity(1024)),
         }
     }
 }
 
 impl CapnpReader {
-    fn deserialize_packed<'a>(
-        &self,
-        buf: &'a mut StreamVec,
-        stats: &mut Summarizer,
-    ) -> Result<(), ()> {
-        // Because `capnp::serialize_packed::PackedRead` is hidden from us, packed reads
-        // *have* to both allocate new segments every read, and copy the buffer into
-        // those same segments, no ability to re-use allocated memory.
-        let reader = read_message_packed(buf, self.read_opts).map_err(|_| ())?;
-
-        let multimsg = reader.get_root::<multi_message::Reader>().unwrap();
-        for msg in multimsg.get_messages().unwrap().iter() {
-            match msg.which() {
-                Ok(message::Trade(tr)) => {
-                    let tr = tr.unwrap();
-                    stats.append_trade_volume(msg.get_symbol().unwrap(), tr.get_size() as u64);
-                }
-                Ok(message::Quote(q)) => {
-                    let q = q.unwrap();
-                    let is_bid = match q.get_side().unwrap() {
-                        Side::Buy => true,
-                        _ => false,
-                    };
-                    stats.update_quote_prices(msg.get_symbol().unwrap(), q.get_price(), is_bid);
-                }
-                _ => panic!("Unrecognized message type!"),
+    /// Read the next message from `buf` and hand it to `read`, only consuming it if `read` succeeds.
+    fn read_packed<F, T>(&self, buf: &mut StreamBuf, read: F) -> Result<T, ()>
+    where
+        F: FnOnce(&capnp::message::Reader<SliceSegments>) -> Result<T, ()>,
+    {
+        // The segment table is packed along with the rest of the message,
+        // so the only way to find the end is to unpack it
+        buf.read_frame(&SelfDelimiting, |data| {
+            /*
+            Because `capnp::serialize_packed::PackedRead` is hidden from us, `read_message_packed`
+            has to both allocate new segments every read, and copy the buffer into those
+            same segments. Instead, we unpack into a word buffer that gets re-used between
+            messages, and then read it using `SliceSegments` the same way unpacked messages are.
+
+            let reader = read_message_packed(buf, self.read_opts).map_err(|_| ())?;
+            */
+            let mut unpacked = self.unpacked.borrow_mut();
+            let bytes_consumed = unpack_message(data, &mut unpacked)?;
+
+            let mut words_data = capnp::Word::words_to_bytes(&unpacked[..]);
+            let (_num_words, offsets) =
+                read_segment_table(&mut words_data, self.read_opts).map_err(|_| ())?;
+            let words = unsafe { capnp::Word::bytes_to_words(words_data) };
+            let reader = capnp::message::Reader::new(
+                SliceSegments {
+                    words,
+                    segment_slices: offsets,
+                },
+                self.read_opts,
+            );
+
+            let result = read(&reader)?;
+            Ok((result, bytes_consumed))
+        })
+    }
+
+    /// Same as `read_packed`, but for the standard (unpacked) encoding.
+    fn read_unpacked<F, T>(&self, buf: &mut StreamBuf, read: F) -> Result<T, ()>
+    where
+        F: FnOnce(&capnp::message::Reader<SliceSegments>) -> Result<T, ()>,
+    {
+        // `SegmentTable` works out how long the message is from the segment table,
+        // so `frame` holds exactly one message.
+        buf.read_frame(&SegmentTable, |frame| {
+            let mut data = frame;
+            let reader_opts = ReaderOptions::default();
+
+            /*
+            Read into `OwnedSegments`, which means we copy the entire message into a new Vec.
+
+            let reader = capnp::serialize::read_message(&mut data, reader_opts)?;
+            */
+
+            /*
+            Read into `SliceSegments`, which allows us to re-use the underlying message storage,
+            but still forces a Vec allocation for `offsets`. Also requires us to copy code from
+            Cap'n'Proto because `SliceSegments` has private fields, and `read_segment_table`
+            is private. And all this because `read_segment_from_words` has a length check
+            that triggers an error if our buffer is too large. What the hell?
+            */
+            let (_num_words, offsets) =
+                read_segment_table(&mut data, reader_opts).map_err(|_| ())?;
+            let words = unsafe { capnp::Word::bytes_to_words(data) };
+            let reader = capnp::message::Reader::new(
+                SliceSegments {
+                    words,
+                    segment_slices: offsets,
+                },
+                reader_opts,
+            );
+
+            let result = read(&reader)?;
+            Ok((result, frame.len()))
+        })
+    }
+}
+
+fn summarize<S>(reader: &capnp::message::Reader<S>, stats: &mut Summarizer) -> Result<(), ()>
+where
+    S: capnp::message::ReaderSegments,
+{
+    let multimsg = reader.get_root::<multi_message::Reader>().map_err(|_| ())?;
+    for msg in multimsg.get_messages().map_err(|_| ())?.iter() {
+        let sym = msg.get_symbol().map_err(|_| ())?;
+
+        match msg.which().map_err(|_| ())? {
+            message::Trade(trade) => {
+                let trade = trade.unwrap();
+                stats.append_trade_volume(sym, trade.get_size().into());
             }
+            message::Quote(quote) => {
+                let quote = quote.unwrap();
+                let is_buy = matches!(quote.get_side().unwrap(), Side::Buy);
+                stats.update_quote_prices(sym, quote.get_price(), is_buy);
+            }
+            _ => (),
         }
-        Ok(())
     }
 
-    fn deserialize_unpacked(&self, buf: &mut StreamVec, stats: &mut Summarizer) -> Result<(), ()> {
-        let mut data = buf.fill_buf().map_err(|_| ())?;
-        if data.len() == 0 {
-            return Err(());
-        }
+    Ok(())
+}
 
-        let orig_data = data;
-        let reader_opts = ReaderOptions::default();
-
-        /*
-        Read into `OwnedSegments`, which means we copy the entire message into a new Vec. Note that
-        the `data` pointer is modified underneath us, can figure out the message length by
-        checking the difference between where we started and what `data` is afterward.
-        This is a trick you learn only by looking at the fuzzing test cases.
-
-        let reader = capnp::serialize::read_message(&mut data, reader_opts)?;
-        let bytes_consumed = orig_data.len() - data.len();
-        */
-
-        /*
-        Read into `SliceSegments`, which allows us to re-use the underlying message storage,
-        but still forces a Vec allocation for `offsets`. Also requires us to copy code from
-        Cap'n'Proto because `SliceSegments` has private fields, and `read_segment_table`
-        is private. And all this because `read_segment_from_words` has a length check
-        that triggers an error if our buffer is too large. What the hell?
-        There is no documentation on how to calculate `bytes_consumed` when parsing by hand
-        that I could find, you just have to guess and check until you figure this one out.
-        */
-        let (num_words, offsets) = read_segment_table(&mut data, reader_opts).map_err(|_| ())?;
-        let words = unsafe { capnp::Word::bytes_to_words(data) };
-        let reader = capnp::message::Reader::new(
-            SliceSegments {
-                words: words,
-                segment_slices: offsets,
+fn normalize<S>(
+    reader: &capnp::message::Reader<S>,
+    messages: &mut Vec<NormalizedMessage>,
+) -> Result<u64, ()>
+where
+    S: capnp::message::ReaderSegments,
+{
+    let multimsg = reader.get_root::<multi_message::Reader>().map_err(|_| ())?;
+    for msg in multimsg.get_messages().map_err(|_| ())?.iter() {
+        let body = match msg.which().map_err(|_| ())? {
+            message::SystemEvent(se) => NormalizedBody::SystemEvent {
+                system_event: se.map_err(|_| ())?.get_system_event(),
             },
-            reader_opts,
-        );
-        let segment_table_bytes = orig_data.len() - data.len();
-        let msg_bytes = num_words * size_of::<capnp::Word>();
-        let bytes_consumed = segment_table_bytes + msg_bytes;
-
-        let multimsg = reader.get_root::<multi_message::Reader>().map_err(|_| ())?;
-        for msg in multimsg.get_messages().map_err(|_| ())?.iter() {
-            let sym = msg.get_symbol().map_err(|_| ())?;
-
-            match msg.which().map_err(|_| ())? {
-                message::Trade(trade) => {
-                    let trade = trade.unwrap();
-                    stats.append_trade_volume(sym, trade.get_size().into());
+            message::SecurityDirectory(sd) => {
+                let sd = sd.map_err(|_| ())?;
+                NormalizedBody::SecurityDirectory {
+                    flags: sd.get_flags(),
+                    lot_size: sd.get_lot_size(),
+                    previous_closing: sd.get_previous_closing(),
+                    luld_tier: sd.get_luld_tier(),
                 }
-                message::Quote(quote) => {
-                    let quote = quote.unwrap();
-                    let is_buy = match quote.get_side().unwrap() {
-                        Side::Buy => true,
-                        _ => false,
-                    };
-                    stats.update_quote_prices(sym, quote.get_price(), is_buy);
+            }
+            message::TradingStatus(ts) => {
+                let ts = ts.map_err(|_| ())?;
+                let reason = ts.get_reason().map_err(|_| ())?;
+                NormalizedBody::TradingStatus {
+                    trading_status: ts.get_trading_status(),
+                    reason: reason.as_bytes().try_into().map_err(|_| ())?,
                 }
             }
-        }
+            message::OperationalHaltStatus(ohs) => NormalizedBody::OperationalHaltStatus {
+                halt_status: ohs.map_err(|_| ())?.get_halt_status(),
+            },
+            message::ShortSalePriceTest(sspt) => {
+                let sspt = sspt.map_err(|_| ())?;
+                NormalizedBody::ShortSalePriceTest {
+                    sspt_status: sspt.get_sspt_status(),
+                    detail: sspt.get_detail(),
+                }
+            }
+            message::SecurityEvent(se) => NormalizedBody::SecurityEvent {
+                security_event: se.map_err(|_| ())?.get_security_event(),
+            },
+            message::Quote(quote) => {
+                let quote = quote.map_err(|_| ())?;
+                NormalizedBody::PriceLevelUpdate {
+                    is_buy: matches!(quote.get_side().map_err(|_| ())?, Side::Buy),
+                    event_flags: quote.get_flags(),
+                    size: quote.get_size(),
+                    price: quote.get_price(),
+                }
+            }
+            message::Trade(trade) => {
+                let trade = trade.map_err(|_| ())?;
+                NormalizedBody::TradeReport {
+                    sale_condition: trade.get_sale_condition(),
+                    size: trade.get_size(),
+                    price: trade.get_price(),
+                    trade_id: trade.get_trade_id(),
+                }
+            }
+            message::OfficialPrice(op) => {
+                let op = op.map_err(|_| ())?;
+                NormalizedBody::OfficialPrice {
+                    price_type: op.get_price_type(),
+                    official_price: op.get_official_price(),
+                }
+            }
+            message::TradeBreak(tb) => {
+                let tb = tb.map_err(|_| ())?;
+                NormalizedBody::TradeBreak {
+                    sale_condition: tb.get_sale_condition(),
+                    size: tb.get_size(),
+                    price: tb.get_price(),
+                    trade_id: tb.get_trade_id(),
+                }
+            }
+            message::AuctionInformation(ai) => {
+                let ai = ai.map_err(|_| ())?;
+                NormalizedBody::AuctionInformation {
+                    auction_type: ai.get_auction_type(),
+                    paired_shares: ai.get_paired_shares(),
+                    reference_price: ai.get_reference_price(),
+                    indicative_clearing_price: ai.get_indicative_clearing_price(),
+                    imbalance_shares: ai.get_imbalance_shares(),
+                    imbalance_side: ai.get_imbalance_side(),
+                    extension_number: ai.get_extension_number(),
+                    scheduled_auction: ai.get_scheduled_auction(),
+                    auction_book_clearing_price: ai.get_auction_book_clearing_price(),
+                    collar_reference_price: ai.get_collar_reference_price(),
+                    lower_auction_collar: ai.get_lower_auction_collar(),
+                    upper_auction_collar: ai.get_upper_auction_collar(),
+                }
+            }
+        };
 
-        buf.consume(bytes_consumed);
-        Ok(())
+        messages.push(NormalizedMessage {
+            timestamp: msg.get_ts(),
+            symbol: msg.get_symbol().map_err(|_| ())?.to_string(),
+            body,
+        });
     }
+
+    Ok(multimsg.get_seq_no())
 }
 
 impl RunnerDeserialize for CapnpReader {
-    fn deserialize<'a>(&self, buf: &'a mut StreamVec, stats: &mut Summarizer) -> Result<(), ()> {
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()> {
         // While this is an extra branch per call, we're going to assume that the overhead
         // is essentially nil in practice
         if self.packed {
-            self.deserialize_packed(buf, stats)
+            self.read_packed(buf, |reader| summarize(reader, stats))
         } else {
-            self.deserialize_unpacked(buf, stats)
+            self.read_unpacked(buf, |reader| summarize(reader, stats))
+        }
+    }
+
+    fn decode(
+        &self,
+        buf: &mut StreamBuf,
+        messages: &mut Vec<NormalizedMessage>,
+    ) -> Result<u64, ()> {
+        if self.packed {
+            self.read_packed(buf, |reader| normalize(reader, messages))
+        } else {
+            self.read_unpacked(buf, |reader| normalize(reader, messages))
         }
     }
 }
@@ -258,7 +607,7 @@ pub struct SliceSegments<'a> {
 }
 
 impl<'a> capnp::message::ReaderSegments for SliceSegments<'a> {
-    fn get_segment<'b>(&'b self, id: u32) -> Option<&'b [capnp::Word]> {
+    fn get_segment(&self, id: u32) -> Option<&[capnp::Word]> {
         if id < self.segment_slices.len() as u32 {
             let (a, b) = self.segment_slices[id as usize];
             Some(&self.words[a..b])
@@ -338,3 +687,301 @@ where
 
     Ok((total_words, segment_slices))
 }
+
+/// Unpack a single message from the
+/// [packed encoding](https://capnproto.org/encoding.html#packing) into `words`,
+/// returning the number of packed bytes consumed. `words` is cleared first,
+/// but keeps its capacity so that repeated calls don't allocate.
+fn unpack_message(packed: &[u8], words: &mut Vec<capnp::Word>) -> Result<usize, ()> {
+    words.clear();
+
+    let mut pos = 0;
+    // We don't know how many words the message has until the segment table is unpacked
+    let mut total_words = None;
+    loop {
+        if total_words.is_none() {
+            total_words = message_words(&words[..]);
+        }
+        match total_words {
+            Some(total) if words.len() == total => return Ok(pos),
+            Some(total) if words.len() > total => return Err(()),
+            _ => (),
+        }
+
+        let tag = *packed.get(pos).ok_or(())?;
+        pos += 1;
+
+        let mut bytes = [0u8; 8];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            if tag & (1u8 << i) != 0 {
+                *b = *packed.get(pos).ok_or(())?;
+                pos += 1;
+            }
+        }
+        words.push(capnp::word(
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ));
+
+        // A tag of 0 is followed by a count of zero words, and a tag of 0xff
+        // is followed by a count of words to be copied verbatim.
+        if tag == 0 {
+            let run_length = *packed.get(pos).ok_or(())? as usize;
+            pos += 1;
+
+            let new_len = words.len() + run_length;
+            words.resize(new_len, capnp::word(0, 0, 0, 0, 0, 0, 0, 0));
+        } else if tag == 0xff {
+            let run_length = *packed.get(pos).ok_or(())? as usize;
+            pos += 1;
+
+            let run_end = pos + run_length * size_of::<capnp::Word>();
+            let run = packed.get(pos..run_end).ok_or(())?;
+            words.extend(
+                run.chunks_exact(size_of::<capnp::Word>())
+                    .map(|b| capnp::word(b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7])),
+            );
+            pos = run_end;
+        }
+    }
+}
+
+/// Pack a whole number of words into `output` using the
+/// [packed encoding](https://capnproto.org/encoding.html#packing); the inverse of `unpack_message`.
+fn pack_message(unpacked: &[u8], output: &mut Vec<u8>) {
+    const WORD: usize = size_of::<capnp::Word>();
+    debug_assert_eq!(unpacked.len() % WORD, 0, "Partial word in message");
+
+    let tag = |word: &[u8]| {
+        word.iter().enumerate().fold(
+            0u8,
+            |tag, (i, b)| if *b != 0 { tag | (1 << i) } else { tag },
+        )
+    };
+
+    let mut words = unpacked.chunks_exact(WORD).peekable();
+    while let Some(word) = words.next() {
+        let word_tag = tag(word);
+        output.push(word_tag);
+        output.extend(word.iter().filter(|b| **b != 0));
+
+        if word_tag == 0 {
+            let mut run_length = 0u8;
+            while run_length < u8::MAX && words.peek().is_some_and(|w| tag(w) == 0) {
+                words.next();
+                run_length += 1;
+            }
+            output.push(run_length);
+        } else if word_tag == 0xff {
+            // Same heuristic as the reference implementation: keep copying words verbatim
+            // until one has at least two zero bytes, where packing starts to pay off.
+            let run_length_pos = output.len();
+            output.push(0);
+            let mut run_length = 0u8;
+            while run_length < u8::MAX
+                && words
+                    .peek()
+                    .is_some_and(|w| w.iter().filter(|b| **b == 0).count() < 2)
+            {
+                output.extend_from_slice(words.next().unwrap());
+                run_length += 1;
+            }
+            output[run_length_pos] = run_length;
+        }
+    }
+}
+
+/// Given the (unpacked) start of a message, calculate the total message size in words
+/// (including the segment table), or `None` if the segment table is incomplete.
+fn message_words(words: &[capnp::Word]) -> Option<usize> {
+    let bytes = capnp::Word::words_to_bytes(words);
+    if bytes.len() < 4 {
+        return None;
+    }
+
+    let segment_count = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as usize + 1;
+    // Segment count and sizes are all u32, padded out to a full word
+    let table_words = segment_count / 2 + 1;
+    if words.len() < table_words {
+        return None;
+    }
+
+    let segment_words: usize = (0..segment_count)
+        .map(|idx| {
+            let start = (idx + 1) * 4;
+            u32::from_le_bytes(bytes[start..start + 4].try_into().unwrap()) as usize
+        })
+        .sum();
+
+    Some(table_words + segment_words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pack_message, unpack_message, CapnpReader, CapnpWriter, ZERO_WORD};
+    use crate::framing::StreamBuf;
+    use crate::iex::IexPayload;
+    use crate::sample;
+    use crate::verify::NormalizedMessage;
+    use crate::{RunnerDeserialize, RunnerSerialize, WriteMode};
+
+    /// IEX payloads of every message type, with `repeats` copies of each; enough
+    /// repeats need more than the first segment.
+    fn payload(n: u64, repeats: usize) -> Vec<u8> {
+        let messages: Vec<Vec<u8>> = (0..repeats)
+            .flat_map(|i| sample::every_message_type(b"ZIEXT   ", n * 100 + i as u64))
+            .collect();
+        sample::iex_payload(n, &messages)
+    }
+
+    #[test]
+    fn reused_segments_match_a_fresh_writer() {
+        // Alternate between small messages and ones big enough to need a second
+        // segment, so every segment gets re-used after holding something bigger.
+        let payloads: Vec<Vec<u8>> = [1, 30, 2, 40, 1, 25, 3]
+            .iter()
+            .enumerate()
+            .map(|(n, &repeats)| payload(n as u64, repeats))
+            .collect();
+
+        // Packed messages are always built from scratch
+        let modes = [
+            (false, WriteMode::Scratch),
+            (false, WriteMode::InPlace),
+            (true, WriteMode::Scratch),
+        ];
+        for &(packed, write_mode) in &modes {
+            let mut writer = CapnpWriter::new(packed, write_mode);
+            let reader = CapnpReader::new(packed);
+
+            for (n, payload) in payloads.iter().enumerate() {
+                let (_, payload) = IexPayload::parse(payload).unwrap();
+
+                let mut output = Vec::new();
+                writer.serialize(&payload, &mut output);
+                assert!(
+                    writer.segments[0].iter().all(|w| *w == ZERO_WORD),
+                    "payload {} left garbage in the first segment",
+                    n
+                );
+
+                let mut fresh_output = Vec::new();
+                CapnpWriter::new(packed, write_mode).serialize(&payload, &mut fresh_output);
+                assert_eq!(output.len(), fresh_output.len(), "payload {}", n);
+                assert!(output == fresh_output, "payload {} doesn't match", n);
+
+                let mut decoded = Vec::new();
+                let seq_no = reader.decode(&mut StreamBuf::new(&output), &mut decoded);
+                assert_eq!(seq_no, Ok(n as u64));
+                let expected: Vec<_> = payload
+                    .messages
+                    .iter()
+                    .map(NormalizedMessage::from)
+                    .collect();
+                assert_eq!(decoded, expected);
+            }
+        }
+
+        // Make sure the payloads were big enough to exercise segments past the first; when
+        // building in place, the output always has room for the whole message.
+        let mut writer = CapnpWriter::new(false, WriteMode::Scratch);
+        for payload in &payloads {
+            writer.serialize(&IexPayload::parse(payload).unwrap().1, &mut Vec::new());
+        }
+        assert!(writer.segments.len() > 1, "never needed a second segment");
+    }
+
+    fn words(bytes: &[u8]) -> Vec<capnp::Word> {
+        bytes
+            .chunks_exact(8)
+            .map(|b| capnp::word(b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]))
+            .collect()
+    }
+
+    fn unpack(packed: &[u8]) -> Result<(usize, Vec<capnp::Word>), ()> {
+        let mut unpacked = Vec::new();
+        unpack_message(packed, &mut unpacked).map(|consumed| (consumed, unpacked))
+    }
+
+    #[test]
+    fn unpack_zero_runs() {
+        // One segment of three words, all zero: a tag of 0 covers the first,
+        // and the count after it covers the other two.
+        let packed = [0x10, 3, 0x00, 2];
+        let mut expected = vec![0, 0, 0, 0, 3, 0, 0, 0];
+        expected.resize(4 * 8, 0);
+
+        assert_eq!(unpack(&packed), Ok((packed.len(), words(&expected))));
+    }
+
+    #[test]
+    fn unpack_literal_runs() {
+        // One segment of three words with no zero bytes: a tag of 0xff copies the
+        // first, and the count after it says how many more to copy verbatim.
+        let literal: Vec<u8> = (1..=24).collect();
+        let mut packed = vec![0x10, 3, 0xff];
+        packed.extend_from_slice(&literal[..8]);
+        packed.push(2);
+        packed.extend_from_slice(&literal[8..]);
+
+        let mut expected = vec![0, 0, 0, 0, 3, 0, 0, 0];
+        expected.extend_from_slice(&literal);
+        assert_eq!(unpack(&packed), Ok((packed.len(), words(&expected))));
+    }
+
+    #[test]
+    fn unpack_segment_table_across_words() {
+        // Two segments need a second word for the segment table, so the message
+        // length isn't known until that word is unpacked.
+        let mut packed = vec![0x11, 1, 1, 0x01, 2, 0x00, 2];
+        let mut expected = vec![1, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        expected.resize(5 * 8, 0);
+
+        // Stop at the end of the message, rather than the end of the input
+        packed.extend_from_slice(&[0x10, 3, 0x00, 2]);
+        assert_eq!(unpack(&packed), Ok((7, words(&expected))));
+    }
+
+    #[test]
+    fn unpack_rejects_runs_past_the_end_of_the_message() {
+        // A single word segment, but the zero run covers three
+        assert_eq!(unpack(&[0x10, 1, 0x00, 2]), Err(()));
+        // Same thing with a literal run
+        let mut packed = vec![0x10, 1, 0xff];
+        packed.extend_from_slice(&[1; 8]);
+        packed.push(1);
+        packed.extend_from_slice(&[1; 8]);
+        assert_eq!(unpack(&packed), Err(()));
+    }
+
+    #[test]
+    fn unpack_rejects_truncated_messages() {
+        let literal: Vec<u8> = (1..=24).collect();
+        let mut packed = vec![0x11, 1, 2, 0x01, 1, 0xff];
+        packed.extend_from_slice(&literal[..8]);
+        packed.push(2);
+        packed.extend_from_slice(&literal[8..]);
+        assert!(unpack(&packed).is_ok());
+
+        for len in 0..packed.len() {
+            assert_eq!(unpack(&packed[..len]), Err(()), "{} bytes", len);
+        }
+    }
+
+    #[test]
+    fn pack_round_trips() {
+        // Segment table, then a mix of zero, sparse and dense words, with runs longer
+        // than a single count byte can hold.
+        let mut unpacked = vec![0, 0, 0, 0, 0, 0, 0, 0];
+        unpacked.extend_from_slice(&[0; 300 * 8]);
+        unpacked.extend_from_slice(&[0, 5, 0, 0, 0, 0, 7, 0]);
+        unpacked.extend((0..300 * 8).map(|i| (i % 255 + 1) as u8));
+        unpacked.extend_from_slice(&[9, 0, 9, 0, 9, 0, 9, 0]);
+        let segment_words = (unpacked.len() / 8 - 1) as u32;
+        unpacked[4..8].copy_from_slice(&segment_words.to_le_bytes());
+
+        let mut packed = Vec::new();
+        pack_message(&unpacked, &mut packed);
+        assert!(packed.len() < unpacked.len());
+        assert_eq!(unpack(&packed), Ok((packed.len(), words(&unpacked))));
+    }
+}
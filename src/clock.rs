@@ -0,0 +1,164 @@
+use std::time::{Duration, Instant};
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::{__cpuid, __rdtscp, _mm_lfence, _rdtsc};
+
+/// Names accepted by `--clock`.
+#[cfg(target_arch = "x86_64")]
+pub const CLOCKS: &[&str] = &["instant", "tsc", "batch"];
+#[cfg(not(target_arch = "x86_64"))]
+pub const CLOCKS: &[&str] = &["instant", "batch"];
+
+/// How long to spin while working out the TSC frequency.
+#[cfg(target_arch = "x86_64")]
+const TSC_CALIBRATION_TIME: Duration = Duration::from_millis(100);
+
+/// Number of back-to-back measurements used to estimate clock overhead.
+const OVERHEAD_SAMPLES: usize = 100_000;
+
+/// Where per-message timings come from. Reading the clock takes about as long as
+/// encoding a small message, so the choice matters for the fastest formats.
+#[derive(Clone, Copy, Debug)]
+pub enum Clock {
+    /// `Instant::now()`, which is a vDSO call on Linux (~20-30ns)
+    Instant(Instant),
+    /// The CPU's timestamp counter, scaled by nanoseconds per tick
+    #[cfg(target_arch = "x86_64")]
+    Tsc(f64),
+    /// No per-message timings at all; only the time for the whole run is measured
+    Batch,
+}
+
+impl Clock {
+    pub fn new(name: &str) -> Clock {
+        match name {
+            "instant" => Clock::Instant(Instant::now()),
+            #[cfg(target_arch = "x86_64")]
+            "tsc" => {
+                if !invariant_tsc() {
+                    eprintln!(
+                        "Warning: this CPU doesn't report an invariant TSC, \
+                         so tsc timings may drift with frequency scaling"
+                    );
+                }
+                Clock::Tsc(calibrate_tsc())
+            }
+            "batch" => Clock::Batch,
+            _ => panic!("Unknown clock={}", name),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Clock::Instant(_) => "instant",
+            #[cfg(target_arch = "x86_64")]
+            Clock::Tsc(_) => "tsc",
+            Clock::Batch => "batch",
+        }
+    }
+
+    /// Whether this clock times individual messages, or only whole runs.
+    pub fn per_message(&self) -> bool {
+        !matches!(self, Clock::Batch)
+    }
+
+    /// Read the clock before the code being measured.
+    #[inline(always)]
+    pub fn start(&self) -> u64 {
+        match self {
+            Clock::Instant(epoch) => epoch.elapsed().as_nanos() as u64,
+            // Keep earlier instructions from finishing inside the measurement,
+            // and later ones from starting before it
+            #[cfg(target_arch = "x86_64")]
+            Clock::Tsc(_) => unsafe {
+                _mm_lfence();
+                let ticks = _rdtsc();
+                _mm_lfence();
+                ticks
+            },
+            Clock::Batch => 0,
+        }
+    }
+
+    /// Read the clock after the code being measured.
+    #[inline(always)]
+    pub fn stop(&self) -> u64 {
+        match self {
+            Clock::Instant(epoch) => epoch.elapsed().as_nanos() as u64,
+            // `rdtscp` waits for everything before it to finish
+            #[cfg(target_arch = "x86_64")]
+            Clock::Tsc(_) => unsafe {
+                let mut aux = 0;
+                let ticks = __rdtscp(&mut aux);
+                _mm_lfence();
+                ticks
+            },
+            Clock::Batch => 0,
+        }
+    }
+
+    /// Nanoseconds between two readings of this clock.
+    pub fn nanos(&self, start: u64, stop: u64) -> u64 {
+        let elapsed = stop.saturating_sub(start);
+        match self {
+            Clock::Instant(_) => elapsed,
+            #[cfg(target_arch = "x86_64")]
+            Clock::Tsc(nanos_per_tick) => (elapsed as f64 * nanos_per_tick) as u64,
+            Clock::Batch => 0,
+        }
+    }
+
+    /// Smallest time this clock can measure, which every per-message timing includes.
+    /// We use the minimum rather than the median so that subtracting it never
+    /// takes away time that was actually spent on the message.
+    pub fn overhead_nanos(&self) -> u64 {
+        if !self.per_message() {
+            return 0;
+        }
+
+        (0..OVERHEAD_SAMPLES)
+            .map(|_| {
+                let start = self.start();
+                self.nanos(start, self.stop())
+            })
+            .min()
+            .unwrap()
+    }
+}
+
+/// A clock, and how much to take off each per-message timing to account for it.
+#[derive(Clone, Copy, Debug)]
+pub struct Timing {
+    pub clock: Clock,
+    pub correction_nanos: u64,
+}
+
+impl Timing {
+    /// Corrected nanoseconds between two readings of the clock.
+    #[inline(always)]
+    pub fn elapsed_nanos(&self, start: u64, stop: u64) -> u64 {
+        self.clock
+            .nanos(start, stop)
+            .saturating_sub(self.correction_nanos)
+    }
+}
+
+/// Work out how long a TSC tick is by comparing it against `Instant`.
+#[cfg(target_arch = "x86_64")]
+fn calibrate_tsc() -> f64 {
+    let clock = Clock::Tsc(1.0);
+    let instant_start = Instant::now();
+    let tsc_start = clock.start();
+    while instant_start.elapsed() < TSC_CALIBRATION_TIME {}
+    let tsc_end = clock.stop();
+    let elapsed = instant_start.elapsed();
+
+    elapsed.as_nanos() as f64 / (tsc_end - tsc_start) as f64
+}
+
+/// Whether the TSC ticks at a constant rate regardless of power state
+/// (CPUID leaf 0x80000007, EDX bit 8).
+#[cfg(target_arch = "x86_64")]
+fn invariant_tsc() -> bool {
+    __cpuid(0x8000_0000).eax >= 0x8000_0007 && __cpuid(0x8000_0007).edx & (1 << 8) != 0
+}
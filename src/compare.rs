@@ -0,0 +1,694 @@
+use std::collections::HashMap;
+use std::fs;
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::Chars;
+
+use crate::stats::{significantly_different, Estimate};
+
+/// Metrics that fail a comparison when they regress, unless `--metrics` says otherwise.
+/// The tail percentiles are left out since they're too noisy to gate on.
+pub const DEFAULT_GATED: &[&str] = &[
+    "serialize_50_nanos",
+    "serialize_99_nanos",
+    "deserialize_50_nanos",
+    "deserialize_99_nanos",
+    "serialize_total_nanos",
+    "deserialize_total_nanos",
+];
+
+/// Compare two sets of results written by `--output-format json`, printing how
+/// every metric changed. Returns whether any gated metric got worse by more than
+/// `threshold` percent; with repeated iterations on both sides, the change also
+/// has to be statistically significant. It's an error if nothing could be compared,
+/// or if a runner with gated metrics in the baseline is missing from the candidate.
+pub fn compare(
+    baseline_path: &Path,
+    candidate_path: &Path,
+    threshold: f64,
+    gated: &[&str],
+    metrics: &[&str],
+) -> Result<bool, String> {
+    let baseline = load(baseline_path)?;
+    let candidate = load(candidate_path)?;
+
+    println!("baseline:  {}", describe(baseline_path, &baseline));
+    println!("candidate: {}\n", describe(candidate_path, &candidate));
+
+    let mut regressions = 0;
+    let mut compared = 0;
+    let mut missing = Vec::new();
+    for base in &baseline {
+        let cand = match candidate.iter().find(|c| c.key() == base.key()) {
+            Some(cand) => cand,
+            None => {
+                println!("{}: only in baseline\n", base.label());
+                if gated.iter().any(|metric| base.samples(metric).is_some()) {
+                    missing.push(base.label());
+                }
+                continue;
+            }
+        };
+
+        println!(
+            "{}: {} vs {} iterations",
+            base.label(),
+            base.records.len(),
+            cand.records.len()
+        );
+        println!(
+            "  {:<26}{:>16}{:>16}{:>10}",
+            "metric", "baseline", "candidate", "change"
+        );
+        for metric in metrics {
+            let (base_samples, cand_samples) = match (base.samples(metric), cand.samples(metric)) {
+                (Some(b), Some(c)) => (b, c),
+                // Results from before the metric existed
+                _ => continue,
+            };
+            compared += 1;
+            let base_mean = Estimate::new(&base_samples).mean;
+            let cand_mean = Estimate::new(&cand_samples).mean;
+            let change = if base_mean == 0.0 {
+                0.0
+            } else {
+                (cand_mean - base_mean) / base_mean * 100.0
+            };
+
+            let significant = significantly_different(&base_samples, &cand_samples);
+            // Throughput regresses by going down, everything else by going up
+            let worsened = if metric.ends_with("_per_sec") {
+                -change
+            } else {
+                change
+            };
+            let regressed =
+                gated.contains(metric) && worsened > threshold && significant != Some(false);
+            if regressed {
+                regressions += 1;
+            }
+
+            println!(
+                "  {:<26}{:>16.3}{:>16.3}{:>+9.2}%{}{}",
+                metric,
+                base_mean,
+                cand_mean,
+                change,
+                match significant {
+                    Some(true) => "  significant",
+                    Some(false) => "  noise",
+                    None => "",
+                },
+                if regressed { "  REGRESSED" } else { "" }
+            );
+        }
+        println!();
+    }
+    for cand in &candidate {
+        if !baseline.iter().any(|b| b.key() == cand.key()) {
+            println!("{}: only in candidate\n", cand.label());
+        }
+    }
+
+    if compared == 0 {
+        return Err(format!(
+            "{} and {} don't have any runners and metrics in common",
+            baseline_path.display(),
+            candidate_path.display()
+        ));
+    }
+    if !missing.is_empty() {
+        return Err(format!(
+            "Gated runners missing from {}: {}",
+            candidate_path.display(),
+            missing.join(", ")
+        ));
+    }
+
+    if regressions == 0 {
+        println!("No regressions beyond {}%", threshold);
+    } else {
+        println!("{} metric(s) regressed beyond {}%", regressions, threshold);
+    }
+    Ok(regressions > 0)
+}
+
+/// Where a set of results came from, using the first record since every record
+/// from one invocation shares the same metadata.
+fn describe(path: &Path, results: &[RunnerResults]) -> String {
+    let first = &results[0].records[0];
+    format!(
+        "{} (host={} revision={})",
+        path.display(),
+        first.text("host"),
+        first.text("git_revision")
+    )
+}
+
+/// Every iteration of one runner, set up the same way.
+struct RunnerResults {
+    format: String,
+    write_mode: String,
+    pipeline: String,
+    protocol: String,
+    records: Vec<Record>,
+}
+
+impl RunnerResults {
+    /// Results are only compared against others with the same key, since a
+    /// different write mode or pipeline is a different benchmark. The capture's
+    /// date and the host aren't part of it, so runs on new data or new hardware
+    /// can be compared against an old baseline.
+    fn key(&self) -> (&str, &str, &str) {
+        (&self.format, &self.write_mode, &self.pipeline)
+    }
+
+    fn label(&self) -> String {
+        format!(
+            "{} (write_mode={}, pipeline={})",
+            self.protocol, self.write_mode, self.pipeline
+        )
+    }
+
+    fn samples(&self, metric: &str) -> Option<Vec<f64>> {
+        self.records.iter().map(|r| r.number(metric)).collect()
+    }
+}
+
+/// Read results, grouping iterations by runner in the order they were run.
+fn load(path: &Path) -> Result<Vec<RunnerResults>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read file={}: {}", path.display(), e))?;
+
+    let mut results: Vec<RunnerResults> = Vec::new();
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = parse_record(line)
+            .ok_or_else(|| format!("{}:{}: not a JSON result record", path.display(), i + 1))?;
+
+        let key = (
+            record.text("format"),
+            record.text("write_mode"),
+            record.text("pipeline"),
+        );
+        match results.iter_mut().position(|r| r.key() == key) {
+            Some(i) => results[i].records.push(record),
+            None => results.push(RunnerResults {
+                format: key.0.to_string(),
+                write_mode: key.1.to_string(),
+                pipeline: key.2.to_string(),
+                protocol: record.text("protocol").to_string(),
+                records: vec![record],
+            }),
+        }
+    }
+
+    if results.is_empty() {
+        return Err(format!("{} doesn't contain any results", path.display()));
+    }
+    Ok(results)
+}
+
+enum Value {
+    Text(String),
+    Number(f64),
+}
+
+/// One line of `--output-format json` output.
+struct Record(HashMap<String, Value>);
+
+impl Record {
+    fn text(&self, key: &str) -> &str {
+        match self.0.get(key) {
+            Some(Value::Text(s)) => s,
+            _ => "",
+        }
+    }
+
+    fn number(&self, key: &str) -> Option<f64> {
+        match self.0.get(key) {
+            Some(Value::Number(n)) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a flat JSON object of strings, numbers and nulls, which is all `report` writes.
+fn parse_record(line: &str) -> Option<Record> {
+    let mut chars = line.trim().chars().peekable();
+    let mut fields = HashMap::new();
+
+    expect(&mut chars, '{')?;
+    skip_whitespace(&mut chars);
+    if chars.peek() == Some(&'}') {
+        chars.next();
+    } else {
+        loop {
+            skip_whitespace(&mut chars);
+            let key = parse_string(&mut chars)?;
+            skip_whitespace(&mut chars);
+            expect(&mut chars, ':')?;
+            skip_whitespace(&mut chars);
+            match chars.peek()? {
+                '"' => {
+                    fields.insert(key, Value::Text(parse_string(&mut chars)?));
+                }
+                // Measurements this build couldn't take, so leave them out
+                'n' => {
+                    for c in "null".chars() {
+                        expect(&mut chars, c)?;
+                    }
+                }
+                _ => {
+                    fields.insert(key, Value::Number(parse_number(&mut chars)?));
+                }
+            }
+
+            skip_whitespace(&mut chars);
+            match chars.next()? {
+                ',' => continue,
+                '}' => break,
+                _ => return None,
+            }
+        }
+    }
+
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(Record(fields))
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while let Some(c) = chars.peek() {
+        if !c.is_whitespace() {
+            break;
+        }
+        chars.next();
+    }
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Option<()> {
+    if chars.next()? == expected {
+        Some(())
+    } else {
+        None
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    expect(chars, '"')?;
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => out.push(match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    std::char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                }
+                c => c,
+            }),
+            c => out.push(c),
+        }
+    }
+}
+
+fn parse_number(chars: &mut Peekable<Chars>) -> Option<f64> {
+    let mut number = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' {
+            number.push(c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    number.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+    use std::process;
+
+    use hdrhistogram::Histogram;
+
+    use super::{compare, parse_record};
+    use crate::alloc::AllocStats;
+    use crate::clock::{Clock, Timing};
+    use crate::histograms::MessageHists;
+    use crate::report::{self, OutputFormat, RunMetadata};
+    use crate::{RunAnalysis, Summarizer};
+
+    fn analysis() -> RunAnalysis {
+        let mut serialize_hist = Histogram::<u64>::new(2).unwrap();
+        let mut deserialize_hist = Histogram::<u64>::new(2).unwrap();
+        for nanos in 100..200 {
+            serialize_hist.record(nanos).unwrap();
+            deserialize_hist.record(nanos * 2).unwrap();
+        }
+        RunAnalysis {
+            serialize_hist,
+            deserialize_hist,
+            serialize_msg_hists: MessageHists::new(),
+            deserialize_msg_hists: MessageHists::new(),
+            summary_stats: Summarizer::default(),
+            serialize_total_nanos: 15_000,
+            deserialize_total_nanos: 30_000,
+            buf_len: 4_000,
+            messages: 100,
+            serialize_allocs: AllocStats::default(),
+            deserialize_allocs: AllocStats::default(),
+            pipeline: None,
+        }
+    }
+
+    #[test]
+    fn parse_record_reads_what_report_writes() {
+        let input =
+            "data/\"quoted\" back\\slash\ttab\nline\u{1}\u{e9}_20190903_IEXTP1_DEEP1.0.pcap";
+        let timing = Timing {
+            clock: Clock::new("instant"),
+            correction_nanos: 12,
+        };
+        let metadata = RunMetadata::collect(Path::new(input), "scratch", &timing, 25, None, None);
+        let analysis = analysis();
+        let line = report::record(
+            OutputFormat::Json,
+            &metadata,
+            "capnp",
+            "Cap'n \"Proto\"",
+            3,
+            1.25,
+            &analysis,
+        );
+
+        let record = parse_record(&line).unwrap();
+        for (name, value) in metadata.fields() {
+            assert_eq!(record.text(name), value, "{}", name);
+        }
+        assert_eq!(record.text("input_file"), input);
+        assert_eq!(record.text("run_date"), "20190903");
+        assert_eq!(record.text("protocol"), "Cap'n \"Proto\"");
+        assert_eq!(record.text("pipeline"), "off");
+        assert_eq!(record.number("iteration"), Some(3.0));
+        assert_eq!(record.number("total_secs"), Some(1.25));
+        assert_eq!(
+            record.number("serialize_50_nanos"),
+            Some(analysis.serialize_hist.value_at_quantile(0.5) as f64)
+        );
+        assert_eq!(record.number("deserialize_total_nanos"), Some(30_000.0));
+        assert_eq!(record.number("clock_correction_nanos"), Some(12.0));
+        assert_eq!(record.number("bytes_per_message"), Some(40.0));
+
+        // Only measured with `--pipeline`, so written as null and left out
+        assert!(line.contains("\"one_way_50_nanos\":null"));
+        assert_eq!(record.number("one_way_50_nanos"), None);
+        for metric in report::metric_columns() {
+            assert_eq!(
+                record.number(metric).is_some(),
+                !line.contains(&format!("\"{}\":null", metric)),
+                "{}",
+                metric
+            );
+        }
+    }
+
+    #[test]
+    fn batch_clock_leaves_out_per_message_timings() {
+        let timing = Timing {
+            clock: Clock::new("batch"),
+            correction_nanos: 0,
+        };
+        let metadata = RunMetadata::collect(Path::new("x.pcap"), "scratch", &timing, 0, None, None);
+        let line = report::record(
+            OutputFormat::Json,
+            &metadata,
+            "sbe",
+            "SBE",
+            0,
+            1.0,
+            &analysis(),
+        );
+
+        let record = parse_record(&line).unwrap();
+        for metric in &[
+            "serialize_50_nanos",
+            "deserialize_999_nanos",
+            "serialize_max_nanos",
+            "deserialize_per_msg_50_nanos",
+        ] {
+            assert_eq!(record.number(metric), None, "{}", metric);
+        }
+        assert_eq!(record.number("serialize_total_nanos"), Some(15_000.0));
+        assert_eq!(record.number("deserialize_msgs_per_sec"), Some(3_333_333.0));
+    }
+
+    #[test]
+    fn parse_record_numbers() {
+        let record = parse_record(r#"{"a":1.5e3,"b":-2E-2,"c":7,"d":1e+2,"e":-0.5}"#).unwrap();
+        assert_eq!(record.number("a"), Some(1500.0));
+        assert_eq!(record.number("b"), Some(-0.02));
+        assert_eq!(record.number("c"), Some(7.0));
+        assert_eq!(record.number("d"), Some(100.0));
+        assert_eq!(record.number("e"), Some(-0.5));
+        assert_eq!(record.text("a"), "");
+        assert_eq!(record.number("missing"), None);
+
+        assert!(parse_record(" { } ").unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn parse_record_rejects_malformed_lines() {
+        for line in &[
+            "",
+            "{",
+            "}",
+            "[1,2]",
+            r#"{"a":1"#,
+            r#"{"a" 1}"#,
+            r#"{"a":1,}"#,
+            r#"{a:1}"#,
+            r#"{"a":"unterminated}"#,
+            r#"{"a":1} trailing"#,
+            r#"{"a":nul}"#,
+            r#"{"a":1x}"#,
+            r#"{"a":-}"#,
+            r#"{"a":"\u00zz"}"#,
+        ] {
+            assert!(parse_record(line).is_none(), "{}", line);
+        }
+    }
+
+    /// JSON records for every iteration of one runner, with one metric.
+    fn run(write_mode: &str, pipeline: &str, metric: &str, samples: &[f64]) -> String {
+        samples
+            .iter()
+            .enumerate()
+            .map(|(i, sample)| {
+                format!(
+                    "{{\"run_date\":\"20190903\",\"format\":\"sbe\",\"protocol\":\"SBE\",\
+                     \"write_mode\":\"{}\",\"pipeline\":\"{}\",\"iteration\":{},\"{}\":{}}}\n",
+                    write_mode, pipeline, i, metric, sample
+                )
+            })
+            .collect()
+    }
+
+    /// Compare `candidate` against `baseline`, failing on regressions of more
+    /// than 5% on any of `gated`.
+    fn compare_runs(
+        test: &str,
+        baseline: &str,
+        candidate: &str,
+        gated: &[&str],
+    ) -> Result<bool, String> {
+        let dir = env::temp_dir();
+        let baseline_path = dir.join(format!(
+            "md_shootout-{}-{}-baseline.json",
+            process::id(),
+            test
+        ));
+        let candidate_path = dir.join(format!(
+            "md_shootout-{}-{}-candidate.json",
+            process::id(),
+            test
+        ));
+        fs::write(&baseline_path, baseline).unwrap();
+        fs::write(&candidate_path, candidate).unwrap();
+
+        let metrics = ["serialize_50_nanos", "serialize_msgs_per_sec"];
+        let result = compare(&baseline_path, &candidate_path, 5.0, gated, &metrics);
+
+        fs::remove_file(&baseline_path).unwrap();
+        fs::remove_file(&candidate_path).unwrap();
+        result
+    }
+
+    /// Whether comparing `candidate` against `baseline` finds a regression.
+    fn regressed(test: &str, baseline: &str, candidate: &str, gated: &[&str]) -> bool {
+        compare_runs(test, baseline, candidate, gated).unwrap()
+    }
+
+    #[test]
+    fn only_significant_gated_regressions_fail() {
+        let latency = |samples: &[f64]| run("scratch", "off", "serialize_50_nanos", samples);
+        let baseline = latency(&[100.0, 101.0, 99.0, 100.0]);
+        let gated = &["serialize_50_nanos"];
+
+        let slower = latency(&[120.0, 121.0, 119.0, 120.0]);
+        assert!(regressed("slower", &baseline, &slower, gated));
+        assert!(!regressed(
+            "ungated",
+            &baseline,
+            &slower,
+            &["serialize_99_nanos"]
+        ));
+
+        let within_threshold = latency(&[103.0, 104.0, 102.0, 103.0]);
+        assert!(!regressed("within", &baseline, &within_threshold, gated));
+
+        // Slower on average, but too noisy to say so
+        let noisy = latency(&[80.0, 160.0, 100.0, 140.0]);
+        assert!(!regressed("noisy", &baseline, &noisy, gated));
+
+        let faster = latency(&[80.0, 81.0, 79.0, 80.0]);
+        assert!(!regressed("faster", &baseline, &faster, gated));
+
+        // A single iteration can't show significance, so the threshold alone decides
+        assert!(regressed(
+            "single",
+            &latency(&[100.0]),
+            &latency(&[120.0]),
+            gated
+        ));
+    }
+
+    #[test]
+    fn throughput_regresses_by_going_down() {
+        let throughput = |samples: &[f64]| run("scratch", "off", "serialize_msgs_per_sec", samples);
+        let baseline = throughput(&[1000.0, 1010.0, 990.0, 1000.0]);
+        let gated = &["serialize_msgs_per_sec"];
+
+        let lower = throughput(&[800.0, 810.0, 790.0, 800.0]);
+        assert!(regressed("lower", &baseline, &lower, gated));
+
+        let higher = throughput(&[1200.0, 1210.0, 1190.0, 1200.0]);
+        assert!(!regressed("higher", &baseline, &higher, gated));
+    }
+
+    #[test]
+    fn runs_are_matched_by_write_mode_and_pipeline() {
+        let latency = |write_mode: &str, pipeline: &str, samples: &[f64]| {
+            run(write_mode, pipeline, "serialize_50_nanos", samples)
+        };
+        let gated = &["serialize_50_nanos"];
+        let baseline = latency("scratch", "off", &[100.0, 101.0, 99.0, 100.0]);
+
+        // Nothing to compare the slower runs against
+        let candidate = latency("scratch", "off", &[100.0, 101.0, 99.0, 100.0])
+            + &latency("in-place", "off", &[200.0, 201.0, 199.0, 200.0])
+            + &latency(
+                "scratch",
+                "producer=0 consumer=1",
+                &[300.0, 301.0, 299.0, 300.0],
+            );
+        assert!(!regressed("matched", &baseline, &candidate, gated));
+
+        let candidate = latency("in-place", "off", &[100.0, 101.0, 99.0, 100.0])
+            + &latency("scratch", "off", &[200.0, 201.0, 199.0, 200.0]);
+        assert!(regressed("mismatched", &baseline, &candidate, gated));
+    }
+
+    #[test]
+    fn runs_on_different_dates_are_compared() {
+        let baseline = run(
+            "scratch",
+            "off",
+            "serialize_50_nanos",
+            &[100.0, 101.0, 99.0],
+        );
+        let candidate = run(
+            "scratch",
+            "off",
+            "serialize_50_nanos",
+            &[120.0, 121.0, 119.0],
+        )
+        .replace("20190903", "20190904");
+        assert!(regressed(
+            "dates",
+            &baseline,
+            &candidate,
+            &["serialize_50_nanos"]
+        ));
+    }
+
+    #[test]
+    fn nothing_in_common_is_an_error() {
+        let gated = &["serialize_50_nanos"];
+        let baseline = run(
+            "scratch",
+            "off",
+            "serialize_50_nanos",
+            &[100.0, 101.0, 99.0],
+        );
+
+        let other_runner = run(
+            "in-place",
+            "off",
+            "serialize_50_nanos",
+            &[100.0, 101.0, 99.0],
+        );
+        assert!(compare_runs("runners", &baseline, &other_runner, gated).is_err());
+
+        let other_metric = run(
+            "scratch",
+            "off",
+            "serialize_msgs_per_sec",
+            &[1000.0, 1010.0],
+        );
+        assert!(compare_runs("metrics", &baseline, &other_metric, gated).is_err());
+    }
+
+    #[test]
+    fn missing_gated_runners_are_an_error() {
+        let latency = |write_mode: &str| {
+            run(
+                write_mode,
+                "off",
+                "serialize_50_nanos",
+                &[100.0, 101.0, 99.0],
+            )
+        };
+        let baseline = latency("scratch") + &latency("in-place");
+
+        let result = compare_runs(
+            "missing",
+            &baseline,
+            &latency("scratch"),
+            &["serialize_50_nanos"],
+        );
+        assert!(result.unwrap_err().contains("write_mode=in-place"));
+
+        // Fine as long as none of the missing runner's metrics are gated
+        assert_eq!(
+            compare_runs(
+                "ungated",
+                &baseline,
+                &latency("scratch"),
+                &["serialize_99_nanos"]
+            ),
+            Ok(false)
+        );
+    }
+}
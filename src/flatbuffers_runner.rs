@@ -1,18 +1,22 @@
 use std::convert::TryInto;
-use std::io::{BufRead, Write};
-use std::mem::size_of;
 
+use crate::framing::{LengthPrefixed, StreamBuf};
 use crate::iex::{IexMessage, IexPayload};
 use crate::marketdata_generated::md_shootout;
-use crate::{RunnerDeserialize, RunnerSerialize, StreamVec, Summarizer};
+use crate::verify::{NormalizedBody, NormalizedMessage};
+use crate::{RunnerDeserialize, RunnerSerialize, Summarizer};
 
-pub struct FlatbuffersWriter<'a> {
-    builder: flatbuffers::FlatBufferBuilder<'a>,
-    message_buffer: Vec<flatbuffers::WIPOffset<md_shootout::Message<'a>>>,
+pub struct FlatbuffersWriter {
+    builder: flatbuffers::FlatBufferBuilder<'static>,
+    message_buffer: Vec<flatbuffers::WIPOffset<md_shootout::Message<'static>>>,
 }
 
-impl<'a> FlatbuffersWriter<'a> {
-    pub fn new() -> FlatbuffersWriter<'static> {
+impl FlatbuffersWriter {
+    /// Flatbuffers builds messages back-to-front, so a message can't be built where it
+    /// belongs in the output without knowing its final size up front; building it at
+    /// the end of reserved space means moving it afterwards, which is slower than
+    /// copying out of a builder that gets re-used. There's no in-place mode.
+    pub fn new() -> FlatbuffersWriter {
         FlatbuffersWriter {
             builder: flatbuffers::FlatBufferBuilder::new(),
             message_buffer: Vec::new(),
@@ -20,134 +24,428 @@ impl<'a> FlatbuffersWriter<'a> {
     }
 }
 
-impl<'a> RunnerSerialize for FlatbuffersWriter<'a> {
+impl RunnerSerialize for FlatbuffersWriter {
     fn serialize(&mut self, payload: &IexPayload, output: &mut Vec<u8>) {
-        // Because FlatBuffers can't handle nested vectors (specifically, we can't track
-        // both the variable-length vector of messages, and the variable-length strings
-        // within those messages), we have to cache the messages as they get built
-        // so they can be added all at once later.
-
-        for iex_msg in &payload.messages {
-            let msg_args = match iex_msg {
-                IexMessage::TradeReport(tr) => {
-                    // The `Args` objects used are wrappers over an underlying `Builder`.
-                    // We trust release builds to optimize out the wrapper.
-                    let trade = md_shootout::Trade::create(
-                        &mut self.builder,
-                        &md_shootout::TradeArgs {
-                            price: tr.price,
-                            size_: tr.size,
-                        },
-                    );
-
-                    let sym_str = self.builder.create_string(crate::parse_symbol(&tr.symbol));
-                    Some(md_shootout::MessageArgs {
-                        ts_nanos: tr.timestamp,
-                        symbol: Some(sym_str),
-                        body_type: md_shootout::MessageBody::Trade,
-                        body: Some(trade.as_union_value()),
-                    })
-                }
-                IexMessage::PriceLevelUpdate(plu) => {
-                    let level_update = md_shootout::LevelUpdate::create(
-                        &mut self.builder,
-                        &md_shootout::LevelUpdateArgs {
-                            price: plu.price,
-                            size_: plu.size,
-                            flags: plu.event_flags,
-                            side: if plu.msg_type == 0x38 {
-                                md_shootout::Side::Buy
-                            } else {
-                                md_shootout::Side::Sell
-                            },
-                        },
-                    );
-
-                    let sym_str = self.builder.create_string(crate::parse_symbol(&plu.symbol));
-                    Some(md_shootout::MessageArgs {
-                        ts_nanos: plu.timestamp,
-                        symbol: Some(sym_str),
-                        body_type: md_shootout::MessageBody::LevelUpdate,
-                        body: Some(level_update.as_union_value()),
-                    })
-                }
-                _ => None,
-            };
-
-            msg_args.map(|a| {
-                let msg = md_shootout::Message::create(&mut self.builder, &a);
-                self.message_buffer.push(msg);
-            });
-        }
+        build_multimessage(&mut self.builder, &mut self.message_buffer, payload);
+        output.extend_from_slice(self.builder.finished_data());
 
-        let messages = self.builder.create_vector(&self.message_buffer[..]);
+        self.builder.reset();
+        self.message_buffer.clear();
+    }
+}
 
-        // Now that we've finished building all the messages, time to set up the final buffer
-        let mut multimsg_builder = md_shootout::MultiMessageBuilder::new(&mut self.builder);
-        multimsg_builder.add_seq_no(payload.first_seq_no);
-        multimsg_builder.add_messages(messages);
-        let multimsg = multimsg_builder.finish();
+fn build_multimessage(
+    builder: &mut flatbuffers::FlatBufferBuilder<'static>,
+    message_buffer: &mut Vec<flatbuffers::WIPOffset<md_shootout::Message<'static>>>,
+    payload: &IexPayload,
+) {
+    // Because FlatBuffers can't handle nested vectors (specifically, we can't track
+    // both the variable-length vector of messages, and the variable-length strings
+    // within those messages), we have to cache the messages as they get built
+    // so they can be added all at once later.
 
-        // IMPORTANT NOTE: If you just `finish`, Flatbuffers has no idea where
-        // an object ends in memory. To support streaming reads, you *must*
-        // use `finish_size_prefixed`. This adds a LE u32 to the front of the payload.
-        self.builder.finish_size_prefixed(multimsg, None);
-        output.write(self.builder.finished_data()).unwrap();
+    for iex_msg in &payload.messages {
+        // The `Args` objects used are wrappers over an underlying `Builder`.
+        // We trust release builds to optimize out the wrapper.
+        let (ts_nanos, symbol, body_type, body) = match iex_msg {
+            IexMessage::SystemEvent(se) => {
+                let system_event = md_shootout::SystemEvent::create(
+                    builder,
+                    &md_shootout::SystemEventArgs {
+                        system_event: se.system_event,
+                    },
+                );
+                // System events aren't tied to a symbol, so it's left unset
+                (
+                    se.timestamp,
+                    None,
+                    md_shootout::MessageBody::SystemEvent,
+                    system_event.as_union_value(),
+                )
+            }
+            IexMessage::SecurityDirectory(sd) => {
+                let security_directory = md_shootout::SecurityDirectory::create(
+                    builder,
+                    &md_shootout::SecurityDirectoryArgs {
+                        flags: sd.flags,
+                        lot_size: sd.lot_size,
+                        previous_closing: sd.previous_closing,
+                        luld_tier: sd.luld_tier,
+                    },
+                );
+                (
+                    sd.timestamp,
+                    Some(&sd.symbol),
+                    md_shootout::MessageBody::SecurityDirectory,
+                    security_directory.as_union_value(),
+                )
+            }
+            IexMessage::TradingStatus(ts) => {
+                let reason = builder.create_string(crate::parse_reason(&ts.reason));
+                let trading_status = md_shootout::TradingStatus::create(
+                    builder,
+                    &md_shootout::TradingStatusArgs {
+                        trading_status: ts.trading_status,
+                        reason: Some(reason),
+                    },
+                );
+                (
+                    ts.timestamp,
+                    Some(&ts.symbol),
+                    md_shootout::MessageBody::TradingStatus,
+                    trading_status.as_union_value(),
+                )
+            }
+            IexMessage::OperationalHaltStatus(ohs) => {
+                let halt_status = md_shootout::OperationalHaltStatus::create(
+                    builder,
+                    &md_shootout::OperationalHaltStatusArgs {
+                        halt_status: ohs.halt_status,
+                    },
+                );
+                (
+                    ohs.timestamp,
+                    Some(&ohs.symbol),
+                    md_shootout::MessageBody::OperationalHaltStatus,
+                    halt_status.as_union_value(),
+                )
+            }
+            IexMessage::ShortSalePriceTest(sspt) => {
+                let short_sale_price_test = md_shootout::ShortSalePriceTest::create(
+                    builder,
+                    &md_shootout::ShortSalePriceTestArgs {
+                        sspt_status: sspt.sspt_status,
+                        detail: sspt.detail,
+                    },
+                );
+                (
+                    sspt.timestamp,
+                    Some(&sspt.symbol),
+                    md_shootout::MessageBody::ShortSalePriceTest,
+                    short_sale_price_test.as_union_value(),
+                )
+            }
+            IexMessage::SecurityEvent(se) => {
+                let security_event = md_shootout::SecurityEvent::create(
+                    builder,
+                    &md_shootout::SecurityEventArgs {
+                        security_event: se.security_event,
+                    },
+                );
+                (
+                    se.timestamp,
+                    Some(&se.symbol),
+                    md_shootout::MessageBody::SecurityEvent,
+                    security_event.as_union_value(),
+                )
+            }
+            IexMessage::PriceLevelUpdate(plu) => {
+                let level_update = md_shootout::LevelUpdate::create(
+                    builder,
+                    &md_shootout::LevelUpdateArgs {
+                        price: plu.price,
+                        size_: plu.size,
+                        flags: plu.event_flags,
+                        side: if plu.msg_type == 0x38 {
+                            md_shootout::Side::Buy
+                        } else {
+                            md_shootout::Side::Sell
+                        },
+                    },
+                );
+                (
+                    plu.timestamp,
+                    Some(&plu.symbol),
+                    md_shootout::MessageBody::LevelUpdate,
+                    level_update.as_union_value(),
+                )
+            }
+            IexMessage::TradeReport(tr) => {
+                let trade = md_shootout::Trade::create(
+                    builder,
+                    &md_shootout::TradeArgs {
+                        price: tr.price,
+                        size_: tr.size,
+                        sale_condition: tr.sale_condition,
+                        trade_id: tr.trade_id,
+                    },
+                );
+                (
+                    tr.timestamp,
+                    Some(&tr.symbol),
+                    md_shootout::MessageBody::Trade,
+                    trade.as_union_value(),
+                )
+            }
+            IexMessage::OfficialPrice(op) => {
+                let official_price = md_shootout::OfficialPrice::create(
+                    builder,
+                    &md_shootout::OfficialPriceArgs {
+                        price_type: op.price_type,
+                        official_price: op.official_price,
+                    },
+                );
+                (
+                    op.timestamp,
+                    Some(&op.symbol),
+                    md_shootout::MessageBody::OfficialPrice,
+                    official_price.as_union_value(),
+                )
+            }
+            IexMessage::TradeBreak(tb) => {
+                let trade_break = md_shootout::TradeBreak::create(
+                    builder,
+                    &md_shootout::TradeBreakArgs {
+                        price: tb.price,
+                        size_: tb.size,
+                        sale_condition: tb.sale_condition,
+                        trade_id: tb.trade_id,
+                    },
+                );
+                (
+                    tb.timestamp,
+                    Some(&tb.symbol),
+                    md_shootout::MessageBody::TradeBreak,
+                    trade_break.as_union_value(),
+                )
+            }
+            IexMessage::AuctionInformation(ai) => {
+                let auction_information = md_shootout::AuctionInformation::create(
+                    builder,
+                    &md_shootout::AuctionInformationArgs {
+                        auction_type: ai.auction_type,
+                        paired_shares: ai.paired_shares,
+                        reference_price: ai.reference_price,
+                        indicative_clearing_price: ai.indicative_clearing_price,
+                        imbalance_shares: ai.imbalance_shares,
+                        imbalance_side: ai.imbalance_side,
+                        extension_number: ai.extension_number,
+                        scheduled_auction: ai.scheduled_auction,
+                        auction_book_clearing_price: ai.auction_book_clearing_price,
+                        collar_reference_price: ai.collar_reference_price,
+                        lower_auction_collar: ai.lower_auction_collar,
+                        upper_auction_collar: ai.upper_auction_collar,
+                    },
+                );
+                (
+                    ai.timestamp,
+                    Some(&ai.symbol),
+                    md_shootout::MessageBody::AuctionInformation,
+                    auction_information.as_union_value(),
+                )
+            }
+        };
 
-        self.builder.reset();
-        self.message_buffer.clear();
+        let symbol = symbol.map(|sym| builder.create_string(crate::parse_symbol(sym)));
+        let msg = md_shootout::Message::create(
+            builder,
+            &md_shootout::MessageArgs {
+                ts_nanos,
+                symbol,
+                body_type,
+                body: Some(body),
+            },
+        );
+        // Offsets are plain integers; dropping the builder lifetime lets the
+        // same buffer be re-used no matter which builder is writing.
+        message_buffer.push(flatbuffers::WIPOffset::new(msg.value()));
     }
+
+    let messages = builder.create_vector(&message_buffer[..]);
+
+    // Now that we've finished building all the messages, time to set up the final buffer
+    let mut multimsg_builder = md_shootout::MultiMessageBuilder::new(builder);
+    multimsg_builder.add_seq_no(payload.first_seq_no);
+    multimsg_builder.add_messages(messages);
+    let multimsg = multimsg_builder.finish();
+
+    // IMPORTANT NOTE: If you just `finish`, Flatbuffers has no idea where
+    // an object ends in memory. To support streaming reads, you *must*
+    // use `finish_size_prefixed`. This adds a LE u32 to the front of the payload.
+    builder.finish_size_prefixed(multimsg, None);
 }
 
-pub struct FlatbuffersReader;
+pub struct FlatbuffersReader {
+    /// Run the Flatbuffers verifier over each message before reading it. Without
+    /// verification, malformed (or malicious) input can cause out-of-bounds reads.
+    verify: bool,
+}
 
 impl FlatbuffersReader {
-    pub fn new() -> FlatbuffersReader {
-        FlatbuffersReader {}
+    pub fn new(verify: bool) -> FlatbuffersReader {
+        FlatbuffersReader { verify }
     }
 }
 
-impl RunnerDeserialize for FlatbuffersReader {
-    fn deserialize<'a>(&self, buf: &'a mut StreamVec, stats: &mut Summarizer) -> Result<(), ()> {
+impl FlatbuffersReader {
+    /// Read the multi-message in `frame`, size prefix included.
+    fn root<'a>(&self, frame: &'a [u8]) -> Result<md_shootout::MultiMessage<'a>, ()> {
         // Flatbuffers has kinda ad-hoc support for streaming: https://github.com/google/flatbuffers/issues/3898
         // Essentially, you can write an optional `u32` value to the front of each message
-        // (`finish_size_prefixed` above) to figure out how long that message actually is.
+        // (`finish_size_prefixed` above) to figure out how long that message actually is,
+        // which is what `LengthPrefixed` does to split `frame` off for us.
         // Ultimately, end-users are responsible for all buffer management, "reading" is just
-        // a view over the underlying buffer.
-        let data = buf.fill_buf().unwrap();
-        if data.len() == 0 {
-            return Err(());
+        // a view over the underlying buffer. Because the verifier only gets the current
+        // message, it doesn't have to check offsets against the entire remaining buffer.
+        if self.verify {
+            flatbuffers::size_prefixed_root::<md_shootout::MultiMessage>(frame).map_err(|_| ())
+        } else {
+            Ok(unsafe {
+                flatbuffers::size_prefixed_root_unchecked::<md_shootout::MultiMessage>(frame)
+            })
         }
+    }
+}
 
-        let msg_len_buf: [u8; 4] = data[..size_of::<u32>()].try_into().unwrap();
-        let msg_len = u32::from_le_bytes(msg_len_buf) as usize;
-
-        let multimsg = flatbuffers::get_size_prefixed_root::<md_shootout::MultiMessage>(data);
-        let msg_vec = match multimsg.messages() {
-            Some(m) => m,
-            None => panic!("Couldn't find messages"),
-        };
+impl RunnerDeserialize for FlatbuffersReader {
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()> {
+        buf.read_frame(&LengthPrefixed, |frame| {
+            let multimsg = self.root(frame)?;
+            let msg_vec = match multimsg.messages() {
+                Some(m) => m,
+                None => panic!("Couldn't find messages"),
+            };
 
-        for i in 0..msg_vec.len() {
-            let msg: md_shootout::Message = msg_vec.get(i);
-            match msg.body_type() {
-                md_shootout::MessageBody::Trade => {
-                    let trade = msg.body_as_trade().unwrap();
-                    stats.append_trade_volume(msg.symbol().unwrap(), trade.size_().into());
+            for i in 0..msg_vec.len() {
+                let msg: md_shootout::Message = msg_vec.get(i);
+                match msg.body_type() {
+                    md_shootout::MessageBody::Trade => {
+                        let trade = msg.body_as_trade().unwrap();
+                        stats.append_trade_volume(msg.symbol().unwrap(), trade.size_().into());
+                    }
+                    md_shootout::MessageBody::LevelUpdate => {
+                        let lu = msg.body_as_level_update().unwrap();
+                        let is_bid = matches!(lu.side(), md_shootout::Side::Buy);
+                        stats.update_quote_prices(msg.symbol().unwrap(), lu.price(), is_bid);
+                    }
+                    md_shootout::MessageBody::NONE => panic!("Message without a body"),
+                    _ => (),
                 }
-                md_shootout::MessageBody::LevelUpdate => {
-                    let lu = msg.body_as_level_update().unwrap();
-                    let is_bid = match lu.side() {
-                        md_shootout::Side::Buy => true,
-                        _ => false,
-                    };
-                    stats.update_quote_prices(msg.symbol().unwrap(), lu.price(), is_bid);
-                }
-                md_shootout::MessageBody::NONE => panic!("Unrecognized message type"),
             }
-        }
 
-        buf.consume(msg_len + size_of::<u32>());
-        Ok(())
+            Ok(((), frame.len()))
+        })
+    }
+
+    fn decode(
+        &self,
+        buf: &mut StreamBuf,
+        messages: &mut Vec<NormalizedMessage>,
+    ) -> Result<u64, ()> {
+        buf.read_frame(&LengthPrefixed, |frame| {
+            let multimsg = self.root(frame)?;
+
+            for msg in multimsg.messages().ok_or(())?.iter() {
+                let body = match msg.body_type() {
+                    md_shootout::MessageBody::SystemEvent => {
+                        let se = msg.body_as_system_event().unwrap();
+                        NormalizedBody::SystemEvent {
+                            system_event: se.system_event(),
+                        }
+                    }
+                    md_shootout::MessageBody::SecurityDirectory => {
+                        let sd = msg.body_as_security_directory().unwrap();
+                        NormalizedBody::SecurityDirectory {
+                            flags: sd.flags(),
+                            lot_size: sd.lot_size(),
+                            previous_closing: sd.previous_closing(),
+                            luld_tier: sd.luld_tier(),
+                        }
+                    }
+                    md_shootout::MessageBody::TradingStatus => {
+                        let ts = msg.body_as_trading_status().unwrap();
+                        NormalizedBody::TradingStatus {
+                            trading_status: ts.trading_status(),
+                            reason: ts
+                                .reason()
+                                .ok_or(())?
+                                .as_bytes()
+                                .try_into()
+                                .map_err(|_| ())?,
+                        }
+                    }
+                    md_shootout::MessageBody::OperationalHaltStatus => {
+                        let ohs = msg.body_as_operational_halt_status().unwrap();
+                        NormalizedBody::OperationalHaltStatus {
+                            halt_status: ohs.halt_status(),
+                        }
+                    }
+                    md_shootout::MessageBody::ShortSalePriceTest => {
+                        let sspt = msg.body_as_short_sale_price_test().unwrap();
+                        NormalizedBody::ShortSalePriceTest {
+                            sspt_status: sspt.sspt_status(),
+                            detail: sspt.detail(),
+                        }
+                    }
+                    md_shootout::MessageBody::SecurityEvent => {
+                        let se = msg.body_as_security_event().unwrap();
+                        NormalizedBody::SecurityEvent {
+                            security_event: se.security_event(),
+                        }
+                    }
+                    md_shootout::MessageBody::LevelUpdate => {
+                        let lu = msg.body_as_level_update().unwrap();
+                        NormalizedBody::PriceLevelUpdate {
+                            is_buy: lu.side() == md_shootout::Side::Buy,
+                            event_flags: lu.flags(),
+                            size: lu.size_(),
+                            price: lu.price(),
+                        }
+                    }
+                    md_shootout::MessageBody::Trade => {
+                        let trade = msg.body_as_trade().unwrap();
+                        NormalizedBody::TradeReport {
+                            sale_condition: trade.sale_condition(),
+                            size: trade.size_(),
+                            price: trade.price(),
+                            trade_id: trade.trade_id(),
+                        }
+                    }
+                    md_shootout::MessageBody::OfficialPrice => {
+                        let op = msg.body_as_official_price().unwrap();
+                        NormalizedBody::OfficialPrice {
+                            price_type: op.price_type(),
+                            official_price: op.official_price(),
+                        }
+                    }
+                    md_shootout::MessageBody::TradeBreak => {
+                        let tb = msg.body_as_trade_break().unwrap();
+                        NormalizedBody::TradeBreak {
+                            sale_condition: tb.sale_condition(),
+                            size: tb.size_(),
+                            price: tb.price(),
+                            trade_id: tb.trade_id(),
+                        }
+                    }
+                    md_shootout::MessageBody::AuctionInformation => {
+                        let ai = msg.body_as_auction_information().unwrap();
+                        NormalizedBody::AuctionInformation {
+                            auction_type: ai.auction_type(),
+                            paired_shares: ai.paired_shares(),
+                            reference_price: ai.reference_price(),
+                            indicative_clearing_price: ai.indicative_clearing_price(),
+                            imbalance_shares: ai.imbalance_shares(),
+                            imbalance_side: ai.imbalance_side(),
+                            extension_number: ai.extension_number(),
+                            scheduled_auction: ai.scheduled_auction(),
+                            auction_book_clearing_price: ai.auction_book_clearing_price(),
+                            collar_reference_price: ai.collar_reference_price(),
+                            lower_auction_collar: ai.lower_auction_collar(),
+                            upper_auction_collar: ai.upper_auction_collar(),
+                        }
+                    }
+                    _ => return Err(()),
+                };
+
+                messages.push(NormalizedMessage {
+                    timestamp: msg.ts_nanos(),
+                    symbol: msg.symbol().unwrap_or("").to_string(),
+                    body,
+                });
+            }
+
+            let seq_no = multimsg.seq_no();
+            Ok((seq_no, frame.len()))
+        })
     }
 }
@@ -0,0 +1,324 @@
+use std::convert::TryInto;
+use std::io::{self, BufRead, Read};
+use std::mem::size_of;
+
+/// Encoded messages for a reader to work through, borrowed from wherever they
+/// were written (the output buffer, a ring buffer slot, a datagram) so readers
+/// can decode them in place.
+pub struct StreamBuf<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> StreamBuf<'a> {
+    pub fn new(data: &'a [u8]) -> StreamBuf<'a> {
+        StreamBuf { data, pos: 0 }
+    }
+
+    /// Everything that hasn't been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+
+    #[cfg_attr(not(feature = "avro"), allow(dead_code))]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Start over from the first frame.
+    pub fn rewind(&mut self) {
+        self.pos = 0;
+    }
+
+    /// Hand the next frame to `read`, using `framing` to find where it ends, and
+    /// move past it if `read` succeeds. Nothing is consumed if there's nothing
+    /// left, the final frame is truncated, or `read` fails.
+    pub fn read_frame<F, R, T>(&mut self, framing: &F, read: R) -> Result<T, ()>
+    where
+        F: Framing,
+        R: FnOnce(&'a [u8]) -> Result<(T, usize), ()>,
+    {
+        let data = self.remaining();
+        if data.is_empty() {
+            return Err(());
+        }
+
+        let (result, frame_len) = framing.frame(data, read)?;
+        self.pos += frame_len;
+        Ok(result)
+    }
+}
+
+impl Read for StreamBuf<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read_size = self.remaining().read(buf)?;
+        self.pos += read_size;
+        Ok(read_size)
+    }
+}
+
+impl BufRead for StreamBuf<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.data.len());
+    }
+}
+
+/// How a format marks where one frame ends and the next begins: a length up
+/// front (`LengthPrefixed`), a header describing what follows (`SegmentTable`),
+/// or nothing at all, so only decoding can tell (`SelfDelimiting`).
+pub trait Framing {
+    /// Hand the frame at the start of `data` to `read`, and return what it decoded
+    /// along with how long the frame was. `read` reports how many bytes it decoded,
+    /// which only matters when there's no other way to find the end of the frame.
+    /// Fails without calling `read` if `data` ends partway through a frame that
+    /// says how long it is.
+    fn frame<'a, R, T>(&self, data: &'a [u8], read: R) -> Result<(T, usize), ()>
+    where
+        R: FnOnce(&'a [u8]) -> Result<(T, usize), ()>;
+}
+
+/// A `u32` little-endian length ahead of each frame, which doesn't count
+/// itself; Flatbuffers' `finish_size_prefixed`. `read` gets the prefix too.
+#[cfg_attr(not(feature = "flatbuffers"), allow(dead_code))]
+pub struct LengthPrefixed;
+
+#[cfg_attr(not(feature = "flatbuffers"), allow(dead_code))]
+impl LengthPrefixed {
+    fn frame_len(data: &[u8]) -> Result<usize, ()> {
+        let prefix = data.get(..size_of::<u32>()).ok_or(())?;
+        let body_len = u32::from_le_bytes(prefix.try_into().unwrap()) as usize;
+        Ok(size_of::<u32>() + body_len)
+    }
+}
+
+impl Framing for LengthPrefixed {
+    fn frame<'a, R, T>(&self, data: &'a [u8], read: R) -> Result<(T, usize), ()>
+    where
+        R: FnOnce(&'a [u8]) -> Result<(T, usize), ()>,
+    {
+        let frame_len = LengthPrefixed::frame_len(data)?;
+        let frame = data.get(..frame_len).ok_or(())?;
+        let (result, _) = read(frame)?;
+        Ok((result, frame_len))
+    }
+}
+
+/// Cap'n Proto's stream framing: a table of how many segments follow and how many
+/// words are in each, padded out to a whole word. `read` gets the table too.
+#[cfg_attr(not(feature = "capnp"), allow(dead_code))]
+pub struct SegmentTable;
+
+#[cfg_attr(not(feature = "capnp"), allow(dead_code))]
+impl SegmentTable {
+    /// Same limit the `capnp` crate puts on segments per message
+    const MAX_SEGMENTS: usize = 512;
+
+    fn frame_len(data: &[u8]) -> Result<usize, ()> {
+        let word = |i: usize| {
+            data.get(i * 4..(i + 1) * 4)
+                .map(|w| u32::from_le_bytes(w.try_into().unwrap()))
+                .ok_or(())
+        };
+
+        let segment_count = word(0)?.wrapping_add(1) as usize;
+        if segment_count == 0 || segment_count >= SegmentTable::MAX_SEGMENTS {
+            return Err(());
+        }
+
+        let mut words = 0;
+        for i in 0..segment_count {
+            words += word(i + 1)? as usize;
+        }
+
+        // The count and every segment size are 4 bytes each, padded to a whole word
+        let table_len = (segment_count / 2 + 1) * 8;
+        Ok(table_len + words * 8)
+    }
+}
+
+impl Framing for SegmentTable {
+    fn frame<'a, R, T>(&self, data: &'a [u8], read: R) -> Result<(T, usize), ()>
+    where
+        R: FnOnce(&'a [u8]) -> Result<(T, usize), ()>,
+    {
+        let frame_len = SegmentTable::frame_len(data)?;
+        let frame = data.get(..frame_len).ok_or(())?;
+        let (result, _) = read(frame)?;
+        Ok((result, frame_len))
+    }
+}
+
+/// Frames that carry no length of their own, like SBE and Avro, or packed
+/// Cap'n Proto where the segment table is packed along with everything else.
+/// `read` gets everything that's left, and its decoder has to notice if that
+/// runs out before the frame does.
+pub struct SelfDelimiting;
+
+impl Framing for SelfDelimiting {
+    fn frame<'a, R, T>(&self, data: &'a [u8], read: R) -> Result<(T, usize), ()>
+    where
+        R: FnOnce(&'a [u8]) -> Result<(T, usize), ()>,
+    {
+        let (result, frame_len) = read(data)?;
+        if frame_len == 0 || frame_len > data.len() {
+            return Err(());
+        }
+        Ok((result, frame_len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Framing, LengthPrefixed, SegmentTable, SelfDelimiting, StreamBuf};
+
+    fn length_prefixed(body: &[u8]) -> Vec<u8> {
+        let mut frame = (body.len() as u32).to_le_bytes().to_vec();
+        frame.extend_from_slice(body);
+        frame
+    }
+
+    fn segment_table(segments: &[&[u8]]) -> Vec<u8> {
+        let mut frame = (segments.len() as u32 - 1).to_le_bytes().to_vec();
+        for segment in segments {
+            assert!(segment.len().is_multiple_of(8));
+            frame.extend_from_slice(&(segment.len() as u32 / 8).to_le_bytes());
+        }
+        if !frame.len().is_multiple_of(8) {
+            frame.extend_from_slice(&[0; 4]);
+        }
+        for segment in segments {
+            frame.extend_from_slice(segment);
+        }
+        frame
+    }
+
+    /// A frame that's a length byte, then that many bytes; only reading it
+    /// finds the end, the way an SBE or Avro decoder would.
+    fn self_delimited(body: &[u8]) -> Vec<u8> {
+        let mut frame = vec![body.len() as u8];
+        frame.extend_from_slice(body);
+        frame
+    }
+
+    fn decode_self_delimited(data: &[u8]) -> Result<(&[u8], usize), ()> {
+        let len = *data.first().ok_or(())? as usize;
+        let body = data.get(1..1 + len).ok_or(())?;
+        Ok((body, 1 + len))
+    }
+
+    /// Read every frame in `stream` with `framing`, returning them along with
+    /// how many bytes were left over.
+    fn read_all<'a, F, R>(stream: &'a [u8], framing: &F, read: R) -> (Vec<&'a [u8]>, usize)
+    where
+        F: Framing,
+        R: Fn(&'a [u8]) -> Result<(&'a [u8], usize), ()>,
+    {
+        let mut buf = StreamBuf::new(stream);
+        let mut frames = Vec::new();
+        while let Ok(frame) = buf.read_frame(framing, &read) {
+            frames.push(frame);
+        }
+        (frames, buf.remaining().len())
+    }
+
+    /// Every way of cutting the last of `frames` short should still read the ones
+    /// before it, and leave the truncated frame where it was, rather than panic
+    /// or hand a partial frame to the reader.
+    fn check_truncated_final_frame<F, R>(frames: &[Vec<u8>], framing: &F, read: R)
+    where
+        F: Framing,
+        R: Fn(&[u8]) -> Result<(&[u8], usize), ()> + Copy,
+    {
+        let stream = frames.concat();
+        let (read_frames, left_over) = read_all(&stream, framing, read);
+        assert_eq!(read_frames.len(), frames.len());
+        assert_eq!(left_over, 0);
+
+        let last_len = frames.last().unwrap().len();
+        for cut in 1..last_len {
+            let truncated = &stream[..stream.len() - cut];
+            let (read_frames, left_over) = read_all(truncated, framing, read);
+            assert_eq!(read_frames.len(), frames.len() - 1, "cut {} bytes", cut);
+            assert_eq!(left_over, last_len - cut, "cut {} bytes", cut);
+        }
+    }
+
+    #[test]
+    fn length_prefixed_truncated_final_frame() {
+        let frames = vec![
+            length_prefixed(b"first"),
+            length_prefixed(b""),
+            length_prefixed(b"and the last one"),
+        ];
+        check_truncated_final_frame(&frames, &LengthPrefixed, |frame| Ok((frame, frame.len())));
+    }
+
+    #[test]
+    fn length_prefixed_hands_over_the_prefix() {
+        let stream = length_prefixed(b"abc");
+        let mut buf = StreamBuf::new(&stream);
+        let frame = buf
+            .read_frame(&LengthPrefixed, |frame| Ok((frame, frame.len())))
+            .unwrap();
+        assert_eq!(frame, &stream[..]);
+        assert!(buf.remaining().is_empty());
+    }
+
+    #[test]
+    fn segment_table_truncated_final_frame() {
+        let frames = vec![
+            segment_table(&[&[1; 16]]),
+            segment_table(&[&[2; 8], &[3; 24]]),
+            segment_table(&[&[4; 8], &[5; 8], &[6; 16]]),
+        ];
+        check_truncated_final_frame(&frames, &SegmentTable, |frame| Ok((frame, frame.len())));
+    }
+
+    #[test]
+    fn segment_table_lengths() {
+        // One segment fits its size in the first word; two need a padded second word
+        assert_eq!(SegmentTable::frame_len(&segment_table(&[&[0; 8]])), Ok(16));
+        assert_eq!(
+            SegmentTable::frame_len(&segment_table(&[&[0; 8], &[0; 8]])),
+            Ok(32)
+        );
+        assert_eq!(
+            SegmentTable::frame_len(&segment_table(&[&[0; 8], &[0; 8], &[0; 8]])),
+            Ok(40)
+        );
+        assert_eq!(SegmentTable::frame_len(&[0xff; 8]), Err(()));
+    }
+
+    #[test]
+    fn self_delimiting_truncated_final_frame() {
+        let frames = vec![
+            self_delimited(b"first"),
+            self_delimited(b""),
+            self_delimited(b"and the last one"),
+        ];
+        check_truncated_final_frame(&frames, &SelfDelimiting, decode_self_delimited);
+    }
+
+    #[test]
+    fn self_delimiting_rejects_overlong_frames() {
+        let stream = self_delimited(b"abc");
+        let mut buf = StreamBuf::new(&stream);
+        // A decoder that doesn't check its bounds can't walk the cursor off the end
+        let res = buf.read_frame(&SelfDelimiting, |data| Ok(((), data.len() + 1)));
+        assert_eq!(res, Err(()));
+        assert_eq!(buf.position(), 0);
+    }
+
+    #[test]
+    fn failed_reads_arent_consumed() {
+        let stream = length_prefixed(b"abc");
+        let mut buf = StreamBuf::new(&stream);
+        let res: Result<(), ()> = buf.read_frame(&LengthPrefixed, |_| Err(()));
+        assert_eq!(res, Err(()));
+        assert_eq!(buf.position(), 0);
+    }
+}
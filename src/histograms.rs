@@ -0,0 +1,238 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use hdrhistogram::serialization::interval_log::{IntervalLogWriterBuilder, Tag};
+use hdrhistogram::serialization::V2DeflateSerializer;
+use hdrhistogram::Histogram;
+
+use crate::clock::Clock;
+use crate::report::RunMetadata;
+use crate::RunAnalysis;
+
+/// Quantiles printed by `spectrum`, between the minimum and maximum.
+const QUANTILES: &[(&str, f64)] = &[
+    ("50", 0.5),
+    ("90", 0.9),
+    ("99", 0.99),
+    ("99.9", 0.999),
+    ("99.99", 0.9999),
+    ("99.999", 0.99999),
+];
+
+/// Packet sizes (in messages) that latencies get grouped by; IEX packets carry
+/// anywhere from one message to a few hundred.
+pub const PACKET_SIZES: &[&str] = &["1", "2-3", "4-7", "8-15", "16-31", "32-63", "64+"];
+
+/// Which of `PACKET_SIZES` a packet with `msg_count` messages falls into.
+pub fn packet_size_bucket(msg_count: u16) -> usize {
+    let log2 = 15 - msg_count.max(1).leading_zeros() as usize;
+    log2.min(PACKET_SIZES.len() - 1)
+}
+
+/// Per-packet latencies, normalized for how many messages were in each packet so
+/// that packet composition doesn't skew comparisons between formats.
+pub struct MessageHists {
+    /// Packet latency divided by its message count
+    pub per_message: Histogram<u64>,
+    /// Packet latency, split up by `PACKET_SIZES`
+    pub by_packet_size: Vec<Histogram<u64>>,
+}
+
+impl MessageHists {
+    pub fn new() -> MessageHists {
+        MessageHists {
+            per_message: Histogram::<u64>::new(2).unwrap(),
+            by_packet_size: PACKET_SIZES
+                .iter()
+                .map(|_| Histogram::<u64>::new(2).unwrap())
+                .collect(),
+        }
+    }
+
+    pub fn record(&mut self, nanos: u64, msg_count: u16) {
+        if msg_count == 0 {
+            return;
+        }
+        self.per_message.record(nanos / msg_count as u64).unwrap();
+        self.by_packet_size[packet_size_bucket(msg_count)]
+            .record(nanos)
+            .unwrap();
+    }
+}
+
+/// When a measured iteration ran, so its histograms can be placed in the log.
+pub struct Iteration {
+    pub start: SystemTime,
+    pub duration: Duration,
+}
+
+/// Where to write the histogram log for a runner: one file per runner and data
+/// date, so logs from different days can share a directory.
+pub fn log_path(dir: &Path, metadata: &RunMetadata, runner: &str) -> PathBuf {
+    let run_date = metadata.run_date().unwrap_or("unknown");
+    dir.join(format!("{}_{}.hlog", run_date, runner))
+}
+
+/// Write every iteration's histograms as an HdrHistogram interval log, using the
+/// compressed V2 encoding. Each iteration gets one interval tagged `serialize`
+/// and one tagged `deserialize`, plus the per-message and per-packet-size
+/// breakdowns and, with `--pipeline`, one tagged `one_way`. The standard
+/// HdrHistogram tools can merge and plot them.
+pub fn write_log(
+    path: &Path,
+    metadata: &RunMetadata,
+    protocol: &str,
+    iterations: &[Iteration],
+    runs: &[RunAnalysis],
+) {
+    let file = File::create(path)
+        .unwrap_or_else(|_| panic!("Unable to create histogram log={}", path.display()));
+    let mut writer = BufWriter::new(file);
+    let mut serializer = V2DeflateSerializer::new();
+
+    let start = iterations[0].start;
+    let mut builder = IntervalLogWriterBuilder::new();
+    builder.add_comment(&format!("protocol: {}", protocol));
+    for (name, value) in metadata.fields() {
+        builder.add_comment(&format!("{}: {}", name, value));
+    }
+    builder.add_comment(&format!("clock: {}", metadata.clock_description()));
+    builder.add_comment(&format!(
+        "scheduling: {}",
+        metadata.scheduling_description()
+    ));
+    builder.add_comment(&format!("pipeline: {}", metadata.pipeline()));
+    builder.add_comment(&format!("replay: {}", metadata.replay()));
+    builder.with_start_time(start).with_base_time(start);
+    let mut log = builder
+        .begin_log_with(&mut writer, &mut serializer)
+        .expect("Unable to write histogram log header");
+
+    for (iteration, run) in iterations.iter().zip(runs) {
+        // Clocks can go backwards, but the offset is only used for plotting
+        let offset = iteration.start.duration_since(start).unwrap_or_default();
+        let mut tagged = vec![
+            ("serialize".to_string(), &run.serialize_hist),
+            ("deserialize".to_string(), &run.deserialize_hist),
+            (
+                "serialize_per_msg".to_string(),
+                &run.serialize_msg_hists.per_message,
+            ),
+            (
+                "deserialize_per_msg".to_string(),
+                &run.deserialize_msg_hists.per_message,
+            ),
+        ];
+        for (i, size) in PACKET_SIZES.iter().enumerate() {
+            tagged.push((
+                format!("serialize_packet_{}", size),
+                &run.serialize_msg_hists.by_packet_size[i],
+            ));
+            tagged.push((
+                format!("deserialize_packet_{}", size),
+                &run.deserialize_msg_hists.by_packet_size[i],
+            ));
+        }
+
+        if let Some(pipeline) = &run.pipeline {
+            tagged.push(("one_way".to_string(), &pipeline.one_way_hist));
+        }
+
+        for (tag, hist) in tagged {
+            log.write_histogram(hist, offset, iteration.duration, Tag::new(&tag))
+                .expect("Unable to write histogram log");
+        }
+    }
+}
+
+/// Combine the histograms from every iteration, so the spectrum covers all of them.
+pub fn merge<'a>(hists: impl Iterator<Item = &'a Histogram<u64>>) -> Histogram<u64> {
+    let mut merged = Histogram::<u64>::new(2).unwrap();
+    for hist in hists {
+        merged.add(hist).unwrap();
+    }
+    merged
+}
+
+/// Latency at each percentile, side by side for serializing and deserializing.
+pub fn spectrum(serialize: &Histogram<u64>, deserialize: &Histogram<u64>) -> String {
+    let mut rows = vec![("min".to_string(), serialize.min(), deserialize.min())];
+    for (name, quantile) in QUANTILES {
+        rows.push((
+            format!("p{}", name),
+            serialize.value_at_quantile(*quantile),
+            deserialize.value_at_quantile(*quantile),
+        ));
+    }
+    rows.push(("max".to_string(), serialize.max(), deserialize.max()));
+
+    let mut out = format!("  {:<8}{:>14}{:>14}", "", "serialize", "deserialize");
+    for (name, serialize, deserialize) in rows {
+        out.push_str(&format!(
+            "\n  {:<8}{:>12}ns{:>12}ns",
+            name, serialize, deserialize
+        ));
+    }
+    out
+}
+
+/// Throughput, then latency per message and per packet size unless the clock is
+/// `batch`, across every iteration.
+pub fn message_stats(runs: &[RunAnalysis], clock: Clock) -> String {
+    let iterations = runs.len() as f64;
+    let mean = |f: &dyn Fn(&RunAnalysis) -> f64| runs.iter().map(f).sum::<f64>() / iterations;
+    let mut out = format!(
+        "  messages={} bytes_per_message={:.1}b serialize={:.0}msgs/s deserialize={:.0}msgs/s",
+        runs[0].messages,
+        runs[0].bytes_per_message(),
+        mean(&|r| r.serialize_msgs_per_sec()),
+        mean(&|r| r.deserialize_msgs_per_sec()),
+    );
+    if !clock.per_message() {
+        out.push_str("\n  latencies: not measured with --clock batch");
+        return out;
+    }
+
+    out.push_str(&format!(
+        "\n  {:<14}{:>10}{:>22}{:>22}",
+        "packet size", "packets", "serialize p50/p99", "deserialize p50/p99"
+    ));
+    let row = |name: &str, serialize: &Histogram<u64>, deserialize: &Histogram<u64>| {
+        format!(
+            "\n  {:<14}{:>10}{:>22}{:>22}",
+            name,
+            serialize.len() / runs.len() as u64,
+            format!(
+                "{}/{}ns",
+                serialize.value_at_quantile(0.5),
+                serialize.value_at_quantile(0.99)
+            ),
+            format!(
+                "{}/{}ns",
+                deserialize.value_at_quantile(0.5),
+                deserialize.value_at_quantile(0.99)
+            ),
+        )
+    };
+    out.push_str(&row(
+        "per message",
+        &merge(runs.iter().map(|r| &r.serialize_msg_hists.per_message)),
+        &merge(runs.iter().map(|r| &r.deserialize_msg_hists.per_message)),
+    ));
+    for (i, size) in PACKET_SIZES.iter().enumerate() {
+        out.push_str(&row(
+            size,
+            &merge(
+                runs.iter()
+                    .map(|r| &r.serialize_msg_hists.by_packet_size[i]),
+            ),
+            &merge(
+                runs.iter()
+                    .map(|r| &r.deserialize_msg_hists.by_packet_size[i]),
+            ),
+        ));
+    }
+    out
+}
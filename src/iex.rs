@@ -2,15 +2,20 @@ use std::convert::TryInto;
 
 use nom::{bytes::complete::take, number::complete::*, sequence::tuple, IResult};
 
-use crate::parsers::{extract_iex_data, read_block, Block};
+use crate::parsers::{extract_iex_data, read_block, timestamp_nanos, Block};
 
 pub struct IexParser<'a> {
     pcap_buffer: &'a [u8],
+    /// `if_tsresol` of every interface in the current section, by interface ID
+    ts_resolutions: Vec<u8>,
 }
 
 impl<'a> IexParser<'a> {
-    pub fn new(pcap_buffer: &[u8]) -> IexParser {
-        IexParser { pcap_buffer }
+    pub fn new(pcap_buffer: &'a [u8]) -> IexParser<'a> {
+        IexParser {
+            pcap_buffer,
+            ts_resolutions: Vec::new(),
+        }
     }
 }
 
@@ -25,10 +30,15 @@ impl<'a> Iterator for IexParser<'a> {
             match block {
                 Block::EnhancedPacket(e) => {
                     let (_, iex_data) = extract_iex_data(e.packet_data).unwrap();
-                    let (_, payload) = IexPayload::parse(iex_data).unwrap();
+                    let (_, mut payload) = IexPayload::parse(iex_data).unwrap();
+                    if let Some(&ts_resolution) = self.ts_resolutions.get(e.interface_id as usize) {
+                        payload.capture_time = Some(timestamp_nanos(e.timestamp, ts_resolution));
+                    }
                     return Some(payload);
                 }
-                _ => (),
+                // Interface IDs start over in each section
+                Block::SectionHeader => self.ts_resolutions.clear(),
+                Block::InterfaceDescription(i) => self.ts_resolutions.push(i.ts_resolution),
             }
         }
 
@@ -38,17 +48,22 @@ impl<'a> Iterator for IexParser<'a> {
 
 #[derive(Debug)]
 pub struct IexPayload {
-    version: u8,
+    _version: u8,
     _reserved: u8,
-    proto_id: u16,
-    channel_id: u32,
-    session_id: u32,
-    payload_len: u16,
+    _proto_id: u16,
+    _channel_id: u32,
+    _session_id: u32,
+    _payload_len: u16,
     pub msg_count: u16,
-    stream_offset: u64,
+    _stream_offset: u64,
     pub first_seq_no: u64,
-    send_time: i64,
+    /// When IEX sent the packet, in nanoseconds since the epoch
+    pub send_time: i64,
     pub messages: smallvec::SmallVec<[IexMessage; 256]>,
+    /// When the packet was captured, in nanoseconds since the epoch. Comes from
+    /// the pcapng file rather than IEX, so it's only set by `IexParser`, and only
+    /// when the packet's interface was described earlier in its section.
+    pub capture_time: Option<u64>,
 }
 
 impl IexPayload {
@@ -56,14 +71,14 @@ impl IexPayload {
         let (
             mut rem,
             (
-                version,
+                _version,
                 _reserved,
-                proto_id,
-                channel_id,
-                session_id,
-                payload_len,
+                _proto_id,
+                _channel_id,
+                _session_id,
+                _payload_len,
                 msg_count,
-                stream_offset,
+                _stream_offset,
                 first_seq_no,
                 send_time,
             ),
@@ -78,21 +93,22 @@ impl IexPayload {
             messages.push(msg);
         }
 
-        debug_assert!(rem.len() == 0);
+        debug_assert!(rem.is_empty());
         Ok((
             rem,
             IexPayload {
-                version,
+                _version,
                 _reserved,
-                proto_id,
-                channel_id,
-                session_id,
-                payload_len,
+                _proto_id,
+                _channel_id,
+                _session_id,
+                _payload_len,
                 msg_count,
-                stream_offset,
+                _stream_offset,
                 first_seq_no,
                 send_time,
                 messages,
+                capture_time: None,
             },
         ))
     }
@@ -151,19 +167,17 @@ impl IexMessage {
 
 #[derive(Debug)]
 pub struct SystemEvent {
-    msg_type: u8,
-    system_event: u8,
-    timestamp: i64,
+    pub system_event: u8,
+    pub timestamp: i64,
 }
 
 impl SystemEvent {
     fn parse(input: &[u8]) -> IResult<&[u8], SystemEvent> {
-        let (rem, (msg_type, system_event, timestamp)) = tuple((le_u8, le_u8, le_i64))(input)?;
+        let (rem, (_msg_type, system_event, timestamp)) = tuple((le_u8, le_u8, le_i64))(input)?;
 
         Ok((
             rem,
             SystemEvent {
-                msg_type,
                 system_event,
                 timestamp,
             },
@@ -173,24 +187,22 @@ impl SystemEvent {
 
 #[derive(Debug)]
 pub struct SecurityDirectory {
-    msg_type: u8,
-    flags: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
-    lot_size: u32,
-    previous_closing: u64,
-    luld_tier: u8,
+    pub flags: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
+    pub lot_size: u32,
+    pub previous_closing: u64,
+    pub luld_tier: u8,
 }
 
 impl SecurityDirectory {
     fn parse(input: &[u8]) -> IResult<&[u8], SecurityDirectory> {
-        let (rem, (msg_type, flags, timestamp, symbol, lot_size, previous_closing, luld_tier)) =
+        let (rem, (_msg_type, flags, timestamp, symbol, lot_size, previous_closing, luld_tier)) =
             tuple((le_u8, le_u8, le_i64, take(8usize), le_u32, le_u64, le_u8))(input)?;
 
         Ok((
             rem,
             SecurityDirectory {
-                msg_type,
                 flags,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -204,22 +216,20 @@ impl SecurityDirectory {
 
 #[derive(Debug)]
 pub struct TradingStatus {
-    msg_type: u8,
-    trading_status: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
-    reason: [u8; 4],
+    pub trading_status: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
+    pub reason: [u8; 4],
 }
 
 impl TradingStatus {
     fn parse(input: &[u8]) -> IResult<&[u8], TradingStatus> {
-        let (rem, (msg_type, trading_status, timestamp, symbol, reason)) =
+        let (rem, (_msg_type, trading_status, timestamp, symbol, reason)) =
             tuple((le_u8, le_u8, le_i64, take(8usize), take(4usize)))(input)?;
 
         Ok((
             rem,
             TradingStatus {
-                msg_type,
                 trading_status,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -231,21 +241,19 @@ impl TradingStatus {
 
 #[derive(Debug)]
 pub struct OperationalHaltStatus {
-    msg_type: u8,
-    halt_status: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
+    pub halt_status: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
 }
 
 impl OperationalHaltStatus {
     fn parse(input: &[u8]) -> IResult<&[u8], OperationalHaltStatus> {
-        let (rem, (msg_type, halt_status, timestamp, symbol)) =
+        let (rem, (_msg_type, halt_status, timestamp, symbol)) =
             tuple((le_u8, le_u8, le_i64, take(8usize)))(input)?;
 
         Ok((
             rem,
             OperationalHaltStatus {
-                msg_type,
                 halt_status,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -256,22 +264,20 @@ impl OperationalHaltStatus {
 
 #[derive(Debug)]
 pub struct ShortSalePriceTest {
-    msg_type: u8,
-    sspt_status: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
-    detail: u8,
+    pub sspt_status: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
+    pub detail: u8,
 }
 
 impl ShortSalePriceTest {
     fn parse(input: &[u8]) -> IResult<&[u8], ShortSalePriceTest> {
-        let (rem, (msg_type, sspt_status, timestamp, symbol, detail)) =
+        let (rem, (_msg_type, sspt_status, timestamp, symbol, detail)) =
             tuple((le_u8, le_u8, le_i64, take(8usize), le_u8))(input)?;
 
         Ok((
             rem,
             ShortSalePriceTest {
-                msg_type,
                 sspt_status,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -283,21 +289,19 @@ impl ShortSalePriceTest {
 
 #[derive(Debug)]
 pub struct SecurityEvent {
-    msg_type: u8,
-    security_event: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
+    pub security_event: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
 }
 
 impl SecurityEvent {
     fn parse(input: &[u8]) -> IResult<&[u8], SecurityEvent> {
-        let (rem, (msg_type, security_event, timestamp, symbol)) =
+        let (rem, (_msg_type, security_event, timestamp, symbol)) =
             tuple((le_u8, le_u8, le_i64, take(8usize)))(input)?;
 
         Ok((
             rem,
             SecurityEvent {
-                msg_type,
                 security_event,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -337,7 +341,6 @@ impl PriceLevelUpdate {
 
 #[derive(Debug)]
 pub struct TradeReport {
-    pub msg_type: u8,
     pub sale_condition: u8,
     pub timestamp: i64,
     pub symbol: [u8; 8],
@@ -348,13 +351,12 @@ pub struct TradeReport {
 
 impl TradeReport {
     fn parse(input: &[u8]) -> IResult<&[u8], TradeReport> {
-        let (rem, (msg_type, sale_condition, timestamp, symbol, size, price, trade_id)) =
+        let (rem, (_msg_type, sale_condition, timestamp, symbol, size, price, trade_id)) =
             tuple((le_u8, le_u8, le_i64, take(8usize), le_u32, le_u64, le_u64))(input)?;
 
         Ok((
             rem,
             TradeReport {
-                msg_type,
                 sale_condition,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -368,22 +370,20 @@ impl TradeReport {
 
 #[derive(Debug)]
 pub struct OfficialPrice {
-    msg_type: u8,
-    price_type: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
-    official_price: u64,
+    pub price_type: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
+    pub official_price: u64,
 }
 
 impl OfficialPrice {
     fn parse(input: &[u8]) -> IResult<&[u8], OfficialPrice> {
-        let (rem, (msg_type, price_type, timestamp, symbol, official_price)) =
+        let (rem, (_msg_type, price_type, timestamp, symbol, official_price)) =
             tuple((le_u8, le_u8, le_i64, take(8usize), le_u64))(input)?;
 
         Ok((
             rem,
             OfficialPrice {
-                msg_type,
                 price_type,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -395,24 +395,22 @@ impl OfficialPrice {
 
 #[derive(Debug)]
 pub struct TradeBreak {
-    msg_type: u8,
-    sale_condition: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
-    size: u32,
-    price: u64,
-    trade_id: u64,
+    pub sale_condition: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
+    pub size: u32,
+    pub price: u64,
+    pub trade_id: u64,
 }
 
 impl TradeBreak {
     fn parse(input: &[u8]) -> IResult<&[u8], TradeBreak> {
-        let (rem, (msg_type, sale_condition, timestamp, symbol, size, price, trade_id)) =
+        let (rem, (_msg_type, sale_condition, timestamp, symbol, size, price, trade_id)) =
             tuple((le_u8, le_u8, le_i64, take(8usize), le_u32, le_u64, le_u64))(input)?;
 
         Ok((
             rem,
             TradeBreak {
-                msg_type,
                 sale_condition,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
@@ -426,21 +424,20 @@ impl TradeBreak {
 
 #[derive(Debug)]
 pub struct AuctionInformation {
-    msg_type: u8,
-    auction_type: u8,
-    timestamp: i64,
-    symbol: [u8; 8],
-    paired_shares: u32,
-    reference_price: u64,
-    indicative_clearing_price: u64,
-    imbalance_shares: u32,
-    imbalance_side: u8,
-    extension_number: u8,
-    scheduled_auction: u32,
-    auction_book_clearing_price: u64,
-    collar_reference_price: u64,
-    lower_auction_collar: u64,
-    upper_auction_collar: u64,
+    pub auction_type: u8,
+    pub timestamp: i64,
+    pub symbol: [u8; 8],
+    pub paired_shares: u32,
+    pub reference_price: u64,
+    pub indicative_clearing_price: u64,
+    pub imbalance_shares: u32,
+    pub imbalance_side: u8,
+    pub extension_number: u8,
+    pub scheduled_auction: u32,
+    pub auction_book_clearing_price: u64,
+    pub collar_reference_price: u64,
+    pub lower_auction_collar: u64,
+    pub upper_auction_collar: u64,
 }
 
 impl AuctionInformation {
@@ -449,7 +446,7 @@ impl AuctionInformation {
         let (
             rem,
             (
-                msg_type,
+                _msg_type,
                 auction_type,
                 timestamp,
                 symbol,
@@ -486,7 +483,6 @@ impl AuctionInformation {
         Ok((
             rem,
             AuctionInformation {
-                msg_type,
                 auction_type,
                 timestamp,
                 symbol: symbol.try_into().unwrap(),
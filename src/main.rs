@@ -1,36 +1,116 @@
 use std::cmp::{max, min};
 use std::collections::hash_map::{DefaultHasher, HashMap};
-use std::fs::File;
+use std::fs::{self, File};
 use std::hash::Hasher;
-use std::io::Error;
-use std::io::{BufRead, Read};
+use std::io::Read;
+use std::mem;
 use std::path::Path;
+use std::process;
 use std::str::from_utf8_unchecked;
 use std::time::{Instant, SystemTime};
 
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg, SubCommand};
 use hdrhistogram::Histogram;
 use nom::{bytes::complete::take_until, IResult};
 
+use crate::alloc::AllocStats;
+use crate::clock::{Clock, Timing};
+use crate::framing::StreamBuf;
+use crate::histograms::MessageHists;
 use crate::iex::{IexParser, IexPayload};
+use crate::pipeline::{Pipeline, PipelineStats, Transport};
+use crate::replay::{Replay, Timestamps};
+use crate::report::{OutputFormat, RunMetadata};
+use crate::stats::Estimate;
+use crate::verify::NormalizedMessage;
 
 // Cap'n'Proto and Flatbuffers typically ask that you generate code on the fly to match
 // the schemas. For purposes of auto-complete and easy browsing in the repository,
 // we generate the code and just copy it into the src/ tree.
-pub mod marketdata_capnp;
-#[allow(unused_imports)]
-pub mod marketdata_generated; // Flatbuffers
-#[allow(dead_code)]
-pub mod marketdata_sbe;
+// With the `out-dir-generated` feature, we use the build script's output directly instead.
+macro_rules! generated_mods {
+    ($($(#[$attr:meta])* $name:ident;)*) => {$(
+        #[cfg(not(feature = "out-dir-generated"))]
+        $(#[$attr])*
+        pub mod $name;
+        #[cfg(feature = "out-dir-generated")]
+        $(#[$attr])*
+        pub mod $name {
+            include!(concat!(env!("OUT_DIR"), "/", stringify!($name), ".rs"));
+        }
+    )*};
+}
+
+// The Cap'n Proto and Flatbuffers compilers don't generate clippy-clean code
+generated_mods! {
+    #[cfg(feature = "capnp")]
+    #[allow(clippy::all, mismatched_lifetime_syntaxes)]
+    marketdata_capnp;
+    #[cfg(feature = "flatbuffers")]
+    #[allow(unused_imports, clippy::all)]
+    marketdata_generated; // Flatbuffers
+    #[cfg(feature = "sbe")]
+    #[allow(dead_code)]
+    marketdata_sbe;
+    #[cfg(feature = "sbe")]
+    #[allow(dead_code)]
+    marketdata_sbe_chained;
+    #[cfg(feature = "sbe")]
+    #[allow(dead_code)]
+    marketdata_sbe_groups;
+    #[cfg(feature = "sbe")]
+    #[allow(dead_code)]
+    marketdata_sbe_templates;
+}
+
+#[cfg(not(any(
+    feature = "avro",
+    feature = "capnp",
+    feature = "flatbuffers",
+    feature = "sbe"
+)))]
+compile_error!("No formats to benchmark; enable at least one of the `avro`, `capnp`, `flatbuffers` or `sbe` features");
 
+mod alloc;
+#[cfg(feature = "avro")]
+mod avro_runner;
+#[cfg(feature = "capnp")]
 mod capnp_runner;
+mod clock;
+mod compare;
+#[cfg(feature = "flatbuffers")]
 mod flatbuffers_runner;
+mod framing;
+mod histograms;
 mod iex;
 mod parsers;
+mod pipeline;
+mod registry;
+mod replay;
+mod report;
+#[cfg(test)]
+mod sample;
+#[cfg(feature = "sbe")]
+mod sbe_chained_runner;
+#[cfg(feature = "sbe")]
+mod sbe_groups_runner;
+#[cfg(feature = "sbe")]
 mod sbe_runner;
+#[cfg(feature = "sbe")]
+mod sbe_templates_runner;
+mod sched;
+mod shm;
+mod stats;
+mod udp;
+mod verify;
 
 fn main() {
+    let runners = registry::runners();
+    let runner_names: Vec<&str> = runners.iter().map(|r| r.name).collect();
+    let metric_names = report::metric_columns();
+
     let matches = App::new("Marketdata Shootout")
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(
             Arg::with_name("file")
                 .short("f")
@@ -40,73 +120,553 @@ fn main() {
                 .required(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("write-mode")
+                .long("write-mode")
+                .value_name("MODE")
+                .help(
+                    "Whether serializers encode in a scratch buffer or directly into the output; \
+                     formats that can't write in place are skipped",
+                )
+                .possible_values(&["scratch", "in-place"])
+                .default_value("scratch")
+                .takes_value(true),
+        )
+        .arg(Arg::with_name("verify").long("verify").help(
+            "Decode every message again after benchmarking and compare it with the IEX original",
+        ))
+        .arg(
+            Arg::with_name("formats")
+                .long("formats")
+                .value_name("FORMAT")
+                .help(
+                    "Only run these formats (comma-separated); defaults to everything compiled in",
+                )
+                .possible_values(&runner_names)
+                .use_delimiter(true)
+                .multiple(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("exclude")
+                .long("exclude")
+                .value_name("FORMAT")
+                .help("Skip these formats (comma-separated)")
+                .possible_values(&runner_names)
+                .use_delimiter(true)
+                .multiple(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("iterations")
+                .long("iterations")
+                .value_name("COUNT")
+                .help("Number of times to run each format; results are summarized across runs")
+                .default_value("1")
+                .validator(is_count)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("warmup")
+                .long("warmup")
+                .value_name("COUNT")
+                .help("Number of runs of each format to throw away before measuring")
+                .default_value("0")
+                .validator(is_count)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("output-format")
+                .long("output-format")
+                .value_name("FORMAT")
+                .help("How to print results; JSON and CSV get one record per iteration")
+                .possible_values(&["text", "json", "csv"])
+                .default_value("text")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("clock")
+                .long("clock")
+                .value_name("CLOCK")
+                .help(
+                    "How to time each message; `batch` skips per-message timings \
+                     and only measures whole runs",
+                )
+                .possible_values(clock::CLOCKS)
+                .default_value("instant")
+                .takes_value(true),
+        )
+        .arg(Arg::with_name("subtract-overhead").long("subtract-overhead").help(
+            "Take the measured cost of reading the clock off every per-message timing",
+        ))
+        .arg(Arg::with_name("per-message").long("per-message").help(
+            "Print latency per message and by packet size, along with throughput, for each format",
+        ))
+        .arg(Arg::with_name("percentiles").long("percentiles").help(
+            "Print the full latency spectrum, from min through p99.999 to max, for each format",
+        ))
+        .arg(
+            Arg::with_name("histogram-dir")
+                .long("histogram-dir")
+                .value_name("DIR")
+                .help("Write each format's histograms to DIR as HdrHistogram interval logs")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("cpu")
+                .long("cpu")
+                .value_name("CPU")
+                .help("Pin the benchmark to these CPUs (comma-separated), like `taskset`")
+                .validator(is_count)
+                .use_delimiter(true)
+                .multiple(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("nice")
+                .long("nice")
+                .value_name("NICENESS")
+                .help("Run at this niceness, from -20 (highest priority) to 19, like `nice -n`")
+                .validator(is_niceness)
+                .allow_hyphen_values(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("sched-fifo")
+                .long("sched-fifo")
+                .value_name("PRIORITY")
+                .help(
+                    "Run with real-time SCHED_FIFO priority from 1 to 99, like `chrt -f`; \
+                     this can starve the rest of the system until the benchmark finishes",
+                )
+                .validator(is_fifo_priority)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("pipeline")
+                .long("pipeline")
+                .help(
+                    "Serialize on one thread and deserialize on another, passing packets \
+                     through a lock-free ring, and measure one-way latency between them",
+                )
+                .conflicts_with("verify"),
+        )
+        .arg(
+            Arg::with_name("processes")
+                .long("processes")
+                .help(
+                    "With --pipeline, deserialize in a forked process instead of a thread, \
+                     with the ring in shared memory",
+                )
+                .requires("pipeline"),
+        )
+        .arg(
+            Arg::with_name("udp")
+                .long("udp")
+                .help(
+                    "With --pipeline, send each packet as its own UDP datagram on localhost \
+                     instead of through a ring, and count the ones that get dropped",
+                )
+                .requires("pipeline")
+                .conflicts_with("processes"),
+        )
+        .arg(
+            Arg::with_name("max-datagram")
+                .long("max-datagram")
+                .value_name("BYTES")
+                .help(
+                    "Largest datagram to send with --udp, header included; bigger packets \
+                     are skipped and counted instead. Defaults to the most UDP allows, \
+                     but 1472 fits in an Ethernet frame",
+                )
+                .requires("udp")
+                .validator(is_datagram_size)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("producer-cpu")
+                .long("producer-cpu")
+                .value_name("CPU")
+                .help("Pin the serializing thread to this CPU with --pipeline")
+                .requires("pipeline")
+                .validator(is_count)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("consumer-cpu")
+                .long("consumer-cpu")
+                .value_name("CPU")
+                .help("Pin the deserializing thread to this CPU with --pipeline")
+                .requires("pipeline")
+                .validator(is_count)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("replay")
+                .long("replay")
+                .value_name("PACE")
+                .help(
+                    "Pace payloads like the original feed instead of serializing them back \
+                     to back, either sped up like `1x` or `10x`, or at a fixed message rate \
+                     like `100000/s`; needs a per-message clock",
+                )
+                .validator(is_pace)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("replay-timestamps")
+                .long("replay-timestamps")
+                .value_name("TIMESTAMPS")
+                .help(
+                    "Which timestamps to keep the gaps between with --replay: when IEX sent \
+                     each payload (the default), or when it was captured",
+                )
+                .possible_values(replay::TIMESTAMPS)
+                .requires("replay")
+                .takes_value(true),
+        )
+        .arg(Arg::with_name("lock-memory").long("lock-memory").help(
+            "Lock all memory with mlockall so page faults don't show up in timings",
+        ))
+        .subcommand(
+            SubCommand::with_name("compare")
+                .about(
+                    "Compare results saved with --output-format json; \
+                     exits with status 1 if the candidate regressed, \
+                     or 2 if the results can't be compared",
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .value_name("BASELINE")
+                        .help("Results to compare against")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("candidate")
+                        .value_name("CANDIDATE")
+                        .help("Results to check for regressions")
+                        .required(true)
+                        .index(2),
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .long("threshold")
+                        .value_name("PERCENT")
+                        .help("How much slower a metric can get before it counts as a regression")
+                        .default_value("5")
+                        .validator(is_percent)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("metrics")
+                        .long("metrics")
+                        .value_name("METRIC")
+                        .help(
+                            "Metrics that fail the comparison when they regress (comma-separated); \
+                             defaults to the p50 and p99 latencies and total times",
+                        )
+                        .possible_values(&metric_names)
+                        .use_delimiter(true)
+                        .multiple(true)
+                        .takes_value(true),
+                ),
+        )
         .get_matches();
 
+    if let Some(matches) = matches.subcommand_matches("compare") {
+        let gated: Vec<&str> = matches
+            .values_of("metrics")
+            .map(|v| v.collect())
+            .unwrap_or_else(|| compare::DEFAULT_GATED.to_vec());
+        let regressed = compare::compare(
+            Path::new(matches.value_of("baseline").unwrap()),
+            Path::new(matches.value_of("candidate").unwrap()),
+            matches.value_of("threshold").unwrap().parse().unwrap(),
+            &gated,
+            &metric_names,
+        );
+        match regressed {
+            Ok(false) => return,
+            Ok(true) => process::exit(1),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(2);
+            }
+        }
+    }
+
     let deep = matches.value_of("file").unwrap();
     let path = Path::new(deep);
-    let mut file = File::open(path).expect(&format!("Unable to open file={}", path.display()));
+    let mut file =
+        File::open(path).unwrap_or_else(|_| panic!("Unable to open file={}", path.display()));
+
+    let write_mode = match matches.value_of("write-mode").unwrap() {
+        "in-place" => WriteMode::InPlace,
+        _ => WriteMode::Scratch,
+    };
+    let verify = matches.is_present("verify");
+    let output_format = match matches.value_of("output-format").unwrap() {
+        "json" => OutputFormat::Json,
+        "csv" => OutputFormat::Csv,
+        _ => OutputFormat::Text,
+    };
+    // Before anything gets measured, including the clock overhead
+    let scheduling = sched::Scheduling {
+        cpus: matches
+            .values_of("cpu")
+            .map(|v| v.map(|cpu| cpu.parse().unwrap()).collect()),
+        nice: matches.value_of("nice").map(|n| n.parse().unwrap()),
+        fifo_priority: matches.value_of("sched-fifo").map(|p| p.parse().unwrap()),
+        lock_memory: matches.is_present("lock-memory"),
+    };
+    if let Err(e) = scheduling.apply() {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    let clock = Clock::new(matches.value_of("clock").unwrap());
+    let clock_overhead = clock.overhead_nanos();
+    let timing = Timing {
+        clock,
+        correction_nanos: if matches.is_present("subtract-overhead") {
+            clock_overhead
+        } else {
+            0
+        },
+    };
+    let pipeline = if matches.is_present("pipeline") {
+        let transport = if matches.is_present("processes") {
+            Transport::Processes
+        } else if matches.is_present("udp") {
+            Transport::Udp {
+                max_datagram: matches
+                    .value_of("max-datagram")
+                    .map_or(udp::MAX_DATAGRAM, |m| m.parse().unwrap()),
+            }
+        } else {
+            Transport::Threads
+        };
+        Some(Pipeline {
+            transport,
+            producer_cpu: matches.value_of("producer-cpu").map(|c| c.parse().unwrap()),
+            consumer_cpu: matches.value_of("consumer-cpu").map(|c| c.parse().unwrap()),
+        })
+    } else {
+        None
+    };
+    let replay = matches.value_of("replay").map(|pace| {
+        Replay::new(
+            pace,
+            matches.value_of("replay-timestamps").unwrap_or("send-time"),
+        )
+    });
+    if replay.is_some() && !clock.per_message() {
+        eprintln!(
+            "--replay needs a per-message clock, since the batch time would include the pauses"
+        );
+        process::exit(1);
+    }
+    let percentiles = matches.is_present("percentiles");
+    let per_message = matches.is_present("per-message");
+    let histogram_dir = matches.value_of("histogram-dir").map(Path::new);
+    if (percentiles || histogram_dir.is_some()) && !clock.per_message() {
+        eprintln!(
+            "--percentiles and --histogram-dir need a per-message clock, \
+             since batch only times whole runs"
+        );
+        process::exit(1);
+    }
+    if let Some(dir) = histogram_dir {
+        fs::create_dir_all(dir)
+            .unwrap_or_else(|_| panic!("Unable to create histogram dir={}", dir.display()));
+    }
+    let iterations: usize = matches.value_of("iterations").unwrap().parse().unwrap();
+    let warmup: usize = matches.value_of("warmup").unwrap().parse().unwrap();
+    if iterations == 0 {
+        eprintln!("Need at least one iteration to measure");
+        process::exit(1);
+    }
+
+    let included: Option<Vec<&str>> = matches.values_of("formats").map(|v| v.collect());
+    let excluded: Vec<&str> = matches
+        .values_of("exclude")
+        .map(|v| v.collect())
+        .unwrap_or_default();
+    let selected: Vec<&registry::Runner> = runners
+        .iter()
+        .filter(|r| match &included {
+            Some(included) => included.contains(&r.name),
+            None => true,
+        })
+        .filter(|r| !excluded.contains(&r.name))
+        .filter(|r| {
+            // Results labelled in-place should only ever come from writers that are
+            let skip = write_mode == WriteMode::InPlace && !r.in_place;
+            if skip {
+                eprintln!("Skipping {}: it can't write in place", r.description);
+            }
+            !skip
+        })
+        .collect();
+    if selected.is_empty() {
+        eprintln!("No formats left to run after applying --formats, --exclude and --write-mode");
+        process::exit(1);
+    }
 
     let mut buf = Vec::new();
     file.read_to_end(&mut buf)
-        .expect(&format!("Unable to read file={}", path.display()));
+        .unwrap_or_else(|_| panic!("Unable to read file={}", path.display()));
+    if let Some(Replay {
+        timestamps: Timestamps::CaptureTime,
+        ..
+    }) = replay
+    {
+        if replay::missing_capture_times(&buf) {
+            eprintln!(
+                "--replay-timestamps capture-time needs every packet to have an interface \
+                 description block; use send-time for this file"
+            );
+            process::exit(1);
+        }
+    }
 
-    let analysis_start = SystemTime::now();
-    let capnp_unpacked = run_analysis(
-        &buf,
-        &mut capnp_runner::CapnpWriter::new(false),
-        &mut capnp_runner::CapnpReader::new(false),
-    );
-    let analysis_end = SystemTime::now()
-        .duration_since(analysis_start)
-        .unwrap()
-        .as_secs();
-    println!("Cap'n Proto Unpacked total time={}s", analysis_end);
-    println!("Cap'n Proto Unpacked:\n{}\n", capnp_unpacked.timing_stats());
-
-    let analysis_start = SystemTime::now();
-    let capnp_packed = run_analysis(
-        &buf,
-        &mut capnp_runner::CapnpWriter::new(true),
-        &mut capnp_runner::CapnpReader::new(true),
-    );
-    let analysis_end = SystemTime::now()
-        .duration_since(analysis_start)
-        .unwrap()
-        .as_secs();
-    println!("Cap'n Proto Packed total time={}s", analysis_end);
-
-    assert_eq!(capnp_unpacked.summary_stats, capnp_packed.summary_stats);
-    println!("Cap'n Proto Packed:\n{}\n", capnp_packed.timing_stats());
-
-    let analysis_start = SystemTime::now();
-    let flatbuffers = run_analysis(
-        &buf,
-        &mut flatbuffers_runner::FlatbuffersWriter::new(),
-        &mut flatbuffers_runner::FlatbuffersReader::new(),
-    );
-    let analysis_end = SystemTime::now()
-        .duration_since(analysis_start)
-        .unwrap()
-        .as_secs();
-    println!("Flatbuffers total time={}s", analysis_end);
-
-    assert_eq!(capnp_packed.summary_stats, flatbuffers.summary_stats);
-    println!("Flatbuffers:\n{}\n", flatbuffers.timing_stats());
-
-    let analysis_start = SystemTime::now();
-    let sbe = run_analysis(
-        &buf,
-        &mut sbe_runner::SBEWriter::new(),
-        &mut sbe_runner::SBEReader::new(),
+    // Every runner has to come up with the same summary as the first one to run
+    let mut expected_stats: Option<(&str, Summarizer)> = None;
+
+    let metadata = RunMetadata::collect(
+        path,
+        matches.value_of("write-mode").unwrap(),
+        &timing,
+        clock_overhead,
+        pipeline,
+        replay,
     );
-    let analysis_end = SystemTime::now()
-        .duration_since(analysis_start)
-        .unwrap()
-        .as_secs();
-    println!("SBE total time={}s", analysis_end);
-
-    assert_eq!(flatbuffers.summary_stats, sbe.summary_stats);
-    println!("SBE:\n{}\n", sbe.timing_stats());
+    match output_format {
+        OutputFormat::Csv => println!("{}", report::csv_header()),
+        // Goes to stderr so the text results keep the same layout for `parse_results.py`
+        OutputFormat::Text => {
+            eprintln!("Clock: {}", metadata.clock_description());
+            eprintln!("Scheduling: {}", metadata.scheduling_description());
+            eprintln!("Replay: {}", metadata.replay());
+        }
+        OutputFormat::Json => {}
+    }
+
+    for runner in selected {
+        for _ in 0..warmup {
+            (runner.run)(&buf, write_mode, timing, false, pipeline, replay);
+        }
+
+        let mut runs = Vec::with_capacity(iterations);
+        let mut total_secs = Vec::with_capacity(iterations);
+        let mut timings = Vec::with_capacity(iterations);
+        for i in 0..iterations {
+            let analysis_start = SystemTime::now();
+            // Every iteration writes exactly the same thing, so only verify the first
+            let mut analysis =
+                (runner.run)(&buf, write_mode, timing, verify && i == 0, pipeline, replay);
+            let analysis_end = SystemTime::now().duration_since(analysis_start).unwrap();
+            total_secs.push(analysis_end.as_secs_f64());
+            timings.push(histograms::Iteration {
+                start: analysis_start,
+                duration: analysis_end,
+            });
+
+            // Nothing to compare if some packets never made it to the reader
+            let complete = match &analysis.pipeline {
+                Some(stats) => stats.complete(),
+                None => true,
+            };
+            if complete {
+                check_summary(
+                    &mut expected_stats,
+                    runner.description,
+                    mem::take(&mut analysis.summary_stats),
+                );
+            }
+            if output_format != OutputFormat::Text {
+                println!(
+                    "{}",
+                    report::record(
+                        output_format,
+                        &metadata,
+                        runner.name,
+                        runner.description,
+                        i,
+                        analysis_end.as_secs_f64(),
+                        &analysis
+                    )
+                );
+            }
+            runs.push(analysis);
+        }
+
+        if let Some(dir) = histogram_dir {
+            let log_path = histograms::log_path(dir, &metadata, runner.name);
+            histograms::write_log(&log_path, &metadata, runner.description, &timings, &runs);
+        }
+
+        if output_format != OutputFormat::Text {
+            continue;
+        }
+        if iterations == 1 {
+            println!(
+                "{} total time={}s",
+                runner.description, total_secs[0] as u64
+            );
+            println!("{}:\n{}\n", runner.description, runs[0].timing_stats(clock));
+        } else {
+            println!(
+                "{} total time: {}",
+                runner.description,
+                format_estimate(&total_secs, "s", 3)
+            );
+            println!(
+                "{} ({} iterations, {} warmup):\n{}\n",
+                runner.description,
+                iterations,
+                warmup,
+                iteration_stats(&runs, clock)
+            );
+        }
+        if percentiles {
+            println!(
+                "{} percentiles:\n{}\n",
+                runner.description,
+                histograms::spectrum(
+                    &histograms::merge(runs.iter().map(|r| &r.serialize_hist)),
+                    &histograms::merge(runs.iter().map(|r| &r.deserialize_hist)),
+                )
+            );
+        }
+        if alloc::ENABLED {
+            println!(
+                "{} allocations:\n{}\n",
+                runner.description,
+                allocation_stats(&runs)
+            );
+        }
+        if per_message {
+            println!(
+                "{} per message:\n{}\n",
+                runner.description,
+                histograms::message_stats(&runs, clock)
+            );
+        }
+        if let Some(pipeline) = pipeline {
+            println!(
+                "{} pipeline ({}):\n{}\n",
+                runner.description,
+                pipeline.description(),
+                pipeline::pipeline_stats(&runs)
+            );
+        }
+    }
+
+    if verify && output_format == OutputFormat::Text {
+        println!("Every format round-tripped every message");
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -128,14 +688,17 @@ impl Summarizer {
     fn entry(&mut self, sym: &str) -> &mut SummaryStats {
         let mut hasher = DefaultHasher::new();
         hasher.write(sym.as_bytes());
-        self.data.entry(hasher.finish()).or_insert(SummaryStats {
-            symbol: sym.to_string(),
-            trade_volume: 0,
-            bid_high: 0,
-            bid_low: u64::max_value(),
-            ask_high: 0,
-            ask_low: u64::max_value(),
-        })
+        // Only build the entry for new symbols, so that updates don't allocate
+        self.data
+            .entry(hasher.finish())
+            .or_insert_with(|| SummaryStats {
+                symbol: sym.to_string(),
+                trade_volume: 0,
+                bid_high: 0,
+                bid_low: u64::MAX,
+                ask_high: 0,
+                ask_low: u64::MAX,
+            })
     }
 
     pub fn append_trade_volume(&mut self, sym: &str, volume: u64) {
@@ -154,41 +717,29 @@ impl Summarizer {
     }
 }
 
-pub struct StreamVec {
-    pos: usize,
-    inner: Vec<u8>,
+/// How runners get their encoded messages into the shared output buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WriteMode {
+    /// Build each message in memory owned by the runner, then copy it to the output.
+    Scratch,
+    /// Build each message directly in the output buffer, where the format allows it.
+    InPlace,
 }
 
-impl StreamVec {
-    pub fn new(buf: Vec<u8>) -> StreamVec {
-        StreamVec { pos: 0, inner: buf }
-    }
-}
-
-impl Read for StreamVec {
-    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
-        // TODO: There's *got* to be a better way to handle this
-        let end = self.pos + buf.len();
-        let end = if end > self.inner.len() {
-            self.inner.len()
-        } else {
-            end
-        };
-        let read_size = end - self.pos;
-        buf[..read_size].copy_from_slice(&self.inner[self.pos..end]);
-        self.pos = end;
-
-        Ok(read_size)
-    }
-}
-
-impl BufRead for StreamVec {
-    fn fill_buf(&mut self) -> Result<&[u8], Error> {
-        Ok(&self.inner[self.pos..])
-    }
-
-    fn consume(&mut self, amt: usize) {
-        self.pos += amt;
+/// The first runner's stats become the expected stats for every runner after it.
+fn check_summary<'a>(
+    expected: &mut Option<(&'a str, Summarizer)>,
+    description: &'a str,
+    actual: Summarizer,
+) {
+    match expected {
+        Some((expected_description, expected)) => assert!(
+            *expected == actual,
+            "{} summary stats don't match {}",
+            description,
+            expected_description
+        ),
+        None => *expected = Some((description, actual)),
     }
 }
 
@@ -197,7 +748,17 @@ trait RunnerSerialize {
 }
 
 trait RunnerDeserialize {
-    fn deserialize<'a>(&self, buf: &'a mut StreamVec, stats: &mut Summarizer) -> Result<(), ()>;
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()>;
+
+    /// Decode the next multi-message into `messages`, returning its sequence number.
+    /// Only used to verify round trips, so unlike `deserialize` it isn't timed.
+    fn decode(&self, buf: &mut StreamBuf, messages: &mut Vec<NormalizedMessage>)
+        -> Result<u64, ()>;
+
+    /// Whether messages decode in the same order they were written.
+    fn preserves_order(&self) -> bool {
+        true
+    }
 }
 
 fn __take_until<'a>(tag: &'static str, input: &'a [u8]) -> IResult<&'a [u8], &'a [u8]> {
@@ -211,110 +772,348 @@ fn parse_symbol(sym: &[u8; 8]) -> &str {
     unsafe { from_utf8_unchecked(sym_bytes) }
 }
 
+fn parse_reason(reason: &[u8; 4]) -> &str {
+    // Unlike symbols, the padding is kept so that every format stores
+    // exactly the 4 characters IEX sent us.
+    unsafe { from_utf8_unchecked(&reason[..]) }
+}
+
 struct RunAnalysis {
     serialize_hist: Histogram<u64>,
     deserialize_hist: Histogram<u64>,
+    serialize_msg_hists: MessageHists,
+    deserialize_msg_hists: MessageHists,
     summary_stats: Summarizer,
     serialize_total_nanos: u128,
     deserialize_total_nanos: u128,
     buf_len: usize,
+    /// IEX messages written, as opposed to the packets they came in
+    messages: u64,
+    /// Heap activity inside `serialize` and `deserialize` calls, if counting is enabled.
+    /// Includes growing the shared output buffer and adding new symbols to the summary.
+    serialize_allocs: AllocStats,
+    deserialize_allocs: AllocStats,
+    /// Only measured with `--pipeline`
+    pipeline: Option<PipelineStats>,
 }
 
 impl RunAnalysis {
-    fn timing_stats(&self) -> String {
-        format!(
+    fn bytes_per_message(&self) -> f64 {
+        self.buf_len as f64 / self.messages as f64
+    }
+
+    fn serialize_msgs_per_sec(&self) -> f64 {
+        self.messages as f64 / self.serialize_total_nanos as f64 * 1e9
+    }
+
+    fn deserialize_msgs_per_sec(&self) -> f64 {
+        self.messages as f64 / self.deserialize_total_nanos as f64 * 1e9
+    }
+
+    /// Latency percentiles, which the batch clock doesn't measure, then totals.
+    fn timing_stats(&self, clock: Clock) -> String {
+        let mut out = if clock.per_message() {
+            format!(
+                concat!(
+                    "  serialize_50={}ns\n",
+                    "  serialize_99={}ns\n",
+                    "  serialize_999={}ns\n",
+                    "  deserialize_50={}ns\n",
+                    "  deserialize_99={}ns\n",
+                    "  deserialize_999={}ns\n",
+                ),
+                self.serialize_hist.value_at_quantile(0.5),
+                self.serialize_hist.value_at_quantile(0.99),
+                self.serialize_hist.value_at_quantile(0.999),
+                self.deserialize_hist.value_at_quantile(0.5),
+                self.deserialize_hist.value_at_quantile(0.99),
+                self.deserialize_hist.value_at_quantile(0.999),
+            )
+        } else {
+            NOT_MEASURED.to_string()
+        };
+        out.push_str(&format!(
             concat!(
-                "  serialize_50={}ns\n",
-                "  serialize_99={}ns\n",
-                "  serialize_999={}ns\n",
-                "  deserialize_50={}ns\n",
-                "  deserialize_99={}ns\n",
-                "  deserialize_999={}ns\n",
                 "  serialize_total={}ns\n",
                 "  deserialize_total={}ns\n",
                 "  write_len={}b"
             ),
-            self.serialize_hist.value_at_quantile(0.5),
-            self.serialize_hist.value_at_quantile(0.99),
-            self.serialize_hist.value_at_quantile(0.999),
-            self.deserialize_hist.value_at_quantile(0.5),
-            self.deserialize_hist.value_at_quantile(0.99),
-            self.deserialize_hist.value_at_quantile(0.999),
-            self.serialize_total_nanos,
-            self.deserialize_total_nanos,
-            self.buf_len
+            self.serialize_total_nanos, self.deserialize_total_nanos, self.buf_len
+        ));
+        out
+    }
+}
+
+/// Stands in for latency percentiles when they weren't measured.
+const NOT_MEASURED: &str = "  latency percentiles: not measured with --clock batch\n";
+
+/// Like `RunAnalysis::timing_stats`, but summarizing every iteration of a runner.
+fn iteration_stats(runs: &[RunAnalysis], clock: Clock) -> String {
+    let estimate = |f: &dyn Fn(&RunAnalysis) -> f64| {
+        let samples: Vec<f64> = runs.iter().map(f).collect();
+        format_estimate(&samples, "ns", 1)
+    };
+
+    let mut out = if clock.per_message() {
+        format!(
+            concat!(
+                "  serialize_50: {}\n",
+                "  serialize_99: {}\n",
+                "  serialize_999: {}\n",
+                "  deserialize_50: {}\n",
+                "  deserialize_99: {}\n",
+                "  deserialize_999: {}\n",
+            ),
+            estimate(&|r| r.serialize_hist.value_at_quantile(0.5) as f64),
+            estimate(&|r| r.serialize_hist.value_at_quantile(0.99) as f64),
+            estimate(&|r| r.serialize_hist.value_at_quantile(0.999) as f64),
+            estimate(&|r| r.deserialize_hist.value_at_quantile(0.5) as f64),
+            estimate(&|r| r.deserialize_hist.value_at_quantile(0.99) as f64),
+            estimate(&|r| r.deserialize_hist.value_at_quantile(0.999) as f64),
+        )
+    } else {
+        NOT_MEASURED.to_string()
+    };
+    out.push_str(&format!(
+        concat!(
+            "  serialize_total: {}\n",
+            "  deserialize_total: {}\n",
+            "  write_len={}b"
+        ),
+        estimate(&|r| r.serialize_total_nanos as f64),
+        estimate(&|r| r.deserialize_total_nanos as f64),
+        // Every iteration writes the same data
+        runs[0].buf_len
+    ));
+    out
+}
+
+/// Heap activity of a runner, which is the same every iteration for most formats.
+fn allocation_stats(runs: &[RunAnalysis]) -> String {
+    let describe = |phase: &str, allocs: &dyn Fn(&RunAnalysis) -> AllocStats| {
+        let total = runs.iter().fold(AllocStats::default(), |mut total, r| {
+            total += allocs(r);
+            total
+        });
+        let iterations = runs.len() as u64;
+        format!(
+            "  {}: allocations={} deallocations={} bytes_allocated={}b",
+            phase,
+            total.allocations / iterations,
+            total.deallocations / iterations,
+            total.bytes_allocated / iterations
         )
+    };
+
+    format!(
+        "{}\n{}",
+        describe("serialize", &|r| r.serialize_allocs),
+        describe("deserialize", &|r| r.deserialize_allocs)
+    )
+}
+
+fn format_estimate(samples: &[f64], unit: &str, precision: usize) -> String {
+    let estimate = Estimate::new(samples);
+    format!(
+        "mean={:.p$}{unit} stddev={:.p$}{unit} ci95=[{:.p$}{unit}, {:.p$}{unit}]",
+        estimate.mean,
+        estimate.stddev,
+        estimate.ci_low,
+        estimate.ci_high,
+        p = precision,
+        unit = unit
+    )
+}
+
+fn is_count(value: String) -> Result<(), String> {
+    value
+        .parse::<usize>()
+        .map(|_| ())
+        .map_err(|_| format!("Expected a whole number, got {}", value))
+}
+
+fn is_percent(value: String) -> Result<(), String> {
+    match value.parse::<f64>() {
+        Ok(percent) if percent >= 0.0 => Ok(()),
+        _ => Err(format!("Expected a non-negative percentage, got {}", value)),
+    }
+}
+
+fn is_niceness(value: String) -> Result<(), String> {
+    match value.parse::<i32>() {
+        Ok(nice) if (-20..=19).contains(&nice) => Ok(()),
+        _ => Err(format!("Expected a niceness from -20 to 19, got {}", value)),
+    }
+}
+
+fn is_pace(value: String) -> Result<(), String> {
+    replay::parse_pace(&value).map(|_| ())
+}
+
+fn is_datagram_size(value: String) -> Result<(), String> {
+    match value.parse::<usize>() {
+        Ok(size) if (udp::HEADER_LEN + 1..=udp::MAX_DATAGRAM).contains(&size) => Ok(()),
+        _ => Err(format!(
+            "Expected a datagram size from {} to {} bytes, got {}",
+            udp::HEADER_LEN + 1,
+            udp::MAX_DATAGRAM,
+            value
+        )),
     }
 }
 
-fn run_analysis<S, D>(iex_data: &Vec<u8>, serializer: &mut S, deserializer: &mut D) -> RunAnalysis
+fn is_fifo_priority(value: String) -> Result<(), String> {
+    match value.parse::<i32>() {
+        Ok(priority) if (1..=sched::MAX_FIFO_PRIORITY).contains(&priority) => Ok(()),
+        _ => Err(format!(
+            "Expected a priority from 1 to {}, got {}",
+            sched::MAX_FIFO_PRIORITY,
+            value
+        )),
+    }
+}
+
+fn run_analysis<S, D>(
+    iex_data: &[u8],
+    timing: Timing,
+    verify: bool,
+    pipeline: Option<Pipeline>,
+    replay: Option<Replay>,
+    serializer: &mut S,
+    deserializer: &mut D,
+) -> RunAnalysis
 where
-    S: RunnerSerialize,
-    D: RunnerDeserialize,
+    S: RunnerSerialize + Send,
+    D: RunnerDeserialize + Send,
 {
-    let iex_parser = IexParser::new(iex_data);
+    if let Some(pipeline) = pipeline {
+        return pipeline::run(iex_data, timing, pipeline, replay, serializer, deserializer);
+    }
+
+    let clock = timing.clock;
 
     let mut output_buf = Vec::with_capacity(iex_data.len());
     // As things stand, the histogram could reallocate, but because that happens outside
     // the measurement critical path, not too worried.
     let mut serialize_hist = Histogram::<u64>::new(2).unwrap();
+    let mut serialize_msg_hists = MessageHists::new();
     let mut serialize_nanos_total = 0u128;
+    let mut serialize_allocs = AllocStats::default();
     let mut serialize_msgs = 0;
+    // Message count of every packet that was written, so deserializing can
+    // normalize by it too
+    let mut msg_counts = Vec::new();
+    // When every packet that was written went out, so deserializing can keep the same pace
+    let mut schedule = Vec::new();
 
-    for iex_payload in iex_parser {
-        let output_len_start = output_buf.len();
-        let serialize_start = Instant::now();
+    if clock.per_message() {
+        let mut pacer = replay.map(|r| r.pacer());
+        for iex_payload in IexParser::new(iex_data) {
+            let scheduled = pacer.as_mut().map(|p| p.wait(&iex_payload));
+            let output_len_start = output_buf.len();
+            let allocs_start = alloc::current();
+            let serialize_start = clock.start();
 
-        serializer.serialize(&iex_payload, &mut output_buf);
+            serializer.serialize(&iex_payload, &mut output_buf);
 
-        let serialize_end = Instant::now().duration_since(serialize_start).as_nanos();
+            let serialize_end = timing.elapsed_nanos(serialize_start, clock.stop());
+            serialize_allocs += alloc::current() - allocs_start;
 
-        serialize_hist.record(serialize_end as u64).unwrap();
-        serialize_nanos_total += serialize_end;
+            serialize_hist.record(serialize_end).unwrap();
+            serialize_nanos_total += serialize_end as u128;
 
-        // If the IEX payload is made up of messages we don't care about
-        // (a multi-message containing nothing but SystemEvent for example),
-        // Cap'n Proto doesn't write anything into the output buffer.
-        // As such, only increment `serialize_msgs` when something was written
-        // so that the read/write counts line up.
-        let write_size = output_buf.len() - output_len_start;
-        if write_size != 0 {
-            serialize_msgs += 1;
+            // If the IEX payload is made up of messages we don't care about
+            // (a multi-message containing nothing but SystemEvent for example),
+            // Cap'n Proto doesn't write anything into the output buffer.
+            // As such, only increment `serialize_msgs` when something was written
+            // so that the read/write counts line up.
+            let write_size = output_buf.len() - output_len_start;
+            if write_size != 0 {
+                serialize_msgs += 1;
+                serialize_msg_hists.record(serialize_end, iex_payload.msg_count);
+                msg_counts.push(iex_payload.msg_count);
+                schedule.extend(scheduled);
+            }
         }
+    } else {
+        // Parse the IEX data up front, so the batch time is only spent serializing
+        let iex_payloads: Vec<IexPayload> = IexParser::new(iex_data).collect();
+        msg_counts.reserve(iex_payloads.len());
+        let allocs_start = alloc::current();
+        let batch_start = Instant::now();
+        for iex_payload in &iex_payloads {
+            let output_len_start = output_buf.len();
+            serializer.serialize(iex_payload, &mut output_buf);
+            if output_buf.len() != output_len_start {
+                serialize_msgs += 1;
+                msg_counts.push(iex_payload.msg_count);
+            }
+        }
+        serialize_nanos_total = batch_start.elapsed().as_nanos();
+        serialize_allocs = alloc::current() - allocs_start;
     }
     let output_len = output_buf.len();
 
-    let mut read_buf = StreamVec::new(output_buf);
+    let mut read_buf = StreamBuf::new(&output_buf);
     let mut summarizer = Summarizer::default();
     let mut deserialize_hist = Histogram::<u64>::new(2).unwrap();
+    let mut deserialize_msg_hists = MessageHists::new();
     let mut parsed_msgs = 0usize;
     let mut deserialize_nanos_total = 0u128;
+    let mut deserialize_allocs = AllocStats::default();
 
+    let mut pacer = replay.map(|r| r.pacer());
+    let batch_start = Instant::now();
     loop {
-        let deserialize_start = Instant::now();
+        if let (Some(pacer), Some(&scheduled)) = (&mut pacer, schedule.get(parsed_msgs)) {
+            pacer.wait_until(scheduled);
+        }
+        let allocs_start = alloc::current();
+        let deserialize_start = clock.start();
 
         let res = deserializer.deserialize(&mut read_buf, &mut summarizer);
 
-        let deserialize_end = Instant::now().duration_since(deserialize_start).as_nanos();
+        let deserialize_stop = clock.stop();
+        deserialize_allocs += alloc::current() - allocs_start;
 
         if res.is_ok() {
-            deserialize_hist.record(deserialize_end as u64).unwrap();
-            deserialize_nanos_total += deserialize_end;
+            if clock.per_message() {
+                let deserialize_end = timing.elapsed_nanos(deserialize_start, deserialize_stop);
+                deserialize_hist.record(deserialize_end).unwrap();
+                deserialize_nanos_total += deserialize_end as u128;
+                // Packets come back in the order they were written
+                deserialize_msg_hists.record(deserialize_end, msg_counts[parsed_msgs]);
+            }
             parsed_msgs += 1;
         } else {
             break;
         }
     }
+    if !clock.per_message() {
+        deserialize_nanos_total = batch_start.elapsed().as_nanos();
+    }
 
     assert_eq!(serialize_msgs, parsed_msgs);
+
+    if verify {
+        // Start over from the beginning of what was just read
+        read_buf.rewind();
+        verify::verify_round_trip(iex_data, &mut read_buf, deserializer);
+    }
     //dbg!(serialize_all);
 
     RunAnalysis {
         serialize_hist,
         deserialize_hist,
+        serialize_msg_hists,
+        deserialize_msg_hists,
         summary_stats: summarizer,
         serialize_total_nanos: serialize_nanos_total,
         deserialize_total_nanos: deserialize_nanos_total,
         buf_len: output_len,
+        messages: msg_counts.iter().map(|&count| count as u64).sum(),
+        serialize_allocs,
+        deserialize_allocs,
+        pipeline: None,
     }
 }
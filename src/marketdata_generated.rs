@@ -1,336 +1,2047 @@
 // automatically generated by the FlatBuffers compiler, do not modify
 
-use std::cmp::Ordering;
-use std::mem;
+// @generated
+
+use core::cmp::Ordering;
+use core::mem;
 
 extern crate flatbuffers;
-use self::flatbuffers::EndianScalar;
+use self::flatbuffers::{EndianScalar, Follow};
 
 #[allow(unused_imports, dead_code)]
 pub mod md_shootout {
 
-    use std::cmp::Ordering;
-    use std::mem;
+    use core::cmp::Ordering;
+    use core::mem;
 
     extern crate flatbuffers;
-    use self::flatbuffers::EndianScalar;
+    use self::flatbuffers::{EndianScalar, Follow};
 
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    pub const ENUM_MIN_MESSAGE_BODY: u8 = 0;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    pub const ENUM_MAX_MESSAGE_BODY: u8 = 11;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
     #[allow(non_camel_case_types)]
-    #[repr(u8)]
-    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-    pub enum MessageBody {
-        NONE = 0,
-        Trade = 1,
-        LevelUpdate = 2,
-    }
+    pub const ENUM_VALUES_MESSAGE_BODY: [MessageBody; 12] = [
+        MessageBody::NONE,
+        MessageBody::Trade,
+        MessageBody::LevelUpdate,
+        MessageBody::SystemEvent,
+        MessageBody::SecurityDirectory,
+        MessageBody::TradingStatus,
+        MessageBody::OperationalHaltStatus,
+        MessageBody::ShortSalePriceTest,
+        MessageBody::SecurityEvent,
+        MessageBody::OfficialPrice,
+        MessageBody::TradeBreak,
+        MessageBody::AuctionInformation,
+    ];
 
-    const ENUM_MIN_MESSAGE_BODY: u8 = 0;
-    const ENUM_MAX_MESSAGE_BODY: u8 = 2;
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    #[repr(transparent)]
+    pub struct MessageBody(pub u8);
+    #[allow(non_upper_case_globals)]
+    impl MessageBody {
+        pub const NONE: Self = Self(0);
+        pub const Trade: Self = Self(1);
+        pub const LevelUpdate: Self = Self(2);
+        pub const SystemEvent: Self = Self(3);
+        pub const SecurityDirectory: Self = Self(4);
+        pub const TradingStatus: Self = Self(5);
+        pub const OperationalHaltStatus: Self = Self(6);
+        pub const ShortSalePriceTest: Self = Self(7);
+        pub const SecurityEvent: Self = Self(8);
+        pub const OfficialPrice: Self = Self(9);
+        pub const TradeBreak: Self = Self(10);
+        pub const AuctionInformation: Self = Self(11);
 
+        pub const ENUM_MIN: u8 = 0;
+        pub const ENUM_MAX: u8 = 11;
+        pub const ENUM_VALUES: &'static [Self] = &[
+            Self::NONE,
+            Self::Trade,
+            Self::LevelUpdate,
+            Self::SystemEvent,
+            Self::SecurityDirectory,
+            Self::TradingStatus,
+            Self::OperationalHaltStatus,
+            Self::ShortSalePriceTest,
+            Self::SecurityEvent,
+            Self::OfficialPrice,
+            Self::TradeBreak,
+            Self::AuctionInformation,
+        ];
+        /// Returns the variant's name or "" if unknown.
+        pub fn variant_name(self) -> Option<&'static str> {
+            match self {
+                Self::NONE => Some("NONE"),
+                Self::Trade => Some("Trade"),
+                Self::LevelUpdate => Some("LevelUpdate"),
+                Self::SystemEvent => Some("SystemEvent"),
+                Self::SecurityDirectory => Some("SecurityDirectory"),
+                Self::TradingStatus => Some("TradingStatus"),
+                Self::OperationalHaltStatus => Some("OperationalHaltStatus"),
+                Self::ShortSalePriceTest => Some("ShortSalePriceTest"),
+                Self::SecurityEvent => Some("SecurityEvent"),
+                Self::OfficialPrice => Some("OfficialPrice"),
+                Self::TradeBreak => Some("TradeBreak"),
+                Self::AuctionInformation => Some("AuctionInformation"),
+                _ => None,
+            }
+        }
+    }
+    impl core::fmt::Debug for MessageBody {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            if let Some(name) = self.variant_name() {
+                f.write_str(name)
+            } else {
+                f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+            }
+        }
+    }
     impl<'a> flatbuffers::Follow<'a> for MessageBody {
         type Inner = Self;
         #[inline]
-        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-            flatbuffers::read_scalar_at::<Self>(buf, loc)
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+            Self(b)
+        }
+    }
+
+    impl flatbuffers::Push for MessageBody {
+        type Output = MessageBody;
+        #[inline]
+        unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+            flatbuffers::emplace_scalar::<u8>(dst, self.0);
         }
     }
 
     impl flatbuffers::EndianScalar for MessageBody {
+        type Scalar = u8;
         #[inline]
-        fn to_little_endian(self) -> Self {
-            let n = u8::to_le(self as u8);
-            let p = &n as *const u8 as *const MessageBody;
-            unsafe { *p }
+        fn to_little_endian(self) -> u8 {
+            self.0.to_le()
         }
         #[inline]
-        fn from_little_endian(self) -> Self {
-            let n = u8::from_le(self as u8);
-            let p = &n as *const u8 as *const MessageBody;
-            unsafe { *p }
+        #[allow(clippy::wrong_self_convention)]
+        fn from_little_endian(v: u8) -> Self {
+            let b = u8::from_le(v);
+            Self(b)
         }
     }
 
-    impl flatbuffers::Push for MessageBody {
-        type Output = MessageBody;
+    impl<'a> flatbuffers::Verifiable for MessageBody {
         #[inline]
-        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
-            flatbuffers::emplace_scalar::<MessageBody>(dst, *self);
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            u8::run_verifier(v, pos)
         }
     }
 
-    #[allow(non_camel_case_types)]
-    const ENUM_VALUES_MESSAGE_BODY: [MessageBody; 3] = [
-        MessageBody::NONE,
-        MessageBody::Trade,
-        MessageBody::LevelUpdate,
-    ];
+    impl flatbuffers::SimpleToVerifyInSlice for MessageBody {}
+    pub struct MessageBodyUnionTableOffset {}
 
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    pub const ENUM_MIN_SIDE: u8 = 0;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
+    pub const ENUM_MAX_SIDE: u8 = 1;
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use associated constants instead. This will no longer be generated in 2021."
+    )]
     #[allow(non_camel_case_types)]
-    const ENUM_NAMES_MESSAGE_BODY: [&'static str; 3] = ["NONE", "Trade", "LevelUpdate"];
+    pub const ENUM_VALUES_SIDE: [Side; 2] = [Side::Buy, Side::Sell];
 
-    pub fn enum_name_message_body(e: MessageBody) -> &'static str {
-        let index = e as u8;
-        ENUM_NAMES_MESSAGE_BODY[index as usize]
-    }
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    #[repr(transparent)]
+    pub struct Side(pub u8);
+    #[allow(non_upper_case_globals)]
+    impl Side {
+        pub const Buy: Self = Self(0);
+        pub const Sell: Self = Self(1);
 
-    pub struct MessageBodyUnionTableOffset {}
-    #[allow(non_camel_case_types)]
-    #[repr(u8)]
-    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-    pub enum Side {
-        Buy = 0,
-        Sell = 1,
+        pub const ENUM_MIN: u8 = 0;
+        pub const ENUM_MAX: u8 = 1;
+        pub const ENUM_VALUES: &'static [Self] = &[Self::Buy, Self::Sell];
+        /// Returns the variant's name or "" if unknown.
+        pub fn variant_name(self) -> Option<&'static str> {
+            match self {
+                Self::Buy => Some("Buy"),
+                Self::Sell => Some("Sell"),
+                _ => None,
+            }
+        }
+    }
+    impl core::fmt::Debug for Side {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            if let Some(name) = self.variant_name() {
+                f.write_str(name)
+            } else {
+                f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+            }
+        }
     }
-
-    const ENUM_MIN_SIDE: u8 = 0;
-    const ENUM_MAX_SIDE: u8 = 1;
-
     impl<'a> flatbuffers::Follow<'a> for Side {
         type Inner = Self;
         #[inline]
-        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-            flatbuffers::read_scalar_at::<Self>(buf, loc)
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+            Self(b)
+        }
+    }
+
+    impl flatbuffers::Push for Side {
+        type Output = Side;
+        #[inline]
+        unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+            flatbuffers::emplace_scalar::<u8>(dst, self.0);
         }
     }
 
     impl flatbuffers::EndianScalar for Side {
+        type Scalar = u8;
+        #[inline]
+        fn to_little_endian(self) -> u8 {
+            self.0.to_le()
+        }
+        #[inline]
+        #[allow(clippy::wrong_self_convention)]
+        fn from_little_endian(v: u8) -> Self {
+            let b = u8::from_le(v);
+            Self(b)
+        }
+    }
+
+    impl<'a> flatbuffers::Verifiable for Side {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            u8::run_verifier(v, pos)
+        }
+    }
+
+    impl flatbuffers::SimpleToVerifyInSlice for Side {}
+    pub enum TradeOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct Trade<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for Trade<'a> {
+        type Inner = Trade<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> Trade<'a> {
+        pub const VT_PRICE: flatbuffers::VOffsetT = 4;
+        pub const VT_SIZE_: flatbuffers::VOffsetT = 6;
+        pub const VT_SALE_CONDITION: flatbuffers::VOffsetT = 8;
+        pub const VT_TRADE_ID: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            Trade { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args TradeArgs,
+        ) -> flatbuffers::WIPOffset<Trade<'bldr>> {
+            let mut builder = TradeBuilder::new(_fbb);
+            builder.add_trade_id(args.trade_id);
+            builder.add_price(args.price);
+            builder.add_size_(args.size_);
+            builder.add_sale_condition(args.sale_condition);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn price(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u64>(Trade::VT_PRICE, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn size_(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(Trade::VT_SIZE_, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn sale_condition(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(Trade::VT_SALE_CONDITION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn trade_id(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u64>(Trade::VT_TRADE_ID, Some(0)).unwrap() }
+        }
+    }
+
+    impl flatbuffers::Verifiable for Trade<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u64>("price", Self::VT_PRICE, false)?
+                .visit_field::<u32>("size", Self::VT_SIZE_, false)?
+                .visit_field::<u8>("sale_condition", Self::VT_SALE_CONDITION, false)?
+                .visit_field::<u64>("trade_id", Self::VT_TRADE_ID, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TradeArgs {
+        pub price: u64,
+        pub size_: u32,
+        pub sale_condition: u8,
+        pub trade_id: u64,
+    }
+    impl<'a> Default for TradeArgs {
+        #[inline]
+        fn default() -> Self {
+            TradeArgs {
+                price: 0,
+                size_: 0,
+                sale_condition: 0,
+                trade_id: 0,
+            }
+        }
+    }
+
+    pub struct TradeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TradeBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_price(&mut self, price: u64) {
+            self.fbb_.push_slot::<u64>(Trade::VT_PRICE, price, 0);
+        }
+        #[inline]
+        pub fn add_size_(&mut self, size_: u32) {
+            self.fbb_.push_slot::<u32>(Trade::VT_SIZE_, size_, 0);
+        }
+        #[inline]
+        pub fn add_sale_condition(&mut self, sale_condition: u8) {
+            self.fbb_
+                .push_slot::<u8>(Trade::VT_SALE_CONDITION, sale_condition, 0);
+        }
+        #[inline]
+        pub fn add_trade_id(&mut self, trade_id: u64) {
+            self.fbb_.push_slot::<u64>(Trade::VT_TRADE_ID, trade_id, 0);
+        }
+        #[inline]
+        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TradeBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            TradeBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<Trade<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for Trade<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Trade");
+            ds.field("price", &self.price());
+            ds.field("size", &self.size_());
+            ds.field("sale_condition", &self.sale_condition());
+            ds.field("trade_id", &self.trade_id());
+            ds.finish()
+        }
+    }
+    pub enum LevelUpdateOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct LevelUpdate<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for LevelUpdate<'a> {
+        type Inner = LevelUpdate<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> LevelUpdate<'a> {
+        pub const VT_PRICE: flatbuffers::VOffsetT = 4;
+        pub const VT_SIZE_: flatbuffers::VOffsetT = 6;
+        pub const VT_FLAGS: flatbuffers::VOffsetT = 8;
+        pub const VT_SIDE: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            LevelUpdate { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args LevelUpdateArgs,
+        ) -> flatbuffers::WIPOffset<LevelUpdate<'bldr>> {
+            let mut builder = LevelUpdateBuilder::new(_fbb);
+            builder.add_price(args.price);
+            builder.add_size_(args.size_);
+            builder.add_side(args.side);
+            builder.add_flags(args.flags);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn price(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(LevelUpdate::VT_PRICE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn size_(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(LevelUpdate::VT_SIZE_, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn flags(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u8>(LevelUpdate::VT_FLAGS, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn side(&self) -> Side {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<Side>(LevelUpdate::VT_SIDE, Some(Side::Buy))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for LevelUpdate<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u64>("price", Self::VT_PRICE, false)?
+                .visit_field::<u32>("size", Self::VT_SIZE_, false)?
+                .visit_field::<u8>("flags", Self::VT_FLAGS, false)?
+                .visit_field::<Side>("side", Self::VT_SIDE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct LevelUpdateArgs {
+        pub price: u64,
+        pub size_: u32,
+        pub flags: u8,
+        pub side: Side,
+    }
+    impl<'a> Default for LevelUpdateArgs {
+        #[inline]
+        fn default() -> Self {
+            LevelUpdateArgs {
+                price: 0,
+                size_: 0,
+                flags: 0,
+                side: Side::Buy,
+            }
+        }
+    }
+
+    pub struct LevelUpdateBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LevelUpdateBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_price(&mut self, price: u64) {
+            self.fbb_.push_slot::<u64>(LevelUpdate::VT_PRICE, price, 0);
+        }
+        #[inline]
+        pub fn add_size_(&mut self, size_: u32) {
+            self.fbb_.push_slot::<u32>(LevelUpdate::VT_SIZE_, size_, 0);
+        }
+        #[inline]
+        pub fn add_flags(&mut self, flags: u8) {
+            self.fbb_.push_slot::<u8>(LevelUpdate::VT_FLAGS, flags, 0);
+        }
+        #[inline]
+        pub fn add_side(&mut self, side: Side) {
+            self.fbb_
+                .push_slot::<Side>(LevelUpdate::VT_SIDE, side, Side::Buy);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> LevelUpdateBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            LevelUpdateBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<LevelUpdate<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for LevelUpdate<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("LevelUpdate");
+            ds.field("price", &self.price());
+            ds.field("size", &self.size_());
+            ds.field("flags", &self.flags());
+            ds.field("side", &self.side());
+            ds.finish()
+        }
+    }
+    pub enum SystemEventOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct SystemEvent<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for SystemEvent<'a> {
+        type Inner = SystemEvent<'a>;
         #[inline]
-        fn to_little_endian(self) -> Self {
-            let n = u8::to_le(self as u8);
-            let p = &n as *const u8 as *const Side;
-            unsafe { *p }
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> SystemEvent<'a> {
+        pub const VT_SYSTEM_EVENT: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            SystemEvent { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SystemEventArgs,
+        ) -> flatbuffers::WIPOffset<SystemEvent<'bldr>> {
+            let mut builder = SystemEventBuilder::new(_fbb);
+            builder.add_system_event(args.system_event);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn system_event(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(SystemEvent::VT_SYSTEM_EVENT, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for SystemEvent<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("system_event", Self::VT_SYSTEM_EVENT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SystemEventArgs {
+        pub system_event: u8,
+    }
+    impl<'a> Default for SystemEventArgs {
+        #[inline]
+        fn default() -> Self {
+            SystemEventArgs { system_event: 0 }
+        }
+    }
+
+    pub struct SystemEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SystemEventBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_system_event(&mut self, system_event: u8) {
+            self.fbb_
+                .push_slot::<u8>(SystemEvent::VT_SYSTEM_EVENT, system_event, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> SystemEventBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            SystemEventBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SystemEvent<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for SystemEvent<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("SystemEvent");
+            ds.field("system_event", &self.system_event());
+            ds.finish()
+        }
+    }
+    pub enum SecurityDirectoryOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct SecurityDirectory<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for SecurityDirectory<'a> {
+        type Inner = SecurityDirectory<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> SecurityDirectory<'a> {
+        pub const VT_FLAGS: flatbuffers::VOffsetT = 4;
+        pub const VT_LOT_SIZE: flatbuffers::VOffsetT = 6;
+        pub const VT_PREVIOUS_CLOSING: flatbuffers::VOffsetT = 8;
+        pub const VT_LULD_TIER: flatbuffers::VOffsetT = 10;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            SecurityDirectory { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SecurityDirectoryArgs,
+        ) -> flatbuffers::WIPOffset<SecurityDirectory<'bldr>> {
+            let mut builder = SecurityDirectoryBuilder::new(_fbb);
+            builder.add_previous_closing(args.previous_closing);
+            builder.add_lot_size(args.lot_size);
+            builder.add_luld_tier(args.luld_tier);
+            builder.add_flags(args.flags);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn flags(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(SecurityDirectory::VT_FLAGS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn lot_size(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(SecurityDirectory::VT_LOT_SIZE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn previous_closing(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(SecurityDirectory::VT_PREVIOUS_CLOSING, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn luld_tier(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(SecurityDirectory::VT_LULD_TIER, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for SecurityDirectory<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("flags", Self::VT_FLAGS, false)?
+                .visit_field::<u32>("lot_size", Self::VT_LOT_SIZE, false)?
+                .visit_field::<u64>("previous_closing", Self::VT_PREVIOUS_CLOSING, false)?
+                .visit_field::<u8>("luld_tier", Self::VT_LULD_TIER, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SecurityDirectoryArgs {
+        pub flags: u8,
+        pub lot_size: u32,
+        pub previous_closing: u64,
+        pub luld_tier: u8,
+    }
+    impl<'a> Default for SecurityDirectoryArgs {
+        #[inline]
+        fn default() -> Self {
+            SecurityDirectoryArgs {
+                flags: 0,
+                lot_size: 0,
+                previous_closing: 0,
+                luld_tier: 0,
+            }
+        }
+    }
+
+    pub struct SecurityDirectoryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SecurityDirectoryBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_flags(&mut self, flags: u8) {
+            self.fbb_
+                .push_slot::<u8>(SecurityDirectory::VT_FLAGS, flags, 0);
+        }
+        #[inline]
+        pub fn add_lot_size(&mut self, lot_size: u32) {
+            self.fbb_
+                .push_slot::<u32>(SecurityDirectory::VT_LOT_SIZE, lot_size, 0);
+        }
+        #[inline]
+        pub fn add_previous_closing(&mut self, previous_closing: u64) {
+            self.fbb_
+                .push_slot::<u64>(SecurityDirectory::VT_PREVIOUS_CLOSING, previous_closing, 0);
+        }
+        #[inline]
+        pub fn add_luld_tier(&mut self, luld_tier: u8) {
+            self.fbb_
+                .push_slot::<u8>(SecurityDirectory::VT_LULD_TIER, luld_tier, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> SecurityDirectoryBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            SecurityDirectoryBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SecurityDirectory<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for SecurityDirectory<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("SecurityDirectory");
+            ds.field("flags", &self.flags());
+            ds.field("lot_size", &self.lot_size());
+            ds.field("previous_closing", &self.previous_closing());
+            ds.field("luld_tier", &self.luld_tier());
+            ds.finish()
+        }
+    }
+    pub enum TradingStatusOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct TradingStatus<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for TradingStatus<'a> {
+        type Inner = TradingStatus<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> TradingStatus<'a> {
+        pub const VT_TRADING_STATUS: flatbuffers::VOffsetT = 4;
+        pub const VT_REASON: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TradingStatus { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args TradingStatusArgs<'args>,
+        ) -> flatbuffers::WIPOffset<TradingStatus<'bldr>> {
+            let mut builder = TradingStatusBuilder::new(_fbb);
+            if let Some(x) = args.reason {
+                builder.add_reason(x);
+            }
+            builder.add_trading_status(args.trading_status);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn trading_status(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(TradingStatus::VT_TRADING_STATUS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn reason(&self) -> Option<&'a str> {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(TradingStatus::VT_REASON, None)
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for TradingStatus<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("trading_status", Self::VT_TRADING_STATUS, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                    "reason",
+                    Self::VT_REASON,
+                    false,
+                )?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TradingStatusArgs<'a> {
+        pub trading_status: u8,
+        pub reason: Option<flatbuffers::WIPOffset<&'a str>>,
+    }
+    impl<'a> Default for TradingStatusArgs<'a> {
+        #[inline]
+        fn default() -> Self {
+            TradingStatusArgs {
+                trading_status: 0,
+                reason: None,
+            }
+        }
+    }
+
+    pub struct TradingStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TradingStatusBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_trading_status(&mut self, trading_status: u8) {
+            self.fbb_
+                .push_slot::<u8>(TradingStatus::VT_TRADING_STATUS, trading_status, 0);
+        }
+        #[inline]
+        pub fn add_reason(&mut self, reason: flatbuffers::WIPOffset<&'b str>) {
+            self.fbb_
+                .push_slot_always::<flatbuffers::WIPOffset<_>>(TradingStatus::VT_REASON, reason);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> TradingStatusBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            TradingStatusBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<TradingStatus<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for TradingStatus<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TradingStatus");
+            ds.field("trading_status", &self.trading_status());
+            ds.field("reason", &self.reason());
+            ds.finish()
+        }
+    }
+    pub enum OperationalHaltStatusOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct OperationalHaltStatus<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for OperationalHaltStatus<'a> {
+        type Inner = OperationalHaltStatus<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> OperationalHaltStatus<'a> {
+        pub const VT_HALT_STATUS: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            OperationalHaltStatus { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args OperationalHaltStatusArgs,
+        ) -> flatbuffers::WIPOffset<OperationalHaltStatus<'bldr>> {
+            let mut builder = OperationalHaltStatusBuilder::new(_fbb);
+            builder.add_halt_status(args.halt_status);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn halt_status(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(OperationalHaltStatus::VT_HALT_STATUS, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for OperationalHaltStatus<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("halt_status", Self::VT_HALT_STATUS, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct OperationalHaltStatusArgs {
+        pub halt_status: u8,
+    }
+    impl<'a> Default for OperationalHaltStatusArgs {
+        #[inline]
+        fn default() -> Self {
+            OperationalHaltStatusArgs { halt_status: 0 }
+        }
+    }
+
+    pub struct OperationalHaltStatusBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OperationalHaltStatusBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_halt_status(&mut self, halt_status: u8) {
+            self.fbb_
+                .push_slot::<u8>(OperationalHaltStatus::VT_HALT_STATUS, halt_status, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> OperationalHaltStatusBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            OperationalHaltStatusBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<OperationalHaltStatus<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for OperationalHaltStatus<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("OperationalHaltStatus");
+            ds.field("halt_status", &self.halt_status());
+            ds.finish()
+        }
+    }
+    pub enum ShortSalePriceTestOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct ShortSalePriceTest<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for ShortSalePriceTest<'a> {
+        type Inner = ShortSalePriceTest<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> ShortSalePriceTest<'a> {
+        pub const VT_SSPT_STATUS: flatbuffers::VOffsetT = 4;
+        pub const VT_DETAIL: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            ShortSalePriceTest { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args ShortSalePriceTestArgs,
+        ) -> flatbuffers::WIPOffset<ShortSalePriceTest<'bldr>> {
+            let mut builder = ShortSalePriceTestBuilder::new(_fbb);
+            builder.add_detail(args.detail);
+            builder.add_sspt_status(args.sspt_status);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn sspt_status(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(ShortSalePriceTest::VT_SSPT_STATUS, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn detail(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(ShortSalePriceTest::VT_DETAIL, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for ShortSalePriceTest<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("sspt_status", Self::VT_SSPT_STATUS, false)?
+                .visit_field::<u8>("detail", Self::VT_DETAIL, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct ShortSalePriceTestArgs {
+        pub sspt_status: u8,
+        pub detail: u8,
+    }
+    impl<'a> Default for ShortSalePriceTestArgs {
+        #[inline]
+        fn default() -> Self {
+            ShortSalePriceTestArgs {
+                sspt_status: 0,
+                detail: 0,
+            }
+        }
+    }
+
+    pub struct ShortSalePriceTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ShortSalePriceTestBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_sspt_status(&mut self, sspt_status: u8) {
+            self.fbb_
+                .push_slot::<u8>(ShortSalePriceTest::VT_SSPT_STATUS, sspt_status, 0);
+        }
+        #[inline]
+        pub fn add_detail(&mut self, detail: u8) {
+            self.fbb_
+                .push_slot::<u8>(ShortSalePriceTest::VT_DETAIL, detail, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> ShortSalePriceTestBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            ShortSalePriceTestBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<ShortSalePriceTest<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for ShortSalePriceTest<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("ShortSalePriceTest");
+            ds.field("sspt_status", &self.sspt_status());
+            ds.field("detail", &self.detail());
+            ds.finish()
+        }
+    }
+    pub enum SecurityEventOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct SecurityEvent<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for SecurityEvent<'a> {
+        type Inner = SecurityEvent<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> SecurityEvent<'a> {
+        pub const VT_SECURITY_EVENT: flatbuffers::VOffsetT = 4;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            SecurityEvent { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args SecurityEventArgs,
+        ) -> flatbuffers::WIPOffset<SecurityEvent<'bldr>> {
+            let mut builder = SecurityEventBuilder::new(_fbb);
+            builder.add_security_event(args.security_event);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn security_event(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(SecurityEvent::VT_SECURITY_EVENT, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for SecurityEvent<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("security_event", Self::VT_SECURITY_EVENT, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct SecurityEventArgs {
+        pub security_event: u8,
+    }
+    impl<'a> Default for SecurityEventArgs {
+        #[inline]
+        fn default() -> Self {
+            SecurityEventArgs { security_event: 0 }
+        }
+    }
+
+    pub struct SecurityEventBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SecurityEventBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_security_event(&mut self, security_event: u8) {
+            self.fbb_
+                .push_slot::<u8>(SecurityEvent::VT_SECURITY_EVENT, security_event, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> SecurityEventBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            SecurityEventBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
+        }
+        #[inline]
+        pub fn finish(self) -> flatbuffers::WIPOffset<SecurityEvent<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
+        }
+    }
+
+    impl core::fmt::Debug for SecurityEvent<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("SecurityEvent");
+            ds.field("security_event", &self.security_event());
+            ds.finish()
+        }
+    }
+    pub enum OfficialPriceOffset {}
+    #[derive(Copy, Clone, PartialEq)]
+
+    pub struct OfficialPrice<'a> {
+        pub _tab: flatbuffers::Table<'a>,
+    }
+
+    impl<'a> flatbuffers::Follow<'a> for OfficialPrice<'a> {
+        type Inner = OfficialPrice<'a>;
+        #[inline]
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+            Self {
+                _tab: flatbuffers::Table::new(buf, loc),
+            }
+        }
+    }
+
+    impl<'a> OfficialPrice<'a> {
+        pub const VT_PRICE_TYPE: flatbuffers::VOffsetT = 4;
+        pub const VT_OFFICIAL_PRICE: flatbuffers::VOffsetT = 6;
+
+        #[inline]
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            OfficialPrice { _tab: table }
+        }
+        #[allow(unused_mut)]
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args OfficialPriceArgs,
+        ) -> flatbuffers::WIPOffset<OfficialPrice<'bldr>> {
+            let mut builder = OfficialPriceBuilder::new(_fbb);
+            builder.add_official_price(args.official_price);
+            builder.add_price_type(args.price_type);
+            builder.finish()
+        }
+
+        #[inline]
+        pub fn price_type(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(OfficialPrice::VT_PRICE_TYPE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn official_price(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(OfficialPrice::VT_OFFICIAL_PRICE, Some(0))
+                    .unwrap()
+            }
+        }
+    }
+
+    impl flatbuffers::Verifiable for OfficialPrice<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("price_type", Self::VT_PRICE_TYPE, false)?
+                .visit_field::<u64>("official_price", Self::VT_OFFICIAL_PRICE, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct OfficialPriceArgs {
+        pub price_type: u8,
+        pub official_price: u64,
+    }
+    impl<'a> Default for OfficialPriceArgs {
+        #[inline]
+        fn default() -> Self {
+            OfficialPriceArgs {
+                price_type: 0,
+                official_price: 0,
+            }
+        }
+    }
+
+    pub struct OfficialPriceBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+    }
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OfficialPriceBuilder<'a, 'b, A> {
+        #[inline]
+        pub fn add_price_type(&mut self, price_type: u8) {
+            self.fbb_
+                .push_slot::<u8>(OfficialPrice::VT_PRICE_TYPE, price_type, 0);
+        }
+        #[inline]
+        pub fn add_official_price(&mut self, official_price: u64) {
+            self.fbb_
+                .push_slot::<u64>(OfficialPrice::VT_OFFICIAL_PRICE, official_price, 0);
         }
         #[inline]
-        fn from_little_endian(self) -> Self {
-            let n = u8::from_le(self as u8);
-            let p = &n as *const u8 as *const Side;
-            unsafe { *p }
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> OfficialPriceBuilder<'a, 'b, A> {
+            let start = _fbb.start_table();
+            OfficialPriceBuilder {
+                fbb_: _fbb,
+                start_: start,
+            }
         }
-    }
-
-    impl flatbuffers::Push for Side {
-        type Output = Side;
         #[inline]
-        fn push(&self, dst: &mut [u8], _rest: &[u8]) {
-            flatbuffers::emplace_scalar::<Side>(dst, *self);
+        pub fn finish(self) -> flatbuffers::WIPOffset<OfficialPrice<'a>> {
+            let o = self.fbb_.end_table(self.start_);
+            flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    #[allow(non_camel_case_types)]
-    const ENUM_VALUES_SIDE: [Side; 2] = [Side::Buy, Side::Sell];
-
-    #[allow(non_camel_case_types)]
-    const ENUM_NAMES_SIDE: [&'static str; 2] = ["Buy", "Sell"];
-
-    pub fn enum_name_side(e: Side) -> &'static str {
-        let index = e as u8;
-        ENUM_NAMES_SIDE[index as usize]
+    impl core::fmt::Debug for OfficialPrice<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("OfficialPrice");
+            ds.field("price_type", &self.price_type());
+            ds.field("official_price", &self.official_price());
+            ds.finish()
+        }
     }
+    pub enum TradeBreakOffset {}
+    #[derive(Copy, Clone, PartialEq)]
 
-    pub enum TradeOffset {}
-    #[derive(Copy, Clone, Debug, PartialEq)]
-
-    pub struct Trade<'a> {
+    pub struct TradeBreak<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for Trade<'a> {
-        type Inner = Trade<'a>;
+    impl<'a> flatbuffers::Follow<'a> for TradeBreak<'a> {
+        type Inner = TradeBreak<'a>;
         #[inline]
-        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
-                _tab: flatbuffers::Table { buf: buf, loc: loc },
+                _tab: flatbuffers::Table::new(buf, loc),
             }
         }
     }
 
-    impl<'a> Trade<'a> {
+    impl<'a> TradeBreak<'a> {
+        pub const VT_PRICE: flatbuffers::VOffsetT = 4;
+        pub const VT_SIZE_: flatbuffers::VOffsetT = 6;
+        pub const VT_SALE_CONDITION: flatbuffers::VOffsetT = 8;
+        pub const VT_TRADE_ID: flatbuffers::VOffsetT = 10;
+
         #[inline]
-        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            Trade { _tab: table }
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            TradeBreak { _tab: table }
         }
         #[allow(unused_mut)]
-        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args TradeArgs,
-        ) -> flatbuffers::WIPOffset<Trade<'bldr>> {
-            let mut builder = TradeBuilder::new(_fbb);
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args TradeBreakArgs,
+        ) -> flatbuffers::WIPOffset<TradeBreak<'bldr>> {
+            let mut builder = TradeBreakBuilder::new(_fbb);
+            builder.add_trade_id(args.trade_id);
             builder.add_price(args.price);
             builder.add_size_(args.size_);
+            builder.add_sale_condition(args.sale_condition);
             builder.finish()
         }
 
-        pub const VT_PRICE: flatbuffers::VOffsetT = 4;
-        pub const VT_SIZE_: flatbuffers::VOffsetT = 6;
-
         #[inline]
         pub fn price(&self) -> u64 {
-            self._tab.get::<u64>(Trade::VT_PRICE, Some(0)).unwrap()
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u64>(TradeBreak::VT_PRICE, Some(0)).unwrap() }
         }
         #[inline]
         pub fn size_(&self) -> u32 {
-            self._tab.get::<u32>(Trade::VT_SIZE_, Some(0)).unwrap()
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<u32>(TradeBreak::VT_SIZE_, Some(0)).unwrap() }
+        }
+        #[inline]
+        pub fn sale_condition(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(TradeBreak::VT_SALE_CONDITION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn trade_id(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(TradeBreak::VT_TRADE_ID, Some(0))
+                    .unwrap()
+            }
         }
     }
 
-    pub struct TradeArgs {
+    impl flatbuffers::Verifiable for TradeBreak<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u64>("price", Self::VT_PRICE, false)?
+                .visit_field::<u32>("size", Self::VT_SIZE_, false)?
+                .visit_field::<u8>("sale_condition", Self::VT_SALE_CONDITION, false)?
+                .visit_field::<u64>("trade_id", Self::VT_TRADE_ID, false)?
+                .finish();
+            Ok(())
+        }
+    }
+    pub struct TradeBreakArgs {
         pub price: u64,
         pub size_: u32,
+        pub sale_condition: u8,
+        pub trade_id: u64,
     }
-    impl<'a> Default for TradeArgs {
+    impl<'a> Default for TradeBreakArgs {
         #[inline]
         fn default() -> Self {
-            TradeArgs { price: 0, size_: 0 }
+            TradeBreakArgs {
+                price: 0,
+                size_: 0,
+                sale_condition: 0,
+                trade_id: 0,
+            }
         }
     }
-    pub struct TradeBuilder<'a: 'b, 'b> {
-        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+
+    pub struct TradeBreakBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> TradeBuilder<'a, 'b> {
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TradeBreakBuilder<'a, 'b, A> {
         #[inline]
         pub fn add_price(&mut self, price: u64) {
-            self.fbb_.push_slot::<u64>(Trade::VT_PRICE, price, 0);
+            self.fbb_.push_slot::<u64>(TradeBreak::VT_PRICE, price, 0);
         }
         #[inline]
         pub fn add_size_(&mut self, size_: u32) {
-            self.fbb_.push_slot::<u32>(Trade::VT_SIZE_, size_, 0);
+            self.fbb_.push_slot::<u32>(TradeBreak::VT_SIZE_, size_, 0);
+        }
+        #[inline]
+        pub fn add_sale_condition(&mut self, sale_condition: u8) {
+            self.fbb_
+                .push_slot::<u8>(TradeBreak::VT_SALE_CONDITION, sale_condition, 0);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TradeBuilder<'a, 'b> {
+        pub fn add_trade_id(&mut self, trade_id: u64) {
+            self.fbb_
+                .push_slot::<u64>(TradeBreak::VT_TRADE_ID, trade_id, 0);
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> TradeBreakBuilder<'a, 'b, A> {
             let start = _fbb.start_table();
-            TradeBuilder {
+            TradeBreakBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<Trade<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<TradeBreak<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
-    pub enum LevelUpdateOffset {}
-    #[derive(Copy, Clone, Debug, PartialEq)]
+    impl core::fmt::Debug for TradeBreak<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("TradeBreak");
+            ds.field("price", &self.price());
+            ds.field("size", &self.size_());
+            ds.field("sale_condition", &self.sale_condition());
+            ds.field("trade_id", &self.trade_id());
+            ds.finish()
+        }
+    }
+    pub enum AuctionInformationOffset {}
+    #[derive(Copy, Clone, PartialEq)]
 
-    pub struct LevelUpdate<'a> {
+    pub struct AuctionInformation<'a> {
         pub _tab: flatbuffers::Table<'a>,
     }
 
-    impl<'a> flatbuffers::Follow<'a> for LevelUpdate<'a> {
-        type Inner = LevelUpdate<'a>;
+    impl<'a> flatbuffers::Follow<'a> for AuctionInformation<'a> {
+        type Inner = AuctionInformation<'a>;
         #[inline]
-        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
-                _tab: flatbuffers::Table { buf: buf, loc: loc },
+                _tab: flatbuffers::Table::new(buf, loc),
             }
         }
     }
 
-    impl<'a> LevelUpdate<'a> {
+    impl<'a> AuctionInformation<'a> {
+        pub const VT_AUCTION_TYPE: flatbuffers::VOffsetT = 4;
+        pub const VT_PAIRED_SHARES: flatbuffers::VOffsetT = 6;
+        pub const VT_REFERENCE_PRICE: flatbuffers::VOffsetT = 8;
+        pub const VT_INDICATIVE_CLEARING_PRICE: flatbuffers::VOffsetT = 10;
+        pub const VT_IMBALANCE_SHARES: flatbuffers::VOffsetT = 12;
+        pub const VT_IMBALANCE_SIDE: flatbuffers::VOffsetT = 14;
+        pub const VT_EXTENSION_NUMBER: flatbuffers::VOffsetT = 16;
+        pub const VT_SCHEDULED_AUCTION: flatbuffers::VOffsetT = 18;
+        pub const VT_AUCTION_BOOK_CLEARING_PRICE: flatbuffers::VOffsetT = 20;
+        pub const VT_COLLAR_REFERENCE_PRICE: flatbuffers::VOffsetT = 22;
+        pub const VT_LOWER_AUCTION_COLLAR: flatbuffers::VOffsetT = 24;
+        pub const VT_UPPER_AUCTION_COLLAR: flatbuffers::VOffsetT = 26;
+
         #[inline]
-        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-            LevelUpdate { _tab: table }
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+            AuctionInformation { _tab: table }
         }
         #[allow(unused_mut)]
-        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-            args: &'args LevelUpdateArgs,
-        ) -> flatbuffers::WIPOffset<LevelUpdate<'bldr>> {
-            let mut builder = LevelUpdateBuilder::new(_fbb);
-            builder.add_price(args.price);
-            builder.add_size_(args.size_);
-            builder.add_side(args.side);
-            builder.add_flags(args.flags);
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+            args: &'args AuctionInformationArgs,
+        ) -> flatbuffers::WIPOffset<AuctionInformation<'bldr>> {
+            let mut builder = AuctionInformationBuilder::new(_fbb);
+            builder.add_upper_auction_collar(args.upper_auction_collar);
+            builder.add_lower_auction_collar(args.lower_auction_collar);
+            builder.add_collar_reference_price(args.collar_reference_price);
+            builder.add_auction_book_clearing_price(args.auction_book_clearing_price);
+            builder.add_indicative_clearing_price(args.indicative_clearing_price);
+            builder.add_reference_price(args.reference_price);
+            builder.add_scheduled_auction(args.scheduled_auction);
+            builder.add_imbalance_shares(args.imbalance_shares);
+            builder.add_paired_shares(args.paired_shares);
+            builder.add_extension_number(args.extension_number);
+            builder.add_imbalance_side(args.imbalance_side);
+            builder.add_auction_type(args.auction_type);
             builder.finish()
         }
 
-        pub const VT_PRICE: flatbuffers::VOffsetT = 4;
-        pub const VT_SIZE_: flatbuffers::VOffsetT = 6;
-        pub const VT_FLAGS: flatbuffers::VOffsetT = 8;
-        pub const VT_SIDE: flatbuffers::VOffsetT = 10;
-
         #[inline]
-        pub fn price(&self) -> u64 {
-            self._tab
-                .get::<u64>(LevelUpdate::VT_PRICE, Some(0))
-                .unwrap()
+        pub fn auction_type(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(AuctionInformation::VT_AUCTION_TYPE, Some(0))
+                    .unwrap()
+            }
         }
         #[inline]
-        pub fn size_(&self) -> u32 {
-            self._tab
-                .get::<u32>(LevelUpdate::VT_SIZE_, Some(0))
-                .unwrap()
+        pub fn paired_shares(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AuctionInformation::VT_PAIRED_SHARES, Some(0))
+                    .unwrap()
+            }
         }
         #[inline]
-        pub fn flags(&self) -> u8 {
-            self._tab.get::<u8>(LevelUpdate::VT_FLAGS, Some(0)).unwrap()
+        pub fn reference_price(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(AuctionInformation::VT_REFERENCE_PRICE, Some(0))
+                    .unwrap()
+            }
         }
         #[inline]
-        pub fn side(&self) -> Side {
-            self._tab
-                .get::<Side>(LevelUpdate::VT_SIDE, Some(Side::Buy))
-                .unwrap()
+        pub fn indicative_clearing_price(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(AuctionInformation::VT_INDICATIVE_CLEARING_PRICE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn imbalance_shares(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AuctionInformation::VT_IMBALANCE_SHARES, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn imbalance_side(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(AuctionInformation::VT_IMBALANCE_SIDE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn extension_number(&self) -> u8 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u8>(AuctionInformation::VT_EXTENSION_NUMBER, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn scheduled_auction(&self) -> u32 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u32>(AuctionInformation::VT_SCHEDULED_AUCTION, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn auction_book_clearing_price(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(AuctionInformation::VT_AUCTION_BOOK_CLEARING_PRICE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn collar_reference_price(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(AuctionInformation::VT_COLLAR_REFERENCE_PRICE, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn lower_auction_collar(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(AuctionInformation::VT_LOWER_AUCTION_COLLAR, Some(0))
+                    .unwrap()
+            }
+        }
+        #[inline]
+        pub fn upper_auction_collar(&self) -> u64 {
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(AuctionInformation::VT_UPPER_AUCTION_COLLAR, Some(0))
+                    .unwrap()
+            }
         }
     }
 
-    pub struct LevelUpdateArgs {
-        pub price: u64,
-        pub size_: u32,
-        pub flags: u8,
-        pub side: Side,
+    impl flatbuffers::Verifiable for AuctionInformation<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u8>("auction_type", Self::VT_AUCTION_TYPE, false)?
+                .visit_field::<u32>("paired_shares", Self::VT_PAIRED_SHARES, false)?
+                .visit_field::<u64>("reference_price", Self::VT_REFERENCE_PRICE, false)?
+                .visit_field::<u64>(
+                    "indicative_clearing_price",
+                    Self::VT_INDICATIVE_CLEARING_PRICE,
+                    false,
+                )?
+                .visit_field::<u32>("imbalance_shares", Self::VT_IMBALANCE_SHARES, false)?
+                .visit_field::<u8>("imbalance_side", Self::VT_IMBALANCE_SIDE, false)?
+                .visit_field::<u8>("extension_number", Self::VT_EXTENSION_NUMBER, false)?
+                .visit_field::<u32>("scheduled_auction", Self::VT_SCHEDULED_AUCTION, false)?
+                .visit_field::<u64>(
+                    "auction_book_clearing_price",
+                    Self::VT_AUCTION_BOOK_CLEARING_PRICE,
+                    false,
+                )?
+                .visit_field::<u64>(
+                    "collar_reference_price",
+                    Self::VT_COLLAR_REFERENCE_PRICE,
+                    false,
+                )?
+                .visit_field::<u64>("lower_auction_collar", Self::VT_LOWER_AUCTION_COLLAR, false)?
+                .visit_field::<u64>("upper_auction_collar", Self::VT_UPPER_AUCTION_COLLAR, false)?
+                .finish();
+            Ok(())
+        }
     }
-    impl<'a> Default for LevelUpdateArgs {
+    pub struct AuctionInformationArgs {
+        pub auction_type: u8,
+        pub paired_shares: u32,
+        pub reference_price: u64,
+        pub indicative_clearing_price: u64,
+        pub imbalance_shares: u32,
+        pub imbalance_side: u8,
+        pub extension_number: u8,
+        pub scheduled_auction: u32,
+        pub auction_book_clearing_price: u64,
+        pub collar_reference_price: u64,
+        pub lower_auction_collar: u64,
+        pub upper_auction_collar: u64,
+    }
+    impl<'a> Default for AuctionInformationArgs {
         #[inline]
         fn default() -> Self {
-            LevelUpdateArgs {
-                price: 0,
-                size_: 0,
-                flags: 0,
-                side: Side::Buy,
+            AuctionInformationArgs {
+                auction_type: 0,
+                paired_shares: 0,
+                reference_price: 0,
+                indicative_clearing_price: 0,
+                imbalance_shares: 0,
+                imbalance_side: 0,
+                extension_number: 0,
+                scheduled_auction: 0,
+                auction_book_clearing_price: 0,
+                collar_reference_price: 0,
+                lower_auction_collar: 0,
+                upper_auction_collar: 0,
             }
         }
     }
-    pub struct LevelUpdateBuilder<'a: 'b, 'b> {
-        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+
+    pub struct AuctionInformationBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> LevelUpdateBuilder<'a, 'b> {
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AuctionInformationBuilder<'a, 'b, A> {
         #[inline]
-        pub fn add_price(&mut self, price: u64) {
-            self.fbb_.push_slot::<u64>(LevelUpdate::VT_PRICE, price, 0);
+        pub fn add_auction_type(&mut self, auction_type: u8) {
+            self.fbb_
+                .push_slot::<u8>(AuctionInformation::VT_AUCTION_TYPE, auction_type, 0);
         }
         #[inline]
-        pub fn add_size_(&mut self, size_: u32) {
-            self.fbb_.push_slot::<u32>(LevelUpdate::VT_SIZE_, size_, 0);
+        pub fn add_paired_shares(&mut self, paired_shares: u32) {
+            self.fbb_
+                .push_slot::<u32>(AuctionInformation::VT_PAIRED_SHARES, paired_shares, 0);
         }
         #[inline]
-        pub fn add_flags(&mut self, flags: u8) {
-            self.fbb_.push_slot::<u8>(LevelUpdate::VT_FLAGS, flags, 0);
+        pub fn add_reference_price(&mut self, reference_price: u64) {
+            self.fbb_
+                .push_slot::<u64>(AuctionInformation::VT_REFERENCE_PRICE, reference_price, 0);
         }
         #[inline]
-        pub fn add_side(&mut self, side: Side) {
+        pub fn add_indicative_clearing_price(&mut self, indicative_clearing_price: u64) {
+            self.fbb_.push_slot::<u64>(
+                AuctionInformation::VT_INDICATIVE_CLEARING_PRICE,
+                indicative_clearing_price,
+                0,
+            );
+        }
+        #[inline]
+        pub fn add_imbalance_shares(&mut self, imbalance_shares: u32) {
+            self.fbb_.push_slot::<u32>(
+                AuctionInformation::VT_IMBALANCE_SHARES,
+                imbalance_shares,
+                0,
+            );
+        }
+        #[inline]
+        pub fn add_imbalance_side(&mut self, imbalance_side: u8) {
             self.fbb_
-                .push_slot::<Side>(LevelUpdate::VT_SIDE, side, Side::Buy);
+                .push_slot::<u8>(AuctionInformation::VT_IMBALANCE_SIDE, imbalance_side, 0);
+        }
+        #[inline]
+        pub fn add_extension_number(&mut self, extension_number: u8) {
+            self.fbb_
+                .push_slot::<u8>(AuctionInformation::VT_EXTENSION_NUMBER, extension_number, 0);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> LevelUpdateBuilder<'a, 'b> {
+        pub fn add_scheduled_auction(&mut self, scheduled_auction: u32) {
+            self.fbb_.push_slot::<u32>(
+                AuctionInformation::VT_SCHEDULED_AUCTION,
+                scheduled_auction,
+                0,
+            );
+        }
+        #[inline]
+        pub fn add_auction_book_clearing_price(&mut self, auction_book_clearing_price: u64) {
+            self.fbb_.push_slot::<u64>(
+                AuctionInformation::VT_AUCTION_BOOK_CLEARING_PRICE,
+                auction_book_clearing_price,
+                0,
+            );
+        }
+        #[inline]
+        pub fn add_collar_reference_price(&mut self, collar_reference_price: u64) {
+            self.fbb_.push_slot::<u64>(
+                AuctionInformation::VT_COLLAR_REFERENCE_PRICE,
+                collar_reference_price,
+                0,
+            );
+        }
+        #[inline]
+        pub fn add_lower_auction_collar(&mut self, lower_auction_collar: u64) {
+            self.fbb_.push_slot::<u64>(
+                AuctionInformation::VT_LOWER_AUCTION_COLLAR,
+                lower_auction_collar,
+                0,
+            );
+        }
+        #[inline]
+        pub fn add_upper_auction_collar(&mut self, upper_auction_collar: u64) {
+            self.fbb_.push_slot::<u64>(
+                AuctionInformation::VT_UPPER_AUCTION_COLLAR,
+                upper_auction_collar,
+                0,
+            );
+        }
+        #[inline]
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> AuctionInformationBuilder<'a, 'b, A> {
             let start = _fbb.start_table();
-            LevelUpdateBuilder {
+            AuctionInformationBuilder {
                 fbb_: _fbb,
                 start_: start,
             }
         }
         #[inline]
-        pub fn finish(self) -> flatbuffers::WIPOffset<LevelUpdate<'a>> {
+        pub fn finish(self) -> flatbuffers::WIPOffset<AuctionInformation<'a>> {
             let o = self.fbb_.end_table(self.start_);
             flatbuffers::WIPOffset::new(o.value())
         }
     }
 
+    impl core::fmt::Debug for AuctionInformation<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("AuctionInformation");
+            ds.field("auction_type", &self.auction_type());
+            ds.field("paired_shares", &self.paired_shares());
+            ds.field("reference_price", &self.reference_price());
+            ds.field(
+                "indicative_clearing_price",
+                &self.indicative_clearing_price(),
+            );
+            ds.field("imbalance_shares", &self.imbalance_shares());
+            ds.field("imbalance_side", &self.imbalance_side());
+            ds.field("extension_number", &self.extension_number());
+            ds.field("scheduled_auction", &self.scheduled_auction());
+            ds.field(
+                "auction_book_clearing_price",
+                &self.auction_book_clearing_price(),
+            );
+            ds.field("collar_reference_price", &self.collar_reference_price());
+            ds.field("lower_auction_collar", &self.lower_auction_collar());
+            ds.field("upper_auction_collar", &self.upper_auction_collar());
+            ds.finish()
+        }
+    }
     pub enum MessageOffset {}
-    #[derive(Copy, Clone, Debug, PartialEq)]
+    #[derive(Copy, Clone, PartialEq)]
 
     pub struct Message<'a> {
         pub _tab: flatbuffers::Table<'a>,
@@ -339,21 +2050,31 @@ pub mod md_shootout {
     impl<'a> flatbuffers::Follow<'a> for Message<'a> {
         type Inner = Message<'a>;
         #[inline]
-        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
-                _tab: flatbuffers::Table { buf: buf, loc: loc },
+                _tab: flatbuffers::Table::new(buf, loc),
             }
         }
     }
 
     impl<'a> Message<'a> {
+        pub const VT_TS_NANOS: flatbuffers::VOffsetT = 4;
+        pub const VT_SYMBOL: flatbuffers::VOffsetT = 6;
+        pub const VT_BODY_TYPE: flatbuffers::VOffsetT = 8;
+        pub const VT_BODY: flatbuffers::VOffsetT = 10;
+
         #[inline]
-        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
             Message { _tab: table }
         }
         #[allow(unused_mut)]
-        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
             args: &'args MessageArgs<'args>,
         ) -> flatbuffers::WIPOffset<Message<'bldr>> {
             let mut builder = MessageBuilder::new(_fbb);
@@ -368,36 +2089,57 @@ pub mod md_shootout {
             builder.finish()
         }
 
-        pub const VT_TS_NANOS: flatbuffers::VOffsetT = 4;
-        pub const VT_SYMBOL: flatbuffers::VOffsetT = 6;
-        pub const VT_BODY_TYPE: flatbuffers::VOffsetT = 8;
-        pub const VT_BODY: flatbuffers::VOffsetT = 10;
-
         #[inline]
         pub fn ts_nanos(&self) -> i64 {
-            self._tab.get::<i64>(Message::VT_TS_NANOS, Some(0)).unwrap()
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe { self._tab.get::<i64>(Message::VT_TS_NANOS, Some(0)).unwrap() }
         }
         #[inline]
         pub fn symbol(&self) -> Option<&'a str> {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(Message::VT_SYMBOL, None)
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<&str>>(Message::VT_SYMBOL, None)
+            }
         }
         #[inline]
         pub fn body_type(&self) -> MessageBody {
-            self._tab
-                .get::<MessageBody>(Message::VT_BODY_TYPE, Some(MessageBody::NONE))
-                .unwrap()
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<MessageBody>(Message::VT_BODY_TYPE, Some(MessageBody::NONE))
+                    .unwrap()
+            }
         }
         #[inline]
         pub fn body(&self) -> Option<flatbuffers::Table<'a>> {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Message::VT_BODY, None)
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                        Message::VT_BODY,
+                        None,
+                    )
+            }
         }
         #[inline]
         #[allow(non_snake_case)]
         pub fn body_as_trade(&self) -> Option<Trade<'a>> {
             if self.body_type() == MessageBody::Trade {
-                self.body().map(|u| Trade::init_from_table(u))
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { Trade::init_from_table(t) }
+                })
             } else {
                 None
             }
@@ -407,13 +2149,183 @@ pub mod md_shootout {
         #[allow(non_snake_case)]
         pub fn body_as_level_update(&self) -> Option<LevelUpdate<'a>> {
             if self.body_type() == MessageBody::LevelUpdate {
-                self.body().map(|u| LevelUpdate::init_from_table(u))
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { LevelUpdate::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_system_event(&self) -> Option<SystemEvent<'a>> {
+            if self.body_type() == MessageBody::SystemEvent {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { SystemEvent::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_security_directory(&self) -> Option<SecurityDirectory<'a>> {
+            if self.body_type() == MessageBody::SecurityDirectory {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { SecurityDirectory::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_trading_status(&self) -> Option<TradingStatus<'a>> {
+            if self.body_type() == MessageBody::TradingStatus {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TradingStatus::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_operational_halt_status(&self) -> Option<OperationalHaltStatus<'a>> {
+            if self.body_type() == MessageBody::OperationalHaltStatus {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { OperationalHaltStatus::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_short_sale_price_test(&self) -> Option<ShortSalePriceTest<'a>> {
+            if self.body_type() == MessageBody::ShortSalePriceTest {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { ShortSalePriceTest::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_security_event(&self) -> Option<SecurityEvent<'a>> {
+            if self.body_type() == MessageBody::SecurityEvent {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { SecurityEvent::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_official_price(&self) -> Option<OfficialPrice<'a>> {
+            if self.body_type() == MessageBody::OfficialPrice {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { OfficialPrice::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_trade_break(&self) -> Option<TradeBreak<'a>> {
+            if self.body_type() == MessageBody::TradeBreak {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { TradeBreak::init_from_table(t) }
+                })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        #[allow(non_snake_case)]
+        pub fn body_as_auction_information(&self) -> Option<AuctionInformation<'a>> {
+            if self.body_type() == MessageBody::AuctionInformation {
+                self.body().map(|t| {
+                    // Safety:
+                    // Created from a valid Table for this object
+                    // Which contains a valid union in this slot
+                    unsafe { AuctionInformation::init_from_table(t) }
+                })
             } else {
                 None
             }
         }
     }
 
+    impl flatbuffers::Verifiable for Message<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+.visit_field::<i64>("ts_nanos", Self::VT_TS_NANOS, false)?
+.visit_field::<flatbuffers::ForwardsUOffset<&str>>("symbol", Self::VT_SYMBOL, false)?
+.visit_union::<MessageBody, _>("body_type", Self::VT_BODY_TYPE, "body", Self::VT_BODY, false, |key, v, pos| {
+match key {
+MessageBody::Trade => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Trade>>("MessageBody::Trade", pos),
+MessageBody::LevelUpdate => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LevelUpdate>>("MessageBody::LevelUpdate", pos),
+MessageBody::SystemEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SystemEvent>>("MessageBody::SystemEvent", pos),
+MessageBody::SecurityDirectory => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SecurityDirectory>>("MessageBody::SecurityDirectory", pos),
+MessageBody::TradingStatus => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TradingStatus>>("MessageBody::TradingStatus", pos),
+MessageBody::OperationalHaltStatus => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OperationalHaltStatus>>("MessageBody::OperationalHaltStatus", pos),
+MessageBody::ShortSalePriceTest => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ShortSalePriceTest>>("MessageBody::ShortSalePriceTest", pos),
+MessageBody::SecurityEvent => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SecurityEvent>>("MessageBody::SecurityEvent", pos),
+MessageBody::OfficialPrice => v.verify_union_variant::<flatbuffers::ForwardsUOffset<OfficialPrice>>("MessageBody::OfficialPrice", pos),
+MessageBody::TradeBreak => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TradeBreak>>("MessageBody::TradeBreak", pos),
+MessageBody::AuctionInformation => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AuctionInformation>>("MessageBody::AuctionInformation", pos),
+_ => Ok(()),
+}
+})?
+.finish();
+            Ok(())
+        }
+    }
     pub struct MessageArgs<'a> {
         pub ts_nanos: i64,
         pub symbol: Option<flatbuffers::WIPOffset<&'a str>>,
@@ -431,11 +2343,12 @@ pub mod md_shootout {
             }
         }
     }
-    pub struct MessageBuilder<'a: 'b, 'b> {
-        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+
+    pub struct MessageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> MessageBuilder<'a, 'b> {
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MessageBuilder<'a, 'b, A> {
         #[inline]
         pub fn add_ts_nanos(&mut self, ts_nanos: i64) {
             self.fbb_
@@ -457,7 +2370,9 @@ pub mod md_shootout {
                 .push_slot_always::<flatbuffers::WIPOffset<_>>(Message::VT_BODY, body);
         }
         #[inline]
-        pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> MessageBuilder<'a, 'b> {
+        pub fn new(
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> MessageBuilder<'a, 'b, A> {
             let start = _fbb.start_table();
             MessageBuilder {
                 fbb_: _fbb,
@@ -471,8 +2386,133 @@ pub mod md_shootout {
         }
     }
 
+    impl core::fmt::Debug for Message<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("Message");
+            ds.field("ts_nanos", &self.ts_nanos());
+            ds.field("symbol", &self.symbol());
+            ds.field("body_type", &self.body_type());
+            match self.body_type() {
+                MessageBody::Trade => {
+                    if let Some(x) = self.body_as_trade() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::LevelUpdate => {
+                    if let Some(x) = self.body_as_level_update() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::SystemEvent => {
+                    if let Some(x) = self.body_as_system_event() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::SecurityDirectory => {
+                    if let Some(x) = self.body_as_security_directory() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::TradingStatus => {
+                    if let Some(x) = self.body_as_trading_status() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::OperationalHaltStatus => {
+                    if let Some(x) = self.body_as_operational_halt_status() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::ShortSalePriceTest => {
+                    if let Some(x) = self.body_as_short_sale_price_test() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::SecurityEvent => {
+                    if let Some(x) = self.body_as_security_event() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::OfficialPrice => {
+                    if let Some(x) = self.body_as_official_price() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::TradeBreak => {
+                    if let Some(x) = self.body_as_trade_break() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                MessageBody::AuctionInformation => {
+                    if let Some(x) = self.body_as_auction_information() {
+                        ds.field("body", &x)
+                    } else {
+                        ds.field(
+                            "body",
+                            &"InvalidFlatbuffer: Union discriminant does not match value.",
+                        )
+                    }
+                }
+                _ => {
+                    let x: Option<()> = None;
+                    ds.field("body", &x)
+                }
+            };
+            ds.finish()
+        }
+    }
     pub enum MultiMessageOffset {}
-    #[derive(Copy, Clone, Debug, PartialEq)]
+    #[derive(Copy, Clone, PartialEq)]
 
     pub struct MultiMessage<'a> {
         pub _tab: flatbuffers::Table<'a>,
@@ -481,21 +2521,29 @@ pub mod md_shootout {
     impl<'a> flatbuffers::Follow<'a> for MultiMessage<'a> {
         type Inner = MultiMessage<'a>;
         #[inline]
-        fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
             Self {
-                _tab: flatbuffers::Table { buf: buf, loc: loc },
+                _tab: flatbuffers::Table::new(buf, loc),
             }
         }
     }
 
     impl<'a> MultiMessage<'a> {
+        pub const VT_SEQ_NO: flatbuffers::VOffsetT = 4;
+        pub const VT_MESSAGES: flatbuffers::VOffsetT = 6;
+
         #[inline]
-        pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
             MultiMessage { _tab: table }
         }
         #[allow(unused_mut)]
-        pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
-            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        pub fn create<
+            'bldr: 'args,
+            'args: 'mut_bldr,
+            'mut_bldr,
+            A: flatbuffers::Allocator + 'bldr,
+        >(
+            _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
             args: &'args MultiMessageArgs<'args>,
         ) -> flatbuffers::WIPOffset<MultiMessage<'bldr>> {
             let mut builder = MultiMessageBuilder::new(_fbb);
@@ -506,25 +2554,48 @@ pub mod md_shootout {
             builder.finish()
         }
 
-        pub const VT_SEQ_NO: flatbuffers::VOffsetT = 4;
-        pub const VT_MESSAGES: flatbuffers::VOffsetT = 6;
-
         #[inline]
         pub fn seq_no(&self) -> u64 {
-            self._tab
-                .get::<u64>(MultiMessage::VT_SEQ_NO, Some(0))
-                .unwrap()
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab
+                    .get::<u64>(MultiMessage::VT_SEQ_NO, Some(0))
+                    .unwrap()
+            }
         }
         #[inline]
         pub fn messages(
             &self,
         ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Message<'a>>>> {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<flatbuffers::ForwardsUOffset<Message<'a>>>,
-            >>(MultiMessage::VT_MESSAGES, None)
+            // Safety:
+            // Created from valid Table for this object
+            // which contains a valid value in this slot
+            unsafe {
+                self._tab.get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Message>>,
+                >>(MultiMessage::VT_MESSAGES, None)
+            }
         }
     }
 
+    impl flatbuffers::Verifiable for MultiMessage<'_> {
+        #[inline]
+        fn run_verifier(
+            v: &mut flatbuffers::Verifier,
+            pos: usize,
+        ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+            use self::flatbuffers::Verifiable;
+            v.visit_table(pos)?
+                .visit_field::<u64>("seq_no", Self::VT_SEQ_NO, false)?
+                .visit_field::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Message>>,
+                >>("messages", Self::VT_MESSAGES, false)?
+                .finish();
+            Ok(())
+        }
+    }
     pub struct MultiMessageArgs<'a> {
         pub seq_no: u64,
         pub messages: Option<
@@ -542,11 +2613,12 @@ pub mod md_shootout {
             }
         }
     }
-    pub struct MultiMessageBuilder<'a: 'b, 'b> {
-        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+
+    pub struct MultiMessageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+        fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
         start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
     }
-    impl<'a: 'b, 'b> MultiMessageBuilder<'a, 'b> {
+    impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MultiMessageBuilder<'a, 'b, A> {
         #[inline]
         pub fn add_seq_no(&mut self, seq_no: u64) {
             self.fbb_
@@ -564,8 +2636,8 @@ pub mod md_shootout {
         }
         #[inline]
         pub fn new(
-            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-        ) -> MultiMessageBuilder<'a, 'b> {
+            _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+        ) -> MultiMessageBuilder<'a, 'b, A> {
             let start = _fbb.start_table();
             MultiMessageBuilder {
                 fbb_: _fbb,
@@ -579,4 +2651,12 @@ pub mod md_shootout {
         }
     }
 
+    impl core::fmt::Debug for MultiMessage<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut ds = f.debug_struct("MultiMessage");
+            ds.field("seq_no", &self.seq_no());
+            ds.field("messages", &self.messages());
+            ds.finish()
+        }
+    }
 } // pub mod MdShootout
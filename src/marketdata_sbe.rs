@@ -1,9 +1,9 @@
-/// Generated code for SBE package marketdata_sbe
+// Generated code for SBE package marketdata_sbe
 
 /// Imports core rather than std to broaden usable environments.
 extern crate core;
 
-/// Result types for error handling
+// Result types for error handling
 
 /// Errors that may occur during the course of encoding or decoding.
 #[derive(Debug)]
@@ -43,6 +43,23 @@ impl<'d> ScratchDecoderData<'d> {
         }
     }
 
+    /// Copy a primitive out of the data buffer. Unlike `read_type`, the value
+    /// doesn't need to be aligned, so this is safe for length prefixes that
+    /// follow variable-length data.
+    /// Advances the `pos` index by the size of the value in bytes.
+    #[inline]
+    fn read_unaligned<T: Copy>(&mut self, num_bytes: usize) -> CodecResult<T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *const T;
+            let v = unsafe { core::ptr::read_unaligned(s) };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
     /// Advances the `pos` index by a set number of bytes.
     #[inline]
     fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
@@ -90,7 +107,7 @@ impl<'d> ScratchEncoderData<'d> {
         if end <= self.data.len() {
             let source_bytes: &[u8] =
                 unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
-            (&mut self.data[self.pos..end]).copy_from_slice(source_bytes);
+            self.data[self.pos..end].copy_from_slice(source_bytes);
             self.pos = end;
             Ok(())
         } else {
@@ -119,7 +136,7 @@ impl<'d> ScratchEncoderData<'d> {
         let end = self.pos + num_bytes;
         if end <= self.data.len() {
             let v: &'d mut T = unsafe {
-                let s = self.data.as_ptr().offset(self.pos as isize) as *mut T;
+                let s = self.data.as_ptr().add(self.pos) as *mut T;
                 &mut *s
             };
             self.pos = end;
@@ -142,7 +159,7 @@ impl<'d> ScratchEncoderData<'d> {
         if end <= self.data.len() {
             let source_bytes: &[u8] =
                 unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
-            (&mut self.data[position..end]).copy_from_slice(source_bytes);
+            self.data[position..end].copy_from_slice(source_bytes);
             Ok(())
         } else {
             Err(CodecErr::NotEnoughBytes)
@@ -183,7 +200,7 @@ impl<'d> ScratchEncoderData<'d> {
         if end <= self.data.len() {
             let source_bytes: &[u8] =
                 unsafe { core::slice::from_raw_parts(t.as_ptr() as *const u8, content_bytes_size) };
-            (&mut self.data[self.pos..end]).copy_from_slice(source_bytes);
+            self.data[self.pos..end].copy_from_slice(source_bytes);
             self.pos = end;
             Ok(())
         } else {
@@ -199,55 +216,43 @@ pub enum Either<L, R> {
     Right(R),
 }
 
-/// Enum Side
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(u8)]
-pub enum Side {
-    Buy = 0u8,
-    Sell = 1u8,
-    NullVal = 255u8,
-}
-impl Default for Side {
-    fn default() -> Self {
-        Side::NullVal
-    }
-}
-
 /// Enum MsgType
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 pub enum MsgType {
     Trade = 0u8,
     Quote = 1u8,
+    SystemEvent = 2u8,
+    SecurityDirectory = 3u8,
+    TradingStatus = 4u8,
+    OperationalHaltStatus = 5u8,
+    ShortSalePriceTest = 6u8,
+    SecurityEvent = 7u8,
+    OfficialPrice = 8u8,
+    TradeBreak = 9u8,
+    AuctionInformation = 10u8,
+    #[default]
     NullVal = 255u8,
 }
-impl Default for MsgType {
-    fn default() -> Self {
-        MsgType::NullVal
-    }
-}
 
-/// Quote
-#[repr(C, packed)]
-#[derive(Default)]
-pub struct Quote {
-    pub price: u64,
-    pub size: u32,
-    pub flags: u8,
-    pub side: Side,
+/// Enum Side
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Side {
+    Buy = 0u8,
+    Sell = 1u8,
+    #[default]
+    NullVal = 255u8,
 }
 
-impl Quote {}
-
-/// Trade
+/// SecurityEvent
 #[repr(C, packed)]
 #[derive(Default)]
-pub struct Trade {
-    pub price: u64,
-    pub size: u32,
+pub struct SecurityEvent {
+    pub security_event: u8,
 }
 
-impl Trade {}
+impl SecurityEvent {}
 
 /// MessageHeader
 #[repr(C, packed)]
@@ -261,6 +266,35 @@ pub struct MessageHeader {
 
 impl MessageHeader {}
 
+/// OperationalHaltStatus
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OperationalHaltStatus {
+    pub halt_status: u8,
+}
+
+impl OperationalHaltStatus {}
+
+/// ShortSalePriceTest
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct ShortSalePriceTest {
+    pub sspt_status: u8,
+    pub detail: u8,
+}
+
+impl ShortSalePriceTest {}
+
+/// TradingStatus
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct TradingStatus {
+    pub trading_status: u8,
+    pub reason: [u8; 4],
+}
+
+impl TradingStatus {}
+
 /// GroupSizeEncoding
 #[repr(C, packed)]
 #[derive(Default)]
@@ -281,11 +315,86 @@ pub struct VarAsciiEncoding {
 
 impl VarAsciiEncoding {}
 
+/// Quote
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct Quote {
+    pub price: u64,
+    pub size: u32,
+    pub flags: u8,
+    pub side: Side,
+}
+
+impl Quote {}
+
+/// SystemEvent
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SystemEvent {
+    pub system_event: u8,
+}
+
+impl SystemEvent {}
+
+/// Trade
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct Trade {
+    pub price: u64,
+    pub size: u32,
+    pub sale_condition: u8,
+    pub trade_id: u64,
+}
+
+impl Trade {}
+
+/// OfficialPrice
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OfficialPrice {
+    pub price_type: u8,
+    pub official_price: u64,
+}
+
+impl OfficialPrice {}
+
+/// AuctionInformation
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct AuctionInformation {
+    pub auction_type: u8,
+    pub paired_shares: u32,
+    pub reference_price: u64,
+    pub indicative_clearing_price: u64,
+    pub imbalance_shares: u32,
+    pub imbalance_side: u8,
+    pub extension_number: u8,
+    pub scheduled_auction: u32,
+    pub auction_book_clearing_price: u64,
+    pub collar_reference_price: u64,
+    pub lower_auction_collar: u64,
+    pub upper_auction_collar: u64,
+}
+
+impl AuctionInformation {}
+
+/// SecurityDirectory
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SecurityDirectory {
+    pub flags: u8,
+    pub lot_size: u32,
+    pub previous_closing: u64,
+    pub luld_tier: u8,
+}
+
+impl SecurityDirectory {}
+
 /// MessageHeader Decoder entry point
 pub fn start_decoding_message_header<'d>(
     data: &'d [u8],
 ) -> CodecResult<(&'d MessageHeader, ScratchDecoderData<'d>)> {
-    let mut scratch = ScratchDecoderData { data: data, pos: 0 };
+    let mut scratch = ScratchDecoderData { data, pos: 0 };
     let v = scratch.read_type::<MessageHeader>(8)?;
     Ok((v, scratch))
 }
@@ -323,7 +432,7 @@ impl Default for MultiMessageMessageHeader {
     }
 }
 
-/// Group fixed-field member representations
+// Group fixed-field member representations
 #[repr(C, packed)]
 #[derive(Default)]
 pub struct MultiMessageMessagesMember {
@@ -331,6 +440,15 @@ pub struct MultiMessageMessagesMember {
     pub msg_type: MsgType,
     pub trade: Trade,
     pub quote: Quote,
+    pub system_event: SystemEvent,
+    pub security_directory: SecurityDirectory,
+    pub trading_status: TradingStatus,
+    pub operational_halt_status: OperationalHaltStatus,
+    pub short_sale_price_test: ShortSalePriceTest,
+    pub security_event: SecurityEvent,
+    pub official_price: OfficialPrice,
+    pub trade_break: Trade,
+    pub auction_information: AuctionInformation,
 }
 
 impl MultiMessageMessagesMember {}
@@ -346,7 +464,7 @@ impl<'d> MultiMessageDecoderDone<'d> {
     }
 
     pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageDecoderDone<'d> {
-        MultiMessageDecoderDone { scratch: scratch }
+        MultiMessageDecoderDone { scratch }
     }
 }
 
@@ -356,7 +474,7 @@ pub struct MultiMessageMessagesSymbolDecoder<'d> {
 }
 impl<'d> MultiMessageMessagesSymbolDecoder<'d> {
     fn wrap(parent: MultiMessageMessagesMemberDecoder<'d>) -> Self {
-        MultiMessageMessagesSymbolDecoder { parent: parent }
+        MultiMessageMessagesSymbolDecoder { parent }
     }
     pub fn symbol(
         mut self,
@@ -364,7 +482,7 @@ impl<'d> MultiMessageMessagesSymbolDecoder<'d> {
         &'d [u8],
         Either<MultiMessageMessagesMemberDecoder<'d>, MultiMessageDecoderDone<'d>>,
     )> {
-        let count = *self.parent.scratch.read_type::<u32>(4)?;
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
         Ok((
             self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
             self.parent.after_member(),
@@ -383,7 +501,7 @@ impl<'d> MultiMessageMessagesMemberDecoder<'d> {
     fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
         assert!(count > 0u16);
         MultiMessageMessagesMemberDecoder {
-            scratch: scratch,
+            scratch,
             max_index: count - 1,
             index: 0,
         }
@@ -395,7 +513,7 @@ impl<'d> MultiMessageMessagesMemberDecoder<'d> {
         &'d MultiMessageMessagesMember,
         MultiMessageMessagesSymbolDecoder<'d>,
     )> {
-        let v = self.scratch.read_type::<MultiMessageMessagesMember>(35)?;
+        let v = self.scratch.read_type::<MultiMessageMessagesMember>(161)?;
         self.index += 1;
         Ok((v, MultiMessageMessagesSymbolDecoder::wrap(self)))
     }
@@ -415,7 +533,7 @@ pub struct MultiMessageMessagesHeaderDecoder<'d> {
 }
 impl<'d> MultiMessageMessagesHeaderDecoder<'d> {
     fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
-        MultiMessageMessagesHeaderDecoder { scratch: scratch }
+        MultiMessageMessagesHeaderDecoder { scratch }
     }
     pub fn messages_individually(
         mut self,
@@ -439,7 +557,7 @@ pub struct MultiMessageFieldsDecoder<'d> {
 }
 impl<'d> MultiMessageFieldsDecoder<'d> {
     pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageFieldsDecoder<'d> {
-        MultiMessageFieldsDecoder { scratch: scratch }
+        MultiMessageFieldsDecoder { scratch }
     }
     pub fn multi_message_fields(
         mut self,
@@ -458,7 +576,7 @@ pub struct MultiMessageMessageHeaderDecoder<'d> {
 }
 impl<'d> MultiMessageMessageHeaderDecoder<'d> {
     pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageMessageHeaderDecoder<'d> {
-        MultiMessageMessageHeaderDecoder { scratch: scratch }
+        MultiMessageMessageHeaderDecoder { scratch }
     }
     pub fn header(mut self) -> CodecResult<(&'d MessageHeader, MultiMessageFieldsDecoder<'d>)> {
         let v = self.scratch.read_type::<MessageHeader>(8)?;
@@ -468,7 +586,7 @@ impl<'d> MultiMessageMessageHeaderDecoder<'d> {
 
 /// MultiMessage Decoder entry point
 pub fn start_decoding_multi_message<'d>(data: &'d [u8]) -> MultiMessageMessageHeaderDecoder<'d> {
-    MultiMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data: data, pos: 0 })
+    MultiMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
 }
 
 /// MultiMessageEncoderDone
@@ -482,7 +600,7 @@ impl<'d> MultiMessageEncoderDone<'d> {
     }
 
     pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageEncoderDone<'d> {
-        MultiMessageEncoderDone { scratch: scratch }
+        MultiMessageEncoderDone { scratch }
     }
 }
 
@@ -492,9 +610,9 @@ pub struct MultiMessageMessagesSymbolEncoder<'d> {
 }
 impl<'d> MultiMessageMessagesSymbolEncoder<'d> {
     fn wrap(parent: MultiMessageMessagesMemberEncoder<'d>) -> Self {
-        MultiMessageMessagesSymbolEncoder { parent: parent }
+        MultiMessageMessagesSymbolEncoder { parent }
     }
-    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<MultiMessageMessagesMemberEncoder> {
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<MultiMessageMessagesMemberEncoder<'d>> {
         let l = s.len();
         if l > 4294967294 {
             return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
@@ -517,8 +635,8 @@ impl<'d> MultiMessageMessagesMemberEncoder<'d> {
     #[inline]
     fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
         MultiMessageMessagesMemberEncoder {
-            scratch: scratch,
-            count_write_pos: count_write_pos,
+            scratch,
+            count_write_pos,
             count: 0,
         }
     }
@@ -529,7 +647,7 @@ impl<'d> MultiMessageMessagesMemberEncoder<'d> {
         fields: &MultiMessageMessagesMember,
     ) -> CodecResult<MultiMessageMessagesSymbolEncoder<'d>> {
         self.scratch
-            .write_type::<MultiMessageMessagesMember>(fields, 35)?; // block length
+            .write_type::<MultiMessageMessagesMember>(fields, 161)?; // block length
         self.count += 1;
         Ok(MultiMessageMessagesSymbolEncoder::wrap(self))
     }
@@ -546,11 +664,11 @@ pub struct MultiMessageMessagesHeaderEncoder<'d> {
 impl<'d> MultiMessageMessagesHeaderEncoder<'d> {
     #[inline]
     fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
-        MultiMessageMessagesHeaderEncoder { scratch: scratch }
+        MultiMessageMessagesHeaderEncoder { scratch }
     }
     #[inline]
     pub fn messages_individually(mut self) -> CodecResult<MultiMessageMessagesMemberEncoder<'d>> {
-        self.scratch.write_type::<u16>(&35u16, 2)?; // block length
+        self.scratch.write_type::<u16>(&161u16, 2)?; // block length
         let count_pos = self.scratch.pos;
         self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
         Ok(MultiMessageMessagesMemberEncoder::new(
@@ -566,7 +684,7 @@ pub struct MultiMessageFieldsEncoder<'d> {
 }
 impl<'d> MultiMessageFieldsEncoder<'d> {
     pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageFieldsEncoder<'d> {
-        MultiMessageFieldsEncoder { scratch: scratch }
+        MultiMessageFieldsEncoder { scratch }
     }
 
     /// Create a mutable struct reference overlaid atop the data buffer
@@ -578,7 +696,7 @@ impl<'d> MultiMessageFieldsEncoder<'d> {
         &'d mut MultiMessageFields,
         MultiMessageMessagesHeaderEncoder<'d>,
     )> {
-        let v = self.scratch.writable_overlay::<MultiMessageFields>(8 + 0)?;
+        let v = self.scratch.writable_overlay::<MultiMessageFields>(8)?;
         Ok((v, MultiMessageMessagesHeaderEncoder::wrap(self.scratch)))
     }
 
@@ -598,14 +716,14 @@ pub struct MultiMessageMessageHeaderEncoder<'d> {
 }
 impl<'d> MultiMessageMessageHeaderEncoder<'d> {
     pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageMessageHeaderEncoder<'d> {
-        MultiMessageMessageHeaderEncoder { scratch: scratch }
+        MultiMessageMessageHeaderEncoder { scratch }
     }
 
     /// Create a mutable struct reference overlaid atop the data buffer
     /// such that changes to the struct directly edit the buffer.
     /// Note that the initial content of the struct's fields may be garbage.
     pub fn header(mut self) -> CodecResult<(&'d mut MessageHeader, MultiMessageFieldsEncoder<'d>)> {
-        let v = self.scratch.writable_overlay::<MessageHeader>(8 + 0)?;
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
         Ok((v, MultiMessageFieldsEncoder::wrap(self.scratch)))
     }
 
@@ -620,5 +738,5 @@ impl<'d> MultiMessageMessageHeaderEncoder<'d> {
 pub fn start_encoding_multi_message<'d>(
     data: &'d mut [u8],
 ) -> MultiMessageMessageHeaderEncoder<'d> {
-    MultiMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data: data, pos: 0 })
+    MultiMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
 }
@@ -0,0 +1,729 @@
+// Generated code for SBE package marketdata_sbe_chained
+
+/// Imports core rather than std to broaden usable environments.
+extern crate core;
+
+// Result types for error handling
+
+/// Errors that may occur during the course of encoding or decoding.
+#[derive(Debug)]
+pub enum CodecErr {
+    /// Too few bytes in the byte-slice to read or write the data structure relevant
+    /// to the current state of the codec
+    NotEnoughBytes,
+
+    /// Groups and vardata are constrained by the numeric type chosen to represent their
+    /// length as well as optional maxima imposed by the schema
+    SliceIsLongerThanAllowedBySchema,
+}
+
+pub type CodecResult<T> = core::result::Result<T, CodecErr>;
+
+/// Scratch Decoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchDecoderData<'d> {
+    data: &'d [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchDecoderData<'d> {
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that the struct's contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the struct in bytes.
+    #[inline]
+    fn read_type<T>(&mut self, num_bytes: usize) -> CodecResult<&'d T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *mut T;
+            let v: &'d T = unsafe { &*s };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy a primitive out of the data buffer. Unlike `read_type`, the value
+    /// doesn't need to be aligned, so this is safe for length prefixes that
+    /// follow variable-length data.
+    /// Advances the `pos` index by the size of the value in bytes.
+    #[inline]
+    fn read_unaligned<T: Copy>(&mut self, num_bytes: usize) -> CodecResult<T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *const T;
+            let v = unsafe { core::ptr::read_unaligned(s) };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a slice reference overlaid atop the data buffer
+    /// such that the slice's members' contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the slice contents in bytes.
+    #[inline]
+    fn read_slice<T>(&mut self, count: usize, bytes_per_item: usize) -> CodecResult<&'d [T]> {
+        let num_bytes = bytes_per_item * count;
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d [T] = unsafe {
+                core::slice::from_raw_parts(self.data[self.pos..end].as_ptr() as *const T, count)
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Scratch Encoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchEncoderData<'d> {
+    data: &'d mut [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchEncoderData<'d> {
+    /// Copy the bytes of a value into the data buffer
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn write_type<T>(&mut self, t: &T, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn writable_overlay<T>(&mut self, num_bytes: usize) -> CodecResult<&'d mut T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d mut T = unsafe {
+                let s = self.data.as_ptr().add(self.pos) as *mut T;
+                &mut *s
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the bytes of a value into the data buffer at a specific position
+    /// Does **not** alter the `pos` index.
+    #[inline]
+    fn write_at_position<T>(
+        &mut self,
+        position: usize,
+        t: &T,
+        num_bytes: usize,
+    ) -> CodecResult<()> {
+        let end = position + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[position..end].copy_from_slice(source_bytes);
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+    /// Create a mutable slice overlaid atop the data buffer directly
+    /// such that changes to the slice contents directly edit the buffer
+    /// Note that the initial content of the slice's members' fields may be garbage.
+    /// Advances the `pos` index to after the region representing the slice.
+    #[inline]
+    fn writable_slice<T>(
+        &mut self,
+        count: usize,
+        bytes_per_item: usize,
+    ) -> CodecResult<&'d mut [T]> {
+        let end = self.pos + (count * bytes_per_item);
+        if end <= self.data.len() {
+            let v: &'d mut [T] = unsafe {
+                core::slice::from_raw_parts_mut(
+                    self.data[self.pos..end].as_mut_ptr() as *mut T,
+                    count,
+                )
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the raw bytes of a slice's contents into the data buffer
+    /// Does **not** encode the length of the slice explicitly into the buffer.
+    /// Advances the `pos` index to after the newly-written slice bytes.
+    #[inline]
+    fn write_slice_without_count<T>(&mut self, t: &[T], bytes_per_item: usize) -> CodecResult<()> {
+        let content_bytes_size = bytes_per_item * t.len();
+        let end = self.pos + content_bytes_size;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t.as_ptr() as *const u8, content_bytes_size) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Convenience Either enum
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Enum MsgType
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum MsgType {
+    Trade = 0u8,
+    Quote = 1u8,
+    SystemEvent = 2u8,
+    SecurityDirectory = 3u8,
+    TradingStatus = 4u8,
+    OperationalHaltStatus = 5u8,
+    ShortSalePriceTest = 6u8,
+    SecurityEvent = 7u8,
+    OfficialPrice = 8u8,
+    TradeBreak = 9u8,
+    AuctionInformation = 10u8,
+    #[default]
+    NullVal = 255u8,
+}
+
+/// Enum Side
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Side {
+    Buy = 0u8,
+    Sell = 1u8,
+    #[default]
+    NullVal = 255u8,
+}
+
+/// MessageStart
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MessageStart {
+    pub timestamp: i64,
+    pub msg_type: MsgType,
+}
+
+impl MessageStart {}
+
+/// SymbolLength
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SymbolLength {
+    pub length: u32,
+}
+
+impl SymbolLength {}
+
+/// SecurityEvent
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SecurityEvent {
+    pub security_event: u8,
+}
+
+impl SecurityEvent {}
+
+/// MessageHeader
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MessageHeader {
+    pub block_length: u16,
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+}
+
+impl MessageHeader {}
+
+/// OperationalHaltStatus
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OperationalHaltStatus {
+    pub halt_status: u8,
+}
+
+impl OperationalHaltStatus {}
+
+/// ShortSalePriceTest
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct ShortSalePriceTest {
+    pub sspt_status: u8,
+    pub detail: u8,
+}
+
+impl ShortSalePriceTest {}
+
+/// TradingStatus
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct TradingStatus {
+    pub trading_status: u8,
+    pub reason: [u8; 4],
+}
+
+impl TradingStatus {}
+
+/// Quote
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct Quote {
+    pub price: u64,
+    pub size: u32,
+    pub flags: u8,
+    pub side: Side,
+}
+
+impl Quote {}
+
+/// SystemEvent
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SystemEvent {
+    pub system_event: u8,
+}
+
+impl SystemEvent {}
+
+/// Trade
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct Trade {
+    pub price: u64,
+    pub size: u32,
+    pub sale_condition: u8,
+    pub trade_id: u64,
+}
+
+impl Trade {}
+
+/// OfficialPrice
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OfficialPrice {
+    pub price_type: u8,
+    pub official_price: u64,
+}
+
+impl OfficialPrice {}
+
+/// AuctionInformation
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct AuctionInformation {
+    pub auction_type: u8,
+    pub paired_shares: u32,
+    pub reference_price: u64,
+    pub indicative_clearing_price: u64,
+    pub imbalance_shares: u32,
+    pub imbalance_side: u8,
+    pub extension_number: u8,
+    pub scheduled_auction: u32,
+    pub auction_book_clearing_price: u64,
+    pub collar_reference_price: u64,
+    pub lower_auction_collar: u64,
+    pub upper_auction_collar: u64,
+}
+
+impl AuctionInformation {}
+
+/// SecurityDirectory
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SecurityDirectory {
+    pub flags: u8,
+    pub lot_size: u32,
+    pub previous_closing: u64,
+    pub luld_tier: u8,
+}
+
+impl SecurityDirectory {}
+
+/// MessageHeader Decoder entry point
+pub fn start_decoding_message_header<'d>(
+    data: &'d [u8],
+) -> CodecResult<(&'d MessageHeader, ScratchDecoderData<'d>)> {
+    let mut scratch = ScratchDecoderData { data, pos: 0 };
+    let v = scratch.read_type::<MessageHeader>(8)?;
+    Ok((v, scratch))
+}
+
+/// MultiMessage Fixed-size Fields (10 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageFields {
+    pub sequence_number: u64,
+    pub message_count: u16,
+}
+
+impl MultiMessageFields {}
+
+/// MultiMessage specific Message Header
+#[repr(C, packed)]
+pub struct MultiMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl MultiMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 10;
+    pub const TEMPLATE_ID: u16 = 1;
+    pub const SCHEMA_ID: u16 = 4;
+    pub const VERSION: u16 = 0;
+}
+impl Default for MultiMessageMessageHeader {
+    fn default() -> MultiMessageMessageHeader {
+        MultiMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 10u16,
+                template_id: 1u16,
+                schema_id: 4u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// MultiMessageDecoderDone
+pub struct MultiMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageDecoderDone<'d> {
+        MultiMessageDecoderDone { scratch }
+    }
+}
+
+/// MultiMessage Fixed fields Decoder
+pub struct MultiMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageFieldsDecoder<'d> {
+        MultiMessageFieldsDecoder { scratch }
+    }
+    pub fn multi_message_fields(
+        mut self,
+    ) -> CodecResult<(&'d MultiMessageFields, MultiMessageDecoderDone<'d>)> {
+        let v = self.scratch.read_type::<MultiMessageFields>(10)?;
+        Ok((v, MultiMessageDecoderDone::wrap(self.scratch)))
+    }
+}
+
+/// MultiMessageMessageHeaderDecoder
+pub struct MultiMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageMessageHeaderDecoder<'d> {
+        MultiMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(mut self) -> CodecResult<(&'d MessageHeader, MultiMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, MultiMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// MultiMessage Decoder entry point
+pub fn start_decoding_multi_message<'d>(data: &'d [u8]) -> MultiMessageMessageHeaderDecoder<'d> {
+    MultiMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// MultiMessageEncoderDone
+pub struct MultiMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageEncoderDone<'d> {
+        MultiMessageEncoderDone { scratch }
+    }
+}
+
+/// MultiMessage Fixed fields Encoder
+pub struct MultiMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageFieldsEncoder<'d> {
+        MultiMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn multi_message_fields(
+        mut self,
+    ) -> CodecResult<(&'d mut MultiMessageFields, MultiMessageEncoderDone<'d>)> {
+        let v = self.scratch.writable_overlay::<MultiMessageFields>(10)?;
+        Ok((v, MultiMessageEncoderDone::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn multi_message_fields_copy(
+        mut self,
+        t: &MultiMessageFields,
+    ) -> CodecResult<MultiMessageEncoderDone<'d>> {
+        self.scratch.write_type::<MultiMessageFields>(t, 10)?;
+        Ok(MultiMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// MultiMessageMessageHeaderEncoder
+pub struct MultiMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageMessageHeaderEncoder<'d> {
+        MultiMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(mut self) -> CodecResult<(&'d mut MessageHeader, MultiMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, MultiMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(mut self, t: &MessageHeader) -> CodecResult<MultiMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(MultiMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// MultiMessage Encoder entry point
+pub fn start_encoding_multi_message<'d>(
+    data: &'d mut [u8],
+) -> MultiMessageMessageHeaderEncoder<'d> {
+    MultiMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// ChainBlocks Fixed-size Fields (144 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct ChainBlocksFields {
+    pub message_start: MessageStart,
+    pub trade: Trade,
+    pub quote: Quote,
+    pub symbol_length: SymbolLength,
+    pub system_event: SystemEvent,
+    pub security_directory: SecurityDirectory,
+    pub trading_status: TradingStatus,
+    pub operational_halt_status: OperationalHaltStatus,
+    pub short_sale_price_test: ShortSalePriceTest,
+    pub security_event: SecurityEvent,
+    pub official_price: OfficialPrice,
+    pub auction_information: AuctionInformation,
+}
+
+impl ChainBlocksFields {}
+
+/// ChainBlocks specific Message Header
+#[repr(C, packed)]
+pub struct ChainBlocksMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl ChainBlocksMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 144;
+    pub const TEMPLATE_ID: u16 = 2;
+    pub const SCHEMA_ID: u16 = 4;
+    pub const VERSION: u16 = 0;
+}
+impl Default for ChainBlocksMessageHeader {
+    fn default() -> ChainBlocksMessageHeader {
+        ChainBlocksMessageHeader {
+            message_header: MessageHeader {
+                block_length: 144u16,
+                template_id: 2u16,
+                schema_id: 4u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// ChainBlocksDecoderDone
+pub struct ChainBlocksDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> ChainBlocksDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> ChainBlocksDecoderDone<'d> {
+        ChainBlocksDecoderDone { scratch }
+    }
+}
+
+/// ChainBlocks Fixed fields Decoder
+pub struct ChainBlocksFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> ChainBlocksFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> ChainBlocksFieldsDecoder<'d> {
+        ChainBlocksFieldsDecoder { scratch }
+    }
+    pub fn chain_blocks_fields(
+        mut self,
+    ) -> CodecResult<(&'d ChainBlocksFields, ChainBlocksDecoderDone<'d>)> {
+        let v = self.scratch.read_type::<ChainBlocksFields>(144)?;
+        Ok((v, ChainBlocksDecoderDone::wrap(self.scratch)))
+    }
+}
+
+/// ChainBlocksMessageHeaderDecoder
+pub struct ChainBlocksMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> ChainBlocksMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> ChainBlocksMessageHeaderDecoder<'d> {
+        ChainBlocksMessageHeaderDecoder { scratch }
+    }
+    pub fn header(mut self) -> CodecResult<(&'d MessageHeader, ChainBlocksFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, ChainBlocksFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// ChainBlocks Decoder entry point
+pub fn start_decoding_chain_blocks<'d>(data: &'d [u8]) -> ChainBlocksMessageHeaderDecoder<'d> {
+    ChainBlocksMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// ChainBlocksEncoderDone
+pub struct ChainBlocksEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> ChainBlocksEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> ChainBlocksEncoderDone<'d> {
+        ChainBlocksEncoderDone { scratch }
+    }
+}
+
+/// ChainBlocks Fixed fields Encoder
+pub struct ChainBlocksFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> ChainBlocksFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> ChainBlocksFieldsEncoder<'d> {
+        ChainBlocksFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn chain_blocks_fields(
+        mut self,
+    ) -> CodecResult<(&'d mut ChainBlocksFields, ChainBlocksEncoderDone<'d>)> {
+        let v = self.scratch.writable_overlay::<ChainBlocksFields>(144)?;
+        Ok((v, ChainBlocksEncoderDone::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn chain_blocks_fields_copy(
+        mut self,
+        t: &ChainBlocksFields,
+    ) -> CodecResult<ChainBlocksEncoderDone<'d>> {
+        self.scratch.write_type::<ChainBlocksFields>(t, 144)?;
+        Ok(ChainBlocksEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// ChainBlocksMessageHeaderEncoder
+pub struct ChainBlocksMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> ChainBlocksMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> ChainBlocksMessageHeaderEncoder<'d> {
+        ChainBlocksMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(mut self) -> CodecResult<(&'d mut MessageHeader, ChainBlocksFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, ChainBlocksFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(mut self, t: &MessageHeader) -> CodecResult<ChainBlocksFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(ChainBlocksFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// ChainBlocks Encoder entry point
+pub fn start_encoding_chain_blocks<'d>(data: &'d mut [u8]) -> ChainBlocksMessageHeaderEncoder<'d> {
+    ChainBlocksMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
@@ -0,0 +1,2509 @@
+// Generated code for SBE package marketdata_sbe_groups
+
+/// Imports core rather than std to broaden usable environments.
+extern crate core;
+
+// Result types for error handling
+
+/// Errors that may occur during the course of encoding or decoding.
+#[derive(Debug)]
+pub enum CodecErr {
+    /// Too few bytes in the byte-slice to read or write the data structure relevant
+    /// to the current state of the codec
+    NotEnoughBytes,
+
+    /// Groups and vardata are constrained by the numeric type chosen to represent their
+    /// length as well as optional maxima imposed by the schema
+    SliceIsLongerThanAllowedBySchema,
+}
+
+pub type CodecResult<T> = core::result::Result<T, CodecErr>;
+
+/// Scratch Decoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchDecoderData<'d> {
+    data: &'d [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchDecoderData<'d> {
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that the struct's contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the struct in bytes.
+    #[inline]
+    fn read_type<T>(&mut self, num_bytes: usize) -> CodecResult<&'d T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *mut T;
+            let v: &'d T = unsafe { &*s };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy a primitive out of the data buffer. Unlike `read_type`, the value
+    /// doesn't need to be aligned, so this is safe for length prefixes that
+    /// follow variable-length data.
+    /// Advances the `pos` index by the size of the value in bytes.
+    #[inline]
+    fn read_unaligned<T: Copy>(&mut self, num_bytes: usize) -> CodecResult<T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *const T;
+            let v = unsafe { core::ptr::read_unaligned(s) };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a slice reference overlaid atop the data buffer
+    /// such that the slice's members' contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the slice contents in bytes.
+    #[inline]
+    fn read_slice<T>(&mut self, count: usize, bytes_per_item: usize) -> CodecResult<&'d [T]> {
+        let num_bytes = bytes_per_item * count;
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d [T] = unsafe {
+                core::slice::from_raw_parts(self.data[self.pos..end].as_ptr() as *const T, count)
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Scratch Encoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchEncoderData<'d> {
+    data: &'d mut [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchEncoderData<'d> {
+    /// Copy the bytes of a value into the data buffer
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn write_type<T>(&mut self, t: &T, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn writable_overlay<T>(&mut self, num_bytes: usize) -> CodecResult<&'d mut T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d mut T = unsafe {
+                let s = self.data.as_ptr().add(self.pos) as *mut T;
+                &mut *s
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the bytes of a value into the data buffer at a specific position
+    /// Does **not** alter the `pos` index.
+    #[inline]
+    fn write_at_position<T>(
+        &mut self,
+        position: usize,
+        t: &T,
+        num_bytes: usize,
+    ) -> CodecResult<()> {
+        let end = position + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[position..end].copy_from_slice(source_bytes);
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+    /// Create a mutable slice overlaid atop the data buffer directly
+    /// such that changes to the slice contents directly edit the buffer
+    /// Note that the initial content of the slice's members' fields may be garbage.
+    /// Advances the `pos` index to after the region representing the slice.
+    #[inline]
+    fn writable_slice<T>(
+        &mut self,
+        count: usize,
+        bytes_per_item: usize,
+    ) -> CodecResult<&'d mut [T]> {
+        let end = self.pos + (count * bytes_per_item);
+        if end <= self.data.len() {
+            let v: &'d mut [T] = unsafe {
+                core::slice::from_raw_parts_mut(
+                    self.data[self.pos..end].as_mut_ptr() as *mut T,
+                    count,
+                )
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the raw bytes of a slice's contents into the data buffer
+    /// Does **not** encode the length of the slice explicitly into the buffer.
+    /// Advances the `pos` index to after the newly-written slice bytes.
+    #[inline]
+    fn write_slice_without_count<T>(&mut self, t: &[T], bytes_per_item: usize) -> CodecResult<()> {
+        let content_bytes_size = bytes_per_item * t.len();
+        let end = self.pos + content_bytes_size;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t.as_ptr() as *const u8, content_bytes_size) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Convenience Either enum
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Enum Side
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Side {
+    Buy = 0u8,
+    Sell = 1u8,
+    #[default]
+    NullVal = 255u8,
+}
+
+/// SecurityEvent
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SecurityEvent {
+    pub security_event: u8,
+}
+
+impl SecurityEvent {}
+
+/// MessageHeader
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MessageHeader {
+    pub block_length: u16,
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+}
+
+impl MessageHeader {}
+
+/// OperationalHaltStatus
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OperationalHaltStatus {
+    pub halt_status: u8,
+}
+
+impl OperationalHaltStatus {}
+
+/// ShortSalePriceTest
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct ShortSalePriceTest {
+    pub sspt_status: u8,
+    pub detail: u8,
+}
+
+impl ShortSalePriceTest {}
+
+/// TradingStatus
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct TradingStatus {
+    pub trading_status: u8,
+    pub reason: [u8; 4],
+}
+
+impl TradingStatus {}
+
+/// GroupSizeEncoding
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct GroupSizeEncoding {
+    pub block_length: u16,
+    pub num_in_group: u16,
+}
+
+impl GroupSizeEncoding {}
+
+/// VarAsciiEncoding
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct VarAsciiEncoding {
+    pub length: u32,
+    pub var_data: u8,
+}
+
+impl VarAsciiEncoding {}
+
+/// Quote
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct Quote {
+    pub price: u64,
+    pub size: u32,
+    pub flags: u8,
+    pub side: Side,
+}
+
+impl Quote {}
+
+/// SystemEvent
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SystemEvent {
+    pub system_event: u8,
+}
+
+impl SystemEvent {}
+
+/// Trade
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct Trade {
+    pub price: u64,
+    pub size: u32,
+    pub sale_condition: u8,
+    pub trade_id: u64,
+}
+
+impl Trade {}
+
+/// OfficialPrice
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OfficialPrice {
+    pub price_type: u8,
+    pub official_price: u64,
+}
+
+impl OfficialPrice {}
+
+/// AuctionInformation
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct AuctionInformation {
+    pub auction_type: u8,
+    pub paired_shares: u32,
+    pub reference_price: u64,
+    pub indicative_clearing_price: u64,
+    pub imbalance_shares: u32,
+    pub imbalance_side: u8,
+    pub extension_number: u8,
+    pub scheduled_auction: u32,
+    pub auction_book_clearing_price: u64,
+    pub collar_reference_price: u64,
+    pub lower_auction_collar: u64,
+    pub upper_auction_collar: u64,
+}
+
+impl AuctionInformation {}
+
+/// SecurityDirectory
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SecurityDirectory {
+    pub flags: u8,
+    pub lot_size: u32,
+    pub previous_closing: u64,
+    pub luld_tier: u8,
+}
+
+impl SecurityDirectory {}
+
+/// MessageHeader Decoder entry point
+pub fn start_decoding_message_header<'d>(
+    data: &'d [u8],
+) -> CodecResult<(&'d MessageHeader, ScratchDecoderData<'d>)> {
+    let mut scratch = ScratchDecoderData { data, pos: 0 };
+    let v = scratch.read_type::<MessageHeader>(8)?;
+    Ok((v, scratch))
+}
+
+/// MultiMessage Fixed-size Fields (8 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageFields {
+    pub sequence_number: u64,
+}
+
+impl MultiMessageFields {}
+
+/// MultiMessage specific Message Header
+#[repr(C, packed)]
+pub struct MultiMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl MultiMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 8;
+    pub const TEMPLATE_ID: u16 = 1;
+    pub const SCHEMA_ID: u16 = 3;
+    pub const VERSION: u16 = 0;
+}
+impl Default for MultiMessageMessageHeader {
+    fn default() -> MultiMessageMessageHeader {
+        MultiMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 8u16,
+                template_id: 1u16,
+                schema_id: 3u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageTradesMember {
+    pub timestamp: i64,
+    pub trade: Trade,
+}
+
+impl MultiMessageTradesMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageQuotesMember {
+    pub timestamp: i64,
+    pub quote: Quote,
+}
+
+impl MultiMessageQuotesMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageSystemEventsMember {
+    pub timestamp: i64,
+    pub system_event: SystemEvent,
+}
+
+impl MultiMessageSystemEventsMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageSecurityDirectoriesMember {
+    pub timestamp: i64,
+    pub security_directory: SecurityDirectory,
+}
+
+impl MultiMessageSecurityDirectoriesMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageTradingStatusesMember {
+    pub timestamp: i64,
+    pub trading_status: TradingStatus,
+}
+
+impl MultiMessageTradingStatusesMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageOperationalHaltStatusesMember {
+    pub timestamp: i64,
+    pub operational_halt_status: OperationalHaltStatus,
+}
+
+impl MultiMessageOperationalHaltStatusesMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageShortSalePriceTestsMember {
+    pub timestamp: i64,
+    pub short_sale_price_test: ShortSalePriceTest,
+}
+
+impl MultiMessageShortSalePriceTestsMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageSecurityEventsMember {
+    pub timestamp: i64,
+    pub security_event: SecurityEvent,
+}
+
+impl MultiMessageSecurityEventsMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageOfficialPricesMember {
+    pub timestamp: i64,
+    pub official_price: OfficialPrice,
+}
+
+impl MultiMessageOfficialPricesMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageTradeBreaksMember {
+    pub timestamp: i64,
+    pub trade_break: Trade,
+}
+
+impl MultiMessageTradeBreaksMember {}
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MultiMessageAuctionInformationsMember {
+    pub timestamp: i64,
+    pub auction_information: AuctionInformation,
+}
+
+impl MultiMessageAuctionInformationsMember {}
+
+/// MultiMessageDecoderDone
+pub struct MultiMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageDecoderDone<'d> {
+        MultiMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageAuctionInformationsSymbolDecoder<'d> {
+    parent: MultiMessageAuctionInformationsMemberDecoder<'d>,
+}
+impl<'d> MultiMessageAuctionInformationsSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageAuctionInformationsMemberDecoder<'d>) -> Self {
+        MultiMessageAuctionInformationsSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<MultiMessageAuctionInformationsMemberDecoder<'d>, MultiMessageDecoderDone<'d>>,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageAuctionInformations Decoder for fields and header
+pub struct MultiMessageAuctionInformationsMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageAuctionInformationsMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageAuctionInformationsMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_auction_informations_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageAuctionInformationsMember,
+        MultiMessageAuctionInformationsSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageAuctionInformationsMember>(71)?;
+        self.index += 1;
+        Ok((v, MultiMessageAuctionInformationsSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<MultiMessageAuctionInformationsMemberDecoder<'d>, MultiMessageDecoderDone<'d>> {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageDecoderDone::wrap(self.scratch))
+        }
+    }
+}
+pub struct MultiMessageAuctionInformationsHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageAuctionInformationsHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageAuctionInformationsHeaderDecoder { scratch }
+    }
+    pub fn auction_informations_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<MultiMessageAuctionInformationsMemberDecoder<'d>, MultiMessageDecoderDone<'d>>,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(
+                MultiMessageAuctionInformationsMemberDecoder::new(self.scratch, dim.num_in_group),
+            ))
+        } else {
+            Ok(Either::Right(MultiMessageDecoderDone::wrap(self.scratch)))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageTradeBreaksSymbolDecoder<'d> {
+    parent: MultiMessageTradeBreaksMemberDecoder<'d>,
+}
+impl<'d> MultiMessageTradeBreaksSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageTradeBreaksMemberDecoder<'d>) -> Self {
+        MultiMessageTradeBreaksSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<
+            MultiMessageTradeBreaksMemberDecoder<'d>,
+            MultiMessageAuctionInformationsHeaderDecoder<'d>,
+        >,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageTradeBreaks Decoder for fields and header
+pub struct MultiMessageTradeBreaksMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageTradeBreaksMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageTradeBreaksMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_trade_breaks_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageTradeBreaksMember,
+        MultiMessageTradeBreaksSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageTradeBreaksMember>(29)?;
+        self.index += 1;
+        Ok((v, MultiMessageTradeBreaksSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<
+        MultiMessageTradeBreaksMemberDecoder<'d>,
+        MultiMessageAuctionInformationsHeaderDecoder<'d>,
+    > {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageAuctionInformationsHeaderDecoder::wrap(
+                self.scratch,
+            ))
+        }
+    }
+}
+pub struct MultiMessageTradeBreaksHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageTradeBreaksHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageTradeBreaksHeaderDecoder { scratch }
+    }
+    pub fn trade_breaks_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageTradeBreaksMemberDecoder<'d>,
+            MultiMessageAuctionInformationsHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(MultiMessageTradeBreaksMemberDecoder::new(
+                self.scratch,
+                dim.num_in_group,
+            )))
+        } else {
+            Ok(Either::Right(
+                MultiMessageAuctionInformationsHeaderDecoder::wrap(self.scratch),
+            ))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageOfficialPricesSymbolDecoder<'d> {
+    parent: MultiMessageOfficialPricesMemberDecoder<'d>,
+}
+impl<'d> MultiMessageOfficialPricesSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageOfficialPricesMemberDecoder<'d>) -> Self {
+        MultiMessageOfficialPricesSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<
+            MultiMessageOfficialPricesMemberDecoder<'d>,
+            MultiMessageTradeBreaksHeaderDecoder<'d>,
+        >,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageOfficialPrices Decoder for fields and header
+pub struct MultiMessageOfficialPricesMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageOfficialPricesMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageOfficialPricesMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_official_prices_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageOfficialPricesMember,
+        MultiMessageOfficialPricesSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageOfficialPricesMember>(17)?;
+        self.index += 1;
+        Ok((v, MultiMessageOfficialPricesSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<MultiMessageOfficialPricesMemberDecoder<'d>, MultiMessageTradeBreaksHeaderDecoder<'d>>
+    {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageTradeBreaksHeaderDecoder::wrap(self.scratch))
+        }
+    }
+}
+pub struct MultiMessageOfficialPricesHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageOfficialPricesHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageOfficialPricesHeaderDecoder { scratch }
+    }
+    pub fn official_prices_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageOfficialPricesMemberDecoder<'d>,
+            MultiMessageTradeBreaksHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(MultiMessageOfficialPricesMemberDecoder::new(
+                self.scratch,
+                dim.num_in_group,
+            )))
+        } else {
+            Ok(Either::Right(MultiMessageTradeBreaksHeaderDecoder::wrap(
+                self.scratch,
+            )))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageSecurityEventsSymbolDecoder<'d> {
+    parent: MultiMessageSecurityEventsMemberDecoder<'d>,
+}
+impl<'d> MultiMessageSecurityEventsSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageSecurityEventsMemberDecoder<'d>) -> Self {
+        MultiMessageSecurityEventsSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<
+            MultiMessageSecurityEventsMemberDecoder<'d>,
+            MultiMessageOfficialPricesHeaderDecoder<'d>,
+        >,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageSecurityEvents Decoder for fields and header
+pub struct MultiMessageSecurityEventsMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageSecurityEventsMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageSecurityEventsMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_security_events_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageSecurityEventsMember,
+        MultiMessageSecurityEventsSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageSecurityEventsMember>(9)?;
+        self.index += 1;
+        Ok((v, MultiMessageSecurityEventsSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<
+        MultiMessageSecurityEventsMemberDecoder<'d>,
+        MultiMessageOfficialPricesHeaderDecoder<'d>,
+    > {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageOfficialPricesHeaderDecoder::wrap(self.scratch))
+        }
+    }
+}
+pub struct MultiMessageSecurityEventsHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageSecurityEventsHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageSecurityEventsHeaderDecoder { scratch }
+    }
+    pub fn security_events_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageSecurityEventsMemberDecoder<'d>,
+            MultiMessageOfficialPricesHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(MultiMessageSecurityEventsMemberDecoder::new(
+                self.scratch,
+                dim.num_in_group,
+            )))
+        } else {
+            Ok(Either::Right(
+                MultiMessageOfficialPricesHeaderDecoder::wrap(self.scratch),
+            ))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageShortSalePriceTestsSymbolDecoder<'d> {
+    parent: MultiMessageShortSalePriceTestsMemberDecoder<'d>,
+}
+impl<'d> MultiMessageShortSalePriceTestsSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageShortSalePriceTestsMemberDecoder<'d>) -> Self {
+        MultiMessageShortSalePriceTestsSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<
+            MultiMessageShortSalePriceTestsMemberDecoder<'d>,
+            MultiMessageSecurityEventsHeaderDecoder<'d>,
+        >,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageShortSalePriceTests Decoder for fields and header
+pub struct MultiMessageShortSalePriceTestsMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageShortSalePriceTestsMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageShortSalePriceTestsMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_short_sale_price_tests_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageShortSalePriceTestsMember,
+        MultiMessageShortSalePriceTestsSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageShortSalePriceTestsMember>(10)?;
+        self.index += 1;
+        Ok((v, MultiMessageShortSalePriceTestsSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<
+        MultiMessageShortSalePriceTestsMemberDecoder<'d>,
+        MultiMessageSecurityEventsHeaderDecoder<'d>,
+    > {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageSecurityEventsHeaderDecoder::wrap(self.scratch))
+        }
+    }
+}
+pub struct MultiMessageShortSalePriceTestsHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageShortSalePriceTestsHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageShortSalePriceTestsHeaderDecoder { scratch }
+    }
+    pub fn short_sale_price_tests_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageShortSalePriceTestsMemberDecoder<'d>,
+            MultiMessageSecurityEventsHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(
+                MultiMessageShortSalePriceTestsMemberDecoder::new(self.scratch, dim.num_in_group),
+            ))
+        } else {
+            Ok(Either::Right(
+                MultiMessageSecurityEventsHeaderDecoder::wrap(self.scratch),
+            ))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageOperationalHaltStatusesSymbolDecoder<'d> {
+    parent: MultiMessageOperationalHaltStatusesMemberDecoder<'d>,
+}
+impl<'d> MultiMessageOperationalHaltStatusesSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageOperationalHaltStatusesMemberDecoder<'d>) -> Self {
+        MultiMessageOperationalHaltStatusesSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<
+            MultiMessageOperationalHaltStatusesMemberDecoder<'d>,
+            MultiMessageShortSalePriceTestsHeaderDecoder<'d>,
+        >,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageOperationalHaltStatuses Decoder for fields and header
+pub struct MultiMessageOperationalHaltStatusesMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageOperationalHaltStatusesMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageOperationalHaltStatusesMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_operational_halt_statuses_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageOperationalHaltStatusesMember,
+        MultiMessageOperationalHaltStatusesSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageOperationalHaltStatusesMember>(9)?;
+        self.index += 1;
+        Ok((
+            v,
+            MultiMessageOperationalHaltStatusesSymbolDecoder::wrap(self),
+        ))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<
+        MultiMessageOperationalHaltStatusesMemberDecoder<'d>,
+        MultiMessageShortSalePriceTestsHeaderDecoder<'d>,
+    > {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageShortSalePriceTestsHeaderDecoder::wrap(
+                self.scratch,
+            ))
+        }
+    }
+}
+pub struct MultiMessageOperationalHaltStatusesHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageOperationalHaltStatusesHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageOperationalHaltStatusesHeaderDecoder { scratch }
+    }
+    pub fn operational_halt_statuses_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageOperationalHaltStatusesMemberDecoder<'d>,
+            MultiMessageShortSalePriceTestsHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(
+                MultiMessageOperationalHaltStatusesMemberDecoder::new(
+                    self.scratch,
+                    dim.num_in_group,
+                ),
+            ))
+        } else {
+            Ok(Either::Right(
+                MultiMessageShortSalePriceTestsHeaderDecoder::wrap(self.scratch),
+            ))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageTradingStatusesSymbolDecoder<'d> {
+    parent: MultiMessageTradingStatusesMemberDecoder<'d>,
+}
+impl<'d> MultiMessageTradingStatusesSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageTradingStatusesMemberDecoder<'d>) -> Self {
+        MultiMessageTradingStatusesSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<
+            MultiMessageTradingStatusesMemberDecoder<'d>,
+            MultiMessageOperationalHaltStatusesHeaderDecoder<'d>,
+        >,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageTradingStatuses Decoder for fields and header
+pub struct MultiMessageTradingStatusesMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageTradingStatusesMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageTradingStatusesMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_trading_statuses_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageTradingStatusesMember,
+        MultiMessageTradingStatusesSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageTradingStatusesMember>(13)?;
+        self.index += 1;
+        Ok((v, MultiMessageTradingStatusesSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<
+        MultiMessageTradingStatusesMemberDecoder<'d>,
+        MultiMessageOperationalHaltStatusesHeaderDecoder<'d>,
+    > {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageOperationalHaltStatusesHeaderDecoder::wrap(
+                self.scratch,
+            ))
+        }
+    }
+}
+pub struct MultiMessageTradingStatusesHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageTradingStatusesHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageTradingStatusesHeaderDecoder { scratch }
+    }
+    pub fn trading_statuses_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageTradingStatusesMemberDecoder<'d>,
+            MultiMessageOperationalHaltStatusesHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(MultiMessageTradingStatusesMemberDecoder::new(
+                self.scratch,
+                dim.num_in_group,
+            )))
+        } else {
+            Ok(Either::Right(
+                MultiMessageOperationalHaltStatusesHeaderDecoder::wrap(self.scratch),
+            ))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageSecurityDirectoriesSymbolDecoder<'d> {
+    parent: MultiMessageSecurityDirectoriesMemberDecoder<'d>,
+}
+impl<'d> MultiMessageSecurityDirectoriesSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageSecurityDirectoriesMemberDecoder<'d>) -> Self {
+        MultiMessageSecurityDirectoriesSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<
+            MultiMessageSecurityDirectoriesMemberDecoder<'d>,
+            MultiMessageTradingStatusesHeaderDecoder<'d>,
+        >,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageSecurityDirectories Decoder for fields and header
+pub struct MultiMessageSecurityDirectoriesMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageSecurityDirectoriesMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageSecurityDirectoriesMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_security_directories_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageSecurityDirectoriesMember,
+        MultiMessageSecurityDirectoriesSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageSecurityDirectoriesMember>(22)?;
+        self.index += 1;
+        Ok((v, MultiMessageSecurityDirectoriesSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<
+        MultiMessageSecurityDirectoriesMemberDecoder<'d>,
+        MultiMessageTradingStatusesHeaderDecoder<'d>,
+    > {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageTradingStatusesHeaderDecoder::wrap(self.scratch))
+        }
+    }
+}
+pub struct MultiMessageSecurityDirectoriesHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageSecurityDirectoriesHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageSecurityDirectoriesHeaderDecoder { scratch }
+    }
+    pub fn security_directories_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageSecurityDirectoriesMemberDecoder<'d>,
+            MultiMessageTradingStatusesHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(
+                MultiMessageSecurityDirectoriesMemberDecoder::new(self.scratch, dim.num_in_group),
+            ))
+        } else {
+            Ok(Either::Right(
+                MultiMessageTradingStatusesHeaderDecoder::wrap(self.scratch),
+            ))
+        }
+    }
+}
+
+/// MultiMessageSystemEvents Decoder for fields and header
+pub struct MultiMessageSystemEventsMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageSystemEventsMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageSystemEventsMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_system_events_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageSystemEventsMember,
+        Either<
+            MultiMessageSystemEventsMemberDecoder<'d>,
+            MultiMessageSecurityDirectoriesHeaderDecoder<'d>,
+        >,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<MultiMessageSystemEventsMember>(9)?;
+        self.index += 1;
+        Ok((v, self.after_member()))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<
+        MultiMessageSystemEventsMemberDecoder<'d>,
+        MultiMessageSecurityDirectoriesHeaderDecoder<'d>,
+    > {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageSecurityDirectoriesHeaderDecoder::wrap(
+                self.scratch,
+            ))
+        }
+    }
+}
+pub struct MultiMessageSystemEventsHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageSystemEventsHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageSystemEventsHeaderDecoder { scratch }
+    }
+    pub fn system_events_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<
+            MultiMessageSystemEventsMemberDecoder<'d>,
+            MultiMessageSecurityDirectoriesHeaderDecoder<'d>,
+        >,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(MultiMessageSystemEventsMemberDecoder::new(
+                self.scratch,
+                dim.num_in_group,
+            )))
+        } else {
+            Ok(Either::Right(
+                MultiMessageSecurityDirectoriesHeaderDecoder::wrap(self.scratch),
+            ))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageQuotesSymbolDecoder<'d> {
+    parent: MultiMessageQuotesMemberDecoder<'d>,
+}
+impl<'d> MultiMessageQuotesSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageQuotesMemberDecoder<'d>) -> Self {
+        MultiMessageQuotesSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<MultiMessageQuotesMemberDecoder<'d>, MultiMessageSystemEventsHeaderDecoder<'d>>,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageQuotes Decoder for fields and header
+pub struct MultiMessageQuotesMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageQuotesMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageQuotesMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_quotes_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageQuotesMember,
+        MultiMessageQuotesSymbolDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<MultiMessageQuotesMember>(22)?;
+        self.index += 1;
+        Ok((v, MultiMessageQuotesSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<MultiMessageQuotesMemberDecoder<'d>, MultiMessageSystemEventsHeaderDecoder<'d>>
+    {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageSystemEventsHeaderDecoder::wrap(self.scratch))
+        }
+    }
+}
+pub struct MultiMessageQuotesHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageQuotesHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageQuotesHeaderDecoder { scratch }
+    }
+    pub fn quotes_individually(
+        mut self,
+    ) -> CodecResult<
+        Either<MultiMessageQuotesMemberDecoder<'d>, MultiMessageSystemEventsHeaderDecoder<'d>>,
+    > {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(MultiMessageQuotesMemberDecoder::new(
+                self.scratch,
+                dim.num_in_group,
+            )))
+        } else {
+            Ok(Either::Right(MultiMessageSystemEventsHeaderDecoder::wrap(
+                self.scratch,
+            )))
+        }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageTradesSymbolDecoder<'d> {
+    parent: MultiMessageTradesMemberDecoder<'d>,
+}
+impl<'d> MultiMessageTradesSymbolDecoder<'d> {
+    fn wrap(parent: MultiMessageTradesMemberDecoder<'d>) -> Self {
+        MultiMessageTradesSymbolDecoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(
+        &'d [u8],
+        Either<MultiMessageTradesMemberDecoder<'d>, MultiMessageQuotesHeaderDecoder<'d>>,
+    )> {
+        let count = self.parent.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.parent.scratch.read_slice::<u8>(count as usize, 1)?,
+            self.parent.after_member(),
+        ))
+    }
+}
+
+/// MultiMessageTrades Decoder for fields and header
+pub struct MultiMessageTradesMemberDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+    max_index: u16,
+    index: u16,
+}
+
+impl<'d> MultiMessageTradesMemberDecoder<'d> {
+    fn new(scratch: ScratchDecoderData<'d>, count: u16) -> Self {
+        assert!(count > 0u16);
+        MultiMessageTradesMemberDecoder {
+            scratch,
+            max_index: count - 1,
+            index: 0,
+        }
+    }
+
+    pub fn next_trades_member(
+        mut self,
+    ) -> CodecResult<(
+        &'d MultiMessageTradesMember,
+        MultiMessageTradesSymbolDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<MultiMessageTradesMember>(29)?;
+        self.index += 1;
+        Ok((v, MultiMessageTradesSymbolDecoder::wrap(self)))
+    }
+    #[inline]
+    fn after_member(
+        self,
+    ) -> Either<MultiMessageTradesMemberDecoder<'d>, MultiMessageQuotesHeaderDecoder<'d>> {
+        if self.index <= self.max_index {
+            Either::Left(self)
+        } else {
+            Either::Right(MultiMessageQuotesHeaderDecoder::wrap(self.scratch))
+        }
+    }
+}
+pub struct MultiMessageTradesHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageTradesHeaderDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        MultiMessageTradesHeaderDecoder { scratch }
+    }
+    pub fn trades_individually(
+        mut self,
+    ) -> CodecResult<Either<MultiMessageTradesMemberDecoder<'d>, MultiMessageQuotesHeaderDecoder<'d>>>
+    {
+        let dim = self.scratch.read_type::<GroupSizeEncoding>(4)?;
+        if dim.num_in_group > 0 {
+            Ok(Either::Left(MultiMessageTradesMemberDecoder::new(
+                self.scratch,
+                dim.num_in_group,
+            )))
+        } else {
+            Ok(Either::Right(MultiMessageQuotesHeaderDecoder::wrap(
+                self.scratch,
+            )))
+        }
+    }
+}
+
+/// MultiMessage Fixed fields Decoder
+pub struct MultiMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageFieldsDecoder<'d> {
+        MultiMessageFieldsDecoder { scratch }
+    }
+    pub fn multi_message_fields(
+        mut self,
+    ) -> CodecResult<(&'d MultiMessageFields, MultiMessageTradesHeaderDecoder<'d>)> {
+        let v = self.scratch.read_type::<MultiMessageFields>(8)?;
+        Ok((v, MultiMessageTradesHeaderDecoder::wrap(self.scratch)))
+    }
+}
+
+/// MultiMessageMessageHeaderDecoder
+pub struct MultiMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> MultiMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> MultiMessageMessageHeaderDecoder<'d> {
+        MultiMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(mut self) -> CodecResult<(&'d MessageHeader, MultiMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, MultiMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// MultiMessage Decoder entry point
+pub fn start_decoding_multi_message<'d>(data: &'d [u8]) -> MultiMessageMessageHeaderDecoder<'d> {
+    MultiMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// MultiMessageEncoderDone
+pub struct MultiMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageEncoderDone<'d> {
+        MultiMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageAuctionInformationsSymbolEncoder<'d> {
+    parent: MultiMessageAuctionInformationsMemberEncoder<'d>,
+}
+impl<'d> MultiMessageAuctionInformationsSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageAuctionInformationsMemberEncoder<'d>) -> Self {
+        MultiMessageAuctionInformationsSymbolEncoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<MultiMessageAuctionInformationsMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageAuctionInformations Encoder for fields and header
+pub struct MultiMessageAuctionInformationsMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageAuctionInformationsMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageAuctionInformationsMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_auction_informations_member(
+        mut self,
+        fields: &MultiMessageAuctionInformationsMember,
+    ) -> CodecResult<MultiMessageAuctionInformationsSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageAuctionInformationsMember>(fields, 71)?; // block length
+        self.count += 1;
+        Ok(MultiMessageAuctionInformationsSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_auction_informations(mut self) -> CodecResult<MultiMessageEncoderDone<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageEncoderDone::wrap(self.scratch))
+    }
+}
+pub struct MultiMessageAuctionInformationsHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageAuctionInformationsHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageAuctionInformationsHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn auction_informations_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageAuctionInformationsMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&71u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageAuctionInformationsMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageTradeBreaksSymbolEncoder<'d> {
+    parent: MultiMessageTradeBreaksMemberEncoder<'d>,
+}
+impl<'d> MultiMessageTradeBreaksSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageTradeBreaksMemberEncoder<'d>) -> Self {
+        MultiMessageTradeBreaksSymbolEncoder { parent }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<MultiMessageTradeBreaksMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageTradeBreaks Encoder for fields and header
+pub struct MultiMessageTradeBreaksMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageTradeBreaksMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageTradeBreaksMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_trade_breaks_member(
+        mut self,
+        fields: &MultiMessageTradeBreaksMember,
+    ) -> CodecResult<MultiMessageTradeBreaksSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageTradeBreaksMember>(fields, 29)?; // block length
+        self.count += 1;
+        Ok(MultiMessageTradeBreaksSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_trade_breaks(
+        mut self,
+    ) -> CodecResult<MultiMessageAuctionInformationsHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageAuctionInformationsHeaderEncoder::wrap(
+            self.scratch,
+        ))
+    }
+}
+pub struct MultiMessageTradeBreaksHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageTradeBreaksHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageTradeBreaksHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn trade_breaks_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageTradeBreaksMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&29u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageTradeBreaksMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageOfficialPricesSymbolEncoder<'d> {
+    parent: MultiMessageOfficialPricesMemberEncoder<'d>,
+}
+impl<'d> MultiMessageOfficialPricesSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageOfficialPricesMemberEncoder<'d>) -> Self {
+        MultiMessageOfficialPricesSymbolEncoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<MultiMessageOfficialPricesMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageOfficialPrices Encoder for fields and header
+pub struct MultiMessageOfficialPricesMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageOfficialPricesMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageOfficialPricesMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_official_prices_member(
+        mut self,
+        fields: &MultiMessageOfficialPricesMember,
+    ) -> CodecResult<MultiMessageOfficialPricesSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageOfficialPricesMember>(fields, 17)?; // block length
+        self.count += 1;
+        Ok(MultiMessageOfficialPricesSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_official_prices(
+        mut self,
+    ) -> CodecResult<MultiMessageTradeBreaksHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageTradeBreaksHeaderEncoder::wrap(self.scratch))
+    }
+}
+pub struct MultiMessageOfficialPricesHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageOfficialPricesHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageOfficialPricesHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn official_prices_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageOfficialPricesMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&17u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageOfficialPricesMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageSecurityEventsSymbolEncoder<'d> {
+    parent: MultiMessageSecurityEventsMemberEncoder<'d>,
+}
+impl<'d> MultiMessageSecurityEventsSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageSecurityEventsMemberEncoder<'d>) -> Self {
+        MultiMessageSecurityEventsSymbolEncoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<MultiMessageSecurityEventsMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageSecurityEvents Encoder for fields and header
+pub struct MultiMessageSecurityEventsMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageSecurityEventsMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageSecurityEventsMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_security_events_member(
+        mut self,
+        fields: &MultiMessageSecurityEventsMember,
+    ) -> CodecResult<MultiMessageSecurityEventsSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageSecurityEventsMember>(fields, 9)?; // block length
+        self.count += 1;
+        Ok(MultiMessageSecurityEventsSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_security_events(
+        mut self,
+    ) -> CodecResult<MultiMessageOfficialPricesHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageOfficialPricesHeaderEncoder::wrap(self.scratch))
+    }
+}
+pub struct MultiMessageSecurityEventsHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageSecurityEventsHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageSecurityEventsHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn security_events_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageSecurityEventsMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&9u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageSecurityEventsMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageShortSalePriceTestsSymbolEncoder<'d> {
+    parent: MultiMessageShortSalePriceTestsMemberEncoder<'d>,
+}
+impl<'d> MultiMessageShortSalePriceTestsSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageShortSalePriceTestsMemberEncoder<'d>) -> Self {
+        MultiMessageShortSalePriceTestsSymbolEncoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<MultiMessageShortSalePriceTestsMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageShortSalePriceTests Encoder for fields and header
+pub struct MultiMessageShortSalePriceTestsMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageShortSalePriceTestsMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageShortSalePriceTestsMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_short_sale_price_tests_member(
+        mut self,
+        fields: &MultiMessageShortSalePriceTestsMember,
+    ) -> CodecResult<MultiMessageShortSalePriceTestsSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageShortSalePriceTestsMember>(fields, 10)?; // block length
+        self.count += 1;
+        Ok(MultiMessageShortSalePriceTestsSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_short_sale_price_tests(
+        mut self,
+    ) -> CodecResult<MultiMessageSecurityEventsHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageSecurityEventsHeaderEncoder::wrap(self.scratch))
+    }
+}
+pub struct MultiMessageShortSalePriceTestsHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageShortSalePriceTestsHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageShortSalePriceTestsHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn short_sale_price_tests_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageShortSalePriceTestsMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&10u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageShortSalePriceTestsMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageOperationalHaltStatusesSymbolEncoder<'d> {
+    parent: MultiMessageOperationalHaltStatusesMemberEncoder<'d>,
+}
+impl<'d> MultiMessageOperationalHaltStatusesSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageOperationalHaltStatusesMemberEncoder<'d>) -> Self {
+        MultiMessageOperationalHaltStatusesSymbolEncoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<MultiMessageOperationalHaltStatusesMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageOperationalHaltStatuses Encoder for fields and header
+pub struct MultiMessageOperationalHaltStatusesMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageOperationalHaltStatusesMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageOperationalHaltStatusesMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_operational_halt_statuses_member(
+        mut self,
+        fields: &MultiMessageOperationalHaltStatusesMember,
+    ) -> CodecResult<MultiMessageOperationalHaltStatusesSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageOperationalHaltStatusesMember>(fields, 9)?; // block length
+        self.count += 1;
+        Ok(MultiMessageOperationalHaltStatusesSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_operational_halt_statuses(
+        mut self,
+    ) -> CodecResult<MultiMessageShortSalePriceTestsHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageShortSalePriceTestsHeaderEncoder::wrap(
+            self.scratch,
+        ))
+    }
+}
+pub struct MultiMessageOperationalHaltStatusesHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageOperationalHaltStatusesHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageOperationalHaltStatusesHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn operational_halt_statuses_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageOperationalHaltStatusesMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&9u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageOperationalHaltStatusesMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageTradingStatusesSymbolEncoder<'d> {
+    parent: MultiMessageTradingStatusesMemberEncoder<'d>,
+}
+impl<'d> MultiMessageTradingStatusesSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageTradingStatusesMemberEncoder<'d>) -> Self {
+        MultiMessageTradingStatusesSymbolEncoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<MultiMessageTradingStatusesMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageTradingStatuses Encoder for fields and header
+pub struct MultiMessageTradingStatusesMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageTradingStatusesMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageTradingStatusesMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_trading_statuses_member(
+        mut self,
+        fields: &MultiMessageTradingStatusesMember,
+    ) -> CodecResult<MultiMessageTradingStatusesSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageTradingStatusesMember>(fields, 13)?; // block length
+        self.count += 1;
+        Ok(MultiMessageTradingStatusesSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_trading_statuses(
+        mut self,
+    ) -> CodecResult<MultiMessageOperationalHaltStatusesHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageOperationalHaltStatusesHeaderEncoder::wrap(
+            self.scratch,
+        ))
+    }
+}
+pub struct MultiMessageTradingStatusesHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageTradingStatusesHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageTradingStatusesHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn trading_statuses_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageTradingStatusesMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&13u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageTradingStatusesMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageSecurityDirectoriesSymbolEncoder<'d> {
+    parent: MultiMessageSecurityDirectoriesMemberEncoder<'d>,
+}
+impl<'d> MultiMessageSecurityDirectoriesSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageSecurityDirectoriesMemberEncoder<'d>) -> Self {
+        MultiMessageSecurityDirectoriesSymbolEncoder { parent }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<MultiMessageSecurityDirectoriesMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageSecurityDirectories Encoder for fields and header
+pub struct MultiMessageSecurityDirectoriesMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageSecurityDirectoriesMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageSecurityDirectoriesMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_security_directories_member(
+        mut self,
+        fields: &MultiMessageSecurityDirectoriesMember,
+    ) -> CodecResult<MultiMessageSecurityDirectoriesSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageSecurityDirectoriesMember>(fields, 22)?; // block length
+        self.count += 1;
+        Ok(MultiMessageSecurityDirectoriesSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_security_directories(
+        mut self,
+    ) -> CodecResult<MultiMessageTradingStatusesHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageTradingStatusesHeaderEncoder::wrap(self.scratch))
+    }
+}
+pub struct MultiMessageSecurityDirectoriesHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageSecurityDirectoriesHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageSecurityDirectoriesHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn security_directories_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageSecurityDirectoriesMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&22u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageSecurityDirectoriesMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// MultiMessageSystemEvents Encoder for fields and header
+pub struct MultiMessageSystemEventsMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageSystemEventsMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageSystemEventsMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_system_events_member(
+        mut self,
+        fields: &MultiMessageSystemEventsMember,
+    ) -> CodecResult<MultiMessageSystemEventsMemberEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageSystemEventsMember>(fields, 9)?; // block length
+        self.count += 1;
+        Ok(self)
+    }
+    #[inline]
+    pub fn done_with_system_events(
+        mut self,
+    ) -> CodecResult<MultiMessageSecurityDirectoriesHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageSecurityDirectoriesHeaderEncoder::wrap(
+            self.scratch,
+        ))
+    }
+}
+pub struct MultiMessageSystemEventsHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageSystemEventsHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageSystemEventsHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn system_events_individually(
+        mut self,
+    ) -> CodecResult<MultiMessageSystemEventsMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&9u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageSystemEventsMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageQuotesSymbolEncoder<'d> {
+    parent: MultiMessageQuotesMemberEncoder<'d>,
+}
+impl<'d> MultiMessageQuotesSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageQuotesMemberEncoder<'d>) -> Self {
+        MultiMessageQuotesSymbolEncoder { parent }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<MultiMessageQuotesMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageQuotes Encoder for fields and header
+pub struct MultiMessageQuotesMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageQuotesMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageQuotesMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_quotes_member(
+        mut self,
+        fields: &MultiMessageQuotesMember,
+    ) -> CodecResult<MultiMessageQuotesSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageQuotesMember>(fields, 22)?; // block length
+        self.count += 1;
+        Ok(MultiMessageQuotesSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_quotes(mut self) -> CodecResult<MultiMessageSystemEventsHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageSystemEventsHeaderEncoder::wrap(self.scratch))
+    }
+}
+pub struct MultiMessageQuotesHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageQuotesHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageQuotesHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn quotes_individually(mut self) -> CodecResult<MultiMessageQuotesMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&22u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageQuotesMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// symbol variable-length data
+pub struct MultiMessageTradesSymbolEncoder<'d> {
+    parent: MultiMessageTradesMemberEncoder<'d>,
+}
+impl<'d> MultiMessageTradesSymbolEncoder<'d> {
+    fn wrap(parent: MultiMessageTradesMemberEncoder<'d>) -> Self {
+        MultiMessageTradesSymbolEncoder { parent }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<MultiMessageTradesMemberEncoder<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.parent.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.parent.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(self.parent)
+    }
+}
+
+/// MultiMessageTrades Encoder for fields and header
+pub struct MultiMessageTradesMemberEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+    count_write_pos: usize,
+    count: u16,
+}
+
+impl<'d> MultiMessageTradesMemberEncoder<'d> {
+    #[inline]
+    fn new(scratch: ScratchEncoderData<'d>, count_write_pos: usize) -> Self {
+        MultiMessageTradesMemberEncoder {
+            scratch,
+            count_write_pos,
+            count: 0,
+        }
+    }
+
+    #[inline]
+    pub fn next_trades_member(
+        mut self,
+        fields: &MultiMessageTradesMember,
+    ) -> CodecResult<MultiMessageTradesSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<MultiMessageTradesMember>(fields, 29)?; // block length
+        self.count += 1;
+        Ok(MultiMessageTradesSymbolEncoder::wrap(self))
+    }
+    #[inline]
+    pub fn done_with_trades(mut self) -> CodecResult<MultiMessageQuotesHeaderEncoder<'d>> {
+        self.scratch
+            .write_at_position::<u16>(self.count_write_pos, &self.count, 2)?;
+        Ok(MultiMessageQuotesHeaderEncoder::wrap(self.scratch))
+    }
+}
+pub struct MultiMessageTradesHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageTradesHeaderEncoder<'d> {
+    #[inline]
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        MultiMessageTradesHeaderEncoder { scratch }
+    }
+    #[inline]
+    pub fn trades_individually(mut self) -> CodecResult<MultiMessageTradesMemberEncoder<'d>> {
+        self.scratch.write_type::<u16>(&29u16, 2)?; // block length
+        let count_pos = self.scratch.pos;
+        self.scratch.write_type::<u16>(&0, 2)?; // preliminary group member count
+        Ok(MultiMessageTradesMemberEncoder::new(
+            self.scratch,
+            count_pos,
+        ))
+    }
+}
+
+/// MultiMessage Fixed fields Encoder
+pub struct MultiMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageFieldsEncoder<'d> {
+        MultiMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn multi_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut MultiMessageFields,
+        MultiMessageTradesHeaderEncoder<'d>,
+    )> {
+        let v = self.scratch.writable_overlay::<MultiMessageFields>(8)?;
+        Ok((v, MultiMessageTradesHeaderEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn multi_message_fields_copy(
+        mut self,
+        t: &MultiMessageFields,
+    ) -> CodecResult<MultiMessageTradesHeaderEncoder<'d>> {
+        self.scratch.write_type::<MultiMessageFields>(t, 8)?;
+        Ok(MultiMessageTradesHeaderEncoder::wrap(self.scratch))
+    }
+}
+
+/// MultiMessageMessageHeaderEncoder
+pub struct MultiMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> MultiMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> MultiMessageMessageHeaderEncoder<'d> {
+        MultiMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(mut self) -> CodecResult<(&'d mut MessageHeader, MultiMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, MultiMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(mut self, t: &MessageHeader) -> CodecResult<MultiMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(MultiMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// MultiMessage Encoder entry point
+pub fn start_encoding_multi_message<'d>(
+    data: &'d mut [u8],
+) -> MultiMessageMessageHeaderEncoder<'d> {
+    MultiMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
@@ -0,0 +1,2847 @@
+// Generated code for SBE package marketdata_sbe_templates
+
+/// Imports core rather than std to broaden usable environments.
+extern crate core;
+
+// Result types for error handling
+
+/// Errors that may occur during the course of encoding or decoding.
+#[derive(Debug)]
+pub enum CodecErr {
+    /// Too few bytes in the byte-slice to read or write the data structure relevant
+    /// to the current state of the codec
+    NotEnoughBytes,
+
+    /// Groups and vardata are constrained by the numeric type chosen to represent their
+    /// length as well as optional maxima imposed by the schema
+    SliceIsLongerThanAllowedBySchema,
+}
+
+pub type CodecResult<T> = core::result::Result<T, CodecErr>;
+
+/// Scratch Decoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchDecoderData<'d> {
+    data: &'d [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchDecoderData<'d> {
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that the struct's contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the struct in bytes.
+    #[inline]
+    fn read_type<T>(&mut self, num_bytes: usize) -> CodecResult<&'d T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *mut T;
+            let v: &'d T = unsafe { &*s };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy a primitive out of the data buffer. Unlike `read_type`, the value
+    /// doesn't need to be aligned, so this is safe for length prefixes that
+    /// follow variable-length data.
+    /// Advances the `pos` index by the size of the value in bytes.
+    #[inline]
+    fn read_unaligned<T: Copy>(&mut self, num_bytes: usize) -> CodecResult<T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let s = self.data[self.pos..end].as_ptr() as *const T;
+            let v = unsafe { core::ptr::read_unaligned(s) };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a slice reference overlaid atop the data buffer
+    /// such that the slice's members' contents directly reflect the buffer.
+    /// Advances the `pos` index by the size of the slice contents in bytes.
+    #[inline]
+    fn read_slice<T>(&mut self, count: usize, bytes_per_item: usize) -> CodecResult<&'d [T]> {
+        let num_bytes = bytes_per_item * count;
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d [T] = unsafe {
+                core::slice::from_raw_parts(self.data[self.pos..end].as_ptr() as *const T, count)
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Scratch Encoder Data Wrapper - codec internal use only
+#[derive(Debug)]
+pub struct ScratchEncoderData<'d> {
+    data: &'d mut [u8],
+    pos: usize,
+}
+
+impl<'d> ScratchEncoderData<'d> {
+    /// Copy the bytes of a value into the data buffer
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn write_type<T>(&mut self, t: &T, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Advances the `pos` index by a set number of bytes.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> CodecResult<()> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Create a struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    /// Advances the `pos` index to after the newly-written bytes.
+    #[inline]
+    fn writable_overlay<T>(&mut self, num_bytes: usize) -> CodecResult<&'d mut T> {
+        let end = self.pos + num_bytes;
+        if end <= self.data.len() {
+            let v: &'d mut T = unsafe {
+                let s = self.data.as_ptr().add(self.pos) as *mut T;
+                &mut *s
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the bytes of a value into the data buffer at a specific position
+    /// Does **not** alter the `pos` index.
+    #[inline]
+    fn write_at_position<T>(
+        &mut self,
+        position: usize,
+        t: &T,
+        num_bytes: usize,
+    ) -> CodecResult<()> {
+        let end = position + num_bytes;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t as *const T as *const u8, num_bytes) };
+            self.data[position..end].copy_from_slice(source_bytes);
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+    /// Create a mutable slice overlaid atop the data buffer directly
+    /// such that changes to the slice contents directly edit the buffer
+    /// Note that the initial content of the slice's members' fields may be garbage.
+    /// Advances the `pos` index to after the region representing the slice.
+    #[inline]
+    fn writable_slice<T>(
+        &mut self,
+        count: usize,
+        bytes_per_item: usize,
+    ) -> CodecResult<&'d mut [T]> {
+        let end = self.pos + (count * bytes_per_item);
+        if end <= self.data.len() {
+            let v: &'d mut [T] = unsafe {
+                core::slice::from_raw_parts_mut(
+                    self.data[self.pos..end].as_mut_ptr() as *mut T,
+                    count,
+                )
+            };
+            self.pos = end;
+            Ok(v)
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+
+    /// Copy the raw bytes of a slice's contents into the data buffer
+    /// Does **not** encode the length of the slice explicitly into the buffer.
+    /// Advances the `pos` index to after the newly-written slice bytes.
+    #[inline]
+    fn write_slice_without_count<T>(&mut self, t: &[T], bytes_per_item: usize) -> CodecResult<()> {
+        let content_bytes_size = bytes_per_item * t.len();
+        let end = self.pos + content_bytes_size;
+        if end <= self.data.len() {
+            let source_bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(t.as_ptr() as *const u8, content_bytes_size) };
+            self.data[self.pos..end].copy_from_slice(source_bytes);
+            self.pos = end;
+            Ok(())
+        } else {
+            Err(CodecErr::NotEnoughBytes)
+        }
+    }
+}
+
+/// Convenience Either enum
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Enum Side
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Side {
+    Buy = 0u8,
+    Sell = 1u8,
+    #[default]
+    NullVal = 255u8,
+}
+
+/// MessageHeader
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct MessageHeader {
+    pub block_length: u16,
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+}
+
+impl MessageHeader {}
+
+/// VarAsciiEncoding
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct VarAsciiEncoding {
+    pub length: u32,
+    pub var_data: u8,
+}
+
+impl VarAsciiEncoding {}
+
+/// Reason
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct Reason {
+    pub code: [u8; 4],
+}
+
+impl Reason {}
+
+/// MessageHeader Decoder entry point
+pub fn start_decoding_message_header<'d>(
+    data: &'d [u8],
+) -> CodecResult<(&'d MessageHeader, ScratchDecoderData<'d>)> {
+    let mut scratch = ScratchDecoderData { data, pos: 0 };
+    let v = scratch.read_type::<MessageHeader>(8)?;
+    Ok((v, scratch))
+}
+
+/// PayloadHeader Fixed-size Fields (10 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct PayloadHeaderFields {
+    pub sequence_number: u64,
+    pub message_count: u16,
+}
+
+impl PayloadHeaderFields {}
+
+/// PayloadHeader specific Message Header
+#[repr(C, packed)]
+pub struct PayloadHeaderMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl PayloadHeaderMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 10;
+    pub const TEMPLATE_ID: u16 = 1;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for PayloadHeaderMessageHeader {
+    fn default() -> PayloadHeaderMessageHeader {
+        PayloadHeaderMessageHeader {
+            message_header: MessageHeader {
+                block_length: 10u16,
+                template_id: 1u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// PayloadHeaderDecoderDone
+pub struct PayloadHeaderDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> PayloadHeaderDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> PayloadHeaderDecoderDone<'d> {
+        PayloadHeaderDecoderDone { scratch }
+    }
+}
+
+/// PayloadHeader Fixed fields Decoder
+pub struct PayloadHeaderFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> PayloadHeaderFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> PayloadHeaderFieldsDecoder<'d> {
+        PayloadHeaderFieldsDecoder { scratch }
+    }
+    pub fn payload_header_fields(
+        mut self,
+    ) -> CodecResult<(&'d PayloadHeaderFields, PayloadHeaderDecoderDone<'d>)> {
+        let v = self.scratch.read_type::<PayloadHeaderFields>(10)?;
+        Ok((v, PayloadHeaderDecoderDone::wrap(self.scratch)))
+    }
+}
+
+/// PayloadHeaderMessageHeaderDecoder
+pub struct PayloadHeaderMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> PayloadHeaderMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> PayloadHeaderMessageHeaderDecoder<'d> {
+        PayloadHeaderMessageHeaderDecoder { scratch }
+    }
+    pub fn header(mut self) -> CodecResult<(&'d MessageHeader, PayloadHeaderFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, PayloadHeaderFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// PayloadHeader Decoder entry point
+pub fn start_decoding_payload_header<'d>(data: &'d [u8]) -> PayloadHeaderMessageHeaderDecoder<'d> {
+    PayloadHeaderMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// PayloadHeaderEncoderDone
+pub struct PayloadHeaderEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> PayloadHeaderEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> PayloadHeaderEncoderDone<'d> {
+        PayloadHeaderEncoderDone { scratch }
+    }
+}
+
+/// PayloadHeader Fixed fields Encoder
+pub struct PayloadHeaderFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> PayloadHeaderFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> PayloadHeaderFieldsEncoder<'d> {
+        PayloadHeaderFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn payload_header_fields(
+        mut self,
+    ) -> CodecResult<(&'d mut PayloadHeaderFields, PayloadHeaderEncoderDone<'d>)> {
+        let v = self.scratch.writable_overlay::<PayloadHeaderFields>(10)?;
+        Ok((v, PayloadHeaderEncoderDone::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn payload_header_fields_copy(
+        mut self,
+        t: &PayloadHeaderFields,
+    ) -> CodecResult<PayloadHeaderEncoderDone<'d>> {
+        self.scratch.write_type::<PayloadHeaderFields>(t, 10)?;
+        Ok(PayloadHeaderEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// PayloadHeaderMessageHeaderEncoder
+pub struct PayloadHeaderMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> PayloadHeaderMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> PayloadHeaderMessageHeaderEncoder<'d> {
+        PayloadHeaderMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d mut MessageHeader, PayloadHeaderFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, PayloadHeaderFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(mut self, t: &MessageHeader) -> CodecResult<PayloadHeaderFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(PayloadHeaderFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// PayloadHeader Encoder entry point
+pub fn start_encoding_payload_header<'d>(
+    data: &'d mut [u8],
+) -> PayloadHeaderMessageHeaderEncoder<'d> {
+    PayloadHeaderMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// TradeMessage Fixed-size Fields (29 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct TradeMessageFields {
+    pub timestamp: i64,
+    pub price: u64,
+    pub size: u32,
+    pub sale_condition: u8,
+    pub trade_id: u64,
+}
+
+impl TradeMessageFields {}
+
+/// TradeMessage specific Message Header
+#[repr(C, packed)]
+pub struct TradeMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl TradeMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 29;
+    pub const TEMPLATE_ID: u16 = 2;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for TradeMessageMessageHeader {
+    fn default() -> TradeMessageMessageHeader {
+        TradeMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 29u16,
+                template_id: 2u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// TradeMessageDecoderDone
+pub struct TradeMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradeMessageDecoderDone<'d> {
+        TradeMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct TradeMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        TradeMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], TradeMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            TradeMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// TradeMessage Fixed fields Decoder
+pub struct TradeMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradeMessageFieldsDecoder<'d> {
+        TradeMessageFieldsDecoder { scratch }
+    }
+    pub fn trade_message_fields(
+        mut self,
+    ) -> CodecResult<(&'d TradeMessageFields, TradeMessageSymbolDecoder<'d>)> {
+        let v = self.scratch.read_type::<TradeMessageFields>(29)?;
+        Ok((v, TradeMessageSymbolDecoder::wrap(self.scratch)))
+    }
+}
+
+/// TradeMessageMessageHeaderDecoder
+pub struct TradeMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradeMessageMessageHeaderDecoder<'d> {
+        TradeMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(mut self) -> CodecResult<(&'d MessageHeader, TradeMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, TradeMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// TradeMessage Decoder entry point
+pub fn start_decoding_trade_message<'d>(data: &'d [u8]) -> TradeMessageMessageHeaderDecoder<'d> {
+    TradeMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// TradeMessageEncoderDone
+pub struct TradeMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradeMessageEncoderDone<'d> {
+        TradeMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct TradeMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        TradeMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<TradeMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(TradeMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// TradeMessage Fixed fields Encoder
+pub struct TradeMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradeMessageFieldsEncoder<'d> {
+        TradeMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn trade_message_fields(
+        mut self,
+    ) -> CodecResult<(&'d mut TradeMessageFields, TradeMessageSymbolEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<TradeMessageFields>(29)?;
+        Ok((v, TradeMessageSymbolEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn trade_message_fields_copy(
+        mut self,
+        t: &TradeMessageFields,
+    ) -> CodecResult<TradeMessageSymbolEncoder<'d>> {
+        self.scratch.write_type::<TradeMessageFields>(t, 29)?;
+        Ok(TradeMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// TradeMessageMessageHeaderEncoder
+pub struct TradeMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradeMessageMessageHeaderEncoder<'d> {
+        TradeMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(mut self) -> CodecResult<(&'d mut MessageHeader, TradeMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, TradeMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(mut self, t: &MessageHeader) -> CodecResult<TradeMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(TradeMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// TradeMessage Encoder entry point
+pub fn start_encoding_trade_message<'d>(
+    data: &'d mut [u8],
+) -> TradeMessageMessageHeaderEncoder<'d> {
+    TradeMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// QuoteMessage Fixed-size Fields (22 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct QuoteMessageFields {
+    pub timestamp: i64,
+    pub price: u64,
+    pub size: u32,
+    pub flags: u8,
+    pub side: Side,
+}
+
+impl QuoteMessageFields {}
+
+/// QuoteMessage specific Message Header
+#[repr(C, packed)]
+pub struct QuoteMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl QuoteMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 22;
+    pub const TEMPLATE_ID: u16 = 3;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for QuoteMessageMessageHeader {
+    fn default() -> QuoteMessageMessageHeader {
+        QuoteMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 22u16,
+                template_id: 3u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// QuoteMessageDecoderDone
+pub struct QuoteMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> QuoteMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> QuoteMessageDecoderDone<'d> {
+        QuoteMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct QuoteMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> QuoteMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        QuoteMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], QuoteMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            QuoteMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// QuoteMessage Fixed fields Decoder
+pub struct QuoteMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> QuoteMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> QuoteMessageFieldsDecoder<'d> {
+        QuoteMessageFieldsDecoder { scratch }
+    }
+    pub fn quote_message_fields(
+        mut self,
+    ) -> CodecResult<(&'d QuoteMessageFields, QuoteMessageSymbolDecoder<'d>)> {
+        let v = self.scratch.read_type::<QuoteMessageFields>(22)?;
+        Ok((v, QuoteMessageSymbolDecoder::wrap(self.scratch)))
+    }
+}
+
+/// QuoteMessageMessageHeaderDecoder
+pub struct QuoteMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> QuoteMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> QuoteMessageMessageHeaderDecoder<'d> {
+        QuoteMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(mut self) -> CodecResult<(&'d MessageHeader, QuoteMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, QuoteMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// QuoteMessage Decoder entry point
+pub fn start_decoding_quote_message<'d>(data: &'d [u8]) -> QuoteMessageMessageHeaderDecoder<'d> {
+    QuoteMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// QuoteMessageEncoderDone
+pub struct QuoteMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> QuoteMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> QuoteMessageEncoderDone<'d> {
+        QuoteMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct QuoteMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> QuoteMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        QuoteMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<QuoteMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(QuoteMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// QuoteMessage Fixed fields Encoder
+pub struct QuoteMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> QuoteMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> QuoteMessageFieldsEncoder<'d> {
+        QuoteMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn quote_message_fields(
+        mut self,
+    ) -> CodecResult<(&'d mut QuoteMessageFields, QuoteMessageSymbolEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<QuoteMessageFields>(22)?;
+        Ok((v, QuoteMessageSymbolEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn quote_message_fields_copy(
+        mut self,
+        t: &QuoteMessageFields,
+    ) -> CodecResult<QuoteMessageSymbolEncoder<'d>> {
+        self.scratch.write_type::<QuoteMessageFields>(t, 22)?;
+        Ok(QuoteMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// QuoteMessageMessageHeaderEncoder
+pub struct QuoteMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> QuoteMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> QuoteMessageMessageHeaderEncoder<'d> {
+        QuoteMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(mut self) -> CodecResult<(&'d mut MessageHeader, QuoteMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, QuoteMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(mut self, t: &MessageHeader) -> CodecResult<QuoteMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(QuoteMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// QuoteMessage Encoder entry point
+pub fn start_encoding_quote_message<'d>(
+    data: &'d mut [u8],
+) -> QuoteMessageMessageHeaderEncoder<'d> {
+    QuoteMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// SystemEventMessage Fixed-size Fields (9 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SystemEventMessageFields {
+    pub timestamp: i64,
+    pub system_event: u8,
+}
+
+impl SystemEventMessageFields {}
+
+/// SystemEventMessage specific Message Header
+#[repr(C, packed)]
+pub struct SystemEventMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl SystemEventMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 9;
+    pub const TEMPLATE_ID: u16 = 4;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for SystemEventMessageMessageHeader {
+    fn default() -> SystemEventMessageMessageHeader {
+        SystemEventMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 9u16,
+                template_id: 4u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// SystemEventMessageDecoderDone
+pub struct SystemEventMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SystemEventMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SystemEventMessageDecoderDone<'d> {
+        SystemEventMessageDecoderDone { scratch }
+    }
+}
+
+/// SystemEventMessage Fixed fields Decoder
+pub struct SystemEventMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SystemEventMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SystemEventMessageFieldsDecoder<'d> {
+        SystemEventMessageFieldsDecoder { scratch }
+    }
+    pub fn system_event_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d SystemEventMessageFields,
+        SystemEventMessageDecoderDone<'d>,
+    )> {
+        let v = self.scratch.read_type::<SystemEventMessageFields>(9)?;
+        Ok((v, SystemEventMessageDecoderDone::wrap(self.scratch)))
+    }
+}
+
+/// SystemEventMessageMessageHeaderDecoder
+pub struct SystemEventMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SystemEventMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SystemEventMessageMessageHeaderDecoder<'d> {
+        SystemEventMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d MessageHeader, SystemEventMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, SystemEventMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// SystemEventMessage Decoder entry point
+pub fn start_decoding_system_event_message<'d>(
+    data: &'d [u8],
+) -> SystemEventMessageMessageHeaderDecoder<'d> {
+    SystemEventMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// SystemEventMessageEncoderDone
+pub struct SystemEventMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SystemEventMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SystemEventMessageEncoderDone<'d> {
+        SystemEventMessageEncoderDone { scratch }
+    }
+}
+
+/// SystemEventMessage Fixed fields Encoder
+pub struct SystemEventMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SystemEventMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SystemEventMessageFieldsEncoder<'d> {
+        SystemEventMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn system_event_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut SystemEventMessageFields,
+        SystemEventMessageEncoderDone<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<SystemEventMessageFields>(9)?;
+        Ok((v, SystemEventMessageEncoderDone::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn system_event_message_fields_copy(
+        mut self,
+        t: &SystemEventMessageFields,
+    ) -> CodecResult<SystemEventMessageEncoderDone<'d>> {
+        self.scratch.write_type::<SystemEventMessageFields>(t, 9)?;
+        Ok(SystemEventMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// SystemEventMessageMessageHeaderEncoder
+pub struct SystemEventMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SystemEventMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SystemEventMessageMessageHeaderEncoder<'d> {
+        SystemEventMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d mut MessageHeader, SystemEventMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, SystemEventMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<SystemEventMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(SystemEventMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// SystemEventMessage Encoder entry point
+pub fn start_encoding_system_event_message<'d>(
+    data: &'d mut [u8],
+) -> SystemEventMessageMessageHeaderEncoder<'d> {
+    SystemEventMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// SecurityDirectoryMessage Fixed-size Fields (22 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SecurityDirectoryMessageFields {
+    pub timestamp: i64,
+    pub flags: u8,
+    pub lot_size: u32,
+    pub previous_closing: u64,
+    pub luld_tier: u8,
+}
+
+impl SecurityDirectoryMessageFields {}
+
+/// SecurityDirectoryMessage specific Message Header
+#[repr(C, packed)]
+pub struct SecurityDirectoryMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl SecurityDirectoryMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 22;
+    pub const TEMPLATE_ID: u16 = 5;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for SecurityDirectoryMessageMessageHeader {
+    fn default() -> SecurityDirectoryMessageMessageHeader {
+        SecurityDirectoryMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 22u16,
+                template_id: 5u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// SecurityDirectoryMessageDecoderDone
+pub struct SecurityDirectoryMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SecurityDirectoryMessageDecoderDone<'d> {
+        SecurityDirectoryMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct SecurityDirectoryMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        SecurityDirectoryMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], SecurityDirectoryMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            SecurityDirectoryMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// SecurityDirectoryMessage Fixed fields Decoder
+pub struct SecurityDirectoryMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SecurityDirectoryMessageFieldsDecoder<'d> {
+        SecurityDirectoryMessageFieldsDecoder { scratch }
+    }
+    pub fn security_directory_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d SecurityDirectoryMessageFields,
+        SecurityDirectoryMessageSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<SecurityDirectoryMessageFields>(22)?;
+        Ok((v, SecurityDirectoryMessageSymbolDecoder::wrap(self.scratch)))
+    }
+}
+
+/// SecurityDirectoryMessageMessageHeaderDecoder
+pub struct SecurityDirectoryMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(
+        scratch: ScratchDecoderData<'d>,
+    ) -> SecurityDirectoryMessageMessageHeaderDecoder<'d> {
+        SecurityDirectoryMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d MessageHeader, SecurityDirectoryMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, SecurityDirectoryMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// SecurityDirectoryMessage Decoder entry point
+pub fn start_decoding_security_directory_message<'d>(
+    data: &'d [u8],
+) -> SecurityDirectoryMessageMessageHeaderDecoder<'d> {
+    SecurityDirectoryMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// SecurityDirectoryMessageEncoderDone
+pub struct SecurityDirectoryMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SecurityDirectoryMessageEncoderDone<'d> {
+        SecurityDirectoryMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct SecurityDirectoryMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        SecurityDirectoryMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<SecurityDirectoryMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(SecurityDirectoryMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// SecurityDirectoryMessage Fixed fields Encoder
+pub struct SecurityDirectoryMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SecurityDirectoryMessageFieldsEncoder<'d> {
+        SecurityDirectoryMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn security_directory_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut SecurityDirectoryMessageFields,
+        SecurityDirectoryMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<SecurityDirectoryMessageFields>(22)?;
+        Ok((v, SecurityDirectoryMessageSymbolEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn security_directory_message_fields_copy(
+        mut self,
+        t: &SecurityDirectoryMessageFields,
+    ) -> CodecResult<SecurityDirectoryMessageSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<SecurityDirectoryMessageFields>(t, 22)?;
+        Ok(SecurityDirectoryMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// SecurityDirectoryMessageMessageHeaderEncoder
+pub struct SecurityDirectoryMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityDirectoryMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(
+        scratch: ScratchEncoderData<'d>,
+    ) -> SecurityDirectoryMessageMessageHeaderEncoder<'d> {
+        SecurityDirectoryMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut MessageHeader,
+        SecurityDirectoryMessageFieldsEncoder<'d>,
+    )> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, SecurityDirectoryMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<SecurityDirectoryMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(SecurityDirectoryMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// SecurityDirectoryMessage Encoder entry point
+pub fn start_encoding_security_directory_message<'d>(
+    data: &'d mut [u8],
+) -> SecurityDirectoryMessageMessageHeaderEncoder<'d> {
+    SecurityDirectoryMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// TradingStatusMessage Fixed-size Fields (13 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct TradingStatusMessageFields {
+    pub timestamp: i64,
+    pub trading_status: u8,
+    pub reason: Reason,
+}
+
+impl TradingStatusMessageFields {}
+
+/// TradingStatusMessage specific Message Header
+#[repr(C, packed)]
+pub struct TradingStatusMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl TradingStatusMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 13;
+    pub const TEMPLATE_ID: u16 = 6;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for TradingStatusMessageMessageHeader {
+    fn default() -> TradingStatusMessageMessageHeader {
+        TradingStatusMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 13u16,
+                template_id: 6u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// TradingStatusMessageDecoderDone
+pub struct TradingStatusMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradingStatusMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradingStatusMessageDecoderDone<'d> {
+        TradingStatusMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct TradingStatusMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradingStatusMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        TradingStatusMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], TradingStatusMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            TradingStatusMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// TradingStatusMessage Fixed fields Decoder
+pub struct TradingStatusMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradingStatusMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradingStatusMessageFieldsDecoder<'d> {
+        TradingStatusMessageFieldsDecoder { scratch }
+    }
+    pub fn trading_status_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d TradingStatusMessageFields,
+        TradingStatusMessageSymbolDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<TradingStatusMessageFields>(13)?;
+        Ok((v, TradingStatusMessageSymbolDecoder::wrap(self.scratch)))
+    }
+}
+
+/// TradingStatusMessageMessageHeaderDecoder
+pub struct TradingStatusMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradingStatusMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradingStatusMessageMessageHeaderDecoder<'d> {
+        TradingStatusMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d MessageHeader, TradingStatusMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, TradingStatusMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// TradingStatusMessage Decoder entry point
+pub fn start_decoding_trading_status_message<'d>(
+    data: &'d [u8],
+) -> TradingStatusMessageMessageHeaderDecoder<'d> {
+    TradingStatusMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// TradingStatusMessageEncoderDone
+pub struct TradingStatusMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradingStatusMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradingStatusMessageEncoderDone<'d> {
+        TradingStatusMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct TradingStatusMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradingStatusMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        TradingStatusMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<TradingStatusMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(TradingStatusMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// TradingStatusMessage Fixed fields Encoder
+pub struct TradingStatusMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradingStatusMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradingStatusMessageFieldsEncoder<'d> {
+        TradingStatusMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn trading_status_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut TradingStatusMessageFields,
+        TradingStatusMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<TradingStatusMessageFields>(13)?;
+        Ok((v, TradingStatusMessageSymbolEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn trading_status_message_fields_copy(
+        mut self,
+        t: &TradingStatusMessageFields,
+    ) -> CodecResult<TradingStatusMessageSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<TradingStatusMessageFields>(t, 13)?;
+        Ok(TradingStatusMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// TradingStatusMessageMessageHeaderEncoder
+pub struct TradingStatusMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradingStatusMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradingStatusMessageMessageHeaderEncoder<'d> {
+        TradingStatusMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d mut MessageHeader, TradingStatusMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, TradingStatusMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<TradingStatusMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(TradingStatusMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// TradingStatusMessage Encoder entry point
+pub fn start_encoding_trading_status_message<'d>(
+    data: &'d mut [u8],
+) -> TradingStatusMessageMessageHeaderEncoder<'d> {
+    TradingStatusMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// OperationalHaltStatusMessage Fixed-size Fields (9 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OperationalHaltStatusMessageFields {
+    pub timestamp: i64,
+    pub halt_status: u8,
+}
+
+impl OperationalHaltStatusMessageFields {}
+
+/// OperationalHaltStatusMessage specific Message Header
+#[repr(C, packed)]
+pub struct OperationalHaltStatusMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl OperationalHaltStatusMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 9;
+    pub const TEMPLATE_ID: u16 = 7;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for OperationalHaltStatusMessageMessageHeader {
+    fn default() -> OperationalHaltStatusMessageMessageHeader {
+        OperationalHaltStatusMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 9u16,
+                template_id: 7u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// OperationalHaltStatusMessageDecoderDone
+pub struct OperationalHaltStatusMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> OperationalHaltStatusMessageDecoderDone<'d> {
+        OperationalHaltStatusMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct OperationalHaltStatusMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        OperationalHaltStatusMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(
+        mut self,
+    ) -> CodecResult<(&'d [u8], OperationalHaltStatusMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            OperationalHaltStatusMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// OperationalHaltStatusMessage Fixed fields Decoder
+pub struct OperationalHaltStatusMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> OperationalHaltStatusMessageFieldsDecoder<'d> {
+        OperationalHaltStatusMessageFieldsDecoder { scratch }
+    }
+    pub fn operational_halt_status_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d OperationalHaltStatusMessageFields,
+        OperationalHaltStatusMessageSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<OperationalHaltStatusMessageFields>(9)?;
+        Ok((
+            v,
+            OperationalHaltStatusMessageSymbolDecoder::wrap(self.scratch),
+        ))
+    }
+}
+
+/// OperationalHaltStatusMessageMessageHeaderDecoder
+pub struct OperationalHaltStatusMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(
+        scratch: ScratchDecoderData<'d>,
+    ) -> OperationalHaltStatusMessageMessageHeaderDecoder<'d> {
+        OperationalHaltStatusMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(
+        &'d MessageHeader,
+        OperationalHaltStatusMessageFieldsDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((
+            v,
+            OperationalHaltStatusMessageFieldsDecoder::wrap(self.scratch),
+        ))
+    }
+}
+
+/// OperationalHaltStatusMessage Decoder entry point
+pub fn start_decoding_operational_halt_status_message<'d>(
+    data: &'d [u8],
+) -> OperationalHaltStatusMessageMessageHeaderDecoder<'d> {
+    OperationalHaltStatusMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// OperationalHaltStatusMessageEncoderDone
+pub struct OperationalHaltStatusMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> OperationalHaltStatusMessageEncoderDone<'d> {
+        OperationalHaltStatusMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct OperationalHaltStatusMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        OperationalHaltStatusMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(
+        mut self,
+        s: &'d [u8],
+    ) -> CodecResult<OperationalHaltStatusMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(OperationalHaltStatusMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// OperationalHaltStatusMessage Fixed fields Encoder
+pub struct OperationalHaltStatusMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> OperationalHaltStatusMessageFieldsEncoder<'d> {
+        OperationalHaltStatusMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn operational_halt_status_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut OperationalHaltStatusMessageFields,
+        OperationalHaltStatusMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<OperationalHaltStatusMessageFields>(9)?;
+        Ok((
+            v,
+            OperationalHaltStatusMessageSymbolEncoder::wrap(self.scratch),
+        ))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn operational_halt_status_message_fields_copy(
+        mut self,
+        t: &OperationalHaltStatusMessageFields,
+    ) -> CodecResult<OperationalHaltStatusMessageSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<OperationalHaltStatusMessageFields>(t, 9)?;
+        Ok(OperationalHaltStatusMessageSymbolEncoder::wrap(
+            self.scratch,
+        ))
+    }
+}
+
+/// OperationalHaltStatusMessageMessageHeaderEncoder
+pub struct OperationalHaltStatusMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OperationalHaltStatusMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(
+        scratch: ScratchEncoderData<'d>,
+    ) -> OperationalHaltStatusMessageMessageHeaderEncoder<'d> {
+        OperationalHaltStatusMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut MessageHeader,
+        OperationalHaltStatusMessageFieldsEncoder<'d>,
+    )> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((
+            v,
+            OperationalHaltStatusMessageFieldsEncoder::wrap(self.scratch),
+        ))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<OperationalHaltStatusMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(OperationalHaltStatusMessageFieldsEncoder::wrap(
+            self.scratch,
+        ))
+    }
+}
+
+/// OperationalHaltStatusMessage Encoder entry point
+pub fn start_encoding_operational_halt_status_message<'d>(
+    data: &'d mut [u8],
+) -> OperationalHaltStatusMessageMessageHeaderEncoder<'d> {
+    OperationalHaltStatusMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// ShortSalePriceTestMessage Fixed-size Fields (10 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct ShortSalePriceTestMessageFields {
+    pub timestamp: i64,
+    pub sspt_status: u8,
+    pub detail: u8,
+}
+
+impl ShortSalePriceTestMessageFields {}
+
+/// ShortSalePriceTestMessage specific Message Header
+#[repr(C, packed)]
+pub struct ShortSalePriceTestMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl ShortSalePriceTestMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 10;
+    pub const TEMPLATE_ID: u16 = 8;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for ShortSalePriceTestMessageMessageHeader {
+    fn default() -> ShortSalePriceTestMessageMessageHeader {
+        ShortSalePriceTestMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 10u16,
+                template_id: 8u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// ShortSalePriceTestMessageDecoderDone
+pub struct ShortSalePriceTestMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> ShortSalePriceTestMessageDecoderDone<'d> {
+        ShortSalePriceTestMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct ShortSalePriceTestMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        ShortSalePriceTestMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], ShortSalePriceTestMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            ShortSalePriceTestMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// ShortSalePriceTestMessage Fixed fields Decoder
+pub struct ShortSalePriceTestMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> ShortSalePriceTestMessageFieldsDecoder<'d> {
+        ShortSalePriceTestMessageFieldsDecoder { scratch }
+    }
+    pub fn short_sale_price_test_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d ShortSalePriceTestMessageFields,
+        ShortSalePriceTestMessageSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<ShortSalePriceTestMessageFields>(10)?;
+        Ok((
+            v,
+            ShortSalePriceTestMessageSymbolDecoder::wrap(self.scratch),
+        ))
+    }
+}
+
+/// ShortSalePriceTestMessageMessageHeaderDecoder
+pub struct ShortSalePriceTestMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(
+        scratch: ScratchDecoderData<'d>,
+    ) -> ShortSalePriceTestMessageMessageHeaderDecoder<'d> {
+        ShortSalePriceTestMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(
+        &'d MessageHeader,
+        ShortSalePriceTestMessageFieldsDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((
+            v,
+            ShortSalePriceTestMessageFieldsDecoder::wrap(self.scratch),
+        ))
+    }
+}
+
+/// ShortSalePriceTestMessage Decoder entry point
+pub fn start_decoding_short_sale_price_test_message<'d>(
+    data: &'d [u8],
+) -> ShortSalePriceTestMessageMessageHeaderDecoder<'d> {
+    ShortSalePriceTestMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// ShortSalePriceTestMessageEncoderDone
+pub struct ShortSalePriceTestMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> ShortSalePriceTestMessageEncoderDone<'d> {
+        ShortSalePriceTestMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct ShortSalePriceTestMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        ShortSalePriceTestMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<ShortSalePriceTestMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(ShortSalePriceTestMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// ShortSalePriceTestMessage Fixed fields Encoder
+pub struct ShortSalePriceTestMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> ShortSalePriceTestMessageFieldsEncoder<'d> {
+        ShortSalePriceTestMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn short_sale_price_test_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut ShortSalePriceTestMessageFields,
+        ShortSalePriceTestMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<ShortSalePriceTestMessageFields>(10)?;
+        Ok((
+            v,
+            ShortSalePriceTestMessageSymbolEncoder::wrap(self.scratch),
+        ))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn short_sale_price_test_message_fields_copy(
+        mut self,
+        t: &ShortSalePriceTestMessageFields,
+    ) -> CodecResult<ShortSalePriceTestMessageSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<ShortSalePriceTestMessageFields>(t, 10)?;
+        Ok(ShortSalePriceTestMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// ShortSalePriceTestMessageMessageHeaderEncoder
+pub struct ShortSalePriceTestMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> ShortSalePriceTestMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(
+        scratch: ScratchEncoderData<'d>,
+    ) -> ShortSalePriceTestMessageMessageHeaderEncoder<'d> {
+        ShortSalePriceTestMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut MessageHeader,
+        ShortSalePriceTestMessageFieldsEncoder<'d>,
+    )> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((
+            v,
+            ShortSalePriceTestMessageFieldsEncoder::wrap(self.scratch),
+        ))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<ShortSalePriceTestMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(ShortSalePriceTestMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// ShortSalePriceTestMessage Encoder entry point
+pub fn start_encoding_short_sale_price_test_message<'d>(
+    data: &'d mut [u8],
+) -> ShortSalePriceTestMessageMessageHeaderEncoder<'d> {
+    ShortSalePriceTestMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// SecurityEventMessage Fixed-size Fields (9 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct SecurityEventMessageFields {
+    pub timestamp: i64,
+    pub security_event: u8,
+}
+
+impl SecurityEventMessageFields {}
+
+/// SecurityEventMessage specific Message Header
+#[repr(C, packed)]
+pub struct SecurityEventMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl SecurityEventMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 9;
+    pub const TEMPLATE_ID: u16 = 9;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for SecurityEventMessageMessageHeader {
+    fn default() -> SecurityEventMessageMessageHeader {
+        SecurityEventMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 9u16,
+                template_id: 9u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// SecurityEventMessageDecoderDone
+pub struct SecurityEventMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityEventMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SecurityEventMessageDecoderDone<'d> {
+        SecurityEventMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct SecurityEventMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityEventMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        SecurityEventMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], SecurityEventMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            SecurityEventMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// SecurityEventMessage Fixed fields Decoder
+pub struct SecurityEventMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityEventMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SecurityEventMessageFieldsDecoder<'d> {
+        SecurityEventMessageFieldsDecoder { scratch }
+    }
+    pub fn security_event_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d SecurityEventMessageFields,
+        SecurityEventMessageSymbolDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<SecurityEventMessageFields>(9)?;
+        Ok((v, SecurityEventMessageSymbolDecoder::wrap(self.scratch)))
+    }
+}
+
+/// SecurityEventMessageMessageHeaderDecoder
+pub struct SecurityEventMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> SecurityEventMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> SecurityEventMessageMessageHeaderDecoder<'d> {
+        SecurityEventMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d MessageHeader, SecurityEventMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, SecurityEventMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// SecurityEventMessage Decoder entry point
+pub fn start_decoding_security_event_message<'d>(
+    data: &'d [u8],
+) -> SecurityEventMessageMessageHeaderDecoder<'d> {
+    SecurityEventMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// SecurityEventMessageEncoderDone
+pub struct SecurityEventMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityEventMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SecurityEventMessageEncoderDone<'d> {
+        SecurityEventMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct SecurityEventMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityEventMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        SecurityEventMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<SecurityEventMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(SecurityEventMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// SecurityEventMessage Fixed fields Encoder
+pub struct SecurityEventMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityEventMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SecurityEventMessageFieldsEncoder<'d> {
+        SecurityEventMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn security_event_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut SecurityEventMessageFields,
+        SecurityEventMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<SecurityEventMessageFields>(9)?;
+        Ok((v, SecurityEventMessageSymbolEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn security_event_message_fields_copy(
+        mut self,
+        t: &SecurityEventMessageFields,
+    ) -> CodecResult<SecurityEventMessageSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<SecurityEventMessageFields>(t, 9)?;
+        Ok(SecurityEventMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// SecurityEventMessageMessageHeaderEncoder
+pub struct SecurityEventMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> SecurityEventMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> SecurityEventMessageMessageHeaderEncoder<'d> {
+        SecurityEventMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d mut MessageHeader, SecurityEventMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, SecurityEventMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<SecurityEventMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(SecurityEventMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// SecurityEventMessage Encoder entry point
+pub fn start_encoding_security_event_message<'d>(
+    data: &'d mut [u8],
+) -> SecurityEventMessageMessageHeaderEncoder<'d> {
+    SecurityEventMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// OfficialPriceMessage Fixed-size Fields (17 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct OfficialPriceMessageFields {
+    pub timestamp: i64,
+    pub price_type: u8,
+    pub official_price: u64,
+}
+
+impl OfficialPriceMessageFields {}
+
+/// OfficialPriceMessage specific Message Header
+#[repr(C, packed)]
+pub struct OfficialPriceMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl OfficialPriceMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 17;
+    pub const TEMPLATE_ID: u16 = 10;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for OfficialPriceMessageMessageHeader {
+    fn default() -> OfficialPriceMessageMessageHeader {
+        OfficialPriceMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 17u16,
+                template_id: 10u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// OfficialPriceMessageDecoderDone
+pub struct OfficialPriceMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OfficialPriceMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> OfficialPriceMessageDecoderDone<'d> {
+        OfficialPriceMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct OfficialPriceMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OfficialPriceMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        OfficialPriceMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], OfficialPriceMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            OfficialPriceMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// OfficialPriceMessage Fixed fields Decoder
+pub struct OfficialPriceMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OfficialPriceMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> OfficialPriceMessageFieldsDecoder<'d> {
+        OfficialPriceMessageFieldsDecoder { scratch }
+    }
+    pub fn official_price_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d OfficialPriceMessageFields,
+        OfficialPriceMessageSymbolDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<OfficialPriceMessageFields>(17)?;
+        Ok((v, OfficialPriceMessageSymbolDecoder::wrap(self.scratch)))
+    }
+}
+
+/// OfficialPriceMessageMessageHeaderDecoder
+pub struct OfficialPriceMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> OfficialPriceMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> OfficialPriceMessageMessageHeaderDecoder<'d> {
+        OfficialPriceMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d MessageHeader, OfficialPriceMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, OfficialPriceMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// OfficialPriceMessage Decoder entry point
+pub fn start_decoding_official_price_message<'d>(
+    data: &'d [u8],
+) -> OfficialPriceMessageMessageHeaderDecoder<'d> {
+    OfficialPriceMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// OfficialPriceMessageEncoderDone
+pub struct OfficialPriceMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OfficialPriceMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> OfficialPriceMessageEncoderDone<'d> {
+        OfficialPriceMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct OfficialPriceMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OfficialPriceMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        OfficialPriceMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<OfficialPriceMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(OfficialPriceMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// OfficialPriceMessage Fixed fields Encoder
+pub struct OfficialPriceMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OfficialPriceMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> OfficialPriceMessageFieldsEncoder<'d> {
+        OfficialPriceMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn official_price_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut OfficialPriceMessageFields,
+        OfficialPriceMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<OfficialPriceMessageFields>(17)?;
+        Ok((v, OfficialPriceMessageSymbolEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn official_price_message_fields_copy(
+        mut self,
+        t: &OfficialPriceMessageFields,
+    ) -> CodecResult<OfficialPriceMessageSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<OfficialPriceMessageFields>(t, 17)?;
+        Ok(OfficialPriceMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// OfficialPriceMessageMessageHeaderEncoder
+pub struct OfficialPriceMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> OfficialPriceMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> OfficialPriceMessageMessageHeaderEncoder<'d> {
+        OfficialPriceMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d mut MessageHeader, OfficialPriceMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, OfficialPriceMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<OfficialPriceMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(OfficialPriceMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// OfficialPriceMessage Encoder entry point
+pub fn start_encoding_official_price_message<'d>(
+    data: &'d mut [u8],
+) -> OfficialPriceMessageMessageHeaderEncoder<'d> {
+    OfficialPriceMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// TradeBreakMessage Fixed-size Fields (29 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct TradeBreakMessageFields {
+    pub timestamp: i64,
+    pub price: u64,
+    pub size: u32,
+    pub sale_condition: u8,
+    pub trade_id: u64,
+}
+
+impl TradeBreakMessageFields {}
+
+/// TradeBreakMessage specific Message Header
+#[repr(C, packed)]
+pub struct TradeBreakMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl TradeBreakMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 29;
+    pub const TEMPLATE_ID: u16 = 11;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for TradeBreakMessageMessageHeader {
+    fn default() -> TradeBreakMessageMessageHeader {
+        TradeBreakMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 29u16,
+                template_id: 11u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// TradeBreakMessageDecoderDone
+pub struct TradeBreakMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeBreakMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradeBreakMessageDecoderDone<'d> {
+        TradeBreakMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct TradeBreakMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeBreakMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        TradeBreakMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], TradeBreakMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            TradeBreakMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// TradeBreakMessage Fixed fields Decoder
+pub struct TradeBreakMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeBreakMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradeBreakMessageFieldsDecoder<'d> {
+        TradeBreakMessageFieldsDecoder { scratch }
+    }
+    pub fn trade_break_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d TradeBreakMessageFields,
+        TradeBreakMessageSymbolDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<TradeBreakMessageFields>(29)?;
+        Ok((v, TradeBreakMessageSymbolDecoder::wrap(self.scratch)))
+    }
+}
+
+/// TradeBreakMessageMessageHeaderDecoder
+pub struct TradeBreakMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> TradeBreakMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> TradeBreakMessageMessageHeaderDecoder<'d> {
+        TradeBreakMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d MessageHeader, TradeBreakMessageFieldsDecoder<'d>)> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((v, TradeBreakMessageFieldsDecoder::wrap(self.scratch)))
+    }
+}
+
+/// TradeBreakMessage Decoder entry point
+pub fn start_decoding_trade_break_message<'d>(
+    data: &'d [u8],
+) -> TradeBreakMessageMessageHeaderDecoder<'d> {
+    TradeBreakMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// TradeBreakMessageEncoderDone
+pub struct TradeBreakMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeBreakMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradeBreakMessageEncoderDone<'d> {
+        TradeBreakMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct TradeBreakMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeBreakMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        TradeBreakMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<TradeBreakMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(TradeBreakMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// TradeBreakMessage Fixed fields Encoder
+pub struct TradeBreakMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeBreakMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradeBreakMessageFieldsEncoder<'d> {
+        TradeBreakMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn trade_break_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut TradeBreakMessageFields,
+        TradeBreakMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<TradeBreakMessageFields>(29)?;
+        Ok((v, TradeBreakMessageSymbolEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn trade_break_message_fields_copy(
+        mut self,
+        t: &TradeBreakMessageFields,
+    ) -> CodecResult<TradeBreakMessageSymbolEncoder<'d>> {
+        self.scratch.write_type::<TradeBreakMessageFields>(t, 29)?;
+        Ok(TradeBreakMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// TradeBreakMessageMessageHeaderEncoder
+pub struct TradeBreakMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> TradeBreakMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> TradeBreakMessageMessageHeaderEncoder<'d> {
+        TradeBreakMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(&'d mut MessageHeader, TradeBreakMessageFieldsEncoder<'d>)> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((v, TradeBreakMessageFieldsEncoder::wrap(self.scratch)))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<TradeBreakMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(TradeBreakMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// TradeBreakMessage Encoder entry point
+pub fn start_encoding_trade_break_message<'d>(
+    data: &'d mut [u8],
+) -> TradeBreakMessageMessageHeaderEncoder<'d> {
+    TradeBreakMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
+
+/// AuctionInformationMessage Fixed-size Fields (71 bytes)
+#[repr(C, packed)]
+#[derive(Default)]
+pub struct AuctionInformationMessageFields {
+    pub timestamp: i64,
+    pub auction_type: u8,
+    pub paired_shares: u32,
+    pub reference_price: u64,
+    pub indicative_clearing_price: u64,
+    pub imbalance_shares: u32,
+    pub imbalance_side: u8,
+    pub extension_number: u8,
+    pub scheduled_auction: u32,
+    pub auction_book_clearing_price: u64,
+    pub collar_reference_price: u64,
+    pub lower_auction_collar: u64,
+    pub upper_auction_collar: u64,
+}
+
+impl AuctionInformationMessageFields {}
+
+/// AuctionInformationMessage specific Message Header
+#[repr(C, packed)]
+pub struct AuctionInformationMessageMessageHeader {
+    pub message_header: MessageHeader,
+}
+impl AuctionInformationMessageMessageHeader {
+    pub const BLOCK_LENGTH: u16 = 71;
+    pub const TEMPLATE_ID: u16 = 12;
+    pub const SCHEMA_ID: u16 = 2;
+    pub const VERSION: u16 = 0;
+}
+impl Default for AuctionInformationMessageMessageHeader {
+    fn default() -> AuctionInformationMessageMessageHeader {
+        AuctionInformationMessageMessageHeader {
+            message_header: MessageHeader {
+                block_length: 71u16,
+                template_id: 12u16,
+                schema_id: 2u16,
+                version: 0u16,
+            },
+        }
+    }
+}
+
+// Group fixed-field member representations
+
+/// AuctionInformationMessageDecoderDone
+pub struct AuctionInformationMessageDecoderDone<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> AuctionInformationMessageDecoderDone<'d> {
+    /// Returns the number of bytes decoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> AuctionInformationMessageDecoderDone<'d> {
+        AuctionInformationMessageDecoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct AuctionInformationMessageSymbolDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> AuctionInformationMessageSymbolDecoder<'d> {
+    fn wrap(scratch: ScratchDecoderData<'d>) -> Self {
+        AuctionInformationMessageSymbolDecoder { scratch }
+    }
+    pub fn symbol(mut self) -> CodecResult<(&'d [u8], AuctionInformationMessageDecoderDone<'d>)> {
+        let count = self.scratch.read_unaligned::<u32>(4)?;
+        Ok((
+            self.scratch.read_slice::<u8>(count as usize, 1)?,
+            AuctionInformationMessageDecoderDone::wrap(self.scratch),
+        ))
+    }
+}
+
+/// AuctionInformationMessage Fixed fields Decoder
+pub struct AuctionInformationMessageFieldsDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> AuctionInformationMessageFieldsDecoder<'d> {
+    pub fn wrap(scratch: ScratchDecoderData<'d>) -> AuctionInformationMessageFieldsDecoder<'d> {
+        AuctionInformationMessageFieldsDecoder { scratch }
+    }
+    pub fn auction_information_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d AuctionInformationMessageFields,
+        AuctionInformationMessageSymbolDecoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .read_type::<AuctionInformationMessageFields>(71)?;
+        Ok((
+            v,
+            AuctionInformationMessageSymbolDecoder::wrap(self.scratch),
+        ))
+    }
+}
+
+/// AuctionInformationMessageMessageHeaderDecoder
+pub struct AuctionInformationMessageMessageHeaderDecoder<'d> {
+    scratch: ScratchDecoderData<'d>,
+}
+impl<'d> AuctionInformationMessageMessageHeaderDecoder<'d> {
+    pub fn wrap(
+        scratch: ScratchDecoderData<'d>,
+    ) -> AuctionInformationMessageMessageHeaderDecoder<'d> {
+        AuctionInformationMessageMessageHeaderDecoder { scratch }
+    }
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(
+        &'d MessageHeader,
+        AuctionInformationMessageFieldsDecoder<'d>,
+    )> {
+        let v = self.scratch.read_type::<MessageHeader>(8)?;
+        Ok((
+            v,
+            AuctionInformationMessageFieldsDecoder::wrap(self.scratch),
+        ))
+    }
+}
+
+/// AuctionInformationMessage Decoder entry point
+pub fn start_decoding_auction_information_message<'d>(
+    data: &'d [u8],
+) -> AuctionInformationMessageMessageHeaderDecoder<'d> {
+    AuctionInformationMessageMessageHeaderDecoder::wrap(ScratchDecoderData { data, pos: 0 })
+}
+
+/// AuctionInformationMessageEncoderDone
+pub struct AuctionInformationMessageEncoderDone<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> AuctionInformationMessageEncoderDone<'d> {
+    /// Returns the number of bytes encoded
+    pub fn unwrap(self) -> usize {
+        self.scratch.pos
+    }
+
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> AuctionInformationMessageEncoderDone<'d> {
+        AuctionInformationMessageEncoderDone { scratch }
+    }
+}
+
+/// symbol variable-length data
+pub struct AuctionInformationMessageSymbolEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> AuctionInformationMessageSymbolEncoder<'d> {
+    fn wrap(scratch: ScratchEncoderData<'d>) -> Self {
+        AuctionInformationMessageSymbolEncoder { scratch }
+    }
+    pub fn symbol(mut self, s: &'d [u8]) -> CodecResult<AuctionInformationMessageEncoderDone<'d>> {
+        let l = s.len();
+        if l > 4294967294 {
+            return Err(CodecErr::SliceIsLongerThanAllowedBySchema);
+        }
+        // Write data length
+        self.scratch.write_type::<u32>(&(l as u32), 4)?; // group length
+        self.scratch.write_slice_without_count::<u8>(s, 1)?;
+        Ok(AuctionInformationMessageEncoderDone::wrap(self.scratch))
+    }
+}
+
+/// AuctionInformationMessage Fixed fields Encoder
+pub struct AuctionInformationMessageFieldsEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> AuctionInformationMessageFieldsEncoder<'d> {
+    pub fn wrap(scratch: ScratchEncoderData<'d>) -> AuctionInformationMessageFieldsEncoder<'d> {
+        AuctionInformationMessageFieldsEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn auction_information_message_fields(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut AuctionInformationMessageFields,
+        AuctionInformationMessageSymbolEncoder<'d>,
+    )> {
+        let v = self
+            .scratch
+            .writable_overlay::<AuctionInformationMessageFields>(71)?;
+        Ok((
+            v,
+            AuctionInformationMessageSymbolEncoder::wrap(self.scratch),
+        ))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn auction_information_message_fields_copy(
+        mut self,
+        t: &AuctionInformationMessageFields,
+    ) -> CodecResult<AuctionInformationMessageSymbolEncoder<'d>> {
+        self.scratch
+            .write_type::<AuctionInformationMessageFields>(t, 71)?;
+        Ok(AuctionInformationMessageSymbolEncoder::wrap(self.scratch))
+    }
+}
+
+/// AuctionInformationMessageMessageHeaderEncoder
+pub struct AuctionInformationMessageMessageHeaderEncoder<'d> {
+    scratch: ScratchEncoderData<'d>,
+}
+impl<'d> AuctionInformationMessageMessageHeaderEncoder<'d> {
+    pub fn wrap(
+        scratch: ScratchEncoderData<'d>,
+    ) -> AuctionInformationMessageMessageHeaderEncoder<'d> {
+        AuctionInformationMessageMessageHeaderEncoder { scratch }
+    }
+
+    /// Create a mutable struct reference overlaid atop the data buffer
+    /// such that changes to the struct directly edit the buffer.
+    /// Note that the initial content of the struct's fields may be garbage.
+    pub fn header(
+        mut self,
+    ) -> CodecResult<(
+        &'d mut MessageHeader,
+        AuctionInformationMessageFieldsEncoder<'d>,
+    )> {
+        let v = self.scratch.writable_overlay::<MessageHeader>(8)?;
+        Ok((
+            v,
+            AuctionInformationMessageFieldsEncoder::wrap(self.scratch),
+        ))
+    }
+
+    /// Copy the bytes of a value into the data buffer
+    pub fn header_copy(
+        mut self,
+        t: &MessageHeader,
+    ) -> CodecResult<AuctionInformationMessageFieldsEncoder<'d>> {
+        self.scratch.write_type::<MessageHeader>(t, 8)?;
+        Ok(AuctionInformationMessageFieldsEncoder::wrap(self.scratch))
+    }
+}
+
+/// AuctionInformationMessage Encoder entry point
+pub fn start_encoding_auction_information_message<'d>(
+    data: &'d mut [u8],
+) -> AuctionInformationMessageMessageHeaderEncoder<'d> {
+    AuctionInformationMessageMessageHeaderEncoder::wrap(ScratchEncoderData { data, pos: 0 })
+}
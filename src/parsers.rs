@@ -1,15 +1,15 @@
 use nom::{
-    branch::alt, bytes::complete::tag, bytes::complete::take, number::complete::*, sequence::tuple,
-    IResult,
+    branch::alt, bytes::complete::tag, bytes::complete::take, error::ErrorKind,
+    number::complete::*, sequence::tuple, Err, IResult,
 };
 
 pub enum Block<'a> {
-    SectionHeader(SectionHeaderBlock),
+    SectionHeader,
     InterfaceDescription(InterfaceDescriptionBlock),
     EnhancedPacket(EnhancedPacketBlock<'a>),
 }
 
-pub fn read_block(input: &[u8]) -> IResult<&[u8], Block> {
+pub fn read_block(input: &[u8]) -> IResult<&[u8], Block<'_>> {
     // TODO: Curious if this is faster than `match`
     // Theoretically it should be because we're almost always using
     // enhanced packet blocks, but don't know if the branch predictor
@@ -21,59 +21,99 @@ pub fn read_block(input: &[u8]) -> IResult<&[u8], Block> {
     ))(input)
 }
 
-#[derive(Debug)]
-pub struct SectionHeaderBlock {
-    block_len: u32,
-}
-
 const SECTION_HEADER: [u8; 4] = [0x0a, 0x0d, 0x0d, 0x0a];
 
-pub fn section_header_block(input: &[u8]) -> IResult<&[u8], Block> {
+pub fn section_header_block(input: &[u8]) -> IResult<&[u8], Block<'_>> {
     let header_len = 12;
     let (rem, (_, block_len, _)) =
         tuple((tag(SECTION_HEADER), le_u32, tag([0x4d, 0x3c, 0x2b, 0x1a])))(input)?;
 
-    take(block_len - header_len)(rem)
-        .map(|i| (i.0, Block::SectionHeader(SectionHeaderBlock { block_len })))
+    take(body_len(input, block_len, header_len)?)(rem).map(|i| (i.0, Block::SectionHeader))
+}
+
+/// What's left of a block after the `read` bytes already parsed, failing
+/// rather than wrapping around if the block says it's shorter than that.
+fn body_len(input: &[u8], block_len: u32, read: u32) -> Result<u32, Err<(&[u8], ErrorKind)>> {
+    block_len
+        .checked_sub(read)
+        .ok_or(Err::Error((input, ErrorKind::LengthValue)))
 }
 
 #[derive(Debug)]
 pub struct InterfaceDescriptionBlock {
-    block_len: u32,
+    /// Resolution of the interface's packet timestamps, encoded as in `if_tsresol`
+    pub ts_resolution: u8,
 }
 
 const INTERFACE_DESCRIPTION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
 
-pub fn interface_description_block(input: &[u8]) -> IResult<&[u8], Block> {
+/// `if_tsresol` to use when an interface doesn't set it: microseconds.
+const DEFAULT_TS_RESOLUTION: u8 = 6;
+
+const OPTION_END: u16 = 0;
+const OPTION_TS_RESOLUTION: u16 = 9;
+
+pub fn interface_description_block(input: &[u8]) -> IResult<&[u8], Block<'_>> {
     let header_len = 8;
     let (rem, (_, block_len)) = tuple((tag(INTERFACE_DESCRIPTION), le_u32))(input)?;
+    let (rem, body) = take(body_len(input, block_len, header_len)?)(rem)?;
 
-    take(block_len - header_len)(rem).map(|i| {
-        (
-            i.0,
-            Block::InterfaceDescription(InterfaceDescriptionBlock { block_len }),
-        )
-    })
+    // Link type (u16), reserved (u16) and snap length (u32) come before the
+    // options, and the block length gets repeated after them
+    let mut options = &body[8.min(body.len())..body.len().saturating_sub(4)];
+    let mut ts_resolution = DEFAULT_TS_RESOLUTION;
+    while let Ok((value, (code, len))) = tuple((le_u16::<()>, le_u16))(options) {
+        let len = len as usize;
+        if code == OPTION_END || value.len() < len {
+            break;
+        }
+        if code == OPTION_TS_RESOLUTION && len >= 1 {
+            ts_resolution = value[0];
+        }
+        // Option values are padded to 32 bits
+        options = &value[((len + 3) & !3).min(value.len())..];
+    }
+
+    Ok((
+        rem,
+        Block::InterfaceDescription(InterfaceDescriptionBlock { ts_resolution }),
+    ))
+}
+
+/// Convert a packet timestamp to nanoseconds, given its interface's `if_tsresol`:
+/// a power of ten if the high bit is clear, or of two if it's set.
+pub fn timestamp_nanos(timestamp: u64, ts_resolution: u8) -> u64 {
+    let exponent = (ts_resolution & 0x7f) as u32;
+    if ts_resolution & 0x80 != 0 {
+        ((timestamp as u128 * 1_000_000_000) >> exponent.min(127)) as u64
+    } else if exponent <= 9 {
+        timestamp.saturating_mul(10u64.pow(9 - exponent))
+    } else {
+        timestamp / 10u64.saturating_pow(exponent - 9)
+    }
 }
 
 pub struct EnhancedPacketBlock<'a> {
-    pub block_len: u32,
+    pub interface_id: u32,
+    /// When the packet was captured, in units of the interface's `if_tsresol`
+    pub timestamp: u64,
     pub packet_data: &'a [u8],
 }
 
 const ENHANCED_PACKET: [u8; 4] = [0x06, 0x00, 0x00, 0x00];
 
-pub fn enhanced_packet_block(input: &[u8]) -> IResult<&[u8], Block> {
-    let header_len = 28;
-    let (rem, (_, block_len, _, _, _, captured_len, _)) = tuple((
-        tag(ENHANCED_PACKET),
-        le_u32,
-        le_u32,
-        le_u32,
-        le_u32,
-        le_u32,
-        le_u32,
-    ))(input)?;
+pub fn enhanced_packet_block(input: &[u8]) -> IResult<&[u8], Block<'_>> {
+    let header_len = 28u32;
+    let (rem, (_, block_len, interface_id, timestamp_high, timestamp_low, captured_len, _)) =
+        tuple((
+            tag(ENHANCED_PACKET),
+            le_u32,
+            le_u32,
+            le_u32,
+            le_u32,
+            le_u32,
+            le_u32,
+        ))(input)?;
 
     let (rem, packet_data) = take(captured_len)(rem)?;
 
@@ -81,11 +121,13 @@ pub fn enhanced_packet_block(input: &[u8]) -> IResult<&[u8], Block> {
     // seem to respect this
     //let packet_total_len = (captured_len + 3) / 4 * 4;
 
-    take(block_len - header_len - captured_len)(rem).map(|i| {
+    let trailer_len = body_len(input, block_len, header_len.saturating_add(captured_len))?;
+    take(trailer_len)(rem).map(|i| {
         (
             i.0,
             Block::EnhancedPacket(EnhancedPacketBlock {
-                block_len,
+                interface_id,
+                timestamp: (timestamp_high as u64) << 32 | timestamp_low as u64,
                 packet_data,
             }),
         )
@@ -107,7 +149,126 @@ pub fn extract_iex_data(input: &[u8]) -> IResult<&[u8], &[u8]> {
 
     let udp_header_len = 8;
     let (rem, iex_data) = take(udp_len - udp_header_len)(rem)?;
-    debug_assert!(rem.len() == 0);
+    debug_assert!(rem.is_empty());
 
     Ok((rem, iex_data))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        enhanced_packet_block, interface_description_block, read_block, section_header_block,
+        timestamp_nanos, Block,
+    };
+
+    /// An option with its value padded out to 32 bits.
+    fn option(code: u16, value: &[u8]) -> Vec<u8> {
+        let mut option = code.to_le_bytes().to_vec();
+        option.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        option.extend_from_slice(value);
+        option.resize((option.len() + 3) & !3, 0);
+        option
+    }
+
+    /// An Ethernet interface description block with `options` as they are.
+    fn interface_description(options: &[u8]) -> Vec<u8> {
+        let block_len = (8 + 8 + options.len() + 4) as u32;
+        let mut block = vec![1, 0, 0, 0];
+        block.extend_from_slice(&block_len.to_le_bytes());
+        block.extend_from_slice(&1u16.to_le_bytes());
+        block.extend_from_slice(&0u16.to_le_bytes());
+        block.extend_from_slice(&65535u32.to_le_bytes());
+        block.extend_from_slice(options);
+        block.extend_from_slice(&block_len.to_le_bytes());
+        block
+    }
+
+    fn ts_resolution(options: &[u8]) -> u8 {
+        let block = interface_description(options);
+        match interface_description_block(&block) {
+            Ok((rem, Block::InterfaceDescription(i))) => {
+                assert!(rem.is_empty());
+                i.ts_resolution
+            }
+            _ => panic!("Not read as an interface description"),
+        }
+    }
+
+    #[test]
+    fn ts_resolution_defaults_to_microseconds() {
+        assert_eq!(ts_resolution(&[]), 6);
+        assert_eq!(ts_resolution(&option(0, &[])), 6);
+        assert_eq!(
+            timestamp_nanos(1_567_468_800_000_001, 6),
+            1_567_468_800_000_001_000
+        );
+    }
+
+    #[test]
+    fn ts_resolution_options() {
+        let nanos = [option(9, &[9]), option(0, &[])].concat();
+        assert_eq!(ts_resolution(&nanos), 9);
+        assert_eq!(
+            timestamp_nanos(1_567_468_800_000_000_001, 9),
+            1_567_468_800_000_000_001
+        );
+
+        // if_tsresol after an if_name that needs padding
+        let named = [option(2, b"eth0\0"), option(9, &[3]), option(0, &[])].concat();
+        assert_eq!(ts_resolution(&named), 3);
+
+        // The last option's padding and the end of options are both optional
+        assert_eq!(ts_resolution(&[9, 0, 1, 0, 9]), 9);
+    }
+
+    #[test]
+    fn ts_resolution_ignores_truncated_options() {
+        // Claims 4 bytes when only 1 is left
+        assert_eq!(ts_resolution(&[9, 0, 4, 0, 9]), 6);
+        // No room for a value at all
+        assert_eq!(ts_resolution(&option(9, &[])), 6);
+        // Cut off partway through the option header
+        assert_eq!(ts_resolution(&[9, 0]), 6);
+        // Options after the end of options don't count
+        assert_eq!(
+            ts_resolution(&[option(0, &[]), option(9, &[9])].concat()),
+            6
+        );
+    }
+
+    #[test]
+    fn timestamp_resolutions() {
+        // Powers of ten, finer and coarser than nanoseconds
+        assert_eq!(timestamp_nanos(1_500, 0), 1_500_000_000_000);
+        assert_eq!(timestamp_nanos(1_500, 3), 1_500_000_000);
+        assert_eq!(timestamp_nanos(1_500, 12), 1);
+        assert_eq!(timestamp_nanos(1_500, 127), 0);
+
+        // Powers of two
+        assert_eq!(timestamp_nanos(1 << 10, 0x80 | 10), 1_000_000_000);
+        assert_eq!(timestamp_nanos(3 << 29, 0x80 | 30), 1_500_000_000);
+        assert_eq!(timestamp_nanos(7, 0x80), 7_000_000_000);
+        assert_eq!(timestamp_nanos(u64::MAX, 0xff), 0);
+    }
+
+    #[test]
+    fn short_block_lengths_are_errors() {
+        let mut interface = interface_description(&[]);
+        interface[4..8].copy_from_slice(&4u32.to_le_bytes());
+        assert!(interface_description_block(&interface).is_err());
+
+        let mut section = vec![0x0a, 0x0d, 0x0d, 0x0a];
+        section.extend_from_slice(&8u32.to_le_bytes());
+        section.extend_from_slice(&[0x4d, 0x3c, 0x2b, 0x1a]);
+        assert!(section_header_block(&section).is_err());
+
+        // Long enough for the header, but not the packet data
+        let mut packet = Vec::new();
+        for field in &[6u32, 32, 0, 0, 0, 8, 8] {
+            packet.extend_from_slice(&field.to_le_bytes());
+        }
+        packet.extend_from_slice(&[0; 12]);
+        assert!(enhanced_packet_block(&packet).is_err());
+        assert!(read_block(&packet).is_err());
+    }
+}
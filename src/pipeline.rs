@@ -0,0 +1,947 @@
+use std::alloc::{alloc_zeroed, dealloc, Layout};
+use std::hint;
+use std::io::{self, Read, Write};
+use std::mem::{align_of, size_of};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Instant;
+
+use hdrhistogram::serialization::{Deserializer, Serializer, V2Serializer};
+use hdrhistogram::Histogram;
+
+use crate::alloc::{self, AllocStats};
+use crate::clock::Timing;
+use crate::framing::StreamBuf;
+use crate::histograms::{self, MessageHists, PACKET_SIZES};
+use crate::iex::{IexParser, IexPayload};
+use crate::replay::Replay;
+use crate::sched;
+use crate::shm::{self, Fork};
+use crate::udp::Udp;
+use crate::{RunAnalysis, RunnerDeserialize, RunnerSerialize, Summarizer, SummaryStats};
+
+/// Size of the ring between the producer and consumer; a few thousand packets' worth.
+const RING_CAPACITY: usize = 1 << 20;
+
+/// Every packet in the ring is preceded by its encoded length (u32), how many
+/// messages it holds (u16), two bytes of padding, and the clock reading taken
+/// just before serializing it (u64). A length of zero means the producer is done.
+const HEADER_LEN: usize = 16;
+
+/// Length of a header that says to skip to the start of the ring.
+const WRAP: u32 = u32::MAX;
+
+/// Where the producer and consumer run when benchmarking with `--pipeline`.
+#[derive(Clone, Copy, Debug)]
+pub struct Pipeline {
+    pub transport: Transport,
+    pub producer_cpu: Option<usize>,
+    pub consumer_cpu: Option<usize>,
+}
+
+/// How packets get from the producer to the consumer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transport {
+    /// A ring shared between two threads
+    Threads,
+    /// A ring in shared memory, with the consumer in a forked process
+    Processes,
+    /// One datagram per packet over UDP on localhost, where packets too big
+    /// for `max_datagram` bytes don't get sent, and the rest can get dropped
+    Udp { max_datagram: usize },
+}
+
+impl Pipeline {
+    pub fn description(&self) -> String {
+        let cpu = |cpu: Option<usize>| match cpu {
+            Some(cpu) => cpu.to_string(),
+            None => "any".to_string(),
+        };
+        let transport = match self.transport {
+            Transport::Threads => "threads".to_string(),
+            Transport::Processes => "processes".to_string(),
+            Transport::Udp { max_datagram } => format!("udp max_datagram={}", max_datagram),
+        };
+        format!(
+            "transport={} producer_cpu={} consumer_cpu={}",
+            transport,
+            cpu(self.producer_cpu),
+            cpu(self.consumer_cpu)
+        )
+    }
+}
+
+/// Measurements that only make sense with the producer and consumer running separately.
+pub struct PipelineStats {
+    /// Time from starting to serialize a packet on the producer, until it's been
+    /// deserialized on the consumer. Includes time spent queued in the ring or
+    /// socket, so it grows once the consumer falls behind.
+    pub one_way_hist: Histogram<u64>,
+    /// Time from the producer starting until the consumer has deserialized everything
+    pub total_nanos: u128,
+    /// IEX messages the consumer deserialized, which is less than were
+    /// serialized if any packets went missing
+    pub messages: u64,
+    /// Number of times the producer had to wait on the consumer to make room
+    pub full_waits: u64,
+    /// Packets that were sent but never arrived
+    pub dropped: u64,
+    /// Packets too big for the transport to send at all
+    pub oversized: u64,
+    /// Packets that arrived but couldn't be deserialized, like when a format
+    /// depends on something in a packet that went missing
+    pub undecodable: u64,
+}
+
+impl PipelineStats {
+    pub fn msgs_per_sec(&self) -> f64 {
+        self.messages as f64 / self.total_nanos as f64 * 1e9
+    }
+
+    /// Whether every packet made it through, so the summary should match running on one thread.
+    pub fn complete(&self) -> bool {
+        self.dropped == 0 && self.oversized == 0 && self.undecodable == 0
+    }
+}
+
+/// The producer's end of whatever carries packets to the consumer.
+pub trait Publisher {
+    /// Send a packet, or return `false` if it's too big for the transport.
+    fn send(&mut self, msg_count: u16, sent: u64, packet: &[u8]) -> bool;
+
+    /// Let the consumer know there's nothing more coming.
+    fn finish(&mut self);
+
+    /// Number of times sending had to wait on the consumer to make room.
+    fn full_waits(&self) -> u64;
+}
+
+/// The consumer's end of whatever carries packets from the producer.
+pub trait Subscriber {
+    /// Wait for the next packet, or `None` once the producer is done.
+    fn recv(&mut self) -> Option<Header>;
+
+    /// The packet `recv` just returned.
+    fn packet(&self, header: &Header) -> &[u8];
+
+    /// Finish with the packet `recv` just returned.
+    fn release(&mut self, header: &Header);
+
+    /// Whether packets can go missing on the way, so the ones that do arrive
+    /// might not make sense to the reader.
+    fn lossy(&self) -> bool;
+}
+
+/// Positions in the ring, which sit at the start of its memory.
+#[repr(C)]
+struct Control {
+    /// Bytes written; only the producer stores to this
+    head: CachePadded<AtomicUsize>,
+    /// Bytes read; only the consumer stores to this
+    tail: CachePadded<AtomicUsize>,
+    /// Set when the consumer gives up, so the producer doesn't wait on it forever
+    closed: CachePadded<AtomicBool>,
+}
+
+/// Keeps each position on its own cache line.
+#[repr(align(64))]
+struct CachePadded<T>(T);
+
+/// Packets passed from a single producer to a single consumer without locking,
+/// in memory that can be shared with a forked process. Both sides count every
+/// byte that has gone through, so the ring is empty when they're equal and full
+/// when they're `RING_CAPACITY` apart. Packets never wrap around the end, so
+/// the consumer can decode them where they are.
+struct Ring {
+    /// A `Control`, followed by `RING_CAPACITY` bytes of packets
+    base: *mut u8,
+    shared: bool,
+}
+
+// Each byte is only ever touched by one side at a time: the producer only writes
+// between `head` and `tail + RING_CAPACITY`, and the consumer only reads between
+// `tail` and `head`.
+unsafe impl Send for Ring {}
+unsafe impl Sync for Ring {}
+
+impl Ring {
+    fn new(shared: bool) -> Result<Ring, String> {
+        // Zeroed memory is an empty ring
+        let base = if shared {
+            shm::map_shared("md_shootout_ring", Ring::len())?
+        } else {
+            unsafe { alloc_zeroed(Ring::layout()) }
+        };
+        Ok(Ring { base, shared })
+    }
+
+    fn len() -> usize {
+        size_of::<Control>() + RING_CAPACITY
+    }
+
+    fn layout() -> Layout {
+        Layout::from_size_align(Ring::len(), align_of::<Control>()).unwrap()
+    }
+
+    fn control(&self) -> &Control {
+        unsafe { &*(self.base as *const Control) }
+    }
+
+    fn data(&self) -> *mut u8 {
+        unsafe { self.base.add(size_of::<Control>()) }
+    }
+
+    fn close(&self) {
+        self.control().closed.0.store(true, Ordering::Release);
+    }
+}
+
+impl Drop for Ring {
+    fn drop(&mut self) {
+        unsafe {
+            if self.shared {
+                shm::unmap(self.base, Ring::len());
+            } else {
+                dealloc(self.base, Ring::layout());
+            }
+        }
+    }
+}
+
+/// Space a packet takes up in the ring, keeping every header 16-byte aligned.
+fn record_len(packet_len: usize) -> usize {
+    (HEADER_LEN + packet_len + 15) & !15
+}
+
+pub struct Header {
+    /// Length of the packet, not counting the header
+    pub len: u32,
+    pub msg_count: u16,
+    /// Clock reading from just before the packet was serialized
+    pub sent: u64,
+}
+
+struct Producer<'a> {
+    ring: &'a Ring,
+    head: usize,
+    /// Last `tail` we saw, so we only touch the consumer's cache line when the ring looks full
+    tail: usize,
+    full_waits: u64,
+}
+
+impl<'a> Producer<'a> {
+    fn new(ring: &'a Ring) -> Producer<'a> {
+        Producer {
+            ring,
+            head: 0,
+            tail: 0,
+            full_waits: 0,
+        }
+    }
+
+    /// Wait for the consumer to leave `len` bytes free past `head`.
+    fn reserve(&mut self, len: usize) {
+        if self.head + len - self.tail <= RING_CAPACITY {
+            return;
+        }
+
+        self.full_waits += 1;
+        let control = self.ring.control();
+        loop {
+            self.tail = control.tail.0.load(Ordering::Acquire);
+            if self.head + len - self.tail <= RING_CAPACITY {
+                return;
+            }
+            if control.closed.0.load(Ordering::Acquire) {
+                panic!("Consumer stopped before reading everything");
+            }
+            hint::spin_loop();
+        }
+    }
+
+    fn write_header(&mut self, offset: usize, len: u32, msg_count: u16, sent: u64) {
+        let mut header = [0u8; HEADER_LEN];
+        header[..4].copy_from_slice(&len.to_le_bytes());
+        header[4..6].copy_from_slice(&msg_count.to_le_bytes());
+        header[8..].copy_from_slice(&sent.to_le_bytes());
+        unsafe {
+            ptr::copy_nonoverlapping(header.as_ptr(), self.ring.data().add(offset), HEADER_LEN);
+        }
+    }
+}
+
+impl Publisher for Producer<'_> {
+    /// Write a packet and its header, and hand them both to the consumer at once.
+    fn send(&mut self, msg_count: u16, sent: u64, packet: &[u8]) -> bool {
+        let len = record_len(packet.len());
+        // Leaves room to skip to the start of the ring without waiting on ourselves
+        assert!(
+            len <= RING_CAPACITY / 2,
+            "Packet of {} bytes doesn't fit in the ring",
+            packet.len()
+        );
+
+        let offset = self.head % RING_CAPACITY;
+        if offset + len > RING_CAPACITY {
+            let skip = RING_CAPACITY - offset;
+            self.reserve(skip);
+            self.write_header(offset, WRAP, 0, 0);
+            self.head += skip;
+        }
+
+        self.reserve(len);
+        let offset = self.head % RING_CAPACITY;
+        self.write_header(offset, packet.len() as u32, msg_count, sent);
+        unsafe {
+            ptr::copy_nonoverlapping(
+                packet.as_ptr(),
+                self.ring.data().add(offset + HEADER_LEN),
+                packet.len(),
+            );
+        }
+        self.head += len;
+        self.ring
+            .control()
+            .head
+            .0
+            .store(self.head, Ordering::Release);
+        true
+    }
+
+    fn finish(&mut self) {
+        self.send(0, 0, &[]);
+    }
+
+    fn full_waits(&self) -> u64 {
+        self.full_waits
+    }
+}
+
+struct Consumer<'a> {
+    ring: &'a Ring,
+    tail: usize,
+    /// Last `head` we saw, so we only touch the producer's cache line when the ring looks empty
+    head: usize,
+}
+
+impl<'a> Consumer<'a> {
+    fn new(ring: &'a Ring) -> Consumer<'a> {
+        Consumer {
+            ring,
+            tail: 0,
+            head: 0,
+        }
+    }
+
+    fn release_bytes(&mut self, len: usize) {
+        self.tail += len;
+        self.ring
+            .control()
+            .tail
+            .0
+            .store(self.tail, Ordering::Release);
+    }
+
+    fn wait_for(&mut self, len: usize) {
+        while self.head - self.tail < len {
+            self.head = self.ring.control().head.0.load(Ordering::Acquire);
+            if self.head - self.tail < len {
+                hint::spin_loop();
+            }
+        }
+    }
+}
+
+impl Subscriber for Consumer<'_> {
+    fn recv(&mut self) -> Option<Header> {
+        loop {
+            self.wait_for(HEADER_LEN);
+            let offset = self.tail % RING_CAPACITY;
+            let mut bytes = [0u8; HEADER_LEN];
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    self.ring.data().add(offset),
+                    bytes.as_mut_ptr(),
+                    HEADER_LEN,
+                );
+            }
+            let mut len = [0u8; 4];
+            let mut msg_count = [0u8; 2];
+            let mut sent = [0u8; 8];
+            len.copy_from_slice(&bytes[..4]);
+            msg_count.copy_from_slice(&bytes[4..6]);
+            sent.copy_from_slice(&bytes[8..]);
+            let header = Header {
+                len: u32::from_le_bytes(len),
+                msg_count: u16::from_le_bytes(msg_count),
+                sent: u64::from_le_bytes(sent),
+            };
+
+            match header.len {
+                WRAP => self.release_bytes(RING_CAPACITY - offset),
+                0 => return None,
+                len => {
+                    self.wait_for(record_len(len as usize));
+                    return Some(header);
+                }
+            }
+        }
+    }
+
+    /// The packet `recv` just returned, still in the ring.
+    fn packet(&self, header: &Header) -> &[u8] {
+        let offset = self.tail % RING_CAPACITY + HEADER_LEN;
+        unsafe { slice::from_raw_parts(self.ring.data().add(offset), header.len as usize) }
+    }
+
+    /// Hand the space for a packet back to the producer, once we're done reading it.
+    fn release(&mut self, header: &Header) {
+        self.release_bytes(record_len(header.len as usize));
+    }
+
+    fn lossy(&self) -> bool {
+        false
+    }
+}
+
+/// Closes the ring if the consumer panics, so the producer finds out.
+impl Drop for Consumer<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.ring.close();
+        }
+    }
+}
+
+/// Pin the current thread to a CPU, if asked to.
+fn pin(cpu: Option<usize>) -> Result<(), String> {
+    match cpu {
+        Some(cpu) => sched::pin_thread(cpu),
+        None => Ok(()),
+    }
+}
+
+/// What the producer measured.
+struct Produced {
+    serialize_hist: Histogram<u64>,
+    serialize_msg_hists: MessageHists,
+    serialize_total_nanos: u128,
+    serialize_allocs: AllocStats,
+    packets: u64,
+    messages: u64,
+    bytes: usize,
+    full_waits: u64,
+    /// Packets that were serialized but too big to send
+    oversized: u64,
+    /// Nanoseconds from the start of the run until the producer started
+    started: u64,
+}
+
+/// What the consumer measured.
+struct Consumed {
+    deserialize_hist: Histogram<u64>,
+    deserialize_msg_hists: MessageHists,
+    deserialize_total_nanos: u128,
+    deserialize_allocs: AllocStats,
+    one_way_hist: Histogram<u64>,
+    /// Packets that arrived, whether or not they could be deserialized
+    packets: u64,
+    /// Messages in the packets that were deserialized
+    messages: u64,
+    undecodable: u64,
+    summarizer: Summarizer,
+    /// Nanoseconds from the start of the run until the consumer finished
+    finished: u64,
+}
+
+/// Payloads for the producer to serialize, and how fast to go through them.
+#[derive(Clone, Copy)]
+struct Feed<'a> {
+    payloads: &'a [IexPayload],
+    replay: Option<Replay>,
+}
+
+fn produce<P: Publisher, S: RunnerSerialize>(
+    mut publisher: P,
+    timing: Timing,
+    epoch: Instant,
+    feed: Feed,
+    serializer: &mut S,
+) -> Produced {
+    let clock = timing.clock;
+    let mut output_buf = Vec::new();
+    let mut produced = Produced {
+        serialize_hist: Histogram::<u64>::new(2).unwrap(),
+        serialize_msg_hists: MessageHists::new(),
+        serialize_total_nanos: 0,
+        serialize_allocs: AllocStats::default(),
+        packets: 0,
+        messages: 0,
+        bytes: 0,
+        full_waits: 0,
+        oversized: 0,
+        started: epoch.elapsed().as_nanos() as u64,
+    };
+
+    let mut pacer = feed.replay.map(|r| r.pacer());
+    let batch_start = Instant::now();
+    for iex_payload in feed.payloads {
+        if let Some(pacer) = &mut pacer {
+            pacer.wait(iex_payload);
+        }
+        output_buf.clear();
+        let allocs_start = alloc::current();
+        let serialize_start = clock.start();
+
+        serializer.serialize(iex_payload, &mut output_buf);
+
+        let serialize_stop = clock.stop();
+        produced.serialize_allocs += alloc::current() - allocs_start;
+
+        // Same as running on one thread, only packets that wrote something count
+        if output_buf.is_empty() {
+            continue;
+        }
+        if clock.per_message() {
+            let serialize_end = timing.elapsed_nanos(serialize_start, serialize_stop);
+            produced.serialize_hist.record(serialize_end).unwrap();
+            produced.serialize_total_nanos += serialize_end as u128;
+            produced
+                .serialize_msg_hists
+                .record(serialize_end, iex_payload.msg_count);
+        }
+        produced.packets += 1;
+        produced.messages += iex_payload.msg_count as u64;
+        produced.bytes += output_buf.len();
+
+        if !publisher.send(iex_payload.msg_count, serialize_start, &output_buf) {
+            produced.oversized += 1;
+        }
+    }
+    // Before finishing, which can mean waiting on the consumer to catch up
+    if !clock.per_message() {
+        produced.serialize_total_nanos = batch_start.elapsed().as_nanos();
+    }
+    publisher.finish();
+    produced.full_waits = publisher.full_waits();
+    produced
+}
+
+fn consume<C: Subscriber, D: RunnerDeserialize>(
+    mut subscriber: C,
+    timing: Timing,
+    epoch: Instant,
+    deserializer: &mut D,
+) -> Consumed {
+    let clock = timing.clock;
+    let mut consumed = Consumed {
+        deserialize_hist: Histogram::<u64>::new(2).unwrap(),
+        deserialize_msg_hists: MessageHists::new(),
+        deserialize_total_nanos: 0,
+        deserialize_allocs: AllocStats::default(),
+        one_way_hist: Histogram::<u64>::new(2).unwrap(),
+        packets: 0,
+        messages: 0,
+        undecodable: 0,
+        summarizer: Summarizer::default(),
+        finished: 0,
+    };
+
+    let batch_start = Instant::now();
+    while let Some(header) = subscriber.recv() {
+        consumed.packets += 1;
+        let mut read_buf = StreamBuf::new(subscriber.packet(&header));
+        let allocs_start = alloc::current();
+        let deserialize_start = clock.start();
+
+        let res = deserializer.deserialize(&mut read_buf, &mut consumed.summarizer);
+
+        let deserialize_stop = clock.stop();
+        consumed.deserialize_allocs += alloc::current() - allocs_start;
+
+        let read_everything = read_buf.remaining().is_empty();
+        if subscriber.lossy() && (res.is_err() || !read_everything) {
+            // Nothing to do but skip it, same as a real subscriber would
+            consumed.undecodable += 1;
+            subscriber.release(&header);
+            continue;
+        }
+        res.expect("Unable to deserialize a packet sent through the pipeline");
+        // Anything left over would be lost once the ring reuses the space
+        assert!(read_everything, "Reader didn't read the whole packet");
+        subscriber.release(&header);
+
+        if clock.per_message() {
+            let deserialize_end = timing.elapsed_nanos(deserialize_start, deserialize_stop);
+            consumed.deserialize_hist.record(deserialize_end).unwrap();
+            consumed.deserialize_total_nanos += deserialize_end as u128;
+            consumed
+                .deserialize_msg_hists
+                .record(deserialize_end, header.msg_count);
+            consumed
+                .one_way_hist
+                .record(timing.elapsed_nanos(header.sent, deserialize_stop))
+                .unwrap();
+        }
+        consumed.messages += header.msg_count as u64;
+    }
+    if !clock.per_message() {
+        consumed.deserialize_total_nanos = batch_start.elapsed().as_nanos();
+    }
+    consumed.finished = epoch.elapsed().as_nanos() as u64;
+    consumed
+}
+
+/// Serialize and deserialize on separate threads, or in separate processes
+/// sharing memory, passing packets between them through a ring buffer like a
+/// feed handler publishing to a strategy. Readers decode straight out of the
+/// ring, so formats that don't need to copy messages out get credit for it.
+/// Over UDP, each packet goes out as its own datagram instead, so readers only
+/// ever see one packet at a time, and have to cope with the ones that get lost.
+/// With the `batch` clock there's nothing to measure one-way latency with, so
+/// each side's total is the time it spent running, transport included.
+pub fn run<S, D>(
+    iex_data: &[u8],
+    timing: Timing,
+    pipeline: Pipeline,
+    replay: Option<Replay>,
+    serializer: &mut S,
+    deserializer: &mut D,
+) -> RunAnalysis
+where
+    S: RunnerSerialize + Send,
+    D: RunnerDeserialize + Send,
+{
+    // Parse the IEX data up front, so the producer is only waiting on serializing
+    let iex_payloads: Vec<IexPayload> = IexParser::new(iex_data).collect();
+    let feed = Feed {
+        payloads: &iex_payloads,
+        replay,
+    };
+
+    let (produced, consumed) = match pipeline.transport {
+        Transport::Threads => {
+            let ring = or_exit(Ring::new(false));
+            across_threads(
+                Producer::new(&ring),
+                Consumer::new(&ring),
+                timing,
+                pipeline,
+                feed,
+                serializer,
+                deserializer,
+            )
+        }
+        Transport::Processes => {
+            let ring = or_exit(Ring::new(true));
+            across_processes(&ring, timing, pipeline, feed, serializer, deserializer)
+        }
+        Transport::Udp { max_datagram } => {
+            let udp = or_exit(Udp::new());
+            across_threads(
+                udp.publisher(max_datagram),
+                udp.subscriber(),
+                timing,
+                pipeline,
+                feed,
+                serializer,
+                deserializer,
+            )
+        }
+    };
+
+    let sent = produced.packets - produced.oversized;
+    if let Transport::Udp { .. } = pipeline.transport {
+        assert!(
+            consumed.packets <= sent,
+            "Received more packets than were sent"
+        );
+    } else {
+        assert_eq!(sent, consumed.packets);
+    }
+
+    RunAnalysis {
+        serialize_hist: produced.serialize_hist,
+        deserialize_hist: consumed.deserialize_hist,
+        serialize_msg_hists: produced.serialize_msg_hists,
+        deserialize_msg_hists: consumed.deserialize_msg_hists,
+        summary_stats: consumed.summarizer,
+        serialize_total_nanos: produced.serialize_total_nanos,
+        deserialize_total_nanos: consumed.deserialize_total_nanos,
+        buf_len: produced.bytes,
+        messages: produced.messages,
+        serialize_allocs: produced.serialize_allocs,
+        deserialize_allocs: consumed.deserialize_allocs,
+        pipeline: Some(PipelineStats {
+            one_way_hist: consumed.one_way_hist,
+            total_nanos: consumed.finished.saturating_sub(produced.started) as u128,
+            messages: consumed.messages,
+            full_waits: produced.full_waits,
+            dropped: sent - consumed.packets,
+            oversized: produced.oversized,
+            undecodable: consumed.undecodable,
+        }),
+    }
+}
+
+fn across_threads<P, C, S, D>(
+    publisher: P,
+    subscriber: C,
+    timing: Timing,
+    pipeline: Pipeline,
+    feed: Feed,
+    serializer: &mut S,
+    deserializer: &mut D,
+) -> (Produced, Consumed)
+where
+    P: Publisher + Send,
+    C: Subscriber + Send,
+    S: RunnerSerialize + Send,
+    D: RunnerDeserialize + Send,
+{
+    // Both sides measure from here
+    let epoch = Instant::now();
+    thread::scope(|scope| {
+        let producer = scope.spawn(move || {
+            pin_or_exit(pipeline.producer_cpu);
+            produce(publisher, timing, epoch, feed, serializer)
+        });
+        let consumer = scope.spawn(move || {
+            pin_or_exit(pipeline.consumer_cpu);
+            consume(subscriber, timing, epoch, deserializer)
+        });
+        (producer.join().unwrap(), consumer.join().unwrap())
+    })
+}
+
+/// Fork a process to deserialize, which sends back what it measured once it's done.
+fn across_processes<S, D>(
+    ring: &Ring,
+    timing: Timing,
+    pipeline: Pipeline,
+    feed: Feed,
+    serializer: &mut S,
+    deserializer: &mut D,
+) -> (Produced, Consumed)
+where
+    S: RunnerSerialize + Send,
+    D: RunnerDeserialize + Send,
+{
+    let (mut results_read, mut results_write) = shm::pipe().unwrap_or_else(|e| panic!("{}", e));
+    // Anything still buffered would get printed by both processes
+    io::stdout().flush().unwrap();
+    // Both sides measure from here, including the forked process since `Instant`
+    // is the same monotonic clock everywhere
+    let epoch = Instant::now();
+
+    match shm::fork().unwrap_or_else(|e| panic!("{}", e)) {
+        Fork::Child => {
+            drop(results_read);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                pin(pipeline.consumer_cpu)?;
+                let consumed = consume(Consumer::new(ring), timing, epoch, deserializer);
+                let mut results = Vec::new();
+                consumed
+                    .write_to(&mut results)
+                    .and_then(|_| results_write.write_all(&results))
+                    .map_err(|e| format!("Unable to send results to the producer: {}", e))
+            }));
+            let status = match result {
+                Ok(Ok(())) => 0,
+                Ok(Err(e)) => {
+                    eprintln!("{}", e);
+                    1
+                }
+                // The panic has already been printed
+                Err(_) => 1,
+            };
+            if status != 0 {
+                ring.close();
+            }
+            // Never return into the rest of the benchmark, which the parent is running
+            shm::exit(status)
+        }
+        Fork::Parent(pid) => {
+            drop(results_write);
+            // On its own thread, so pinning it doesn't pin everything that runs afterwards
+            let produced = thread::scope(|scope| {
+                scope
+                    .spawn(move || {
+                        pin_or_exit(pipeline.producer_cpu);
+                        produce(Producer::new(ring), timing, epoch, feed, serializer)
+                    })
+                    .join()
+                    .unwrap()
+            });
+
+            let mut results = Vec::new();
+            results_read
+                .read_to_end(&mut results)
+                .expect("Unable to read results from the consumer");
+            shm::wait(pid).unwrap_or_else(|e| panic!("Consumer process failed: {}", e));
+            let consumed = Consumed::read_from(&mut &results[..])
+                .expect("Unable to read results from the consumer");
+            (produced, consumed)
+        }
+    }
+}
+
+fn pin_or_exit(cpu: Option<usize>) {
+    or_exit(pin(cpu))
+}
+
+fn or_exit<T>(result: Result<T, String>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    })
+}
+
+impl Consumed {
+    fn write_to(&self, out: &mut Vec<u8>) -> io::Result<()> {
+        write_hist(out, &self.deserialize_hist)?;
+        write_msg_hists(out, &self.deserialize_msg_hists)?;
+        write_u64(out, self.deserialize_total_nanos as u64)?;
+        write_u64(out, self.deserialize_allocs.allocations)?;
+        write_u64(out, self.deserialize_allocs.deallocations)?;
+        write_u64(out, self.deserialize_allocs.bytes_allocated)?;
+        write_hist(out, &self.one_way_hist)?;
+        write_u64(out, self.packets)?;
+        write_u64(out, self.messages)?;
+        write_u64(out, self.undecodable)?;
+        write_u64(out, self.finished)?;
+
+        write_u64(out, self.summarizer.data.len() as u64)?;
+        for (key, stats) in &self.summarizer.data {
+            write_u64(out, *key)?;
+            write_u64(out, stats.symbol.len() as u64)?;
+            out.write_all(stats.symbol.as_bytes())?;
+            for n in &[
+                stats.trade_volume,
+                stats.bid_high,
+                stats.bid_low,
+                stats.ask_high,
+                stats.ask_low,
+            ] {
+                write_u64(out, *n)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_from(input: &mut impl Read) -> io::Result<Consumed> {
+        let mut consumed = Consumed {
+            deserialize_hist: read_hist(input)?,
+            deserialize_msg_hists: read_msg_hists(input)?,
+            deserialize_total_nanos: read_u64(input)? as u128,
+            deserialize_allocs: AllocStats {
+                allocations: read_u64(input)?,
+                deallocations: read_u64(input)?,
+                bytes_allocated: read_u64(input)?,
+            },
+            one_way_hist: read_hist(input)?,
+            packets: read_u64(input)?,
+            messages: read_u64(input)?,
+            undecodable: read_u64(input)?,
+            finished: read_u64(input)?,
+            summarizer: Summarizer::default(),
+        };
+
+        for _ in 0..read_u64(input)? {
+            let key = read_u64(input)?;
+            let mut symbol = vec![0; read_u64(input)? as usize];
+            input.read_exact(&mut symbol)?;
+            let stats = SummaryStats {
+                symbol: String::from_utf8(symbol)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                trade_volume: read_u64(input)?,
+                bid_high: read_u64(input)?,
+                bid_low: read_u64(input)?,
+                ask_high: read_u64(input)?,
+                ask_low: read_u64(input)?,
+            };
+            consumed.summarizer.data.insert(key, stats);
+        }
+        Ok(consumed)
+    }
+}
+
+fn write_u64(out: &mut impl Write, n: u64) -> io::Result<()> {
+    out.write_all(&n.to_le_bytes())
+}
+
+fn read_u64(input: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    input.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn write_hist(out: &mut Vec<u8>, hist: &Histogram<u64>) -> io::Result<()> {
+    V2Serializer::new()
+        .serialize(hist, out)
+        .map_err(|e| io::Error::other(format!("{:?}", e)))?;
+    Ok(())
+}
+
+fn read_hist(input: &mut impl Read) -> io::Result<Histogram<u64>> {
+    Deserializer::new()
+        .deserialize(input)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", e)))
+}
+
+fn write_msg_hists(out: &mut Vec<u8>, hists: &MessageHists) -> io::Result<()> {
+    write_hist(out, &hists.per_message)?;
+    for hist in &hists.by_packet_size {
+        write_hist(out, hist)?;
+    }
+    Ok(())
+}
+
+fn read_msg_hists(input: &mut impl Read) -> io::Result<MessageHists> {
+    let per_message = read_hist(input)?;
+    let by_packet_size = PACKET_SIZES
+        .iter()
+        .map(|_| read_hist(input))
+        .collect::<io::Result<_>>()?;
+    Ok(MessageHists {
+        per_message,
+        by_packet_size,
+    })
+}
+
+/// One-way latency and throughput between the producer and consumer, and how
+/// many packets went missing, across every iteration.
+pub fn pipeline_stats(runs: &[RunAnalysis]) -> String {
+    let stats: Vec<&PipelineStats> = runs.iter().filter_map(|r| r.pipeline.as_ref()).collect();
+    let one_way = histograms::merge(stats.iter().map(|s| &s.one_way_hist));
+    let iterations = stats.len() as f64;
+    let mean =
+        |f: &dyn Fn(&PipelineStats) -> f64| stats.iter().map(|s| f(s)).sum::<f64>() / iterations;
+
+    format!(
+        concat!(
+            "  one_way_50={}ns\n",
+            "  one_way_99={}ns\n",
+            "  one_way_999={}ns\n",
+            "  one_way_max={}ns\n",
+            "  msgs_per_sec={:.0}\n",
+            "  ring_full_waits={:.0}\n",
+            "  dropped_packets={:.0}\n",
+            "  oversized_packets={:.0}\n",
+            "  undecodable_packets={:.0}"
+        ),
+        one_way.value_at_quantile(0.5),
+        one_way.value_at_quantile(0.99),
+        one_way.value_at_quantile(0.999),
+        one_way.max(),
+        mean(&|s| s.msgs_per_sec()),
+        mean(&|s| s.full_waits as f64),
+        mean(&|s| s.dropped as f64),
+        mean(&|s| s.oversized as f64),
+        mean(&|s| s.undecodable as f64)
+    )
+}
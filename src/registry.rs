@@ -0,0 +1,271 @@
+use crate::clock::Timing;
+use crate::pipeline::Pipeline;
+use crate::replay::Replay;
+use crate::{run_analysis, RunAnalysis, WriteMode};
+
+#[cfg(feature = "avro")]
+use crate::avro_runner;
+#[cfg(feature = "capnp")]
+use crate::capnp_runner;
+#[cfg(feature = "flatbuffers")]
+use crate::flatbuffers_runner;
+#[cfg(feature = "sbe")]
+use crate::{sbe_chained_runner, sbe_groups_runner, sbe_runner, sbe_templates_runner};
+
+/// A serializer/deserializer pair that can be benchmarked.
+pub struct Runner {
+    /// Name used to pick the runner with `--formats` and `--exclude`
+    pub name: &'static str,
+    /// Name used when printing results
+    pub description: &'static str,
+    /// Whether the writer can build messages directly in the output buffer; runners
+    /// that can't are skipped with `--write-mode in-place`
+    pub in_place: bool,
+    /// Build a fresh writer and reader, then run them over the IEX data
+    pub run: RunFn,
+}
+
+pub type RunFn = fn(
+    iex_data: &[u8],
+    write_mode: WriteMode,
+    timing: Timing,
+    verify: bool,
+    pipeline: Option<Pipeline>,
+    replay: Option<Replay>,
+) -> RunAnalysis;
+
+/// Every runner compiled into this build, in the order they get run.
+/// Adding a format only requires adding it here.
+pub fn runners() -> Vec<Runner> {
+    let mut runners = Vec::new();
+
+    #[cfg(feature = "capnp")]
+    {
+        runners.push(Runner {
+            name: "capnp",
+            description: "Cap'n Proto Unpacked",
+            in_place: true,
+            run: |iex_data, write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut capnp_runner::CapnpWriter::new(false, write_mode),
+                    &mut capnp_runner::CapnpReader::new(false),
+                )
+            },
+        });
+        runners.push(Runner {
+            name: "capnp-packed",
+            description: "Cap'n Proto Packed",
+            in_place: false,
+            run: |iex_data, write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut capnp_runner::CapnpWriter::new(true, write_mode),
+                    &mut capnp_runner::CapnpReader::new(true),
+                )
+            },
+        });
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    {
+        runners.push(Runner {
+            name: "flatbuffers",
+            description: "Flatbuffers",
+            in_place: false,
+            run: |iex_data, _write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut flatbuffers_runner::FlatbuffersWriter::new(),
+                    &mut flatbuffers_runner::FlatbuffersReader::new(false),
+                )
+            },
+        });
+        runners.push(Runner {
+            name: "flatbuffers-verified",
+            description: "Flatbuffers Verified",
+            in_place: false,
+            run: |iex_data, _write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut flatbuffers_runner::FlatbuffersWriter::new(),
+                    &mut flatbuffers_runner::FlatbuffersReader::new(true),
+                )
+            },
+        });
+    }
+
+    #[cfg(feature = "sbe")]
+    {
+        runners.push(Runner {
+            name: "sbe",
+            description: "SBE",
+            in_place: true,
+            run: |iex_data, write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut sbe_runner::SBEWriter::new(write_mode),
+                    &mut sbe_runner::SBEReader::new(),
+                )
+            },
+        });
+        runners.push(Runner {
+            name: "sbe-templates",
+            description: "SBE Templates",
+            in_place: true,
+            run: |iex_data, write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut sbe_templates_runner::SBETemplatesWriter::new(write_mode),
+                    &mut sbe_templates_runner::SBETemplatesReader::new(),
+                )
+            },
+        });
+        runners.push(Runner {
+            name: "sbe-groups",
+            description: "SBE Groups",
+            in_place: true,
+            run: |iex_data, write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut sbe_groups_runner::SBEGroupsWriter::new(write_mode),
+                    &mut sbe_groups_runner::SBEGroupsReader::new(),
+                )
+            },
+        });
+        runners.push(Runner {
+            name: "sbe-chained",
+            description: "SBE Chained",
+            in_place: true,
+            run: |iex_data, write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut sbe_chained_runner::SBEChainedWriter::new(write_mode),
+                    &mut sbe_chained_runner::SBEChainedReader::new(),
+                )
+            },
+        });
+    }
+
+    #[cfg(feature = "avro")]
+    {
+        runners.push(Runner {
+            name: "avro",
+            description: "Avro Single-Object",
+            in_place: false,
+            run: |iex_data, _write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut avro_runner::AvroWriter::new(avro_runner::AvroFraming::SingleObject),
+                    &mut avro_runner::AvroReader::new(
+                        avro_runner::AvroFraming::SingleObject,
+                        false,
+                    ),
+                )
+            },
+        });
+        runners.push(Runner {
+            name: "avro-container",
+            description: "Avro Container",
+            in_place: false,
+            run: |iex_data, _write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut avro_runner::AvroWriter::new(avro_runner::AvroFraming::Container),
+                    &mut avro_runner::AvroReader::new(avro_runner::AvroFraming::Container, false),
+                )
+            },
+        });
+        runners.push(Runner {
+            name: "avro-resolved",
+            description: "Avro Schema Resolution",
+            in_place: false,
+            run: |iex_data, _write_mode, timing, verify, pipeline, replay| {
+                run_analysis(
+                    iex_data,
+                    timing,
+                    verify,
+                    pipeline,
+                    replay,
+                    &mut avro_runner::AvroWriter::new(avro_runner::AvroFraming::SingleObject),
+                    &mut avro_runner::AvroReader::new(avro_runner::AvroFraming::SingleObject, true),
+                )
+            },
+        });
+    }
+
+    runners
+}
+
+#[cfg(all(test, feature = "avro"))]
+mod tests {
+    use super::runners;
+    use crate::clock::{Clock, Timing};
+    use crate::{sample, WriteMode};
+
+    /// `--verify --formats avro,avro-container,avro-resolved` over a capture
+    /// holding every message type.
+    #[test]
+    fn avro_runners_verify() {
+        let payloads: Vec<Vec<u8>> = (0..4)
+            .map(|n| sample::iex_payload(n * 12, &sample::every_message_type(b"ZIEXT   ", n)))
+            .collect();
+        let pcap = sample::pcap(&payloads);
+        let timing = Timing {
+            clock: Clock::new("instant"),
+            correction_nanos: 0,
+        };
+
+        let avro_runners: Vec<_> = runners()
+            .into_iter()
+            .filter(|r| r.name.starts_with("avro"))
+            .collect();
+        assert_eq!(avro_runners.len(), 3);
+        for runner in avro_runners {
+            assert!(!runner.in_place, "{}", runner.name);
+            // Any message that doesn't come back the same panics
+            let analysis = (runner.run)(&pcap, WriteMode::Scratch, timing, true, None, None);
+            assert_eq!(analysis.messages, 4 * 12, "{}", runner.name);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::hint;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::iex::{IexParser, IexPayload};
+
+/// Which timestamps `--replay-timestamps` can pace by.
+pub const TIMESTAMPS: &[&str] = &["send-time", "capture-time"];
+
+/// Closer to a deadline than this, spin instead of sleeping, since sleeps tend
+/// to overshoot by tens of microseconds.
+const SPIN_THRESHOLD: Duration = Duration::from_micros(200);
+
+/// How fast to feed payloads to the serializer with `--replay`.
+#[derive(Clone, Copy, Debug)]
+pub enum Pace {
+    /// Keep the gaps between payloads from the capture, divided by this factor
+    Speed(f64),
+    /// Space payloads out to send this many messages per second, whatever the
+    /// gaps were originally
+    Rate(f64),
+}
+
+/// Where the gaps between payloads come from with `Pace::Speed`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Timestamps {
+    /// When IEX sent each payload
+    SendTime,
+    /// When the pcapng file says each packet was captured
+    CaptureTime,
+}
+
+/// Replay payloads at a realistic pace, rather than as fast as they can be
+/// serialized, so there's time for caches to go cold between them.
+#[derive(Clone, Copy, Debug)]
+pub struct Replay {
+    pub pace: Pace,
+    pub timestamps: Timestamps,
+}
+
+impl Replay {
+    /// Read `--replay` and `--replay-timestamps`; the pace should already have
+    /// been checked with `parse_pace`.
+    pub fn new(pace: &str, timestamps: &str) -> Replay {
+        Replay {
+            pace: parse_pace(pace).unwrap(),
+            timestamps: match timestamps {
+                "send-time" => Timestamps::SendTime,
+                "capture-time" => Timestamps::CaptureTime,
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self.pace {
+            Pace::Speed(speed) => format!(
+                "speed={}x timestamps={}",
+                speed,
+                match self.timestamps {
+                    Timestamps::SendTime => "send-time",
+                    Timestamps::CaptureTime => "capture-time",
+                }
+            ),
+            Pace::Rate(rate) => format!("rate={}/s", rate),
+        }
+    }
+
+    pub fn pacer(&self) -> Pacer {
+        Pacer {
+            replay: *self,
+            start: None,
+            first_timestamp: None,
+            messages: 0,
+        }
+    }
+}
+
+/// Keeps payloads to the schedule set by a `Replay`, starting from the first one.
+pub struct Pacer {
+    replay: Replay,
+    start: Option<Instant>,
+    first_timestamp: Option<u64>,
+    /// Messages scheduled so far, for `Pace::Rate`
+    messages: u64,
+}
+
+impl Pacer {
+    /// Wait until it's time for `payload` to go out, and return when that was
+    /// relative to the first payload.
+    pub fn wait(&mut self, payload: &IexPayload) -> Duration {
+        let offset = match self.replay.pace {
+            Pace::Speed(speed) => {
+                let timestamp = match self.replay.timestamps {
+                    Timestamps::SendTime => payload.send_time as u64,
+                    // Checked for every payload before replaying; see `missing_capture_times`
+                    Timestamps::CaptureTime => payload
+                        .capture_time
+                        .expect("Payload without a capture time"),
+                };
+                let first = *self.first_timestamp.get_or_insert(timestamp);
+                // Out-of-order timestamps go out straight away
+                Duration::from_nanos((timestamp.saturating_sub(first) as f64 / speed) as u64)
+            }
+            Pace::Rate(rate) => {
+                let offset = Duration::from_secs_f64(self.messages as f64 / rate);
+                self.messages += payload.msg_count as u64;
+                offset
+            }
+        };
+        self.wait_until(offset);
+        offset
+    }
+
+    /// Wait until `offset` after the first call to either `wait` or `wait_until`.
+    /// Running behind doesn't get made up for by skipping any waits; everything
+    /// just goes out as soon as it can until the schedule catches up.
+    pub fn wait_until(&mut self, offset: Duration) {
+        let deadline = *self.start.get_or_insert_with(Instant::now) + offset;
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return;
+            }
+            let remaining = deadline - now;
+            if remaining > SPIN_THRESHOLD {
+                thread::sleep(remaining - SPIN_THRESHOLD);
+            } else {
+                hint::spin_loop();
+            }
+        }
+    }
+}
+
+/// Whether any packet in the capture is missing the capture time that
+/// `Timestamps::CaptureTime` paces by.
+pub fn missing_capture_times(iex_data: &[u8]) -> bool {
+    IexParser::new(iex_data).any(|payload| payload.capture_time.is_none())
+}
+
+/// Read a `--replay` value: a speed like `1x` or `0.5x`, or a rate like `100000/s`.
+pub fn parse_pace(value: &str) -> Result<Pace, String> {
+    let pace = if let Some(speed) = value.strip_suffix('x') {
+        speed.parse().ok().map(Pace::Speed)
+    } else if let Some(rate) = value.strip_suffix("/s") {
+        rate.parse().ok().map(Pace::Rate)
+    } else {
+        None
+    };
+    match pace {
+        Some(Pace::Speed(n)) | Some(Pace::Rate(n)) if n > 0.0 && n.is_finite() => Ok(pace.unwrap()),
+        _ => Err(format!(
+            "Expected a speed like 1x or a message rate like 100000/s, got {}",
+            value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{missing_capture_times, parse_pace, Pace, Replay, Timestamps};
+    use crate::iex::IexPayload;
+    use crate::sample;
+
+    /// An IEX payload sent `send_offset` nanoseconds after the sample payloads
+    /// are, and captured at `capture_time`, with `msg_count` messages.
+    fn payload(msg_count: usize, send_offset: i64, capture_time: u64) -> IexPayload {
+        let messages: Vec<Vec<u8>> = sample::every_message_type(b"ZIEXT   ", 0)
+            .into_iter()
+            .cycle()
+            .take(msg_count)
+            .collect();
+        let (_, mut payload) = IexPayload::parse(&sample::iex_payload(0, &messages)).unwrap();
+        payload.send_time += send_offset;
+        payload.capture_time = Some(capture_time);
+        payload
+    }
+
+    #[test]
+    fn parse_paces() {
+        assert!(matches!(parse_pace("1x"), Ok(Pace::Speed(s)) if s == 1.0));
+        assert!(matches!(parse_pace("2.5x"), Ok(Pace::Speed(s)) if s == 2.5));
+        assert!(matches!(parse_pace("1000/s"), Ok(Pace::Rate(r)) if r == 1000.0));
+
+        for garbage in &[
+            "", "x", "/s", "1", "fast", "1 x", "0x", "-1x", "0/s", "infx", "NaN/s",
+        ] {
+            assert!(parse_pace(garbage).is_err(), "{}", garbage);
+        }
+    }
+
+    #[test]
+    fn speed_scales_the_gaps_between_timestamps() {
+        let replay = Replay {
+            pace: Pace::Speed(1000.0),
+            timestamps: Timestamps::SendTime,
+        };
+        let mut pacer = replay.pacer();
+        assert_eq!(pacer.wait(&payload(1, 0, 0)), Duration::from_nanos(0));
+        assert_eq!(
+            pacer.wait(&payload(1, 2_000_000, 0)),
+            Duration::from_micros(2)
+        );
+        assert_eq!(
+            pacer.wait(&payload(1, 5_000_000, 0)),
+            Duration::from_micros(5)
+        );
+        // Out of order, so it goes out straight away
+        assert_eq!(
+            pacer.wait(&payload(1, -1_000_000, 0)),
+            Duration::from_nanos(0)
+        );
+
+        let replay = Replay {
+            pace: Pace::Speed(0.5),
+            timestamps: Timestamps::CaptureTime,
+        };
+        let mut pacer = replay.pacer();
+        // Send times are ignored
+        assert_eq!(pacer.wait(&payload(1, 0, 1_000)), Duration::from_nanos(0));
+        assert_eq!(pacer.wait(&payload(1, 0, 2_000)), Duration::from_micros(2));
+    }
+
+    #[test]
+    fn rate_spaces_out_messages() {
+        let replay = Replay {
+            pace: Pace::Rate(1_000_000.0),
+            timestamps: Timestamps::SendTime,
+        };
+        let mut pacer = replay.pacer();
+        // Each payload waits for the messages before it, whatever its timestamps
+        assert_eq!(pacer.wait(&payload(3, 0, 0)), Duration::from_micros(0));
+        assert_eq!(pacer.wait(&payload(2, 0, 0)), Duration::from_micros(3));
+        assert_eq!(
+            pacer.wait(&payload(1, 9_000_000, 0)),
+            Duration::from_micros(5)
+        );
+    }
+
+    #[test]
+    fn wait_until_waits_for_the_deadline() {
+        let replay = Replay {
+            pace: Pace::Rate(1.0),
+            timestamps: Timestamps::SendTime,
+        };
+        let mut pacer = replay.pacer();
+        let start = Instant::now();
+        pacer.wait_until(Duration::from_millis(0));
+        pacer.wait_until(Duration::from_millis(2));
+        assert!(start.elapsed() >= Duration::from_millis(2));
+    }
+
+    #[test]
+    fn capture_times_need_an_interface_description() {
+        let pcap = sample::pcap(&[sample::iex_payload(0, &[])]);
+        assert!(missing_capture_times(&pcap));
+
+        // Ethernet interface with no options, so microsecond timestamps
+        let mut described = vec![1, 0, 0, 0, 20, 0, 0, 0, 1, 0, 0, 0, 0xff, 0xff, 0, 0];
+        described.extend_from_slice(&20u32.to_le_bytes());
+        described.extend_from_slice(&pcap);
+        assert!(!missing_capture_times(&described));
+    }
+}
@@ -0,0 +1,435 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use crate::alloc;
+use crate::clock::Timing;
+use crate::pipeline::{Pipeline, PipelineStats};
+use crate::replay::Replay;
+use crate::RunAnalysis;
+
+/// How benchmark results get printed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    /// Human-readable summary; the only format that reports statistics across iterations.
+    Text,
+    /// One JSON object per line, one line per measured iteration.
+    Json,
+    /// Comma-separated values with a header row, one row per measured iteration.
+    Csv,
+}
+
+/// Where and how the benchmark was run, attached to every result record.
+pub struct RunMetadata {
+    input_file: String,
+    /// `YYYYMMDD` date of the IEX data, taken from the file name
+    /// (`data_feeds_20190903_20190903_IEXTP1_DEEP1.0.pcap`)
+    run_date: String,
+    host: String,
+    cpu: String,
+    /// CPUs the process is allowed to run on, e.g. after `taskset`
+    cpus_allowed: String,
+    /// Niceness and scheduling policy, e.g. after `nice` or `chrt`
+    nice: String,
+    sched_policy: String,
+    /// Real-time priority, which is 0 unless the policy is `fifo` or `rr`
+    sched_priority: String,
+    /// How much memory is locked with `mlock`, e.g. after `--lock-memory`
+    locked_memory: String,
+    /// Where the producer and consumer ran with `--pipeline`, or `off`
+    pipeline: String,
+    /// How payloads were paced with `--replay`, or `off`
+    replay: String,
+    write_mode: String,
+    git_revision: &'static str,
+    clock: &'static str,
+    /// Whether the clock timed each message, rather than only whole runs
+    per_message: bool,
+    /// Measured cost of reading the clock, and how much of it gets subtracted
+    /// from each per-message timing
+    clock_overhead_nanos: u64,
+    clock_correction_nanos: u64,
+}
+
+impl RunMetadata {
+    pub fn collect(
+        input_file: &Path,
+        write_mode: &str,
+        timing: &Timing,
+        clock_overhead_nanos: u64,
+        pipeline: Option<Pipeline>,
+        replay: Option<Replay>,
+    ) -> RunMetadata {
+        // Fields 19, 40 and 41 of `/proc/self/stat`; the command name (field 2) is in
+        // parentheses and can contain spaces, so start counting after it.
+        let stat = fs::read_to_string("/proc/self/stat").unwrap_or_default();
+        let stat_fields: Vec<&str> = match stat.rfind(')') {
+            Some(end) => stat[end + 1..].split_whitespace().collect(),
+            None => Vec::new(),
+        };
+        let stat_field = |field: usize| stat_fields.get(field - 3).map(|f| f.to_string());
+
+        RunMetadata {
+            input_file: input_file.display().to_string(),
+            run_date: input_file
+                .file_name()
+                .map(|name| name.to_string_lossy())
+                .and_then(|name| {
+                    name.split('_')
+                        .find(|part| part.len() == 8 && part.bytes().all(|b| b.is_ascii_digit()))
+                        .map(|part| part.to_string())
+                })
+                .unwrap_or_default(),
+            host: fs::read_to_string("/proc/sys/kernel/hostname")
+                .map(|host| host.trim().to_string())
+                .or_else(|_| env::var("HOSTNAME"))
+                .unwrap_or_else(|_| "unknown".to_string()),
+            cpu: proc_value("/proc/cpuinfo", "model name").unwrap_or_else(|| "unknown".to_string()),
+            cpus_allowed: proc_value("/proc/self/status", "Cpus_allowed_list")
+                .unwrap_or_else(|| "unknown".to_string()),
+            nice: stat_field(19).unwrap_or_else(|| "unknown".to_string()),
+            sched_policy: match stat_field(41).as_deref() {
+                Some("0") => "normal",
+                Some("1") => "fifo",
+                Some("2") => "rr",
+                Some("3") => "batch",
+                Some("5") => "idle",
+                Some("6") => "deadline",
+                _ => "unknown",
+            }
+            .to_string(),
+            sched_priority: stat_field(40).unwrap_or_else(|| "unknown".to_string()),
+            locked_memory: proc_value("/proc/self/status", "VmLck")
+                .unwrap_or_else(|| "unknown".to_string()),
+            pipeline: pipeline
+                .map(|p| p.description())
+                .unwrap_or_else(|| "off".to_string()),
+            replay: replay
+                .map(|r| r.description())
+                .unwrap_or_else(|| "off".to_string()),
+            write_mode: write_mode.to_string(),
+            git_revision: env!("GIT_REVISION"),
+            clock: timing.clock.name(),
+            per_message: timing.clock.per_message(),
+            clock_overhead_nanos,
+            clock_correction_nanos: timing.correction_nanos,
+        }
+    }
+
+    /// Which clock timed each message, and what it costs.
+    pub fn clock_description(&self) -> String {
+        format!(
+            "{} (overhead={}ns, subtracted={}ns)",
+            self.clock, self.clock_overhead_nanos, self.clock_correction_nanos
+        )
+    }
+
+    /// Where and how the benchmark is being scheduled, as the kernel sees it.
+    pub fn scheduling_description(&self) -> String {
+        format!(
+            "cpus={} nice={} policy={} priority={} locked={}",
+            self.cpus_allowed,
+            self.nice,
+            self.sched_policy,
+            self.sched_priority,
+            self.locked_memory
+        )
+    }
+
+    /// Where the producer and consumer ran with `--pipeline`, or `off`.
+    pub fn pipeline(&self) -> &str {
+        &self.pipeline
+    }
+
+    /// How payloads were paced with `--replay`, or `off`.
+    pub fn replay(&self) -> &str {
+        &self.replay
+    }
+
+    /// Date of the IEX data, or `None` if the file name doesn't include one.
+    pub fn run_date(&self) -> Option<&str> {
+        if self.run_date.is_empty() {
+            None
+        } else {
+            Some(&self.run_date)
+        }
+    }
+
+    /// Every metadata field as `(name, value)`, named the same as their columns.
+    pub fn fields(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("input_file", &self.input_file),
+            ("run_date", &self.run_date),
+            ("host", &self.host),
+            ("cpu", &self.cpu),
+            ("cpus_allowed", &self.cpus_allowed),
+            ("nice", &self.nice),
+            ("sched_policy", &self.sched_policy),
+            ("write_mode", &self.write_mode),
+            ("git_revision", self.git_revision),
+        ]
+    }
+}
+
+/// Find the first `key: value` line in a `/proc` file.
+fn proc_value(path: &str, key: &str) -> Option<String> {
+    let contents = fs::read_to_string(path).ok()?;
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            Some((parts.next()?.trim(), parts.next()?.trim()))
+        })
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v.to_string())
+}
+
+enum Field {
+    Text(String),
+    Number(String),
+    /// Not measured in this build or by this clock; `null` in JSON and empty in CSV
+    Missing,
+}
+
+/// Column names, in the order they're written. Changing these breaks anything
+/// reading old results, so only ever add to the end.
+const COLUMNS: &[&str] = &[
+    "input_file",
+    "run_date",
+    "host",
+    "cpu",
+    "cpus_allowed",
+    "nice",
+    "sched_policy",
+    "write_mode",
+    "git_revision",
+    "format",
+    "protocol",
+    "iteration",
+    "total_secs",
+    "serialize_50_nanos",
+    "serialize_99_nanos",
+    "serialize_999_nanos",
+    "deserialize_50_nanos",
+    "deserialize_99_nanos",
+    "deserialize_999_nanos",
+    "serialize_total_nanos",
+    "deserialize_total_nanos",
+    "write_len_bytes",
+    "serialize_min_nanos",
+    "serialize_9999_nanos",
+    "serialize_max_nanos",
+    "deserialize_min_nanos",
+    "deserialize_9999_nanos",
+    "deserialize_max_nanos",
+    "clock",
+    "clock_overhead_nanos",
+    "clock_correction_nanos",
+    "messages",
+    "bytes_per_message",
+    "serialize_msgs_per_sec",
+    "deserialize_msgs_per_sec",
+    "serialize_per_msg_50_nanos",
+    "serialize_per_msg_99_nanos",
+    "serialize_per_msg_999_nanos",
+    "deserialize_per_msg_50_nanos",
+    "deserialize_per_msg_99_nanos",
+    "deserialize_per_msg_999_nanos",
+    "serialize_allocations",
+    "serialize_deallocations",
+    "serialize_alloc_bytes",
+    "deserialize_allocations",
+    "deserialize_deallocations",
+    "deserialize_alloc_bytes",
+    "sched_priority",
+    "locked_memory",
+    "pipeline",
+    "one_way_50_nanos",
+    "one_way_99_nanos",
+    "one_way_999_nanos",
+    "pipeline_msgs_per_sec",
+    "ring_full_waits",
+    "dropped_packets",
+    "oversized_packets",
+    "undecodable_packets",
+    "replay",
+];
+
+/// Columns holding measurements, rather than describing the run.
+pub fn metric_columns() -> Vec<&'static str> {
+    COLUMNS
+        .iter()
+        .cloned()
+        .filter(|c| !DESCRIPTIVE_COLUMNS.contains(c))
+        .collect()
+}
+
+/// Columns describing the run, rather than holding measurements.
+const DESCRIPTIVE_COLUMNS: &[&str] = &[
+    "input_file",
+    "run_date",
+    "host",
+    "cpu",
+    "cpus_allowed",
+    "nice",
+    "sched_policy",
+    "write_mode",
+    "git_revision",
+    "format",
+    "protocol",
+    "iteration",
+    "clock",
+    "sched_priority",
+    "locked_memory",
+    "pipeline",
+    "replay",
+];
+
+/// Header row to print before any CSV records.
+pub fn csv_header() -> String {
+    COLUMNS.join(",")
+}
+
+/// Format the results of a single iteration as a JSON or CSV record.
+pub fn record(
+    output_format: OutputFormat,
+    metadata: &RunMetadata,
+    format: &str,
+    protocol: &str,
+    iteration: usize,
+    total_secs: f64,
+    analysis: &RunAnalysis,
+) -> String {
+    let text = |s: &str| Field::Text(s.to_string());
+    let number = |n: &dyn ToString| Field::Number(n.to_string());
+    let counted = |n: u64| {
+        if alloc::ENABLED {
+            Field::Number(n.to_string())
+        } else {
+            Field::Missing
+        }
+    };
+    let timed = |n: u64| {
+        if metadata.per_message {
+            Field::Number(n.to_string())
+        } else {
+            Field::Missing
+        }
+    };
+    let pipelined = |f: &dyn Fn(&PipelineStats) -> String| match &analysis.pipeline {
+        Some(stats) => Field::Number(f(stats)),
+        None => Field::Missing,
+    };
+    let serialize_per_msg = &analysis.serialize_msg_hists.per_message;
+    let deserialize_per_msg = &analysis.deserialize_msg_hists.per_message;
+    let mut fields: Vec<Field> = metadata
+        .fields()
+        .into_iter()
+        .map(|(_, value)| text(value))
+        .collect();
+    fields.extend(vec![
+        text(format),
+        text(protocol),
+        number(&iteration),
+        number(&format!("{:.3}", total_secs)),
+        timed(analysis.serialize_hist.value_at_quantile(0.5)),
+        timed(analysis.serialize_hist.value_at_quantile(0.99)),
+        timed(analysis.serialize_hist.value_at_quantile(0.999)),
+        timed(analysis.deserialize_hist.value_at_quantile(0.5)),
+        timed(analysis.deserialize_hist.value_at_quantile(0.99)),
+        timed(analysis.deserialize_hist.value_at_quantile(0.999)),
+        number(&analysis.serialize_total_nanos),
+        number(&analysis.deserialize_total_nanos),
+        number(&analysis.buf_len),
+        timed(analysis.serialize_hist.min()),
+        timed(analysis.serialize_hist.value_at_quantile(0.9999)),
+        timed(analysis.serialize_hist.max()),
+        timed(analysis.deserialize_hist.min()),
+        timed(analysis.deserialize_hist.value_at_quantile(0.9999)),
+        timed(analysis.deserialize_hist.max()),
+        text(metadata.clock),
+        number(&metadata.clock_overhead_nanos),
+        number(&metadata.clock_correction_nanos),
+        number(&analysis.messages),
+        number(&format!("{:.3}", analysis.bytes_per_message())),
+        number(&format!("{:.0}", analysis.serialize_msgs_per_sec())),
+        number(&format!("{:.0}", analysis.deserialize_msgs_per_sec())),
+        timed(serialize_per_msg.value_at_quantile(0.5)),
+        timed(serialize_per_msg.value_at_quantile(0.99)),
+        timed(serialize_per_msg.value_at_quantile(0.999)),
+        timed(deserialize_per_msg.value_at_quantile(0.5)),
+        timed(deserialize_per_msg.value_at_quantile(0.99)),
+        timed(deserialize_per_msg.value_at_quantile(0.999)),
+        counted(analysis.serialize_allocs.allocations),
+        counted(analysis.serialize_allocs.deallocations),
+        counted(analysis.serialize_allocs.bytes_allocated),
+        counted(analysis.deserialize_allocs.allocations),
+        counted(analysis.deserialize_allocs.deallocations),
+        counted(analysis.deserialize_allocs.bytes_allocated),
+        text(&metadata.sched_priority),
+        text(&metadata.locked_memory),
+        text(&metadata.pipeline),
+        pipelined(&|s| s.one_way_hist.value_at_quantile(0.5).to_string()),
+        pipelined(&|s| s.one_way_hist.value_at_quantile(0.99).to_string()),
+        pipelined(&|s| s.one_way_hist.value_at_quantile(0.999).to_string()),
+        pipelined(&|s| format!("{:.0}", s.msgs_per_sec())),
+        pipelined(&|s| s.full_waits.to_string()),
+        pipelined(&|s| s.dropped.to_string()),
+        pipelined(&|s| s.oversized.to_string()),
+        pipelined(&|s| s.undecodable.to_string()),
+        text(&metadata.replay),
+    ]);
+
+    match output_format {
+        OutputFormat::Json => {
+            let members: Vec<String> = COLUMNS
+                .iter()
+                .zip(fields.iter())
+                .map(|(column, field)| match field {
+                    Field::Text(s) => format!("{}:{}", json_string(column), json_string(s)),
+                    Field::Number(n) => format!("{}:{}", json_string(column), n),
+                    Field::Missing => format!("{}:null", json_string(column)),
+                })
+                .collect();
+            format!("{{{}}}", members.join(","))
+        }
+        OutputFormat::Csv => {
+            let values: Vec<String> = fields
+                .iter()
+                .map(|field| match field {
+                    Field::Text(s) => csv_string(s),
+                    Field::Number(n) => n.clone(),
+                    Field::Missing => String::new(),
+                })
+                .collect();
+            values.join(",")
+        }
+        OutputFormat::Text => panic!("Text output doesn't use records"),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_string(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
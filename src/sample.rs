@@ -0,0 +1,148 @@
+//! IEX DEEP data built by hand for tests, in the same shape as the real captures.
+
+/// One message of every IEX DEEP type, each with its length prefix, and with
+/// field values derived from `n` so that different calls give different messages.
+pub fn every_message_type(symbol: &[u8; 8], n: u64) -> Vec<Vec<u8>> {
+    let timestamp = 1_567_468_800_000_000_000i64 + n as i64;
+    let header = |msg_type: u8, flags: u8| {
+        let mut message = vec![msg_type, flags];
+        message.extend_from_slice(&timestamp.to_le_bytes());
+        message
+    };
+
+    let system_event = header(0x53, b'R');
+
+    let mut security_directory = header(0x44, 0x80);
+    security_directory.extend_from_slice(symbol);
+    security_directory.extend_from_slice(&100u32.to_le_bytes());
+    security_directory.extend_from_slice(&(1_000_000 + n).to_le_bytes());
+    security_directory.push(1);
+
+    let mut trading_status = header(0x48, b'T');
+    trading_status.extend_from_slice(symbol);
+    trading_status.extend_from_slice(b"T1  ");
+
+    let mut operational_halt_status = header(0x4f, b'N');
+    operational_halt_status.extend_from_slice(symbol);
+
+    let mut short_sale_price_test = header(0x50, 1);
+    short_sale_price_test.extend_from_slice(symbol);
+    short_sale_price_test.push(b'A');
+
+    let mut security_event = header(0x45, b'O');
+    security_event.extend_from_slice(symbol);
+
+    let mut buy_update = header(0x38, 1);
+    buy_update.extend_from_slice(symbol);
+    buy_update.extend_from_slice(&(200 + n as u32).to_le_bytes());
+    buy_update.extend_from_slice(&(1_499_000 + n).to_le_bytes());
+
+    let mut sell_update = header(0x35, 0);
+    sell_update.extend_from_slice(symbol);
+    sell_update.extend_from_slice(&(300 + n as u32).to_le_bytes());
+    sell_update.extend_from_slice(&(1_501_000 + n).to_le_bytes());
+
+    let mut trade_report = header(0x54, 0x40);
+    trade_report.extend_from_slice(symbol);
+    trade_report.extend_from_slice(&(100 + n as u32).to_le_bytes());
+    trade_report.extend_from_slice(&(1_500_000 + n).to_le_bytes());
+    trade_report.extend_from_slice(&(10_000 + n).to_le_bytes());
+
+    let mut official_price = header(0x58, b'Q');
+    official_price.extend_from_slice(symbol);
+    official_price.extend_from_slice(&(1_500_500 + n).to_le_bytes());
+
+    let mut trade_break = header(0x42, 0x40);
+    trade_break.extend_from_slice(symbol);
+    trade_break.extend_from_slice(&(100 + n as u32).to_le_bytes());
+    trade_break.extend_from_slice(&(1_500_000 + n).to_le_bytes());
+    trade_break.extend_from_slice(&(10_000 + n).to_le_bytes());
+
+    let mut auction_information = header(0x41, b'C');
+    auction_information.extend_from_slice(symbol);
+    auction_information.extend_from_slice(&(5_000 + n as u32).to_le_bytes());
+    auction_information.extend_from_slice(&(1_500_100 + n).to_le_bytes());
+    auction_information.extend_from_slice(&(1_500_200 + n).to_le_bytes());
+    auction_information.extend_from_slice(&(700 + n as u32).to_le_bytes());
+    auction_information.push(b'B');
+    auction_information.push(1);
+    auction_information.extend_from_slice(&1_567_526_400u32.to_le_bytes());
+    auction_information.extend_from_slice(&(1_500_300 + n).to_le_bytes());
+    auction_information.extend_from_slice(&(1_500_400 + n).to_le_bytes());
+    auction_information.extend_from_slice(&(1_400_000 + n).to_le_bytes());
+    auction_information.extend_from_slice(&(1_600_000 + n).to_le_bytes());
+
+    vec![
+        system_event,
+        security_directory,
+        trading_status,
+        operational_halt_status,
+        short_sale_price_test,
+        security_event,
+        buy_update,
+        sell_update,
+        trade_report,
+        official_price,
+        trade_break,
+        auction_information,
+    ]
+    .into_iter()
+    .map(|message| {
+        let mut prefixed = (message.len() as u16).to_le_bytes().to_vec();
+        prefixed.extend_from_slice(&message);
+        prefixed
+    })
+    .collect()
+}
+
+/// An IEX-TP payload holding `messages`, as made by `every_message_type`.
+pub fn iex_payload(first_seq_no: u64, messages: &[Vec<u8>]) -> Vec<u8> {
+    let messages_len: usize = messages.iter().map(|m| m.len()).sum();
+
+    let mut iex = vec![1, 0];
+    iex.extend_from_slice(&0x8004u16.to_le_bytes());
+    iex.extend_from_slice(&1u32.to_le_bytes());
+    iex.extend_from_slice(&0x4200_0000u32.to_le_bytes());
+    iex.extend_from_slice(&(messages_len as u16).to_le_bytes());
+    iex.extend_from_slice(&(messages.len() as u16).to_le_bytes());
+    iex.extend_from_slice(&0u64.to_le_bytes());
+    iex.extend_from_slice(&first_seq_no.to_le_bytes());
+    iex.extend_from_slice(&1_567_468_800_000_000_000i64.to_le_bytes());
+    for message in messages {
+        iex.extend_from_slice(message);
+    }
+    iex
+}
+
+/// A pcapng capture with one packet for each of `payloads`, as made by
+/// `iex_payload`.
+pub fn pcap(payloads: &[Vec<u8>]) -> Vec<u8> {
+    let mut pcap = Vec::new();
+    for iex in payloads {
+        // Ethernet, then a 20-byte IPv4 header, then UDP
+        let mut packet_data = vec![0; 12];
+        packet_data.extend_from_slice(&[0x08, 0x00, 0x45]);
+        packet_data.extend_from_slice(&[0; 19]);
+        packet_data.extend_from_slice(&[0; 4]);
+        packet_data.extend_from_slice(&(iex.len() as u16 + 8).to_be_bytes());
+        packet_data.extend_from_slice(&[0; 2]);
+        packet_data.extend_from_slice(iex);
+
+        // Enhanced packet block
+        let block_len = 28 + packet_data.len() as u32 + 4;
+        for field in &[
+            6,
+            block_len,
+            0,
+            0,
+            0,
+            packet_data.len() as u32,
+            packet_data.len() as u32,
+        ] {
+            pcap.extend_from_slice(&field.to_le_bytes());
+        }
+        pcap.extend_from_slice(&packet_data);
+        pcap.extend_from_slice(&block_len.to_le_bytes());
+    }
+    pcap
+}
@@ -0,0 +1,490 @@
+use std::mem::size_of;
+use std::slice;
+use std::str::from_utf8_unchecked;
+
+use crate::framing::{SelfDelimiting, StreamBuf};
+use crate::iex::{IexMessage, IexPayload};
+use crate::marketdata_sbe_chained::{
+    start_decoding_multi_message, start_encoding_multi_message, AuctionInformation, MessageStart,
+    MsgType, MultiMessageFields, MultiMessageMessageHeader, OfficialPrice, OperationalHaltStatus,
+    Quote, SecurityDirectory, SecurityEvent, ShortSalePriceTest, Side, SymbolLength, SystemEvent,
+    Trade, TradingStatus,
+};
+use crate::verify::{NormalizedBody, NormalizedMessage};
+use crate::{RunnerDeserialize, RunnerSerialize, Summarizer, WriteMode};
+
+/// SBE runner where the schema only describes the individual blocks of a message,
+/// and we chain them together by hand:
+///
+/// ```text
+/// MultiMessage -> (MessageStart -> (Trade | Quote | ...) -> [SymbolLength -> symbol bytes])*
+/// ```
+///
+/// System events aren't tied to a symbol, so they're the only messages without one.
+///
+/// The generated code doesn't know anything about the chain, so we're responsible
+/// for making sure blocks are read in the same order they were written.
+pub struct SBEChainedWriter {
+    scratch_buffer: Vec<u8>,
+    default_header: MultiMessageMessageHeader,
+    write_mode: WriteMode,
+}
+
+impl SBEChainedWriter {
+    pub fn new(write_mode: WriteMode) -> SBEChainedWriter {
+        SBEChainedWriter {
+            // Same sizing as `SBEWriter`
+            scratch_buffer: vec![0; 1024 * 32],
+            default_header: MultiMessageMessageHeader::default(),
+            write_mode,
+        }
+    }
+}
+
+/// Upper bound on the encoded size of a single chained message (message start,
+/// message body block, and an 8-character symbol), with room to spare.
+/// The widest body is `AuctionInformation`, at 63 bytes.
+const MAX_MESSAGE_BYTES: usize = 128;
+
+impl RunnerSerialize for SBEChainedWriter {
+    fn serialize(&mut self, payload: &IexPayload, output: &mut Vec<u8>) {
+        match self.write_mode {
+            WriteMode::Scratch => {
+                let data_len =
+                    encode_chain(&self.default_header, payload, &mut self.scratch_buffer[..])
+                        .unwrap();
+                output.extend_from_slice(&self.scratch_buffer[..data_len]);
+            }
+            WriteMode::InPlace => {
+                let start = output.len();
+                output.resize(start + (payload.messages.len() + 1) * MAX_MESSAGE_BYTES, 0);
+                let data_len =
+                    encode_chain(&self.default_header, payload, &mut output[start..]).unwrap();
+                output.truncate(start + data_len);
+            }
+        }
+    }
+}
+
+fn encode_chain(
+    default_header: &MultiMessageMessageHeader,
+    payload: &IexPayload,
+    buffer: &mut [u8],
+) -> Result<usize, ()> {
+    let multimsg = MultiMessageFields {
+        sequence_number: payload.first_seq_no,
+        message_count: payload.messages.len() as u16,
+    };
+    let header_len = start_encoding_multi_message(buffer)
+        .header_copy(&default_header.message_header)
+        .map_err(|_| ())?
+        .multi_message_fields_copy(&multimsg)
+        .map_err(|_| ())?
+        .unwrap();
+
+    let mut encoder = ChainEncoder {
+        data: buffer,
+        pos: header_len,
+    };
+    for iex_msg in payload.messages.iter() {
+        let sym = match iex_msg {
+            IexMessage::SystemEvent(se) => {
+                encoder.block(&MessageStart {
+                    timestamp: se.timestamp,
+                    msg_type: MsgType::SystemEvent,
+                })?;
+                encoder.block(&SystemEvent {
+                    system_event: se.system_event,
+                })?;
+                continue;
+            }
+            IexMessage::SecurityDirectory(sd) => {
+                encoder.block(&MessageStart {
+                    timestamp: sd.timestamp,
+                    msg_type: MsgType::SecurityDirectory,
+                })?;
+                encoder.block(&SecurityDirectory {
+                    flags: sd.flags,
+                    lot_size: sd.lot_size,
+                    previous_closing: sd.previous_closing,
+                    luld_tier: sd.luld_tier,
+                })?;
+                crate::parse_symbol(&sd.symbol)
+            }
+            IexMessage::TradingStatus(ts) => {
+                encoder.block(&MessageStart {
+                    timestamp: ts.timestamp,
+                    msg_type: MsgType::TradingStatus,
+                })?;
+                encoder.block(&TradingStatus {
+                    trading_status: ts.trading_status,
+                    reason: ts.reason,
+                })?;
+                crate::parse_symbol(&ts.symbol)
+            }
+            IexMessage::OperationalHaltStatus(ohs) => {
+                encoder.block(&MessageStart {
+                    timestamp: ohs.timestamp,
+                    msg_type: MsgType::OperationalHaltStatus,
+                })?;
+                encoder.block(&OperationalHaltStatus {
+                    halt_status: ohs.halt_status,
+                })?;
+                crate::parse_symbol(&ohs.symbol)
+            }
+            IexMessage::ShortSalePriceTest(sspt) => {
+                encoder.block(&MessageStart {
+                    timestamp: sspt.timestamp,
+                    msg_type: MsgType::ShortSalePriceTest,
+                })?;
+                encoder.block(&ShortSalePriceTest {
+                    sspt_status: sspt.sspt_status,
+                    detail: sspt.detail,
+                })?;
+                crate::parse_symbol(&sspt.symbol)
+            }
+            IexMessage::SecurityEvent(se) => {
+                encoder.block(&MessageStart {
+                    timestamp: se.timestamp,
+                    msg_type: MsgType::SecurityEvent,
+                })?;
+                encoder.block(&SecurityEvent {
+                    security_event: se.security_event,
+                })?;
+                crate::parse_symbol(&se.symbol)
+            }
+            IexMessage::PriceLevelUpdate(plu) => {
+                encoder.block(&MessageStart {
+                    timestamp: plu.timestamp,
+                    msg_type: MsgType::Quote,
+                })?;
+                encoder.block(&Quote {
+                    price: plu.price,
+                    size: plu.size,
+                    flags: plu.event_flags,
+                    side: if plu.msg_type == 0x38 {
+                        Side::Buy
+                    } else {
+                        Side::Sell
+                    },
+                })?;
+                crate::parse_symbol(&plu.symbol)
+            }
+            IexMessage::TradeReport(tr) => {
+                encoder.block(&MessageStart {
+                    timestamp: tr.timestamp,
+                    msg_type: MsgType::Trade,
+                })?;
+                encoder.block(&Trade {
+                    price: tr.price,
+                    size: tr.size,
+                    sale_condition: tr.sale_condition,
+                    trade_id: tr.trade_id,
+                })?;
+                crate::parse_symbol(&tr.symbol)
+            }
+            IexMessage::OfficialPrice(op) => {
+                encoder.block(&MessageStart {
+                    timestamp: op.timestamp,
+                    msg_type: MsgType::OfficialPrice,
+                })?;
+                encoder.block(&OfficialPrice {
+                    price_type: op.price_type,
+                    official_price: op.official_price,
+                })?;
+                crate::parse_symbol(&op.symbol)
+            }
+            IexMessage::TradeBreak(tb) => {
+                encoder.block(&MessageStart {
+                    timestamp: tb.timestamp,
+                    msg_type: MsgType::TradeBreak,
+                })?;
+                encoder.block(&Trade {
+                    price: tb.price,
+                    size: tb.size,
+                    sale_condition: tb.sale_condition,
+                    trade_id: tb.trade_id,
+                })?;
+                crate::parse_symbol(&tb.symbol)
+            }
+            IexMessage::AuctionInformation(ai) => {
+                encoder.block(&MessageStart {
+                    timestamp: ai.timestamp,
+                    msg_type: MsgType::AuctionInformation,
+                })?;
+                encoder.block(&AuctionInformation {
+                    auction_type: ai.auction_type,
+                    paired_shares: ai.paired_shares,
+                    reference_price: ai.reference_price,
+                    indicative_clearing_price: ai.indicative_clearing_price,
+                    imbalance_shares: ai.imbalance_shares,
+                    imbalance_side: ai.imbalance_side,
+                    extension_number: ai.extension_number,
+                    scheduled_auction: ai.scheduled_auction,
+                    auction_book_clearing_price: ai.auction_book_clearing_price,
+                    collar_reference_price: ai.collar_reference_price,
+                    lower_auction_collar: ai.lower_auction_collar,
+                    upper_auction_collar: ai.upper_auction_collar,
+                })?;
+                crate::parse_symbol(&ai.symbol)
+            }
+        };
+
+        encoder.block(&SymbolLength {
+            length: sym.len() as u32,
+        })?;
+        encoder.bytes(sym.as_bytes())?;
+    }
+
+    Ok(encoder.pos)
+}
+
+/// Writes SBE blocks back-to-back. Generated composites are `#[repr(C, packed)]`,
+/// so their in-memory layout is exactly their wire format.
+struct ChainEncoder<'d> {
+    data: &'d mut [u8],
+    pos: usize,
+}
+
+impl<'d> ChainEncoder<'d> {
+    fn block<T>(&mut self, block: &T) -> Result<(), ()> {
+        let bytes =
+            unsafe { slice::from_raw_parts(block as *const T as *const u8, size_of::<T>()) };
+        self.bytes(bytes)
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) -> Result<(), ()> {
+        let end = self.pos + bytes.len();
+        self.data
+            .get_mut(self.pos..end)
+            .ok_or(())?
+            .copy_from_slice(bytes);
+        self.pos = end;
+        Ok(())
+    }
+}
+
+/// Reads SBE blocks back-to-back; the counterpart to `ChainEncoder`.
+struct ChainDecoder<'d> {
+    data: &'d [u8],
+    pos: usize,
+}
+
+impl<'d> ChainDecoder<'d> {
+    fn block<T>(&mut self) -> Result<&'d T, ()> {
+        // Packed structs have an alignment of 1, so we can point straight into the buffer
+        let bytes = self.bytes(size_of::<T>())?;
+        Ok(unsafe { &*(bytes.as_ptr() as *const T) })
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'d [u8], ()> {
+        let end = self.pos + len;
+        let bytes = self.data.get(self.pos..end).ok_or(())?;
+        self.pos = end;
+        Ok(bytes)
+    }
+}
+
+pub struct SBEChainedReader;
+
+impl SBEChainedReader {
+    pub fn new() -> SBEChainedReader {
+        SBEChainedReader {}
+    }
+}
+
+impl RunnerDeserialize for SBEChainedReader {
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_multi_message(data).header().unwrap();
+            let (fields, decoder) = decoder.multi_message_fields().unwrap();
+            let message_count = fields.message_count;
+
+            let mut decoder = ChainDecoder {
+                data,
+                pos: decoder.unwrap(),
+            };
+            for _ in 0..message_count {
+                let start = decoder.block::<MessageStart>()?;
+                match start.msg_type {
+                    MsgType::Trade => {
+                        let trade = decoder.block::<Trade>()?;
+                        let sym_len = decoder.block::<SymbolLength>()?.length;
+                        let sym = decoder.bytes(sym_len as usize)?;
+                        stats.append_trade_volume(
+                            unsafe { from_utf8_unchecked(sym) },
+                            trade.size as u64,
+                        );
+                    }
+                    MsgType::Quote => {
+                        let quote = decoder.block::<Quote>()?;
+                        let sym_len = decoder.block::<SymbolLength>()?.length;
+                        let sym = decoder.bytes(sym_len as usize)?;
+                        stats.update_quote_prices(
+                            unsafe { from_utf8_unchecked(sym) },
+                            quote.price,
+                            matches!(quote.side, Side::Buy),
+                        );
+                    }
+                    MsgType::SystemEvent => {
+                        decoder.block::<SystemEvent>()?;
+                    }
+                    msg_type => {
+                        // The summary only needs trades and quotes, so everything else is skipped
+                        let body_len = match msg_type {
+                            MsgType::SecurityDirectory => size_of::<SecurityDirectory>(),
+                            MsgType::TradingStatus => size_of::<TradingStatus>(),
+                            MsgType::OperationalHaltStatus => size_of::<OperationalHaltStatus>(),
+                            MsgType::ShortSalePriceTest => size_of::<ShortSalePriceTest>(),
+                            MsgType::SecurityEvent => size_of::<SecurityEvent>(),
+                            MsgType::OfficialPrice => size_of::<OfficialPrice>(),
+                            MsgType::TradeBreak => size_of::<Trade>(),
+                            MsgType::AuctionInformation => size_of::<AuctionInformation>(),
+                            _ => panic!("Unrecognized message type"),
+                        };
+                        decoder.bytes(body_len)?;
+                        let sym_len = decoder.block::<SymbolLength>()?.length;
+                        decoder.bytes(sym_len as usize)?;
+                    }
+                }
+            }
+
+            let msg_len = decoder.pos;
+            Ok(((), msg_len))
+        })
+    }
+    fn decode(
+        &self,
+        buf: &mut StreamBuf,
+        messages: &mut Vec<NormalizedMessage>,
+    ) -> Result<u64, ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_multi_message(data)
+                .header()
+                .map_err(|_| ())?;
+            let (fields, decoder) = decoder.multi_message_fields().map_err(|_| ())?;
+            let seq_no = fields.sequence_number;
+            let message_count = fields.message_count;
+
+            let mut decoder = ChainDecoder {
+                data,
+                pos: decoder.unwrap(),
+            };
+            for _ in 0..message_count {
+                let start = decoder.block::<MessageStart>()?;
+                let body = match start.msg_type {
+                    MsgType::SystemEvent => {
+                        let se = decoder.block::<SystemEvent>()?;
+                        messages.push(NormalizedMessage {
+                            timestamp: start.timestamp,
+                            symbol: String::new(),
+                            body: NormalizedBody::SystemEvent {
+                                system_event: se.system_event,
+                            },
+                        });
+                        // No symbol to read
+                        continue;
+                    }
+                    MsgType::SecurityDirectory => {
+                        let sd = decoder.block::<SecurityDirectory>()?;
+                        NormalizedBody::SecurityDirectory {
+                            flags: sd.flags,
+                            lot_size: sd.lot_size,
+                            previous_closing: sd.previous_closing,
+                            luld_tier: sd.luld_tier,
+                        }
+                    }
+                    MsgType::TradingStatus => {
+                        let ts = decoder.block::<TradingStatus>()?;
+                        NormalizedBody::TradingStatus {
+                            trading_status: ts.trading_status,
+                            reason: ts.reason,
+                        }
+                    }
+                    MsgType::OperationalHaltStatus => {
+                        let ohs = decoder.block::<OperationalHaltStatus>()?;
+                        NormalizedBody::OperationalHaltStatus {
+                            halt_status: ohs.halt_status,
+                        }
+                    }
+                    MsgType::ShortSalePriceTest => {
+                        let sspt = decoder.block::<ShortSalePriceTest>()?;
+                        NormalizedBody::ShortSalePriceTest {
+                            sspt_status: sspt.sspt_status,
+                            detail: sspt.detail,
+                        }
+                    }
+                    MsgType::SecurityEvent => {
+                        let se = decoder.block::<SecurityEvent>()?;
+                        NormalizedBody::SecurityEvent {
+                            security_event: se.security_event,
+                        }
+                    }
+                    MsgType::Quote => {
+                        let quote = decoder.block::<Quote>()?;
+                        NormalizedBody::PriceLevelUpdate {
+                            is_buy: matches!(quote.side, Side::Buy),
+                            event_flags: quote.flags,
+                            size: quote.size,
+                            price: quote.price,
+                        }
+                    }
+                    MsgType::Trade => {
+                        let trade = decoder.block::<Trade>()?;
+                        NormalizedBody::TradeReport {
+                            sale_condition: trade.sale_condition,
+                            size: trade.size,
+                            price: trade.price,
+                            trade_id: trade.trade_id,
+                        }
+                    }
+                    MsgType::OfficialPrice => {
+                        let op = decoder.block::<OfficialPrice>()?;
+                        NormalizedBody::OfficialPrice {
+                            price_type: op.price_type,
+                            official_price: op.official_price,
+                        }
+                    }
+                    MsgType::TradeBreak => {
+                        let tb = decoder.block::<Trade>()?;
+                        NormalizedBody::TradeBreak {
+                            sale_condition: tb.sale_condition,
+                            size: tb.size,
+                            price: tb.price,
+                            trade_id: tb.trade_id,
+                        }
+                    }
+                    MsgType::AuctionInformation => {
+                        let ai = decoder.block::<AuctionInformation>()?;
+                        NormalizedBody::AuctionInformation {
+                            auction_type: ai.auction_type,
+                            paired_shares: ai.paired_shares,
+                            reference_price: ai.reference_price,
+                            indicative_clearing_price: ai.indicative_clearing_price,
+                            imbalance_shares: ai.imbalance_shares,
+                            imbalance_side: ai.imbalance_side,
+                            extension_number: ai.extension_number,
+                            scheduled_auction: ai.scheduled_auction,
+                            auction_book_clearing_price: ai.auction_book_clearing_price,
+                            collar_reference_price: ai.collar_reference_price,
+                            lower_auction_collar: ai.lower_auction_collar,
+                            upper_auction_collar: ai.upper_auction_collar,
+                        }
+                    }
+                    MsgType::NullVal => return Err(()),
+                };
+
+                let sym_len = decoder.block::<SymbolLength>()?.length;
+                let sym = decoder.bytes(sym_len as usize)?;
+                messages.push(NormalizedMessage {
+                    timestamp: start.timestamp,
+                    symbol: unsafe { from_utf8_unchecked(sym) }.to_string(),
+                    body,
+                });
+            }
+
+            let msg_len = decoder.pos;
+            Ok((seq_no, msg_len))
+        })
+    }
+}
@@ -0,0 +1,619 @@
+use std::str::from_utf8_unchecked;
+
+use crate::framing::{SelfDelimiting, StreamBuf};
+use crate::iex::{IexMessage, IexPayload};
+use crate::marketdata_sbe_groups::{
+    start_decoding_multi_message, start_encoding_multi_message, AuctionInformation, Either,
+    MultiMessageAuctionInformationsMember, MultiMessageAuctionInformationsMemberEncoder,
+    MultiMessageMessageHeader, MultiMessageOfficialPricesMember,
+    MultiMessageOfficialPricesMemberEncoder, MultiMessageOperationalHaltStatusesMember,
+    MultiMessageOperationalHaltStatusesMemberEncoder, MultiMessageQuotesMember,
+    MultiMessageQuotesMemberEncoder, MultiMessageSecurityDirectoriesMember,
+    MultiMessageSecurityDirectoriesMemberEncoder, MultiMessageSecurityEventsMember,
+    MultiMessageSecurityEventsMemberEncoder, MultiMessageShortSalePriceTestsMember,
+    MultiMessageShortSalePriceTestsMemberEncoder, MultiMessageSystemEventsMember,
+    MultiMessageSystemEventsMemberEncoder, MultiMessageTradeBreaksMember,
+    MultiMessageTradeBreaksMemberEncoder, MultiMessageTradesMember,
+    MultiMessageTradesMemberEncoder, MultiMessageTradingStatusesMember,
+    MultiMessageTradingStatusesMemberEncoder, OfficialPrice, OperationalHaltStatus, Quote,
+    SecurityDirectory, SecurityEvent, ShortSalePriceTest, Side, SystemEvent, Trade, TradingStatus,
+};
+use crate::verify::{NormalizedBody, NormalizedMessage};
+use crate::{RunnerDeserialize, RunnerSerialize, Summarizer, WriteMode};
+
+/// SBE runner that gives each message type its own repeating group,
+/// instead of reserving space for all of them in every group entry.
+pub struct SBEGroupsWriter {
+    scratch_buffer: Vec<u8>,
+    default_header: MultiMessageMessageHeader,
+    write_mode: WriteMode,
+}
+
+impl SBEGroupsWriter {
+    pub fn new(write_mode: WriteMode) -> SBEGroupsWriter {
+        SBEGroupsWriter {
+            // Same sizing as `SBEWriter`
+            scratch_buffer: vec![0; 1024 * 32],
+            default_header: MultiMessageMessageHeader::default(),
+            write_mode,
+        }
+    }
+}
+
+/// Upper bound on the encoded size of a single group member (fixed fields,
+/// plus the length prefix and contents of an 8-character symbol), with room to spare.
+/// The widest member is in `auction_informations`, at 83 bytes.
+const MAX_MEMBER_BYTES: usize = 128;
+
+impl RunnerSerialize for SBEGroupsWriter {
+    fn serialize(&mut self, payload: &IexPayload, output: &mut Vec<u8>) {
+        match self.write_mode {
+            WriteMode::Scratch => {
+                let data_len = encode_multi_message(
+                    &self.default_header,
+                    payload,
+                    &mut self.scratch_buffer[..],
+                );
+                output.extend_from_slice(&self.scratch_buffer[..data_len]);
+            }
+            WriteMode::InPlace => {
+                let start = output.len();
+                output.resize(start + (payload.messages.len() + 1) * MAX_MEMBER_BYTES, 0);
+                let data_len =
+                    encode_multi_message(&self.default_header, payload, &mut output[start..]);
+                output.truncate(start + data_len);
+            }
+        }
+    }
+}
+
+/// Write every message of one type into its group; groups have to be written
+/// in order, so each group is a separate pass over the payload.
+macro_rules! encode_group {
+    ($encoder:expr, $payload:expr, $next_member:ident, $variant:ident($msg:ident) => $member:expr) => {
+        $payload.messages.iter().fold($encoder, |enc, m| match m {
+            IexMessage::$variant($msg) => enc
+                .$next_member(&$member)
+                .unwrap()
+                .symbol(crate::parse_symbol(&$msg.symbol).as_bytes())
+                .unwrap(),
+            _ => enc,
+        })
+    };
+}
+
+fn encode_multi_message(
+    default_header: &MultiMessageMessageHeader,
+    payload: &IexPayload,
+    buffer: &mut [u8],
+) -> usize {
+    let (fields, encoder) = start_encoding_multi_message(buffer)
+        .header_copy(&default_header.message_header)
+        .unwrap()
+        .multi_message_fields()
+        .unwrap();
+    fields.sequence_number = payload.first_seq_no;
+
+    let encoder = encoder.trades_individually().unwrap();
+    let encoder: MultiMessageTradesMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_trades_member,
+        TradeReport(tr) => MultiMessageTradesMember {
+            timestamp: tr.timestamp,
+            trade: Trade {
+                price: tr.price,
+                size: tr.size,
+                sale_condition: tr.sale_condition,
+                trade_id: tr.trade_id,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_trades()
+        .unwrap()
+        .quotes_individually()
+        .unwrap();
+    let encoder: MultiMessageQuotesMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_quotes_member,
+        PriceLevelUpdate(plu) => MultiMessageQuotesMember {
+            timestamp: plu.timestamp,
+            quote: Quote {
+                price: plu.price,
+                size: plu.size,
+                flags: plu.event_flags,
+                side: if plu.msg_type == 0x38 {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                },
+            },
+        }
+    );
+
+    // System events aren't tied to a symbol, so their group doesn't have one
+    let encoder = encoder
+        .done_with_quotes()
+        .unwrap()
+        .system_events_individually()
+        .unwrap();
+    let encoder: MultiMessageSystemEventsMemberEncoder =
+        payload.messages.iter().fold(encoder, |enc, m| match m {
+            IexMessage::SystemEvent(se) => {
+                let member = MultiMessageSystemEventsMember {
+                    timestamp: se.timestamp,
+                    system_event: SystemEvent {
+                        system_event: se.system_event,
+                    },
+                };
+                enc.next_system_events_member(&member).unwrap()
+            }
+            _ => enc,
+        });
+
+    let encoder = encoder
+        .done_with_system_events()
+        .unwrap()
+        .security_directories_individually()
+        .unwrap();
+    let encoder: MultiMessageSecurityDirectoriesMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_security_directories_member,
+        SecurityDirectory(sd) => MultiMessageSecurityDirectoriesMember {
+            timestamp: sd.timestamp,
+            security_directory: SecurityDirectory {
+                flags: sd.flags,
+                lot_size: sd.lot_size,
+                previous_closing: sd.previous_closing,
+                luld_tier: sd.luld_tier,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_security_directories()
+        .unwrap()
+        .trading_statuses_individually()
+        .unwrap();
+    let encoder: MultiMessageTradingStatusesMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_trading_statuses_member,
+        TradingStatus(ts) => MultiMessageTradingStatusesMember {
+            timestamp: ts.timestamp,
+            trading_status: TradingStatus {
+                trading_status: ts.trading_status,
+                reason: ts.reason,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_trading_statuses()
+        .unwrap()
+        .operational_halt_statuses_individually()
+        .unwrap();
+    let encoder: MultiMessageOperationalHaltStatusesMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_operational_halt_statuses_member,
+        OperationalHaltStatus(ohs) => MultiMessageOperationalHaltStatusesMember {
+            timestamp: ohs.timestamp,
+            operational_halt_status: OperationalHaltStatus {
+                halt_status: ohs.halt_status,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_operational_halt_statuses()
+        .unwrap()
+        .short_sale_price_tests_individually()
+        .unwrap();
+    let encoder: MultiMessageShortSalePriceTestsMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_short_sale_price_tests_member,
+        ShortSalePriceTest(sspt) => MultiMessageShortSalePriceTestsMember {
+            timestamp: sspt.timestamp,
+            short_sale_price_test: ShortSalePriceTest {
+                sspt_status: sspt.sspt_status,
+                detail: sspt.detail,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_short_sale_price_tests()
+        .unwrap()
+        .security_events_individually()
+        .unwrap();
+    let encoder: MultiMessageSecurityEventsMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_security_events_member,
+        SecurityEvent(se) => MultiMessageSecurityEventsMember {
+            timestamp: se.timestamp,
+            security_event: SecurityEvent {
+                security_event: se.security_event,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_security_events()
+        .unwrap()
+        .official_prices_individually()
+        .unwrap();
+    let encoder: MultiMessageOfficialPricesMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_official_prices_member,
+        OfficialPrice(op) => MultiMessageOfficialPricesMember {
+            timestamp: op.timestamp,
+            official_price: OfficialPrice {
+                price_type: op.price_type,
+                official_price: op.official_price,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_official_prices()
+        .unwrap()
+        .trade_breaks_individually()
+        .unwrap();
+    let encoder: MultiMessageTradeBreaksMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_trade_breaks_member,
+        TradeBreak(tb) => MultiMessageTradeBreaksMember {
+            timestamp: tb.timestamp,
+            trade_break: Trade {
+                price: tb.price,
+                size: tb.size,
+                sale_condition: tb.sale_condition,
+                trade_id: tb.trade_id,
+            },
+        }
+    );
+
+    let encoder = encoder
+        .done_with_trade_breaks()
+        .unwrap()
+        .auction_informations_individually()
+        .unwrap();
+    let encoder: MultiMessageAuctionInformationsMemberEncoder = encode_group!(
+        encoder,
+        payload,
+        next_auction_informations_member,
+        AuctionInformation(ai) => MultiMessageAuctionInformationsMember {
+            timestamp: ai.timestamp,
+            auction_information: AuctionInformation {
+                auction_type: ai.auction_type,
+                paired_shares: ai.paired_shares,
+                reference_price: ai.reference_price,
+                indicative_clearing_price: ai.indicative_clearing_price,
+                imbalance_shares: ai.imbalance_shares,
+                imbalance_side: ai.imbalance_side,
+                extension_number: ai.extension_number,
+                scheduled_auction: ai.scheduled_auction,
+                auction_book_clearing_price: ai.auction_book_clearing_price,
+                collar_reference_price: ai.collar_reference_price,
+                lower_auction_collar: ai.lower_auction_collar,
+                upper_auction_collar: ai.upper_auction_collar,
+            },
+        }
+    );
+
+    let finished = encoder.done_with_auction_informations().unwrap();
+    finished.unwrap()
+}
+
+/// Read past a group that the summary doesn't care about, returning the
+/// decoder for whatever comes next.
+macro_rules! skip_group {
+    ($decoder:expr, $individually:ident, $next_member:ident) => {{
+        let mut decoder = $decoder.$individually().unwrap();
+        while let Either::Left(member) = decoder {
+            let (_member, sym_dec) = member.$next_member().unwrap();
+            let (_sym, next_dec) = sym_dec.symbol().unwrap();
+            decoder = next_dec;
+        }
+        match decoder {
+            Either::Right(r) => r,
+            _ => panic!("Didn't parse all group members"),
+        }
+    }};
+}
+
+/// Decode every member of a group into `messages`, using `$body` to build each
+/// message body from its group member. Returns the decoder for whatever comes next.
+macro_rules! decode_group {
+    ($decoder:expr, $individually:ident, $next_member:ident, $messages:ident, |$member:ident| $body:expr) => {{
+        let mut decoder = $decoder.$individually().map_err(|_| ())?;
+        while let Either::Left(group) = decoder {
+            let ($member, sym_dec) = group.$next_member().map_err(|_| ())?;
+            let (sym, next_dec) = sym_dec.symbol().map_err(|_| ())?;
+            $messages.push(NormalizedMessage {
+                timestamp: $member.timestamp,
+                symbol: unsafe { from_utf8_unchecked(sym) }.to_string(),
+                body: $body,
+            });
+            decoder = next_dec;
+        }
+        match decoder {
+            Either::Right(r) => r,
+            _ => panic!("Didn't parse all group members"),
+        }
+    }};
+}
+
+pub struct SBEGroupsReader;
+
+impl SBEGroupsReader {
+    pub fn new() -> SBEGroupsReader {
+        SBEGroupsReader {}
+    }
+}
+
+impl RunnerDeserialize for SBEGroupsReader {
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_multi_message(data).header().unwrap();
+
+            let (_fields, decoder) = decoder.multi_message_fields().unwrap();
+            let mut trade_decoder = decoder.trades_individually().unwrap();
+            while let Either::Left(trade) = trade_decoder {
+                let (member, sym_dec) = trade.next_trades_member().unwrap();
+                let (sym, next_trade_dec) = sym_dec.symbol().unwrap();
+                stats.append_trade_volume(
+                    unsafe { from_utf8_unchecked(sym) },
+                    member.trade.size as u64,
+                );
+                trade_decoder = next_trade_dec;
+            }
+
+            let quotes_decoder = match trade_decoder {
+                Either::Right(r) => r,
+                _ => panic!("Didn't parse all trades"),
+            };
+            let mut quote_decoder = quotes_decoder.quotes_individually().unwrap();
+            while let Either::Left(quote) = quote_decoder {
+                let (member, sym_dec) = quote.next_quotes_member().unwrap();
+                let (sym, next_quote_dec) = sym_dec.symbol().unwrap();
+                stats.update_quote_prices(
+                    unsafe { from_utf8_unchecked(sym) },
+                    member.quote.price,
+                    matches!(member.quote.side, Side::Buy),
+                );
+                quote_decoder = next_quote_dec;
+            }
+
+            let system_events_decoder = match quote_decoder {
+                Either::Right(r) => r,
+                _ => panic!("Didn't parse all quotes"),
+            };
+            let mut system_event_decoder =
+                system_events_decoder.system_events_individually().unwrap();
+            while let Either::Left(system_event) = system_event_decoder {
+                let (_member, next_system_event_dec) =
+                    system_event.next_system_events_member().unwrap();
+                system_event_decoder = next_system_event_dec;
+            }
+            let decoder = match system_event_decoder {
+                Either::Right(r) => r,
+                _ => panic!("Didn't parse all system events"),
+            };
+
+            let decoder = skip_group!(
+                decoder,
+                security_directories_individually,
+                next_security_directories_member
+            );
+            let decoder = skip_group!(
+                decoder,
+                trading_statuses_individually,
+                next_trading_statuses_member
+            );
+            let decoder = skip_group!(
+                decoder,
+                operational_halt_statuses_individually,
+                next_operational_halt_statuses_member
+            );
+            let decoder = skip_group!(
+                decoder,
+                short_sale_price_tests_individually,
+                next_short_sale_price_tests_member
+            );
+            let decoder = skip_group!(
+                decoder,
+                security_events_individually,
+                next_security_events_member
+            );
+            let decoder = skip_group!(
+                decoder,
+                official_prices_individually,
+                next_official_prices_member
+            );
+            let decoder = skip_group!(decoder, trade_breaks_individually, next_trade_breaks_member);
+            let decoder = skip_group!(
+                decoder,
+                auction_informations_individually,
+                next_auction_informations_member
+            );
+
+            let msg_len = decoder.unwrap();
+            Ok(((), msg_len))
+        })
+    }
+    fn decode(
+        &self,
+        buf: &mut StreamBuf,
+        messages: &mut Vec<NormalizedMessage>,
+    ) -> Result<u64, ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_multi_message(data)
+                .header()
+                .map_err(|_| ())?;
+
+            let (fields, decoder) = decoder.multi_message_fields().map_err(|_| ())?;
+            let seq_no = fields.sequence_number;
+            let decoder = decode_group!(
+                decoder,
+                trades_individually,
+                next_trades_member,
+                messages,
+                |m| {
+                    NormalizedBody::TradeReport {
+                        sale_condition: m.trade.sale_condition,
+                        size: m.trade.size,
+                        price: m.trade.price,
+                        trade_id: m.trade.trade_id,
+                    }
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                quotes_individually,
+                next_quotes_member,
+                messages,
+                |m| {
+                    NormalizedBody::PriceLevelUpdate {
+                        is_buy: matches!(m.quote.side, Side::Buy),
+                        event_flags: m.quote.flags,
+                        size: m.quote.size,
+                        price: m.quote.price,
+                    }
+                }
+            );
+
+            // System events don't have a symbol, so they can't go through `decode_group!`
+            let mut system_event_decoder = decoder.system_events_individually().map_err(|_| ())?;
+            while let Either::Left(system_event) = system_event_decoder {
+                let (member, next_system_event_dec) =
+                    system_event.next_system_events_member().map_err(|_| ())?;
+                messages.push(NormalizedMessage {
+                    timestamp: member.timestamp,
+                    symbol: String::new(),
+                    body: NormalizedBody::SystemEvent {
+                        system_event: member.system_event.system_event,
+                    },
+                });
+                system_event_decoder = next_system_event_dec;
+            }
+            let decoder = match system_event_decoder {
+                Either::Right(r) => r,
+                _ => panic!("Didn't parse all system events"),
+            };
+
+            let decoder = decode_group!(
+                decoder,
+                security_directories_individually,
+                next_security_directories_member,
+                messages,
+                |m| NormalizedBody::SecurityDirectory {
+                    flags: m.security_directory.flags,
+                    lot_size: m.security_directory.lot_size,
+                    previous_closing: m.security_directory.previous_closing,
+                    luld_tier: m.security_directory.luld_tier,
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                trading_statuses_individually,
+                next_trading_statuses_member,
+                messages,
+                |m| NormalizedBody::TradingStatus {
+                    trading_status: m.trading_status.trading_status,
+                    reason: m.trading_status.reason,
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                operational_halt_statuses_individually,
+                next_operational_halt_statuses_member,
+                messages,
+                |m| NormalizedBody::OperationalHaltStatus {
+                    halt_status: m.operational_halt_status.halt_status,
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                short_sale_price_tests_individually,
+                next_short_sale_price_tests_member,
+                messages,
+                |m| NormalizedBody::ShortSalePriceTest {
+                    sspt_status: m.short_sale_price_test.sspt_status,
+                    detail: m.short_sale_price_test.detail,
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                security_events_individually,
+                next_security_events_member,
+                messages,
+                |m| NormalizedBody::SecurityEvent {
+                    security_event: m.security_event.security_event,
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                official_prices_individually,
+                next_official_prices_member,
+                messages,
+                |m| NormalizedBody::OfficialPrice {
+                    price_type: m.official_price.price_type,
+                    official_price: m.official_price.official_price,
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                trade_breaks_individually,
+                next_trade_breaks_member,
+                messages,
+                |m| NormalizedBody::TradeBreak {
+                    sale_condition: m.trade_break.sale_condition,
+                    size: m.trade_break.size,
+                    price: m.trade_break.price,
+                    trade_id: m.trade_break.trade_id,
+                }
+            );
+            let decoder = decode_group!(
+                decoder,
+                auction_informations_individually,
+                next_auction_informations_member,
+                messages,
+                |m| {
+                    let ai = &m.auction_information;
+                    NormalizedBody::AuctionInformation {
+                        auction_type: ai.auction_type,
+                        paired_shares: ai.paired_shares,
+                        reference_price: ai.reference_price,
+                        indicative_clearing_price: ai.indicative_clearing_price,
+                        imbalance_shares: ai.imbalance_shares,
+                        imbalance_side: ai.imbalance_side,
+                        extension_number: ai.extension_number,
+                        scheduled_auction: ai.scheduled_auction,
+                        auction_book_clearing_price: ai.auction_book_clearing_price,
+                        collar_reference_price: ai.collar_reference_price,
+                        lower_auction_collar: ai.lower_auction_collar,
+                        upper_auction_collar: ai.upper_auction_collar,
+                    }
+                }
+            );
+
+            let msg_len = decoder.unwrap();
+            Ok((seq_no, msg_len))
+        })
+    }
+
+    fn preserves_order(&self) -> bool {
+        // Messages come back grouped by type
+        false
+    }
+}
@@ -1,66 +1,157 @@
-use std::io::{BufRead, Write};
 use std::str::from_utf8_unchecked;
 
+use crate::framing::{SelfDelimiting, StreamBuf};
 use crate::iex::{IexMessage, IexPayload};
 use crate::marketdata_sbe::{
-    start_decoding_multi_message, start_encoding_multi_message, Either, MultiMessageMessageHeader,
-    MultiMessageMessagesMember, MultiMessageMessagesMemberEncoder,
+    start_decoding_multi_message, start_encoding_multi_message, Either, MsgType,
+    MultiMessageMessageHeader, MultiMessageMessagesMember, MultiMessageMessagesMemberEncoder,
     MultiMessageMessagesSymbolEncoder, Side,
 };
-use crate::{marketdata_sbe, RunnerDeserialize, RunnerSerialize, StreamVec, Summarizer};
+use crate::verify::{NormalizedBody, NormalizedMessage};
+use crate::{marketdata_sbe, RunnerDeserialize, RunnerSerialize, Summarizer, WriteMode};
 
 pub struct SBEWriter {
-    /// Buffer to construct messages before copying. While SBE benefits
-    /// from easily being able to create messages directly in output buffer,
-    /// we'll construct in a scratch buffer and then copy to more fairly
-    /// benchmark against Cap'n Proto and Flatbuffers.
+    /// Buffer to construct messages before copying when using `WriteMode::Scratch`.
+    /// SBE benefits from easily being able to create messages directly in the
+    /// output buffer, so `WriteMode::InPlace` skips the copy entirely.
     scratch_buffer: Vec<u8>,
     default_header: MultiMessageMessageHeader,
+    write_mode: WriteMode,
 }
 
 impl SBEWriter {
-    pub fn new() -> SBEWriter {
+    pub fn new(write_mode: WriteMode) -> SBEWriter {
         SBEWriter {
-            // 8K scratch buffer is *way* more than necessary,
-            // but we don't want to run into issues with not enough
-            // data to encode messages
-            scratch_buffer: vec![0; 1024 * 8],
+            // Every group member reserves space for every message type (173 bytes with
+            // an 8-character symbol), and the smallest IEX messages take 12 bytes, so a
+            // full 1500-byte packet of them encodes to about 20K. 32K leaves room to spare.
+            scratch_buffer: vec![0; 1024 * 32],
             default_header: MultiMessageMessageHeader::default(),
+            write_mode,
         }
     }
 }
 
+/// Upper bound on the encoded size of a single group member (fixed fields for
+/// every message type, plus the length prefix and contents of an 8-character symbol),
+/// with room to spare.
+const MAX_MEMBER_BYTES: usize = 256;
+
 impl RunnerSerialize for SBEWriter {
     fn serialize(&mut self, payload: &IexPayload, output: &mut Vec<u8>) {
-        let (fields, encoder) = start_encoding_multi_message(&mut self.scratch_buffer[..])
-            .header_copy(&self.default_header.message_header)
-            .unwrap()
-            .multi_message_fields()
-            .unwrap();
-        fields.sequence_number = payload.first_seq_no;
-
-        let encoder = encoder.messages_individually().unwrap();
-        let encoder: MultiMessageMessagesMemberEncoder =
-            payload.messages.iter().fold(encoder, |enc, m| match m {
-                IexMessage::TradeReport(tr) => {
-                    let fields = MultiMessageMessagesMember {
-                        msg_type: marketdata_sbe::MsgType::Trade,
-                        timestamp: tr.timestamp,
-                        trade: marketdata_sbe::Trade {
-                            size: tr.size,
-                            price: tr.price,
+        match self.write_mode {
+            WriteMode::Scratch => {
+                let data_len = encode_multi_message(
+                    &self.default_header,
+                    payload,
+                    &mut self.scratch_buffer[..],
+                );
+                output.extend_from_slice(&self.scratch_buffer[..data_len]);
+            }
+            WriteMode::InPlace => {
+                // Unlike the scratch buffer, this space gets zeroed for every message,
+                // so only reserve as much as the payload could possibly need.
+                let start = output.len();
+                output.resize(start + (payload.messages.len() + 1) * MAX_MEMBER_BYTES, 0);
+                let data_len =
+                    encode_multi_message(&self.default_header, payload, &mut output[start..]);
+                output.truncate(start + data_len);
+            }
+        }
+    }
+}
+
+fn encode_multi_message(
+    default_header: &MultiMessageMessageHeader,
+    payload: &IexPayload,
+    buffer: &mut [u8],
+) -> usize {
+    let (fields, encoder) = start_encoding_multi_message(buffer)
+        .header_copy(&default_header.message_header)
+        .unwrap()
+        .multi_message_fields()
+        .unwrap();
+    fields.sequence_number = payload.first_seq_no;
+
+    let encoder = encoder.messages_individually().unwrap();
+    let encoder: MultiMessageMessagesMemberEncoder =
+        payload.messages.iter().fold(encoder, |enc, m| {
+            let (fields, sym) = match m {
+                IexMessage::SystemEvent(se) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::SystemEvent,
+                        timestamp: se.timestamp,
+                        system_event: marketdata_sbe::SystemEvent {
+                            system_event: se.system_event,
                         },
                         ..Default::default()
-                    };
-                    let sym_enc: MultiMessageMessagesSymbolEncoder =
-                        enc.next_messages_member(&fields).unwrap();
-                    sym_enc
-                        .symbol(crate::parse_symbol(&tr.symbol).as_bytes())
-                        .unwrap()
-                }
-                IexMessage::PriceLevelUpdate(plu) => {
-                    let fields = MultiMessageMessagesMember {
-                        msg_type: marketdata_sbe::MsgType::Quote,
+                    },
+                    // System events aren't tied to a symbol
+                    "",
+                ),
+                IexMessage::SecurityDirectory(sd) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::SecurityDirectory,
+                        timestamp: sd.timestamp,
+                        security_directory: marketdata_sbe::SecurityDirectory {
+                            flags: sd.flags,
+                            lot_size: sd.lot_size,
+                            previous_closing: sd.previous_closing,
+                            luld_tier: sd.luld_tier,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&sd.symbol),
+                ),
+                IexMessage::TradingStatus(ts) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::TradingStatus,
+                        timestamp: ts.timestamp,
+                        trading_status: marketdata_sbe::TradingStatus {
+                            trading_status: ts.trading_status,
+                            reason: ts.reason,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&ts.symbol),
+                ),
+                IexMessage::OperationalHaltStatus(ohs) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::OperationalHaltStatus,
+                        timestamp: ohs.timestamp,
+                        operational_halt_status: marketdata_sbe::OperationalHaltStatus {
+                            halt_status: ohs.halt_status,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&ohs.symbol),
+                ),
+                IexMessage::ShortSalePriceTest(sspt) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::ShortSalePriceTest,
+                        timestamp: sspt.timestamp,
+                        short_sale_price_test: marketdata_sbe::ShortSalePriceTest {
+                            sspt_status: sspt.sspt_status,
+                            detail: sspt.detail,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&sspt.symbol),
+                ),
+                IexMessage::SecurityEvent(se) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::SecurityEvent,
+                        timestamp: se.timestamp,
+                        security_event: marketdata_sbe::SecurityEvent {
+                            security_event: se.security_event,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&se.symbol),
+                ),
+                IexMessage::PriceLevelUpdate(plu) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::Quote,
                         timestamp: plu.timestamp,
                         quote: marketdata_sbe::Quote {
                             price: plu.price,
@@ -73,21 +164,80 @@ impl RunnerSerialize for SBEWriter {
                             },
                         },
                         ..Default::default()
-                    };
-                    let sym_enc: MultiMessageMessagesSymbolEncoder =
-                        enc.next_messages_member(&fields).unwrap();
-                    sym_enc
-                        .symbol(crate::parse_symbol(&plu.symbol).as_bytes())
-                        .unwrap()
-                }
-                _ => enc,
-            });
+                    },
+                    crate::parse_symbol(&plu.symbol),
+                ),
+                IexMessage::TradeReport(tr) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::Trade,
+                        timestamp: tr.timestamp,
+                        trade: marketdata_sbe::Trade {
+                            size: tr.size,
+                            price: tr.price,
+                            sale_condition: tr.sale_condition,
+                            trade_id: tr.trade_id,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&tr.symbol),
+                ),
+                IexMessage::OfficialPrice(op) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::OfficialPrice,
+                        timestamp: op.timestamp,
+                        official_price: marketdata_sbe::OfficialPrice {
+                            price_type: op.price_type,
+                            official_price: op.official_price,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&op.symbol),
+                ),
+                IexMessage::TradeBreak(tb) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::TradeBreak,
+                        timestamp: tb.timestamp,
+                        trade_break: marketdata_sbe::Trade {
+                            size: tb.size,
+                            price: tb.price,
+                            sale_condition: tb.sale_condition,
+                            trade_id: tb.trade_id,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&tb.symbol),
+                ),
+                IexMessage::AuctionInformation(ai) => (
+                    MultiMessageMessagesMember {
+                        msg_type: MsgType::AuctionInformation,
+                        timestamp: ai.timestamp,
+                        auction_information: marketdata_sbe::AuctionInformation {
+                            auction_type: ai.auction_type,
+                            paired_shares: ai.paired_shares,
+                            reference_price: ai.reference_price,
+                            indicative_clearing_price: ai.indicative_clearing_price,
+                            imbalance_shares: ai.imbalance_shares,
+                            imbalance_side: ai.imbalance_side,
+                            extension_number: ai.extension_number,
+                            scheduled_auction: ai.scheduled_auction,
+                            auction_book_clearing_price: ai.auction_book_clearing_price,
+                            collar_reference_price: ai.collar_reference_price,
+                            lower_auction_collar: ai.lower_auction_collar,
+                            upper_auction_collar: ai.upper_auction_collar,
+                        },
+                        ..Default::default()
+                    },
+                    crate::parse_symbol(&ai.symbol),
+                ),
+            };
 
-        let finished = encoder.done_with_messages().unwrap();
-        let data_len = finished.unwrap();
+            let sym_enc: MultiMessageMessagesSymbolEncoder =
+                enc.next_messages_member(&fields).unwrap();
+            sym_enc.symbol(sym.as_bytes()).unwrap()
+        });
 
-        output.write(&self.scratch_buffer[..data_len]).unwrap();
-    }
+    let finished = encoder.done_with_messages().unwrap();
+    finished.unwrap()
 }
 
 pub struct SBEReader;
@@ -99,50 +249,163 @@ impl SBEReader {
 }
 
 impl RunnerDeserialize for SBEReader {
-    fn deserialize<'a>(&self, buf: &'a mut StreamVec, stats: &mut Summarizer) -> Result<(), ()> {
-        let data = buf.fill_buf().unwrap();
-        if data.len() == 0 {
-            return Err(());
-        }
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_multi_message(data).header().unwrap();
 
-        let (_header, decoder) = start_decoding_multi_message(data).header().unwrap();
-
-        let (_fields, decoder) = decoder.multi_message_fields().unwrap();
-        let mut msg_decoder = decoder.messages_individually().unwrap();
-        while let Either::Left(msg) = msg_decoder {
-            let (member, sym_dec) = msg.next_messages_member().unwrap();
-            let (sym, next_msg_dec) = sym_dec.symbol().unwrap();
-            match member.msg_type {
-                marketdata_sbe::MsgType::Trade => stats.append_trade_volume(
-                    unsafe { from_utf8_unchecked(sym) },
-                    member.trade.size as u64,
-                ),
-                marketdata_sbe::MsgType::Quote => stats.update_quote_prices(
-                    unsafe { from_utf8_unchecked(sym) },
-                    member.quote.price,
-                    match member.quote.side {
-                        Side::Buy => true,
-                        _ => false,
-                    },
-                ),
-                _ => (),
+            let (_fields, decoder) = decoder.multi_message_fields().unwrap();
+            let mut msg_decoder = decoder.messages_individually().unwrap();
+            while let Either::Left(msg) = msg_decoder {
+                let (member, sym_dec) = msg.next_messages_member().unwrap();
+                let (sym, next_msg_dec) = sym_dec.symbol().unwrap();
+                match member.msg_type {
+                    MsgType::Trade => stats.append_trade_volume(
+                        unsafe { from_utf8_unchecked(sym) },
+                        member.trade.size as u64,
+                    ),
+                    MsgType::Quote => stats.update_quote_prices(
+                        unsafe { from_utf8_unchecked(sym) },
+                        member.quote.price,
+                        matches!(member.quote.side, Side::Buy),
+                    ),
+                    _ => (),
+                }
+                msg_decoder = next_msg_dec;
             }
-            msg_decoder = next_msg_dec;
-        }
 
-        // We now have a `Right`, which is a finished messages block
-        let msg_decoder = match msg_decoder {
-            Either::Right(r) => r,
-            _ => panic!("Didn't parse all messages"),
-        };
-
-        // Interestingly enough, `buf.consume(msg_decoder.unwrap())` isn't OK,
-        // presumably something to do with when *precisely* the drop of `self`
-        // happens for `msg_decoder`. Leave it as two statments so that
-        // Rust is able to prove our immutable borrow of `data` ends in time
-        // to consume the buffer
-        let msg_len = msg_decoder.unwrap();
-        buf.consume(msg_len);
-        Ok(())
+            // We now have a `Right`, which is a finished messages block
+            let msg_decoder = match msg_decoder {
+                Either::Right(r) => r,
+                _ => panic!("Didn't parse all messages"),
+            };
+
+            let msg_len = msg_decoder.unwrap();
+            Ok(((), msg_len))
+        })
     }
+    fn decode(
+        &self,
+        buf: &mut StreamBuf,
+        messages: &mut Vec<NormalizedMessage>,
+    ) -> Result<u64, ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_multi_message(data)
+                .header()
+                .map_err(|_| ())?;
+
+            let (fields, decoder) = decoder.multi_message_fields().map_err(|_| ())?;
+            let seq_no = fields.sequence_number;
+            let mut msg_decoder = decoder.messages_individually().map_err(|_| ())?;
+            while let Either::Left(msg) = msg_decoder {
+                let (member, sym_dec) = msg.next_messages_member().map_err(|_| ())?;
+                let (sym, next_msg_dec) = sym_dec.symbol().map_err(|_| ())?;
+                messages.push(NormalizedMessage {
+                    timestamp: member.timestamp,
+                    symbol: unsafe { from_utf8_unchecked(sym) }.to_string(),
+                    body: normalize_member(member)?,
+                });
+                msg_decoder = next_msg_dec;
+            }
+
+            let msg_decoder = match msg_decoder {
+                Either::Right(r) => r,
+                _ => panic!("Didn't parse all messages"),
+            };
+
+            let msg_len = msg_decoder.unwrap();
+            Ok((seq_no, msg_len))
+        })
+    }
+}
+
+/// Pick out the body that `msg_type` says is in use; the others are all left at their defaults.
+fn normalize_member(member: &MultiMessageMessagesMember) -> Result<NormalizedBody, ()> {
+    let body = match member.msg_type {
+        MsgType::SystemEvent => NormalizedBody::SystemEvent {
+            system_event: member.system_event.system_event,
+        },
+        MsgType::SecurityDirectory => {
+            let sd = &member.security_directory;
+            NormalizedBody::SecurityDirectory {
+                flags: sd.flags,
+                lot_size: sd.lot_size,
+                previous_closing: sd.previous_closing,
+                luld_tier: sd.luld_tier,
+            }
+        }
+        MsgType::TradingStatus => {
+            let ts = &member.trading_status;
+            NormalizedBody::TradingStatus {
+                trading_status: ts.trading_status,
+                reason: ts.reason,
+            }
+        }
+        MsgType::OperationalHaltStatus => NormalizedBody::OperationalHaltStatus {
+            halt_status: member.operational_halt_status.halt_status,
+        },
+        MsgType::ShortSalePriceTest => {
+            let sspt = &member.short_sale_price_test;
+            NormalizedBody::ShortSalePriceTest {
+                sspt_status: sspt.sspt_status,
+                detail: sspt.detail,
+            }
+        }
+        MsgType::SecurityEvent => NormalizedBody::SecurityEvent {
+            security_event: member.security_event.security_event,
+        },
+        MsgType::Quote => {
+            let quote = &member.quote;
+            NormalizedBody::PriceLevelUpdate {
+                is_buy: matches!(quote.side, Side::Buy),
+                event_flags: quote.flags,
+                size: quote.size,
+                price: quote.price,
+            }
+        }
+        MsgType::Trade => {
+            let trade = &member.trade;
+            NormalizedBody::TradeReport {
+                sale_condition: trade.sale_condition,
+                size: trade.size,
+                price: trade.price,
+                trade_id: trade.trade_id,
+            }
+        }
+        MsgType::OfficialPrice => {
+            let op = &member.official_price;
+            NormalizedBody::OfficialPrice {
+                price_type: op.price_type,
+                official_price: op.official_price,
+            }
+        }
+        MsgType::TradeBreak => {
+            let tb = &member.trade_break;
+            NormalizedBody::TradeBreak {
+                sale_condition: tb.sale_condition,
+                size: tb.size,
+                price: tb.price,
+                trade_id: tb.trade_id,
+            }
+        }
+        MsgType::AuctionInformation => {
+            let ai = &member.auction_information;
+            NormalizedBody::AuctionInformation {
+                auction_type: ai.auction_type,
+                paired_shares: ai.paired_shares,
+                reference_price: ai.reference_price,
+                indicative_clearing_price: ai.indicative_clearing_price,
+                imbalance_shares: ai.imbalance_shares,
+                imbalance_side: ai.imbalance_side,
+                extension_number: ai.extension_number,
+                scheduled_auction: ai.scheduled_auction,
+                auction_book_clearing_price: ai.auction_book_clearing_price,
+                collar_reference_price: ai.collar_reference_price,
+                lower_auction_collar: ai.lower_auction_collar,
+                upper_auction_collar: ai.upper_auction_collar,
+            }
+        }
+        MsgType::NullVal => return Err(()),
+    };
+
+    Ok(body)
 }
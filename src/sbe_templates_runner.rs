@@ -0,0 +1,603 @@
+use std::str::from_utf8_unchecked;
+
+use crate::framing::{SelfDelimiting, StreamBuf};
+use crate::iex::{IexMessage, IexPayload};
+use crate::marketdata_sbe_templates::{
+    start_decoding_auction_information_message, start_decoding_message_header,
+    start_decoding_official_price_message, start_decoding_operational_halt_status_message,
+    start_decoding_payload_header, start_decoding_quote_message,
+    start_decoding_security_directory_message, start_decoding_security_event_message,
+    start_decoding_short_sale_price_test_message, start_decoding_system_event_message,
+    start_decoding_trade_break_message, start_decoding_trade_message,
+    start_decoding_trading_status_message, start_encoding_auction_information_message,
+    start_encoding_official_price_message, start_encoding_operational_halt_status_message,
+    start_encoding_payload_header, start_encoding_quote_message,
+    start_encoding_security_directory_message, start_encoding_security_event_message,
+    start_encoding_short_sale_price_test_message, start_encoding_system_event_message,
+    start_encoding_trade_break_message, start_encoding_trade_message,
+    start_encoding_trading_status_message, AuctionInformationMessageFields,
+    AuctionInformationMessageMessageHeader, OfficialPriceMessageFields,
+    OfficialPriceMessageMessageHeader, OperationalHaltStatusMessageFields,
+    OperationalHaltStatusMessageMessageHeader, PayloadHeaderMessageHeader, QuoteMessageFields,
+    QuoteMessageMessageHeader, Reason, SecurityDirectoryMessageFields,
+    SecurityDirectoryMessageMessageHeader, SecurityEventMessageFields,
+    SecurityEventMessageMessageHeader, ShortSalePriceTestMessageFields,
+    ShortSalePriceTestMessageMessageHeader, Side, SystemEventMessageFields,
+    SystemEventMessageMessageHeader, TradeBreakMessageFields, TradeBreakMessageMessageHeader,
+    TradeMessageFields, TradeMessageMessageHeader, TradingStatusMessageFields,
+    TradingStatusMessageMessageHeader,
+};
+use crate::verify::{NormalizedBody, NormalizedMessage};
+use crate::{RunnerDeserialize, RunnerSerialize, Summarizer, WriteMode};
+
+/// SBE runner that writes each IEX message as its own SBE message,
+/// using the templateId in the SBE header to tell them apart.
+pub struct SBETemplatesWriter {
+    scratch_buffer: Vec<u8>,
+    default_headers: DefaultHeaders,
+    write_mode: WriteMode,
+}
+
+struct DefaultHeaders {
+    payload: PayloadHeaderMessageHeader,
+    trade: TradeMessageMessageHeader,
+    quote: QuoteMessageMessageHeader,
+    system_event: SystemEventMessageMessageHeader,
+    security_directory: SecurityDirectoryMessageMessageHeader,
+    trading_status: TradingStatusMessageMessageHeader,
+    operational_halt_status: OperationalHaltStatusMessageMessageHeader,
+    short_sale_price_test: ShortSalePriceTestMessageMessageHeader,
+    security_event: SecurityEventMessageMessageHeader,
+    official_price: OfficialPriceMessageMessageHeader,
+    trade_break: TradeBreakMessageMessageHeader,
+    auction_information: AuctionInformationMessageMessageHeader,
+}
+
+impl SBETemplatesWriter {
+    pub fn new(write_mode: WriteMode) -> SBETemplatesWriter {
+        SBETemplatesWriter {
+            // Same sizing as `SBEWriter`
+            scratch_buffer: vec![0; 1024 * 32],
+            default_headers: DefaultHeaders {
+                payload: PayloadHeaderMessageHeader::default(),
+                trade: TradeMessageMessageHeader::default(),
+                quote: QuoteMessageMessageHeader::default(),
+                system_event: SystemEventMessageMessageHeader::default(),
+                security_directory: SecurityDirectoryMessageMessageHeader::default(),
+                trading_status: TradingStatusMessageMessageHeader::default(),
+                operational_halt_status: OperationalHaltStatusMessageMessageHeader::default(),
+                short_sale_price_test: ShortSalePriceTestMessageMessageHeader::default(),
+                security_event: SecurityEventMessageMessageHeader::default(),
+                official_price: OfficialPriceMessageMessageHeader::default(),
+                trade_break: TradeBreakMessageMessageHeader::default(),
+                auction_information: AuctionInformationMessageMessageHeader::default(),
+            },
+            write_mode,
+        }
+    }
+}
+
+/// Upper bound on the encoded size of a single message (header, fixed fields
+/// and an 8-character symbol), with room to spare. The widest message is
+/// `AuctionInformationMessage`, at 91 bytes.
+const MAX_MESSAGE_BYTES: usize = 128;
+
+impl RunnerSerialize for SBETemplatesWriter {
+    fn serialize(&mut self, payload: &IexPayload, output: &mut Vec<u8>) {
+        match self.write_mode {
+            WriteMode::Scratch => {
+                let data_len =
+                    encode_payload(&self.default_headers, payload, &mut self.scratch_buffer[..]);
+                output.extend_from_slice(&self.scratch_buffer[..data_len]);
+            }
+            WriteMode::InPlace => {
+                let start = output.len();
+                output.resize(start + (payload.messages.len() + 1) * MAX_MESSAGE_BYTES, 0);
+                let data_len = encode_payload(&self.default_headers, payload, &mut output[start..]);
+                output.truncate(start + data_len);
+            }
+        }
+    }
+}
+
+/// Every message type other than system events is encoded the same way: header,
+/// then fixed fields, then the symbol.
+macro_rules! encode_message {
+    ($buffer:expr, $start:ident, $header:expr, $fields_copy:ident, $fields:expr, $symbol:expr) => {
+        $start($buffer)
+            .header_copy(&$header.message_header)
+            .unwrap()
+            .$fields_copy(&$fields)
+            .unwrap()
+            .symbol(crate::parse_symbol($symbol).as_bytes())
+            .unwrap()
+            .unwrap()
+    };
+}
+
+fn encode_payload(headers: &DefaultHeaders, payload: &IexPayload, buffer: &mut [u8]) -> usize {
+    let (fields, encoder) = start_encoding_payload_header(buffer)
+        .header_copy(&headers.payload.message_header)
+        .unwrap()
+        .payload_header_fields()
+        .unwrap();
+    fields.sequence_number = payload.first_seq_no;
+    fields.message_count = payload.messages.len() as u16;
+    let mut data_len = encoder.unwrap();
+
+    for iex_msg in payload.messages.iter() {
+        let buffer = &mut buffer[data_len..];
+        data_len += match iex_msg {
+            IexMessage::SystemEvent(se) => {
+                // System events aren't tied to a symbol, so the message doesn't have one
+                let fields = SystemEventMessageFields {
+                    timestamp: se.timestamp,
+                    system_event: se.system_event,
+                };
+                start_encoding_system_event_message(buffer)
+                    .header_copy(&headers.system_event.message_header)
+                    .unwrap()
+                    .system_event_message_fields_copy(&fields)
+                    .unwrap()
+                    .unwrap()
+            }
+            IexMessage::SecurityDirectory(sd) => {
+                let fields = SecurityDirectoryMessageFields {
+                    timestamp: sd.timestamp,
+                    flags: sd.flags,
+                    lot_size: sd.lot_size,
+                    previous_closing: sd.previous_closing,
+                    luld_tier: sd.luld_tier,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_security_directory_message,
+                    headers.security_directory,
+                    security_directory_message_fields_copy,
+                    fields,
+                    &sd.symbol
+                )
+            }
+            IexMessage::TradingStatus(ts) => {
+                let fields = TradingStatusMessageFields {
+                    timestamp: ts.timestamp,
+                    trading_status: ts.trading_status,
+                    reason: Reason { code: ts.reason },
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_trading_status_message,
+                    headers.trading_status,
+                    trading_status_message_fields_copy,
+                    fields,
+                    &ts.symbol
+                )
+            }
+            IexMessage::OperationalHaltStatus(ohs) => {
+                let fields = OperationalHaltStatusMessageFields {
+                    timestamp: ohs.timestamp,
+                    halt_status: ohs.halt_status,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_operational_halt_status_message,
+                    headers.operational_halt_status,
+                    operational_halt_status_message_fields_copy,
+                    fields,
+                    &ohs.symbol
+                )
+            }
+            IexMessage::ShortSalePriceTest(sspt) => {
+                let fields = ShortSalePriceTestMessageFields {
+                    timestamp: sspt.timestamp,
+                    sspt_status: sspt.sspt_status,
+                    detail: sspt.detail,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_short_sale_price_test_message,
+                    headers.short_sale_price_test,
+                    short_sale_price_test_message_fields_copy,
+                    fields,
+                    &sspt.symbol
+                )
+            }
+            IexMessage::SecurityEvent(se) => {
+                let fields = SecurityEventMessageFields {
+                    timestamp: se.timestamp,
+                    security_event: se.security_event,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_security_event_message,
+                    headers.security_event,
+                    security_event_message_fields_copy,
+                    fields,
+                    &se.symbol
+                )
+            }
+            IexMessage::PriceLevelUpdate(plu) => {
+                let fields = QuoteMessageFields {
+                    timestamp: plu.timestamp,
+                    price: plu.price,
+                    size: plu.size,
+                    flags: plu.event_flags,
+                    side: if plu.msg_type == 0x38 {
+                        Side::Buy
+                    } else {
+                        Side::Sell
+                    },
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_quote_message,
+                    headers.quote,
+                    quote_message_fields_copy,
+                    fields,
+                    &plu.symbol
+                )
+            }
+            IexMessage::TradeReport(tr) => {
+                let fields = TradeMessageFields {
+                    timestamp: tr.timestamp,
+                    price: tr.price,
+                    size: tr.size,
+                    sale_condition: tr.sale_condition,
+                    trade_id: tr.trade_id,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_trade_message,
+                    headers.trade,
+                    trade_message_fields_copy,
+                    fields,
+                    &tr.symbol
+                )
+            }
+            IexMessage::OfficialPrice(op) => {
+                let fields = OfficialPriceMessageFields {
+                    timestamp: op.timestamp,
+                    price_type: op.price_type,
+                    official_price: op.official_price,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_official_price_message,
+                    headers.official_price,
+                    official_price_message_fields_copy,
+                    fields,
+                    &op.symbol
+                )
+            }
+            IexMessage::TradeBreak(tb) => {
+                let fields = TradeBreakMessageFields {
+                    timestamp: tb.timestamp,
+                    price: tb.price,
+                    size: tb.size,
+                    sale_condition: tb.sale_condition,
+                    trade_id: tb.trade_id,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_trade_break_message,
+                    headers.trade_break,
+                    trade_break_message_fields_copy,
+                    fields,
+                    &tb.symbol
+                )
+            }
+            IexMessage::AuctionInformation(ai) => {
+                let fields = AuctionInformationMessageFields {
+                    timestamp: ai.timestamp,
+                    auction_type: ai.auction_type,
+                    paired_shares: ai.paired_shares,
+                    reference_price: ai.reference_price,
+                    indicative_clearing_price: ai.indicative_clearing_price,
+                    imbalance_shares: ai.imbalance_shares,
+                    imbalance_side: ai.imbalance_side,
+                    extension_number: ai.extension_number,
+                    scheduled_auction: ai.scheduled_auction,
+                    auction_book_clearing_price: ai.auction_book_clearing_price,
+                    collar_reference_price: ai.collar_reference_price,
+                    lower_auction_collar: ai.lower_auction_collar,
+                    upper_auction_collar: ai.upper_auction_collar,
+                };
+                encode_message!(
+                    buffer,
+                    start_encoding_auction_information_message,
+                    headers.auction_information,
+                    auction_information_message_fields_copy,
+                    fields,
+                    &ai.symbol
+                )
+            }
+        };
+    }
+
+    data_len
+}
+
+/// Decode a message that the summary doesn't care about, returning its length
+macro_rules! skip_message {
+    ($data:expr, $start:ident, $fields:ident) => {{
+        let (_header, decoder) = $start($data).header().unwrap();
+        let (_fields, sym_dec) = decoder.$fields().unwrap();
+        let (_sym, done) = sym_dec.symbol().unwrap();
+        done.unwrap()
+    }};
+}
+
+/// Decode a message into `messages`, using `$body` to build the message body
+/// from its fields. Returns the message length.
+macro_rules! decode_message {
+    ($data:expr, $messages:ident, $start:ident, $fields:ident, |$f:ident| $body:expr) => {{
+        let (_header, decoder) = $start($data).header().map_err(|_| ())?;
+        let ($f, sym_dec) = decoder.$fields().map_err(|_| ())?;
+        let (sym, done) = sym_dec.symbol().map_err(|_| ())?;
+        $messages.push(NormalizedMessage {
+            timestamp: $f.timestamp,
+            symbol: unsafe { from_utf8_unchecked(sym) }.to_string(),
+            body: $body,
+        });
+        done.unwrap()
+    }};
+}
+
+pub struct SBETemplatesReader;
+
+impl SBETemplatesReader {
+    pub fn new() -> SBETemplatesReader {
+        SBETemplatesReader {}
+    }
+}
+
+impl RunnerDeserialize for SBETemplatesReader {
+    fn deserialize(&self, buf: &mut StreamBuf, stats: &mut Summarizer) -> Result<(), ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_payload_header(data).header().unwrap();
+            let (fields, decoder) = decoder.payload_header_fields().unwrap();
+            let message_count = fields.message_count;
+            let mut msg_len = decoder.unwrap();
+
+            for _ in 0..message_count {
+                // Peek at the header to find out which message comes next
+                let (header, _) = start_decoding_message_header(&data[msg_len..]).unwrap();
+                msg_len += match header.template_id {
+                    TradeMessageMessageHeader::TEMPLATE_ID => {
+                        let (_header, decoder) = start_decoding_trade_message(&data[msg_len..])
+                            .header()
+                            .unwrap();
+                        let (fields, sym_dec) = decoder.trade_message_fields().unwrap();
+                        let (sym, done) = sym_dec.symbol().unwrap();
+                        stats.append_trade_volume(
+                            unsafe { from_utf8_unchecked(sym) },
+                            fields.size as u64,
+                        );
+                        done.unwrap()
+                    }
+                    QuoteMessageMessageHeader::TEMPLATE_ID => {
+                        let (_header, decoder) = start_decoding_quote_message(&data[msg_len..])
+                            .header()
+                            .unwrap();
+                        let (fields, sym_dec) = decoder.quote_message_fields().unwrap();
+                        let (sym, done) = sym_dec.symbol().unwrap();
+                        stats.update_quote_prices(
+                            unsafe { from_utf8_unchecked(sym) },
+                            fields.price,
+                            matches!(fields.side, Side::Buy),
+                        );
+                        done.unwrap()
+                    }
+                    SystemEventMessageMessageHeader::TEMPLATE_ID => {
+                        let (_header, decoder) =
+                            start_decoding_system_event_message(&data[msg_len..])
+                                .header()
+                                .unwrap();
+                        let (_fields, done) = decoder.system_event_message_fields().unwrap();
+                        done.unwrap()
+                    }
+                    SecurityDirectoryMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_security_directory_message,
+                        security_directory_message_fields
+                    ),
+                    TradingStatusMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_trading_status_message,
+                        trading_status_message_fields
+                    ),
+                    OperationalHaltStatusMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_operational_halt_status_message,
+                        operational_halt_status_message_fields
+                    ),
+                    ShortSalePriceTestMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_short_sale_price_test_message,
+                        short_sale_price_test_message_fields
+                    ),
+                    SecurityEventMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_security_event_message,
+                        security_event_message_fields
+                    ),
+                    OfficialPriceMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_official_price_message,
+                        official_price_message_fields
+                    ),
+                    TradeBreakMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_trade_break_message,
+                        trade_break_message_fields
+                    ),
+                    AuctionInformationMessageMessageHeader::TEMPLATE_ID => skip_message!(
+                        &data[msg_len..],
+                        start_decoding_auction_information_message,
+                        auction_information_message_fields
+                    ),
+                    _ => panic!("Unrecognized template ID"),
+                };
+            }
+
+            Ok(((), msg_len))
+        })
+    }
+    fn decode(
+        &self,
+        buf: &mut StreamBuf,
+        messages: &mut Vec<NormalizedMessage>,
+    ) -> Result<u64, ()> {
+        buf.read_frame(&SelfDelimiting, |data| {
+            let (_header, decoder) = start_decoding_payload_header(data)
+                .header()
+                .map_err(|_| ())?;
+            let (fields, decoder) = decoder.payload_header_fields().map_err(|_| ())?;
+            let seq_no = fields.sequence_number;
+            let message_count = fields.message_count;
+            let mut msg_len = decoder.unwrap();
+
+            for _ in 0..message_count {
+                let (header, _) =
+                    start_decoding_message_header(&data[msg_len..]).map_err(|_| ())?;
+                msg_len += match header.template_id {
+                    SystemEventMessageMessageHeader::TEMPLATE_ID => {
+                        let (_header, decoder) =
+                            start_decoding_system_event_message(&data[msg_len..])
+                                .header()
+                                .map_err(|_| ())?;
+                        let (fields, done) =
+                            decoder.system_event_message_fields().map_err(|_| ())?;
+                        messages.push(NormalizedMessage {
+                            timestamp: fields.timestamp,
+                            symbol: String::new(),
+                            body: NormalizedBody::SystemEvent {
+                                system_event: fields.system_event,
+                            },
+                        });
+                        done.unwrap()
+                    }
+                    SecurityDirectoryMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_security_directory_message,
+                        security_directory_message_fields,
+                        |f| NormalizedBody::SecurityDirectory {
+                            flags: f.flags,
+                            lot_size: f.lot_size,
+                            previous_closing: f.previous_closing,
+                            luld_tier: f.luld_tier,
+                        }
+                    ),
+                    TradingStatusMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_trading_status_message,
+                        trading_status_message_fields,
+                        |f| NormalizedBody::TradingStatus {
+                            trading_status: f.trading_status,
+                            reason: f.reason.code,
+                        }
+                    ),
+                    OperationalHaltStatusMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_operational_halt_status_message,
+                        operational_halt_status_message_fields,
+                        |f| NormalizedBody::OperationalHaltStatus {
+                            halt_status: f.halt_status,
+                        }
+                    ),
+                    ShortSalePriceTestMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_short_sale_price_test_message,
+                        short_sale_price_test_message_fields,
+                        |f| NormalizedBody::ShortSalePriceTest {
+                            sspt_status: f.sspt_status,
+                            detail: f.detail,
+                        }
+                    ),
+                    SecurityEventMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_security_event_message,
+                        security_event_message_fields,
+                        |f| NormalizedBody::SecurityEvent {
+                            security_event: f.security_event,
+                        }
+                    ),
+                    QuoteMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_quote_message,
+                        quote_message_fields,
+                        |f| NormalizedBody::PriceLevelUpdate {
+                            is_buy: matches!(f.side, Side::Buy),
+                            event_flags: f.flags,
+                            size: f.size,
+                            price: f.price,
+                        }
+                    ),
+                    TradeMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_trade_message,
+                        trade_message_fields,
+                        |f| NormalizedBody::TradeReport {
+                            sale_condition: f.sale_condition,
+                            size: f.size,
+                            price: f.price,
+                            trade_id: f.trade_id,
+                        }
+                    ),
+                    OfficialPriceMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_official_price_message,
+                        official_price_message_fields,
+                        |f| NormalizedBody::OfficialPrice {
+                            price_type: f.price_type,
+                            official_price: f.official_price,
+                        }
+                    ),
+                    TradeBreakMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_trade_break_message,
+                        trade_break_message_fields,
+                        |f| NormalizedBody::TradeBreak {
+                            sale_condition: f.sale_condition,
+                            size: f.size,
+                            price: f.price,
+                            trade_id: f.trade_id,
+                        }
+                    ),
+                    AuctionInformationMessageMessageHeader::TEMPLATE_ID => decode_message!(
+                        &data[msg_len..],
+                        messages,
+                        start_decoding_auction_information_message,
+                        auction_information_message_fields,
+                        |f| NormalizedBody::AuctionInformation {
+                            auction_type: f.auction_type,
+                            paired_shares: f.paired_shares,
+                            reference_price: f.reference_price,
+                            indicative_clearing_price: f.indicative_clearing_price,
+                            imbalance_shares: f.imbalance_shares,
+                            imbalance_side: f.imbalance_side,
+                            extension_number: f.extension_number,
+                            scheduled_auction: f.scheduled_auction,
+                            auction_book_clearing_price: f.auction_book_clearing_price,
+                            collar_reference_price: f.collar_reference_price,
+                            lower_auction_collar: f.lower_auction_collar,
+                            upper_auction_collar: f.upper_auction_collar,
+                        }
+                    ),
+                    _ => return Err(()),
+                };
+            }
+
+            Ok((seq_no, msg_len))
+        })
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::{Error, ErrorKind};
+
+/// Highest priority `SCHED_FIFO` accepts; the lowest is 1.
+pub const MAX_FIFO_PRIORITY: i32 = 99;
+
+/// How the process should be scheduled, set from the command line rather than
+/// wrapping the benchmark in `taskset`, `nice` and `chrt`.
+#[derive(Debug, Default)]
+pub struct Scheduling {
+    /// CPUs to pin to
+    pub cpus: Option<Vec<usize>>,
+    /// Niceness, from -20 (highest priority) to 19
+    pub nice: Option<i32>,
+    /// Real-time `SCHED_FIFO` priority, from 1 to 99
+    pub fifo_priority: Option<i32>,
+    /// Lock every page in memory, so timings don't include page faults
+    pub lock_memory: bool,
+}
+
+impl Scheduling {
+    /// Apply everything requested to the current process. Only the calling
+    /// thread gets pinned and prioritized, which is fine since that's the one
+    /// running the benchmarks.
+    pub fn apply(&self) -> Result<(), String> {
+        if let Some(cpus) = &self.cpus {
+            set_affinity(cpus).map_err(|e| format!("Unable to pin to cpus={:?}: {}", cpus, e))?;
+        }
+        if let Some(nice) = self.nice {
+            set_nice(nice).map_err(|e| format!("Unable to set nice={}: {}", nice, e))?;
+        }
+        if let Some(priority) = self.fifo_priority {
+            set_fifo(priority)
+                .map_err(|e| format!("Unable to set SCHED_FIFO priority={}: {}", priority, e))?;
+        }
+        if self.lock_memory {
+            lock_memory().map_err(|e| format!("Unable to lock memory: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Pin just the calling thread to one CPU.
+pub fn pin_thread(cpu: usize) -> Result<(), String> {
+    set_affinity(&[cpu]).map_err(|e| format!("Unable to pin thread to cpu={}: {}", cpu, e))
+}
+
+#[cfg(target_os = "linux")]
+fn check(ret: libc::c_int) -> Result<(), Error> {
+    if ret == -1 {
+        Err(Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn set_affinity(cpus: &[usize]) -> Result<(), Error> {
+    let max_cpus = libc::CPU_SETSIZE as usize;
+    if let Some(cpu) = cpus.iter().find(|&&cpu| cpu >= max_cpus) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("cpu={} is beyond the limit of {} CPUs", cpu, max_cpus),
+        ));
+    }
+
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        for &cpu in cpus {
+            libc::CPU_SET(cpu, &mut set);
+        }
+        check(libc::sched_setaffinity(
+            0,
+            std::mem::size_of::<libc::cpu_set_t>(),
+            &set,
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn set_nice(nice: i32) -> Result<(), Error> {
+    check(unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, nice) })
+}
+
+#[cfg(target_os = "linux")]
+fn set_fifo(priority: i32) -> Result<(), Error> {
+    let param = libc::sched_param {
+        sched_priority: priority,
+    };
+    check(unsafe { libc::sched_setscheduler(0, libc::SCHED_FIFO, &param) })
+}
+
+#[cfg(target_os = "linux")]
+fn lock_memory() -> Result<(), Error> {
+    check(unsafe { libc::mlockall(libc::MCL_CURRENT | libc::MCL_FUTURE) })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn unsupported() -> Result<(), Error> {
+    Err(Error::new(ErrorKind::Other, "only supported on Linux"))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_affinity(_cpus: &[usize]) -> Result<(), Error> {
+    unsupported()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_nice(_nice: i32) -> Result<(), Error> {
+    unsupported()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_fifo(_priority: i32) -> Result<(), Error> {
+    unsupported()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn lock_memory() -> Result<(), Error> {
+    unsupported()
+}
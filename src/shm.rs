@@ -0,0 +1,142 @@
+use std::fs::File;
+use std::io::{Error, ErrorKind};
+
+/// Which side of a `fork` we ended up on.
+pub enum Fork {
+    Child,
+    Parent(i32),
+}
+
+/// Map `len` bytes of zeroed memory backed by a memfd, which stays shared with
+/// any processes forked afterwards.
+pub fn map_shared(name: &str, len: usize) -> Result<*mut u8, String> {
+    map(name, len).map_err(|e| format!("Unable to map {} bytes of shared memory: {}", len, e))
+}
+
+/// Fork the current process; the child gets a copy of everything, except that
+/// memory from `map_shared` is still shared.
+pub fn fork() -> Result<Fork, String> {
+    fork_process().map_err(|e| format!("Unable to fork: {}", e))
+}
+
+/// A pipe for sending results between processes, as `(read, write)`.
+pub fn pipe() -> Result<(File, File), String> {
+    open_pipe().map_err(|e| format!("Unable to create pipe: {}", e))
+}
+
+/// Wait for a forked process, and check it exited successfully.
+pub fn wait(pid: i32) -> Result<(), String> {
+    match wait_pid(pid) {
+        Ok(0) => Ok(()),
+        Ok(status) => Err(format!("Process {} failed with status={}", pid, status)),
+        Err(e) => Err(format!("Unable to wait for process {}: {}", pid, e)),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn check(ret: libc::c_int) -> Result<libc::c_int, Error> {
+    if ret == -1 {
+        Err(Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn map(name: &str, len: usize) -> Result<*mut u8, Error> {
+    use std::ffi::CString;
+    use std::os::unix::io::FromRawFd;
+
+    let name = CString::new(name).map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+    unsafe {
+        let fd = check(libc::memfd_create(name.as_ptr(), libc::MFD_CLOEXEC))?;
+        // Closed on the way out; the mapping keeps the memory around without it
+        let _file = File::from_raw_fd(fd);
+        check(libc::ftruncate(fd, len as libc::off_t))?;
+        let addr = libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_SHARED,
+            fd,
+            0,
+        );
+        if addr == libc::MAP_FAILED {
+            return Err(Error::last_os_error());
+        }
+        Ok(addr as *mut u8)
+    }
+}
+
+/// Safety: `addr` and `len` have to come from `map_shared`, and nothing can use
+/// the memory afterwards.
+#[cfg(target_os = "linux")]
+pub unsafe fn unmap(addr: *mut u8, len: usize) {
+    libc::munmap(addr as *mut libc::c_void, len);
+}
+
+#[cfg(target_os = "linux")]
+fn fork_process() -> Result<Fork, Error> {
+    match check(unsafe { libc::fork() })? {
+        0 => Ok(Fork::Child),
+        pid => Ok(Fork::Parent(pid)),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn open_pipe() -> Result<(File, File), Error> {
+    use std::os::unix::io::FromRawFd;
+
+    let mut fds = [0; 2];
+    unsafe {
+        check(libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC))?;
+        Ok((File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn wait_pid(pid: i32) -> Result<i32, Error> {
+    let mut status = 0;
+    check(unsafe { libc::waitpid(pid, &mut status, 0) })?;
+    Ok(status)
+}
+
+/// Leave a forked process straight away, without running anything the parent
+/// would run on exit, like flushing its buffered output a second time.
+#[cfg(target_os = "linux")]
+pub fn exit(code: i32) -> ! {
+    unsafe { libc::_exit(code) }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn unsupported() -> Error {
+    Error::new(ErrorKind::Other, "only supported on Linux")
+}
+
+#[cfg(not(target_os = "linux"))]
+fn map(_name: &str, _len: usize) -> Result<*mut u8, Error> {
+    Err(unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub unsafe fn unmap(_addr: *mut u8, _len: usize) {}
+
+#[cfg(not(target_os = "linux"))]
+fn fork_process() -> Result<Fork, Error> {
+    Err(unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_pipe() -> Result<(File, File), Error> {
+    Err(unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn wait_pid(_pid: i32) -> Result<i32, Error> {
+    Err(unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn exit(code: i32) -> ! {
+    std::process::exit(code)
+}
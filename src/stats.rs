@@ -0,0 +1,78 @@
+use std::cmp::max;
+
+/// Summary of a measurement repeated across iterations.
+#[derive(Debug)]
+pub struct Estimate {
+    pub mean: f64,
+    /// Sample standard deviation; zero when there's only one sample.
+    pub stddev: f64,
+    /// Bounds of the 95% confidence interval for the mean.
+    pub ci_low: f64,
+    pub ci_high: f64,
+}
+
+impl Estimate {
+    pub fn new(samples: &[f64]) -> Estimate {
+        assert!(!samples.is_empty(), "Can't estimate from zero samples");
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let stddev = if samples.len() > 1 {
+            let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        // Iteration counts are usually small, so use Student's t rather than the normal distribution
+        let margin = t_critical_95(samples.len() - 1) * stddev / n.sqrt();
+
+        Estimate {
+            mean,
+            stddev,
+            ci_low: mean - margin,
+            ci_high: mean + margin,
+        }
+    }
+}
+
+/// Whether two sets of samples have different means at the 95% level, using
+/// Welch's t-test since runs on different machines won't share a variance.
+/// `None` if either side has too few samples to tell.
+pub fn significantly_different(a: &[f64], b: &[f64]) -> Option<bool> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+
+    let (a_n, b_n) = (a.len() as f64, b.len() as f64);
+    let (a, b) = (Estimate::new(a), Estimate::new(b));
+    // Variance of each mean
+    let a_var = a.stddev.powi(2) / a_n;
+    let b_var = b.stddev.powi(2) / b_n;
+    if a_var + b_var == 0.0 {
+        // Perfectly repeatable on both sides, so any change at all is real
+        return Some(a.mean != b.mean);
+    }
+
+    let t = (a.mean - b.mean).abs() / (a_var + b_var).sqrt();
+    // Welch-Satterthwaite
+    let df = (a_var + b_var).powi(2) / (a_var.powi(2) / (a_n - 1.0) + b_var.powi(2) / (b_n - 1.0));
+    Some(t > t_critical_95(max(df.floor() as usize, 1)))
+}
+
+/// Two-sided 95% critical value of Student's t distribution for `df` degrees of freedom.
+fn t_critical_95(df: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+
+    match df {
+        // With a single sample there's no spread to build an interval from
+        0 => 0.0,
+        1..=30 => TABLE[df - 1],
+        // Close enough to the normal distribution from here on out
+        _ => 1.960,
+    }
+}
@@ -0,0 +1,174 @@
+use std::io::{self, ErrorKind};
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use crate::pipeline::{Header, Publisher, Subscriber};
+
+/// Largest payload a UDP datagram can carry over IPv4.
+pub const MAX_DATAGRAM: usize = 65_507;
+
+/// Every datagram starts with how many messages it holds (u16), six bytes of
+/// padding, and the clock reading taken just before serializing it (u64), so
+/// packets start as aligned as they do in the ring. An empty datagram means the
+/// producer is done.
+pub const HEADER_LEN: usize = 16;
+
+/// How long the subscriber waits without hearing anything before giving up on the publisher.
+const RECV_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the publisher repeats that it's done, in case that got dropped too.
+const FINISH_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A pair of sockets on localhost, one to publish packets from and one to subscribe to them.
+pub struct Udp {
+    publisher: UdpSocket,
+    subscriber: UdpSocket,
+    /// Set once the subscriber stops listening, whether it finished or gave up
+    stopped: AtomicBool,
+}
+
+impl Udp {
+    pub fn new() -> Result<Udp, String> {
+        Udp::bind().map_err(|e| format!("Unable to open UDP sockets on localhost: {}", e))
+    }
+
+    fn bind() -> io::Result<Udp> {
+        let publisher = UdpSocket::bind("127.0.0.1:0")?;
+        let subscriber = UdpSocket::bind("127.0.0.1:0")?;
+        // Each only hears from the other, so nothing else on the port gets mixed in
+        publisher.connect(subscriber.local_addr()?)?;
+        subscriber.connect(publisher.local_addr()?)?;
+        subscriber.set_read_timeout(Some(RECV_TIMEOUT))?;
+        Ok(Udp {
+            publisher,
+            subscriber,
+            stopped: AtomicBool::new(false),
+        })
+    }
+
+    pub fn publisher(&self, max_datagram: usize) -> UdpPublisher<'_> {
+        UdpPublisher {
+            udp: self,
+            max_datagram,
+            datagram: Vec::with_capacity(max_datagram),
+        }
+    }
+
+    pub fn subscriber(&self) -> UdpSubscriber<'_> {
+        UdpSubscriber {
+            udp: self,
+            // Big enough for any datagram, so none get truncated
+            datagram: vec![0; MAX_DATAGRAM],
+        }
+    }
+}
+
+/// Sends each packet as a single datagram, without any flow control; if the
+/// subscriber falls behind, the kernel drops whatever doesn't fit in its buffer.
+pub struct UdpPublisher<'a> {
+    udp: &'a Udp,
+    max_datagram: usize,
+    datagram: Vec<u8>,
+}
+
+impl Publisher for UdpPublisher<'_> {
+    fn send(&mut self, msg_count: u16, sent: u64, packet: &[u8]) -> bool {
+        if HEADER_LEN + packet.len() > self.max_datagram {
+            return false;
+        }
+        if self.udp.stopped.load(Ordering::Relaxed) {
+            panic!("Consumer stopped before reading everything");
+        }
+
+        self.datagram.clear();
+        self.datagram.extend_from_slice(&msg_count.to_le_bytes());
+        self.datagram.extend_from_slice(&[0; 6]);
+        self.datagram.extend_from_slice(&sent.to_le_bytes());
+        self.datagram.extend_from_slice(packet);
+        self.udp
+            .publisher
+            .send(&self.datagram)
+            .expect("Unable to send datagram");
+        true
+    }
+
+    /// Keep saying we're done until the subscriber hears it.
+    fn finish(&mut self) {
+        while !self.udp.stopped.load(Ordering::Acquire) {
+            self.udp
+                .publisher
+                .send(&[])
+                .expect("Unable to send datagram");
+            thread::sleep(FINISH_INTERVAL);
+        }
+    }
+
+    /// The socket never waits on the subscriber; it drops packets instead.
+    fn full_waits(&self) -> u64 {
+        0
+    }
+}
+
+/// Receives datagrams one at a time, and hands out packets straight from where they landed.
+pub struct UdpSubscriber<'a> {
+    udp: &'a Udp,
+    datagram: Vec<u8>,
+}
+
+impl Subscriber for UdpSubscriber<'_> {
+    fn recv(&mut self) -> Option<Header> {
+        let len = loop {
+            match self.udp.subscriber.recv(&mut self.datagram) {
+                Ok(len) => break len,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(ref e)
+                    if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut =>
+                {
+                    panic!("Nothing heard from the producer for {:?}", RECV_TIMEOUT)
+                }
+                Err(e) => panic!("Unable to receive datagram: {}", e),
+            }
+        };
+        if len == 0 {
+            self.udp.stopped.store(true, Ordering::Release);
+            return None;
+        }
+        assert!(
+            len >= HEADER_LEN,
+            "Datagram of {} bytes is too short for its header",
+            len
+        );
+
+        let mut msg_count = [0u8; 2];
+        let mut sent = [0u8; 8];
+        msg_count.copy_from_slice(&self.datagram[..2]);
+        sent.copy_from_slice(&self.datagram[8..HEADER_LEN]);
+        Some(Header {
+            len: (len - HEADER_LEN) as u32,
+            msg_count: u16::from_le_bytes(msg_count),
+            sent: u64::from_le_bytes(sent),
+        })
+    }
+
+    fn packet(&self, header: &Header) -> &[u8] {
+        &self.datagram[HEADER_LEN..HEADER_LEN + header.len as usize]
+    }
+
+    /// The next datagram just lands on top of this one.
+    fn release(&mut self, _header: &Header) {}
+
+    fn lossy(&self) -> bool {
+        true
+    }
+}
+
+/// Lets the publisher know if the subscriber panics, so it stops sending.
+impl Drop for UdpSubscriber<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.udp.stopped.store(true, Ordering::Release);
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::framing::StreamBuf;
+use crate::iex::{IexMessage, IexParser};
+use crate::RunnerDeserialize;
+
+/// Format-independent view of a single message, holding everything the schemas
+/// can store. Runners decode into this so that any format can be checked against
+/// the IEX message it was built from.
+#[derive(Debug, PartialEq)]
+pub struct NormalizedMessage {
+    pub timestamp: i64,
+    /// Padding is trimmed, and system events (which aren't tied to a symbol) use `""`.
+    pub symbol: String,
+    pub body: NormalizedBody,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NormalizedBody {
+    SystemEvent {
+        system_event: u8,
+    },
+    SecurityDirectory {
+        flags: u8,
+        lot_size: u32,
+        previous_closing: u64,
+        luld_tier: u8,
+    },
+    TradingStatus {
+        trading_status: u8,
+        reason: [u8; 4],
+    },
+    OperationalHaltStatus {
+        halt_status: u8,
+    },
+    ShortSalePriceTest {
+        sspt_status: u8,
+        detail: u8,
+    },
+    SecurityEvent {
+        security_event: u8,
+    },
+    PriceLevelUpdate {
+        is_buy: bool,
+        event_flags: u8,
+        size: u32,
+        price: u64,
+    },
+    TradeReport {
+        sale_condition: u8,
+        size: u32,
+        price: u64,
+        trade_id: u64,
+    },
+    OfficialPrice {
+        price_type: u8,
+        official_price: u64,
+    },
+    TradeBreak {
+        sale_condition: u8,
+        size: u32,
+        price: u64,
+        trade_id: u64,
+    },
+    AuctionInformation {
+        auction_type: u8,
+        paired_shares: u32,
+        reference_price: u64,
+        indicative_clearing_price: u64,
+        imbalance_shares: u32,
+        imbalance_side: u8,
+        extension_number: u8,
+        scheduled_auction: u32,
+        auction_book_clearing_price: u64,
+        collar_reference_price: u64,
+        lower_auction_collar: u64,
+        upper_auction_collar: u64,
+    },
+}
+
+impl NormalizedBody {
+    /// Sort key for formats that group messages by type rather than keeping them in order.
+    fn kind(&self) -> u8 {
+        match self {
+            NormalizedBody::SystemEvent { .. } => 0,
+            NormalizedBody::SecurityDirectory { .. } => 1,
+            NormalizedBody::TradingStatus { .. } => 2,
+            NormalizedBody::OperationalHaltStatus { .. } => 3,
+            NormalizedBody::ShortSalePriceTest { .. } => 4,
+            NormalizedBody::SecurityEvent { .. } => 5,
+            NormalizedBody::PriceLevelUpdate { .. } => 6,
+            NormalizedBody::TradeReport { .. } => 7,
+            NormalizedBody::OfficialPrice { .. } => 8,
+            NormalizedBody::TradeBreak { .. } => 9,
+            NormalizedBody::AuctionInformation { .. } => 10,
+        }
+    }
+}
+
+impl From<&IexMessage> for NormalizedMessage {
+    fn from(msg: &IexMessage) -> NormalizedMessage {
+        let (timestamp, symbol, body) = match msg {
+            IexMessage::SystemEvent(se) => (
+                se.timestamp,
+                "",
+                NormalizedBody::SystemEvent {
+                    system_event: se.system_event,
+                },
+            ),
+            IexMessage::SecurityDirectory(sd) => (
+                sd.timestamp,
+                crate::parse_symbol(&sd.symbol),
+                NormalizedBody::SecurityDirectory {
+                    flags: sd.flags,
+                    lot_size: sd.lot_size,
+                    previous_closing: sd.previous_closing,
+                    luld_tier: sd.luld_tier,
+                },
+            ),
+            IexMessage::TradingStatus(ts) => (
+                ts.timestamp,
+                crate::parse_symbol(&ts.symbol),
+                NormalizedBody::TradingStatus {
+                    trading_status: ts.trading_status,
+                    reason: ts.reason,
+                },
+            ),
+            IexMessage::OperationalHaltStatus(ohs) => (
+                ohs.timestamp,
+                crate::parse_symbol(&ohs.symbol),
+                NormalizedBody::OperationalHaltStatus {
+                    halt_status: ohs.halt_status,
+                },
+            ),
+            IexMessage::ShortSalePriceTest(sspt) => (
+                sspt.timestamp,
+                crate::parse_symbol(&sspt.symbol),
+                NormalizedBody::ShortSalePriceTest {
+                    sspt_status: sspt.sspt_status,
+                    detail: sspt.detail,
+                },
+            ),
+            IexMessage::SecurityEvent(se) => (
+                se.timestamp,
+                crate::parse_symbol(&se.symbol),
+                NormalizedBody::SecurityEvent {
+                    security_event: se.security_event,
+                },
+            ),
+            IexMessage::PriceLevelUpdate(plu) => (
+                plu.timestamp,
+                crate::parse_symbol(&plu.symbol),
+                NormalizedBody::PriceLevelUpdate {
+                    is_buy: plu.msg_type == 0x38,
+                    event_flags: plu.event_flags,
+                    size: plu.size,
+                    price: plu.price,
+                },
+            ),
+            IexMessage::TradeReport(tr) => (
+                tr.timestamp,
+                crate::parse_symbol(&tr.symbol),
+                NormalizedBody::TradeReport {
+                    sale_condition: tr.sale_condition,
+                    size: tr.size,
+                    price: tr.price,
+                    trade_id: tr.trade_id,
+                },
+            ),
+            IexMessage::OfficialPrice(op) => (
+                op.timestamp,
+                crate::parse_symbol(&op.symbol),
+                NormalizedBody::OfficialPrice {
+                    price_type: op.price_type,
+                    official_price: op.official_price,
+                },
+            ),
+            IexMessage::TradeBreak(tb) => (
+                tb.timestamp,
+                crate::parse_symbol(&tb.symbol),
+                NormalizedBody::TradeBreak {
+                    sale_condition: tb.sale_condition,
+                    size: tb.size,
+                    price: tb.price,
+                    trade_id: tb.trade_id,
+                },
+            ),
+            IexMessage::AuctionInformation(ai) => (
+                ai.timestamp,
+                crate::parse_symbol(&ai.symbol),
+                NormalizedBody::AuctionInformation {
+                    auction_type: ai.auction_type,
+                    paired_shares: ai.paired_shares,
+                    reference_price: ai.reference_price,
+                    indicative_clearing_price: ai.indicative_clearing_price,
+                    imbalance_shares: ai.imbalance_shares,
+                    imbalance_side: ai.imbalance_side,
+                    extension_number: ai.extension_number,
+                    scheduled_auction: ai.scheduled_auction,
+                    auction_book_clearing_price: ai.auction_book_clearing_price,
+                    collar_reference_price: ai.collar_reference_price,
+                    lower_auction_collar: ai.lower_auction_collar,
+                    upper_auction_collar: ai.upper_auction_collar,
+                },
+            ),
+        };
+
+        NormalizedMessage {
+            timestamp,
+            symbol: symbol.to_string(),
+            body,
+        }
+    }
+}
+
+/// Decode everything in `buf` and compare it field-by-field against the IEX messages
+/// in `iex_data`, panicking with the sequence number of the first message that
+/// doesn't match.
+pub fn verify_round_trip<D>(iex_data: &[u8], buf: &mut StreamBuf, deserializer: &D)
+where
+    D: RunnerDeserialize,
+{
+    // Payloads without any messages (heartbeats) aren't written by every format,
+    // so they're skipped on both sides.
+    let mut payloads = IexParser::new(iex_data).filter(|p| !p.messages.is_empty());
+    let mut decoded = Vec::new();
+
+    while let Ok(seq_no) = deserializer.decode(buf, &mut decoded) {
+        if decoded.is_empty() {
+            continue;
+        }
+
+        let payload = payloads
+            .next()
+            .unwrap_or_else(|| panic!("Decoded seq_no={} past the end of the IEX data", seq_no));
+        assert_eq!(
+            payload.first_seq_no, seq_no,
+            "Sequence number mismatch for payload starting at seq_no={}",
+            payload.first_seq_no
+        );
+        assert_eq!(
+            payload.messages.len(),
+            decoded.len(),
+            "Message count mismatch for payload starting at seq_no={}",
+            seq_no
+        );
+
+        let mut expected: Vec<(u64, NormalizedMessage)> = payload
+            .messages
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (seq_no + i as u64, NormalizedMessage::from(m)))
+            .collect();
+        if !deserializer.preserves_order() {
+            // Sorts are stable, so messages of the same type stay in the order
+            // they arrived in, which is what the format keeps.
+            expected.sort_by_key(|(_, m)| m.body.kind());
+            decoded.sort_by_key(|m| m.body.kind());
+        }
+
+        for ((msg_seq_no, expected), actual) in expected.iter().zip(decoded.iter()) {
+            if expected != actual {
+                panic!(
+                    "Round trip mismatch at seq_no={}:\n  expected={:?}\n  decoded={:?}",
+                    msg_seq_no, expected, actual
+                );
+            }
+        }
+
+        decoded.clear();
+    }
+
+    if let Some(payload) = payloads.next() {
+        panic!(
+            "Payload starting at seq_no={} was never decoded",
+            payload.first_seq_no
+        );
+    }
+}